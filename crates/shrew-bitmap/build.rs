@@ -1,6 +1,7 @@
 fn main() -> std::io::Result<()> {
     prost_build::Config::new()
         .type_attribute(".", "#[derive(serde::Serialize, serde::Deserialize)]")
-        .compile_protos(&["proto/bitmap.proto"], &["proto/"])?;
+        .extern_path(".shrew_support", "::shrew_support::pagination")
+        .compile_protos(&["proto/bitmap.proto"], &["proto/", "../shrew-support/proto/"])?;
     Ok(())
 }
@@ -1,11 +1,10 @@
 use crate::tables::*;
-use shrew_support::inscription::InscriptionEntry;
+use shrew_ord::entries;
+use shrew_support::diagnostics::{self, BlockDiagnostics};
 use shrew_ord::tables::{
-    SEQUENCE_TO_INSCRIPTION_ENTRY, INSCRIPTION_CONTENT, GLOBAL_SEQUENCE_COUNTER,
+    InscriptionContentTable, GLOBAL_SEQUENCE_COUNTER,
 };
 use bitcoin::Block;
-use metashrew_support::index_pointer::KeyValuePointer;
-use std::sync::Arc;
 
 pub struct BitmapIndexer;
 
@@ -18,20 +17,13 @@ impl BitmapIndexer {
         // A bitmap inscription has text/plain content matching ^[0-9]+\.bitmap$
         // The number must be <= block_height, no leading zeros, and first-wins.
 
-        let seq_bytes = GLOBAL_SEQUENCE_COUNTER.get();
-        if seq_bytes.is_empty() { return; }
-        let max_seq = u32::from_le_bytes(seq_bytes[..4].try_into().unwrap_or([0; 4]));
+        let max_seq = GLOBAL_SEQUENCE_COUNTER.get().unwrap_or(0);
 
         // We scan the last batch of inscriptions (those created at this height)
         // In practice, we'd track the starting sequence for this block.
         // For now, scan all sequences and check height.
         for seq in 1..=max_seq {
-            let entry_bytes = SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq.to_le_bytes().to_vec()).get();
-            if entry_bytes.is_empty() { continue; }
-            let entry = match InscriptionEntry::from_bytes(&entry_bytes) {
-                Ok(e) => e,
-                Err(_) => continue,
-            };
+            let Some(entry) = entries::load_or_report(seq, height) else { continue };
 
             // Only process inscriptions at this height
             if entry.height != height { continue; }
@@ -46,8 +38,7 @@ impl BitmapIndexer {
             }
 
             // Get content
-            let inscription_id_str = entry.id.to_string();
-            let content_bytes = INSCRIPTION_CONTENT.select(&inscription_id_str.as_bytes().to_vec()).get();
+            let content_bytes = InscriptionContentTable::new().get(&entry.id).unwrap_or_default();
             if content_bytes.is_empty() { continue; }
 
             let content = match std::str::from_utf8(&content_bytes) {
@@ -72,14 +63,13 @@ impl BitmapIndexer {
             if bitmap_number > height as u64 { continue; }
 
             // First-wins: check if already registered
-            let existing = BITMAP_NUMBER_TO_ID.select(&bitmap_number.to_le_bytes().to_vec()).get();
-            if !existing.is_empty() { continue; }
+            if BITMAP_NUMBER_TO_ID.contains(&bitmap_number) { continue; }
 
             // Register
-            let id_bytes = entry.id.to_bytes();
-            BITMAP_NUMBER_TO_ID.select(&bitmap_number.to_le_bytes().to_vec()).set(Arc::new(id_bytes.clone()));
-            BITMAP_ID_TO_NUMBER.select(&id_bytes).set(Arc::new(bitmap_number.to_le_bytes().to_vec()));
-            BITMAP_HEIGHT_TO_ENTRIES.select(&height.to_le_bytes().to_vec()).append(Arc::new(id_bytes));
+            BITMAP_NUMBER_TO_ID.set(&bitmap_number, &entry.id);
+            BITMAP_ID_TO_NUMBER.set(&entry.id, &bitmap_number);
+            BITMAP_HEIGHT_TO_ENTRIES.append(&height, &entry.id);
         }
+        diagnostics::add(height, "bitmap", BlockDiagnostics::default());
     }
 }
@@ -0,0 +1,18 @@
+//! Errors of the bitmap views.
+
+use shrew_support::error::{CodedError, ErrorCode};
+use shrew_support::pagination::PaginationError;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum BitmapError {
+    #[error(transparent)]
+    Pagination(#[from] PaginationError),
+}
+
+impl CodedError for BitmapError {
+    fn code(&self) -> ErrorCode {
+        match self {
+            BitmapError::Pagination(_) => ErrorCode::InvalidArgument,
+        }
+    }
+}
@@ -1,8 +1,9 @@
 #[cfg(feature = "entrypoint")]
-use bitcoin::{Block, consensus::deserialize};
-use serde_json::{from_slice, to_vec};
+use shrew_support::diagnostics;
+use shrew_support::wire::serve;
 
 pub mod bitmap_indexer;
+pub mod error;
 pub mod tables;
 pub mod view;
 
@@ -15,10 +16,10 @@ pub use bitmap_indexer::BitmapIndexer;
 #[cfg(feature = "entrypoint")]
 #[metashrew_core::main]
 fn main_logic(height: u32, block_data: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
-    if let Ok(block) = deserialize::<Block>(block_data) {
-        let mut inscription_indexer = shrew_ord::indexer::InscriptionIndexer::new();
-        let _ = inscription_indexer.load_state();
-        let _ = inscription_indexer.index_block(&block, height);
+    if let Some(block) = diagnostics::decode_block(height, block_data) {
+        if !shrew_ord::indexer::InscriptionIndexer::index_or_report(&block, height) {
+            return Ok(());
+        }
 
         let indexer = BitmapIndexer::new();
         indexer.index_block(&block, height);
@@ -28,14 +29,12 @@ fn main_logic(height: u32, block_data: &[u8]) -> Result<(), Box<dyn std::error::
 
 #[metashrew_core::view]
 pub fn getbitmap(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetBitmapRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_bitmap(&req)?)?)
+    serve(input, view::get_bitmap)
 }
 
 #[metashrew_core::view]
 pub fn getbitmapsbyheight(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetBitmapsByHeightRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_bitmaps_by_height(&req)?)?)
+    serve(input, view::get_bitmaps_by_height)
 }
 
 #[cfg(test)]
@@ -1,11 +1,11 @@
-use metashrew_core::index_pointer::IndexPointer;
-use metashrew_support::index_pointer::KeyValuePointer;
+use shrew_support::inscription::InscriptionId;
+use shrew_support::table::{ListTable, Table};
 
 lazy_static::lazy_static! {
-    /// Bitmap number -> InscriptionId bytes (first valid inscription wins)
-    pub static ref BITMAP_NUMBER_TO_ID: IndexPointer = IndexPointer::from_keyword("/bitmap/number_to_id/");
+    /// Bitmap number -> InscriptionId (first valid inscription wins)
+    pub static ref BITMAP_NUMBER_TO_ID: Table<u64, InscriptionId> = Table::new("/bitmap/number_to_id/");
     /// InscriptionId -> bitmap number
-    pub static ref BITMAP_ID_TO_NUMBER: IndexPointer = IndexPointer::from_keyword("/bitmap/id_to_number/");
+    pub static ref BITMAP_ID_TO_NUMBER: Table<InscriptionId, u64> = Table::new("/bitmap/id_to_number/");
     /// Height -> list of bitmap entries inscribed at that height
-    pub static ref BITMAP_HEIGHT_TO_ENTRIES: IndexPointer = IndexPointer::from_keyword("/bitmap/height_to_entries/");
+    pub static ref BITMAP_HEIGHT_TO_ENTRIES: ListTable<u32, InscriptionId> = ListTable::new("/bitmap/height_to_entries/");
 }
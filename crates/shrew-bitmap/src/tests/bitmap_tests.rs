@@ -5,8 +5,6 @@ use shrew_test_helpers::indexing::{index_ord_block, index_bitmap_block};
 use shrew_test_helpers::bitmap::{create_bitmap_inscription_block, create_invalid_bitmap_block};
 use shrew_test_helpers::blocks::{create_coinbase_transaction, create_block_with_txs};
 use shrew_test_helpers::transactions::create_inscription_transaction;
-use shrew_support::inscription::InscriptionId;
-use metashrew_support::index_pointer::KeyValuePointer;
 #[allow(unused_imports)]
 use bitcoin_hashes::Hash;
 use std::str::FromStr;
@@ -23,10 +21,10 @@ fn test_valid_bitmap_inscription() {
     index_ord_block(&block, height).unwrap();
     index_bitmap_block(&block, height);
 
-    let data = BITMAP_NUMBER_TO_ID.select(&100u64.to_le_bytes().to_vec()).get();
-    assert!(!data.is_empty(), "Bitmap 100 should be registered at height 100");
+    let data = BITMAP_NUMBER_TO_ID.get(&100);
+    assert!(data.is_some(), "Bitmap 100 should be registered at height 100");
 
-    let stored_id = InscriptionId::from_bytes(&data).expect("Valid inscription id");
+    let stored_id = data.unwrap();
     assert_eq!(stored_id.txid, tx.txid(), "Stored inscription should match the tx");
 }
 
@@ -38,8 +36,8 @@ fn test_bitmap_at_exact_height() {
     index_ord_block(&block, height).unwrap();
     index_bitmap_block(&block, height);
 
-    let data = BITMAP_NUMBER_TO_ID.select(&500u64.to_le_bytes().to_vec()).get();
-    assert!(!data.is_empty(), "Bitmap 500 should be registered at exact height 500");
+    let data = BITMAP_NUMBER_TO_ID.get(&500);
+    assert!(data.is_some(), "Bitmap 500 should be registered at exact height 500");
 }
 
 #[test]
@@ -50,8 +48,8 @@ fn test_bitmap_future_height_rejected() {
     index_ord_block(&block, height).unwrap();
     index_bitmap_block(&block, height);
 
-    let data = BITMAP_NUMBER_TO_ID.select(&1000u64.to_le_bytes().to_vec()).get();
-    assert!(data.is_empty(), "Bitmap 1000 should be rejected at height 500 (future height)");
+    let data = BITMAP_NUMBER_TO_ID.get(&1000);
+    assert!(data.is_none(), "Bitmap 1000 should be rejected at height 500 (future height)");
 }
 
 #[test]
@@ -62,8 +60,8 @@ fn test_bitmap_zero() {
     index_ord_block(&block, height).unwrap();
     index_bitmap_block(&block, height);
 
-    let data = BITMAP_NUMBER_TO_ID.select(&0u64.to_le_bytes().to_vec()).get();
-    assert!(!data.is_empty(), "Bitmap 0 should be valid");
+    let data = BITMAP_NUMBER_TO_ID.get(&0);
+    assert!(data.is_some(), "Bitmap 0 should be valid");
 }
 
 #[test]
@@ -74,8 +72,8 @@ fn test_bitmap_leading_zeros_rejected() {
     index_ord_block(&block, height).unwrap();
     index_bitmap_block(&block, height);
 
-    let data = BITMAP_NUMBER_TO_ID.select(&7u64.to_le_bytes().to_vec()).get();
-    assert!(data.is_empty(), "Bitmap '007.bitmap' should be rejected due to leading zeros");
+    let data = BITMAP_NUMBER_TO_ID.get(&7);
+    assert!(data.is_none(), "Bitmap '007.bitmap' should be rejected due to leading zeros");
 }
 
 #[test]
@@ -88,7 +86,7 @@ fn test_bitmap_non_numeric_rejected() {
 
     // abc is non-numeric, so nothing should be registered
     // Check that no bitmap entries were stored at this height
-    let entries = BITMAP_HEIGHT_TO_ENTRIES.select(&height.to_le_bytes().to_vec()).get();
+    let entries = BITMAP_HEIGHT_TO_ENTRIES.get_list(&height);
     assert!(entries.is_empty(), "'abc.bitmap' should be rejected as non-numeric");
 }
 
@@ -100,8 +98,8 @@ fn test_bitmap_missing_suffix_rejected() {
     index_ord_block(&block, height).unwrap();
     index_bitmap_block(&block, height);
 
-    let data = BITMAP_NUMBER_TO_ID.select(&100u64.to_le_bytes().to_vec()).get();
-    assert!(data.is_empty(), "'100' without .bitmap suffix should be rejected");
+    let data = BITMAP_NUMBER_TO_ID.get(&100);
+    assert!(data.is_none(), "'100' without .bitmap suffix should be rejected");
 }
 
 #[test]
@@ -112,8 +110,8 @@ fn test_bitmap_wrong_suffix_rejected() {
     index_ord_block(&block, height).unwrap();
     index_bitmap_block(&block, height);
 
-    let data = BITMAP_NUMBER_TO_ID.select(&100u64.to_le_bytes().to_vec()).get();
-    assert!(data.is_empty(), "'100.ord' should be rejected (wrong suffix)");
+    let data = BITMAP_NUMBER_TO_ID.get(&100);
+    assert!(data.is_none(), "'100.ord' should be rejected (wrong suffix)");
 }
 
 #[test]
@@ -126,9 +124,9 @@ fn test_bitmap_first_wins() {
     index_ord_block(&block1, height).unwrap();
     index_bitmap_block(&block1, height);
 
-    let data1 = BITMAP_NUMBER_TO_ID.select(&50u64.to_le_bytes().to_vec()).get();
-    assert!(!data1.is_empty(), "First bitmap 50 should be registered");
-    let first_id = InscriptionId::from_bytes(&data1).unwrap();
+    let data1 = BITMAP_NUMBER_TO_ID.get(&50);
+    assert!(data1.is_some(), "First bitmap 50 should be registered");
+    let first_id = data1.unwrap();
 
     // Second inscription for same bitmap 50 at a later height (different outpoint for unique txid)
     let height2 = 101u32;
@@ -141,9 +139,7 @@ fn test_bitmap_first_wins() {
     index_ord_block(&block2, height2).unwrap();
     index_bitmap_block(&block2, height2);
 
-    let data2 = BITMAP_NUMBER_TO_ID.select(&50u64.to_le_bytes().to_vec()).get();
-    let stored_id = InscriptionId::from_bytes(&data2).unwrap();
-    assert_eq!(stored_id, first_id, "First inscription should win for bitmap 50");
+    assert_eq!(BITMAP_NUMBER_TO_ID.get(&50), Some(first_id), "First inscription should win for bitmap 50");
 }
 
 #[test]
@@ -170,10 +166,10 @@ fn test_bitmap_different_numbers() {
     index_ord_block(&block, height).unwrap();
     index_bitmap_block(&block, height);
 
-    let data100 = BITMAP_NUMBER_TO_ID.select(&100u64.to_le_bytes().to_vec()).get();
-    let data150 = BITMAP_NUMBER_TO_ID.select(&150u64.to_le_bytes().to_vec()).get();
-    assert!(!data100.is_empty(), "Bitmap 100 should be registered");
-    assert!(!data150.is_empty(), "Bitmap 150 should be registered");
+    let data100 = BITMAP_NUMBER_TO_ID.get(&100);
+    let data150 = BITMAP_NUMBER_TO_ID.get(&150);
+    assert!(data100.is_some(), "Bitmap 100 should be registered");
+    assert!(data150.is_some(), "Bitmap 150 should be registered");
 }
 
 #[test]
@@ -185,7 +181,7 @@ fn test_bitmap_decimal_rejected() {
     index_bitmap_block(&block, height);
 
     // "1.5.bitmap" is not a valid integer prefix
-    let entries = BITMAP_HEIGHT_TO_ENTRIES.select(&height.to_le_bytes().to_vec()).get();
+    let entries = BITMAP_HEIGHT_TO_ENTRIES.get_list(&height);
     assert!(entries.is_empty(), "'1.5.bitmap' should be rejected (decimal number)");
 }
 
@@ -197,7 +193,7 @@ fn test_bitmap_empty_content_rejected() {
     index_ord_block(&block, height).unwrap();
     index_bitmap_block(&block, height);
 
-    let entries = BITMAP_HEIGHT_TO_ENTRIES.select(&height.to_le_bytes().to_vec()).get();
+    let entries = BITMAP_HEIGHT_TO_ENTRIES.get_list(&height);
     assert!(entries.is_empty(), "Empty content should be rejected");
 }
 
@@ -219,8 +215,8 @@ fn test_bitmap_cursed_inscription_rejected() {
     index_ord_block(&block, height).unwrap();
     index_bitmap_block(&block, height);
 
-    let data = BITMAP_NUMBER_TO_ID.select(&50u64.to_le_bytes().to_vec()).get();
-    assert!(data.is_empty(), "Cursed (coinbase) inscription should not register a bitmap");
+    let data = BITMAP_NUMBER_TO_ID.get(&50);
+    assert!(data.is_none(), "Cursed (coinbase) inscription should not register a bitmap");
 }
 
 #[test]
@@ -232,14 +228,8 @@ fn test_bitmap_lookup_by_number() {
     index_bitmap_block(&block, height);
 
     // Forward lookup: number -> id
-    let id_data = BITMAP_NUMBER_TO_ID.select(&200u64.to_le_bytes().to_vec()).get();
-    assert!(!id_data.is_empty(), "Forward lookup should find bitmap 200");
-
-    let inscription_id = InscriptionId::from_bytes(&id_data).unwrap();
+    let inscription_id = BITMAP_NUMBER_TO_ID.get(&200).expect("Forward lookup should find bitmap 200");
 
     // Reverse lookup: id -> number
-    let number_data = BITMAP_ID_TO_NUMBER.select(&inscription_id.to_bytes()).get();
-    assert!(!number_data.is_empty(), "Reverse lookup should find the number");
-    let number = u64::from_le_bytes(number_data[..8].try_into().unwrap());
-    assert_eq!(number, 200, "Reverse lookup should return 200");
+    assert_eq!(BITMAP_ID_TO_NUMBER.get(&inscription_id), Some(200), "Reverse lookup should return 200");
 }
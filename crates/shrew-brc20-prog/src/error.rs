@@ -0,0 +1,28 @@
+//! Errors of the programmable BRC-20 views.
+//!
+//! A call that reverts or halts is not an error: `call` reports it in the
+//! `CallResponse`, as `eth_call` would.
+
+use shrew_support::error::{CodedError, ErrorCode};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ProgError {
+    /// The request is not the JSON object the view expects
+    #[error("malformed request: {0}")]
+    MalformedRequest(String),
+    /// A request field is present but malformed
+    #[error("invalid {field}: {reason}")]
+    InvalidField { field: &'static str, reason: String },
+    /// EVM state could not be read
+    #[error("failed to read EVM state: {0}")]
+    Database(String),
+}
+
+impl CodedError for ProgError {
+    fn code(&self) -> ErrorCode {
+        match self {
+            ProgError::MalformedRequest(_) | ProgError::InvalidField { .. } => ErrorCode::InvalidArgument,
+            ProgError::Database(_) => ErrorCode::Internal,
+        }
+    }
+}
@@ -1,8 +1,12 @@
 #[cfg(feature = "entrypoint")]
-use bitcoin::{Block, consensus::deserialize};
-use serde_json::{from_slice, to_vec};
+use shrew_support::diagnostics;
+use error::ProgError;
+use shrew_support::error::ErrorResponse;
+use shrew_support::wire::{encode_error, serve, WireFormat};
 
 pub mod prog_indexer;
+pub mod error;
+pub mod tables;
 pub mod controller;
 pub mod trace_hash;
 pub mod view;
@@ -16,11 +20,11 @@ pub use prog_indexer::ProgrammableBrc20Indexer;
 #[cfg(feature = "entrypoint")]
 #[metashrew_core::main]
 fn main_logic(height: u32, block_data: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
-    if let Ok(block) = deserialize::<Block>(block_data) {
+    if let Some(block) = diagnostics::decode_block(height, block_data) {
         // Run inscription indexer
-        let mut inscription_indexer = shrew_ord::indexer::InscriptionIndexer::new();
-        let _ = inscription_indexer.load_state();
-        let _ = inscription_indexer.index_block(&block, height);
+        if !shrew_ord::indexer::InscriptionIndexer::index_or_report(&block, height) {
+            return Ok(());
+        }
 
         // Run BRC20 indexer
         let brc20_indexer = shrew_brc20::Brc20Indexer::new();
@@ -35,8 +39,7 @@ fn main_logic(height: u32, block_data: &[u8]) -> Result<(), Box<dyn std::error::
 
 #[metashrew_core::view]
 pub fn call(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::CallRequest = from_slice(input)?;
-    Ok(to_vec(&view::call(&req)?)?)
+    serve(input, view::call)
 }
 
 /// Read a storage slot from any EVM account.
@@ -44,31 +47,7 @@ pub fn call(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
 /// Returns: JSON { "value": "0x..." }
 #[metashrew_core::view]
 pub fn storage_at(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    use shrew_evm::database::MetashrewDB;
-    use revm::Database;
-    use revm::primitives::{Address, U256};
-
-    let req: serde_json::Value = serde_json::from_slice(input)?;
-    let addr_hex = req["address"].as_str().unwrap_or("");
-    let slot_hex = req["slot"].as_str().unwrap_or("0x0");
-
-    let addr_bytes = hex::decode(addr_hex.strip_prefix("0x").unwrap_or(addr_hex))?;
-    if addr_bytes.len() != 20 {
-        return Ok(serde_json::to_vec(&serde_json::json!({"error": "invalid address"}))?);
-    }
-    let address = Address::from_slice(&addr_bytes);
-
-    let slot_bytes = hex::decode(slot_hex.strip_prefix("0x").unwrap_or(slot_hex))?;
-    let mut slot_arr = [0u8; 32];
-    let start = 32usize.saturating_sub(slot_bytes.len());
-    slot_arr[start..].copy_from_slice(&slot_bytes[..slot_bytes.len().min(32)]);
-    let slot = U256::from_be_bytes(slot_arr);
-
-    let mut db = MetashrewDB;
-    let value = db.storage(address, slot)?;
-
-    let value_hex = format!("0x{}", hex::encode(value.to_be_bytes::<32>()));
-    Ok(serde_json::to_vec(&serde_json::json!({"value": value_hex}))?)
+    serve_json(input, view::storage_at)
 }
 
 /// Read account info (code size, nonce, balance) at an address.
@@ -76,40 +55,21 @@ pub fn storage_at(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
 /// Returns: JSON { "code_size": N, "nonce": N, "has_code": bool, "code_hash": "0x..." }
 #[metashrew_core::view]
 pub fn code_at(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    use shrew_evm::database::MetashrewDB;
-    use revm::Database;
-    use revm::primitives::Address;
-
-    let req: serde_json::Value = serde_json::from_slice(input)?;
-    let addr_hex = req["address"].as_str().unwrap_or("");
-    let addr_bytes = hex::decode(addr_hex.strip_prefix("0x").unwrap_or(addr_hex))?;
-    if addr_bytes.len() != 20 {
-        return Ok(serde_json::to_vec(&serde_json::json!({"error": "invalid address"}))?);
-    }
-    let address = Address::from_slice(&addr_bytes);
+    serve_json(input, view::code_at)
+}
 
-    let mut db = MetashrewDB;
-    match db.basic(address)? {
-        Some(info) => {
-            let code_size = info.code.as_ref().map(|c| c.len()).unwrap_or(0);
-            let code_hash_hex = format!("0x{}", hex::encode(info.code_hash.as_slice()));
-            Ok(serde_json::to_vec(&serde_json::json!({
-                "code_size": code_size,
-                "nonce": info.nonce,
-                "has_code": code_size > 0,
-                "code_hash": code_hash_hex,
-                "code_is_none": info.code.is_none(),
-            }))?)
-        }
-        None => {
-            Ok(serde_json::to_vec(&serde_json::json!({
-                "code_size": 0,
-                "nonce": 0,
-                "has_code": false,
-                "code_hash": "none",
-                "exists": false,
-            }))?)
-        }
+/// Run a free-form JSON view. Failures are answered with an `ErrorResponse`,
+/// as for the protobuf-typed views.
+fn serve_json<F>(input: &[u8], view: F) -> Result<Vec<u8>, Box<dyn std::error::Error>>
+where
+    F: FnOnce(&serde_json::Value) -> Result<serde_json::Value, ProgError>,
+{
+    let result = serde_json::from_slice(input)
+        .map_err(|e| ProgError::MalformedRequest(e.to_string()))
+        .and_then(|request| view(&request));
+    match result {
+        Ok(response) => Ok(serde_json::to_vec(&response)?),
+        Err(error) => encode_error(&ErrorResponse::from_error(&error), WireFormat::Json),
     }
 }
 
@@ -1,9 +1,11 @@
-use shrew_support::inscription::InscriptionEntry;
+use shrew_support::diagnostics::{self, BlockDiagnostics};
+use shrew_support::inscription::{InscriptionEntry, InscriptionId};
 use shrew_support::constants::{
     BRC20_PROG_GAS_PER_BYTE, BRC20_PROG_MAX_CALL_GAS, BRC20_PROG_PRAGUE_HARDFORK,
 };
+use shrew_ord::entries;
 use shrew_ord::tables::{
-    SEQUENCE_TO_INSCRIPTION_ENTRY, INSCRIPTION_CONTENT, GLOBAL_SEQUENCE_COUNTER,
+    InscriptionContentTable, GLOBAL_SEQUENCE_COUNTER,
 };
 use shrew_evm::database::MetashrewDB;
 use shrew_evm::tables::{
@@ -13,6 +15,9 @@ use shrew_evm::tables::{
 use shrew_brc20::tables::Brc20ProgDeposits;
 use shrew_evm::ShrewPrecompiles;
 use crate::controller::{CONTROLLER_ADDRESS, controller_bytecode};
+use crate::tables::{
+    DeferredInscription, CONTROLLER_DEPLOYED, PROG_ACTIVATION_MAP, PROG_DEFERRED_INSCRIPTIONS,
+};
 use revm::primitives::{Address, Bytes, U256, B256, TxKind};
 use revm::primitives::hardfork::SpecId;
 use revm::state::{AccountInfo, Bytecode};
@@ -22,9 +27,10 @@ use revm::handler::instructions::EthInstructions;
 use revm::handler::EthFrame;
 use revm::interpreter::interpreter::EthInterpreter;
 use revm::ExecuteCommitEvm;
-use bitcoin::Block;
+use bitcoin::{Block, Txid};
 use metashrew_support::index_pointer::KeyValuePointer;
 use serde::Deserialize;
+use std::str::FromStr;
 use std::sync::Arc;
 
 type Ctx = Context<BlockEnv, TxEnv, CfgEnv, MetashrewDB, Journal<MetashrewDB>, ()>;
@@ -94,9 +100,6 @@ fn derive_sender_address(entry: &InscriptionEntry, block: &Block) -> Address {
     Address::from_slice(&hash[12..32])
 }
 
-/// Table key for tracking whether controller has been deployed
-const CONTROLLER_DEPLOYED_KEY: &str = "/prog/controller_deployed";
-
 /// Debug: last processed inscription content (for diagnosing devnet issues)
 const DEBUG_LAST_INSCRIPTION_KEY: &str = "/debug/last_inscription";
 /// Debug: last processed inscription result
@@ -110,24 +113,6 @@ struct ProgOperation {
     data: serde_json::Value,
 }
 
-/// Serializable representation of a deferred inscription awaiting activation.
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
-struct DeferredInscription {
-    /// Raw inscription content bytes (the brc20-prog JSON)
-    content: Vec<u8>,
-    /// Inscription entry bytes (serialized)
-    entry_bytes: Vec<u8>,
-    /// The inscription's reveal txid (for looking up the activation mapping)
-    reveal_txid: Vec<u8>,
-    /// Height the inscription was first seen
-    height: u32,
-    /// Pre-computed sender EVM address (20 bytes)
-    /// Stored because the sender derivation needs the reveal block, which
-    /// won't be available when the deferred inscription is re-executed
-    /// in the activation block context.
-    sender: Vec<u8>,
-}
-
 #[derive(Debug, Deserialize)]
 struct DeployOp {
     d: String, // hex bytecode
@@ -182,11 +167,28 @@ fn make_tx(kind: TxKind, data: Bytes, _gas_limit: u64, caller: Address) -> TxEnv
 pub struct ProgrammableBrc20Indexer {
     current_height: u32,
     controller_deployed: bool,
+    /// EVM executions of the current block and the gas they used
+    executions: u64,
+    gas_used: u64,
 }
 
 impl ProgrammableBrc20Indexer {
     pub fn new() -> Self {
-        Self { current_height: 0, controller_deployed: false }
+        Self { current_height: 0, controller_deployed: false, executions: 0, gas_used: 0 }
+    }
+
+    fn count_execution(&mut self, result: &ExecutionResult) {
+        self.executions += 1;
+        self.gas_used += result.gas_used();
+    }
+
+    /// Add the block's execution counters to its diagnostics
+    fn record_diagnostics(&mut self, height: u32) {
+        diagnostics::add(height, "brc20-prog", BlockDiagnostics {
+            prog_executions: std::mem::take(&mut self.executions),
+            prog_gas: std::mem::take(&mut self.gas_used),
+            ..Default::default()
+        });
     }
 
     /// Build an EVM instance with custom BRC20-prog precompiles.
@@ -231,8 +233,7 @@ impl ProgrammableBrc20Indexer {
         if self.controller_deployed { return; }
 
         // Check persistent state
-        let mut deployed_marker = metashrew_core::index_pointer::IndexPointer::from_keyword(CONTROLLER_DEPLOYED_KEY);
-        if !deployed_marker.get().is_empty() {
+        if CONTROLLER_DEPLOYED.get().unwrap_or(false) {
             self.controller_deployed = true;
             return;
         }
@@ -247,8 +248,7 @@ impl ProgrammableBrc20Indexer {
         };
 
         // Store bytecode
-        CODE_HASH_TO_BYTECODE.select(&code_hash.to_vec())
-            .set(Arc::new(bytecode.clone()));
+        CODE_HASH_TO_BYTECODE.set(&code_hash.0, &bytecode);
 
         // Store account info at controller address
         let account_info = AccountInfo {
@@ -258,17 +258,17 @@ impl ProgrammableBrc20Indexer {
             account_id: None,
             code: Some(Bytecode::new_raw(Bytes::from(bytecode))),
         };
-        let account_bytes = bincode::serialize(&account_info).expect("serialize account info");
-        EVM_ACCOUNTS.select(&CONTROLLER_ADDRESS.to_vec())
-            .set(Arc::new(account_bytes));
+        EVM_ACCOUNTS.set(&CONTROLLER_ADDRESS, &account_info);
 
         // Mark as deployed
-        deployed_marker.set(Arc::new(vec![1]));
+        CONTROLLER_DEPLOYED.set(&true);
         self.controller_deployed = true;
     }
 
     pub fn index_block(&mut self, block: &Block, height: u32) {
         self.current_height = height;
+        self.executions = 0;
+        self.gas_used = 0;
 
         // Scan for BRC20PROG activation transactions FIRST.
         // The activation tx may appear in the same block as the inscription
@@ -280,25 +280,18 @@ impl ProgrammableBrc20Indexer {
         // Ensure controller contract is deployed
         self.ensure_controller_deployed();
 
-        let seq_bytes = GLOBAL_SEQUENCE_COUNTER.get();
-        if seq_bytes.is_empty() {
+        let Some(max_seq) = GLOBAL_SEQUENCE_COUNTER.get() else {
             // Still process pending deposits even if no inscriptions
             self.process_pending_deposits(height);
+            self.record_diagnostics(height);
             return;
-        }
-        let max_seq = u32::from_le_bytes(seq_bytes[..4].try_into().unwrap_or([0; 4]));
+        };
 
         for seq in 1..=max_seq {
-            let entry_bytes = SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq.to_le_bytes().to_vec()).get();
-            if entry_bytes.is_empty() { continue; }
-            let entry = match InscriptionEntry::from_bytes(&entry_bytes) {
-                Ok(e) => e,
-                Err(_) => continue,
-            };
+            let Some(entry) = entries::load_or_report(seq, height) else { continue };
             if entry.height != height { continue; }
 
-            let inscription_id_str = entry.id.to_string();
-            let content_bytes = INSCRIPTION_CONTENT.select(&inscription_id_str.as_bytes().to_vec()).get();
+            let content_bytes = InscriptionContentTable::new().get(&entry.id).unwrap_or_default();
             if content_bytes.is_empty() { continue; }
 
             if let Ok(op) = serde_json::from_slice::<ProgOperation>(&content_bytes) {
@@ -333,17 +326,12 @@ impl ProgrammableBrc20Indexer {
                             let sender_addr = derive_sender_address(&entry, block);
                             let deferred = DeferredInscription {
                                 content: content_bytes.to_vec(),
-                                entry_bytes: entry_bytes.to_vec(),
+                                entry_bytes: entry.to_bytes(),
                                 reveal_txid: entry.id.txid[..].to_vec(),
                                 height,
                                 sender: sender_addr.to_vec(),
                             };
-                            if let Ok(data) = serde_json::to_vec(&deferred) {
-                                let mut ptr = metashrew_core::index_pointer::IndexPointer::from_keyword(
-                                    Self::DEFERRED_INSCRIPTIONS_PREFIX,
-                                ).select(&entry.id.txid[..].to_vec());
-                                ptr.set(Arc::new(data));
-                            }
+                            PROG_DEFERRED_INSCRIPTIONS.set(&entry.id.txid, &deferred);
                             // DON'T skip — execute optimistically. Pure EVM calls
                             // (initialize, setSigner, etc.) work without activation.
                             // Calls needing getTxId() will revert, and the deferred
@@ -375,6 +363,7 @@ impl ProgrammableBrc20Indexer {
 
         // Process any BRC20 deposit events from the BRC-20 indexer
         self.process_pending_deposits(height);
+        self.record_diagnostics(height);
     }
 
     /// Process pending BRC20-PROG deposit events from the BRC-20 indexer.
@@ -385,7 +374,7 @@ impl ProgrammableBrc20Indexer {
         if events.is_empty() { return; }
 
         for event in &events {
-            // The sender address is a Bitcoin address string.
+            // The sender is the BRC-20 ledger key, a hex pkscript.
             // For EVM representation, we hash it to get a deterministic 20-byte address.
             let recipient = {
                 use sha2::{Sha256, Digest};
@@ -405,23 +394,13 @@ impl ProgrammableBrc20Indexer {
     /// Convert a Bitcoin Txid to B256 in display (big-endian) byte order.
     /// The getTxDetails precompile receives bytes32 from Solidity and reverses
     /// to get Bitcoin's internal LE order for the lookup, so we must store BE.
-    fn txid_to_b256_be(txid: &bitcoin::Txid) -> B256 {
+    fn txid_to_b256_be(txid: &Txid) -> B256 {
         let mut bytes = [0u8; 32];
         bytes.copy_from_slice(&txid[..]);
         bytes.reverse(); // LE (internal) → BE (display/EVM)
         B256::from(bytes)
     }
 
-    /// Resolve the op_return_tx_id for a brc20-prog inscription.
-    ///
-    /// Table key prefix for storing activation tx mappings.
-    /// Maps reveal_txid → activation_txid (both in internal LE byte order).
-    const ACTIVATION_MAP_PREFIX: &'static str = "/prog/activation_map/";
-
-    /// Table prefix for deferred inscription entries awaiting activation.
-    /// Maps reveal_txid → serialized inscription content bytes.
-    const DEFERRED_INSCRIPTIONS_PREFIX: &'static str = "/prog/deferred/";
-
     /// Scan a block for BRC20PROG activation transactions and store mappings.
     /// Also triggers deferred execution of inscriptions that were waiting for activation.
     fn scan_for_activation_txs(&mut self, block: &Block, height: u32) {
@@ -437,27 +416,17 @@ impl ProgrammableBrc20Indexer {
             };
             if is_brc20prog_op_return {
                 // This is an activation tx. input[0] spends the reveal tx.
-                let reveal_txid_bytes = tx.input[0].previous_output.txid[..].to_vec();
-                let activation_txid_bytes = tx.compute_txid()[..].to_vec();
+                let reveal_txid = tx.input[0].previous_output.txid;
 
                 // Store activation mapping
-                let mut pointer = metashrew_core::index_pointer::IndexPointer::from_keyword(
-                    Self::ACTIVATION_MAP_PREFIX,
-                ).select(&reveal_txid_bytes);
-                pointer.set(Arc::new(activation_txid_bytes));
+                PROG_ACTIVATION_MAP.set(&reveal_txid, &tx.compute_txid());
 
                 // Check if there's a deferred inscription waiting for this activation
-                let mut deferred_ptr = metashrew_core::index_pointer::IndexPointer::from_keyword(
-                    Self::DEFERRED_INSCRIPTIONS_PREFIX,
-                ).select(&reveal_txid_bytes);
-                let deferred_data = deferred_ptr.get();
-                if !deferred_data.is_empty() {
+                if let Some(deferred) = PROG_DEFERRED_INSCRIPTIONS.get(&reveal_txid) {
                     // Re-execute the deferred inscription with the activation context
-                    if let Ok(deferred) = serde_json::from_slice::<DeferredInscription>(&deferred_data) {
-                        self.execute_deferred(&deferred, block);
-                    }
+                    self.execute_deferred(&deferred, block);
                     // Clear the deferred entry
-                    deferred_ptr.set(Arc::new(vec![]));
+                    PROG_DEFERRED_INSCRIPTIONS.delete(&reveal_txid);
                 }
             }
         }
@@ -470,18 +439,10 @@ impl ProgrammableBrc20Indexer {
     /// activation tx's id. Otherwise returns the reveal tx's id (2-tx pattern).
     fn resolve_op_return_tx_id(entry: &InscriptionEntry, block: &Block) -> B256 {
         let reveal_txid = entry.id.txid;
-        let reveal_txid_bytes = reveal_txid[..].to_vec();
 
         // First check the persistent activation map (handles cross-block activation)
-        let pointer = metashrew_core::index_pointer::IndexPointer::from_keyword(
-            Self::ACTIVATION_MAP_PREFIX,
-        ).select(&reveal_txid_bytes);
-        let stored = pointer.get();
-        if !stored.is_empty() && stored.len() == 32 {
-            let mut bytes = [0u8; 32];
-            bytes.copy_from_slice(&stored);
-            bytes.reverse(); // LE → BE for EVM
-            return B256::from(bytes);
+        if let Some(activation_txid) = PROG_ACTIVATION_MAP.get(&reveal_txid) {
+            return Self::txid_to_b256_be(&activation_txid);
         }
 
         // Also scan current block (handles same-block activation)
@@ -506,17 +467,16 @@ impl ProgrammableBrc20Indexer {
 
     /// Check if an activation mapping exists for an inscription's reveal tx.
     fn has_activation_mapping(entry: &InscriptionEntry) -> bool {
-        let reveal_txid_bytes = entry.id.txid[..].to_vec();
-        let pointer = metashrew_core::index_pointer::IndexPointer::from_keyword(
-            Self::ACTIVATION_MAP_PREFIX,
-        ).select(&reveal_txid_bytes);
-        !pointer.get().is_empty()
+        PROG_ACTIVATION_MAP.contains(&entry.id.txid)
     }
 
     /// Execute a deferred inscription that was waiting for its activation tx.
     /// Uses the pre-stored sender address since the reveal block isn't available.
     fn execute_deferred(&mut self, deferred: &DeferredInscription, _block: &Block) {
-        let entry = match InscriptionEntry::from_bytes(&deferred.entry_bytes) {
+        // Records deferred before entries were versioned hold the legacy encoding
+        let entry = match InscriptionEntry::from_bytes(&deferred.entry_bytes)
+            .or_else(|_| InscriptionEntry::from_legacy_bytes(&deferred.entry_bytes))
+        {
             Ok(e) => e,
             Err(_) => return,
         };
@@ -551,18 +511,8 @@ impl ProgrammableBrc20Indexer {
 
     /// Resolve op_return_tx_id using only the stored activation mapping (no block scan).
     fn resolve_op_return_tx_id_from_entry(entry: &InscriptionEntry) -> B256 {
-        let reveal_txid_bytes = entry.id.txid[..].to_vec();
-        let pointer = metashrew_core::index_pointer::IndexPointer::from_keyword(
-            Self::ACTIVATION_MAP_PREFIX,
-        ).select(&reveal_txid_bytes);
-        let stored = pointer.get();
-        if !stored.is_empty() && stored.len() == 32 {
-            let mut bytes = [0u8; 32];
-            bytes.copy_from_slice(&stored);
-            bytes.reverse();
-            return B256::from(bytes);
-        }
-        Self::txid_to_b256_be(&entry.id.txid)
+        let txid = PROG_ACTIVATION_MAP.get(&entry.id.txid).unwrap_or(entry.id.txid);
+        Self::txid_to_b256_be(&txid)
     }
 
     fn execute_deploy(&mut self, entry: &InscriptionEntry, op: DeployOp, block: &Block) {
@@ -611,14 +561,14 @@ impl ProgrammableBrc20Indexer {
             ptr.set(Arc::new(result_str.into_bytes()));
         }
 
+        if let Ok(exec_result) = &result {
+            self.count_execution(exec_result);
+        }
         if let Ok(exec_result) = result {
             if let ExecutionResult::Success { output, .. } = exec_result {
                 if let Output::Create(_, Some(address)) = output {
-                    let inscription_id_bytes = entry.id.to_bytes();
-                    CONTRACT_ADDRESS_TO_INSCRIPTION_ID.select(&address.to_vec())
-                        .set(Arc::new(inscription_id_bytes.clone()));
-                    INSCRIPTION_ID_TO_CONTRACT_ADDRESS.select(&inscription_id_bytes)
-                        .set(Arc::new(address.to_vec()));
+                    CONTRACT_ADDRESS_TO_INSCRIPTION_ID.set(&address, &entry.id);
+                    INSCRIPTION_ID_TO_CONTRACT_ADDRESS.set(&entry.id, &address);
                 }
             }
         }
@@ -644,10 +594,9 @@ impl ProgrammableBrc20Indexer {
             if bytes.len() != 20 { return; }
             Address::from_slice(&bytes)
         } else if let Some(ref inscription_id) = op.i {
-            let pointer = INSCRIPTION_ID_TO_CONTRACT_ADDRESS.select(&inscription_id.as_bytes().to_vec());
-            let result = pointer.get();
-            if result.is_empty() { return; }
-            Address::from_slice(&result)
+            let Ok(inscription_id) = InscriptionId::from_str(inscription_id) else { return };
+            let Some(address) = INSCRIPTION_ID_TO_CONTRACT_ADDRESS.get(&inscription_id) else { return };
+            address
         } else {
             return; // No address source
         };
@@ -668,6 +617,7 @@ impl ProgrammableBrc20Indexer {
 
             match evm.transact(tx) {
                 Ok(result_and_state) => {
+                    self.count_execution(&result_and_state.result);
                     let result_str = match &result_and_state.result {
                         ExecutionResult::Success { gas_used, output, .. } => {
                             // Inspect state diff
@@ -741,7 +691,9 @@ impl ProgrammableBrc20Indexer {
         let mut evm = self.build_evm(Self::resolve_op_return_tx_id(entry, block));
         let tx = make_tx(TxKind::Call(address), data, gas_limit, sender);
 
-        let _ = evm.transact_commit(tx);
+        if let Ok(result) = evm.transact_commit(tx) {
+            self.count_execution(&result);
+        }
     }
 
     /// Execute a call with pre-computed sender and op_return_tx_id (for deferred execution).
@@ -752,10 +704,9 @@ impl ProgrammableBrc20Indexer {
             if bytes.len() != 20 { return; }
             Address::from_slice(&bytes)
         } else if let Some(ref inscription_id) = op.i {
-            let pointer = INSCRIPTION_ID_TO_CONTRACT_ADDRESS.select(&inscription_id.as_bytes().to_vec());
-            let result = pointer.get();
-            if result.is_empty() { return; }
-            Address::from_slice(&result)
+            let Ok(inscription_id) = InscriptionId::from_str(inscription_id) else { return };
+            let Some(address) = INSCRIPTION_ID_TO_CONTRACT_ADDRESS.get(&inscription_id) else { return };
+            address
         } else {
             return;
         };
@@ -766,7 +717,9 @@ impl ProgrammableBrc20Indexer {
 
         let mut evm = self.build_evm(op_return_tx_id);
         let tx = make_tx(TxKind::Call(address), data, gas_limit, sender);
-        let _ = evm.transact_commit(tx);
+        if let Ok(result) = evm.transact_commit(tx) {
+            self.count_execution(&result);
+        }
     }
 
     /// Execute a transact with pre-computed sender and op_return_tx_id (for deferred execution).
@@ -783,7 +736,9 @@ impl ProgrammableBrc20Indexer {
 
         let mut evm = self.build_evm(op_return_tx_id);
         let tx = make_tx(TxKind::Call(address), data, gas_limit, sender);
-        let _ = evm.transact_commit(tx);
+        if let Ok(result) = evm.transact_commit(tx) {
+            self.count_execution(&result);
+        }
     }
 
     /// Call the controller's mint function to create EVM-side token representation.
@@ -818,7 +773,9 @@ impl ProgrammableBrc20Indexer {
 
         let mut evm = self.build_evm(B256::ZERO);
         let tx = make_tx(TxKind::Call(CONTROLLER_ADDRESS), data, gas_limit, CONTROLLER_ADDRESS);
-        let _ = evm.transact_commit(tx);
+        if let Ok(result) = evm.transact_commit(tx) {
+            self.count_execution(&result);
+        }
     }
 
     /// Call the controller's burn function to destroy EVM-side token representation.
@@ -846,6 +803,8 @@ impl ProgrammableBrc20Indexer {
 
         let mut evm = self.build_evm(B256::ZERO);
         let tx = make_tx(TxKind::Call(CONTROLLER_ADDRESS), data, gas_limit, CONTROLLER_ADDRESS);
-        let _ = evm.transact_commit(tx);
+        if let Ok(result) = evm.transact_commit(tx) {
+            self.count_execution(&result);
+        }
     }
 }
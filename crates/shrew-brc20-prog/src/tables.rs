@@ -0,0 +1,29 @@
+use bitcoin::Txid;
+use shrew_support::table::{Json, Slot, Table};
+
+lazy_static::lazy_static! {
+    /// Whether the BRC20 controller contract has been deployed
+    pub static ref CONTROLLER_DEPLOYED: Slot<bool> = Slot::new("/prog/controller_deployed");
+    /// Reveal txid -> BRC20PROG activation txid (3-tx pattern)
+    pub static ref PROG_ACTIVATION_MAP: Table<Txid, Txid> = Table::new("/prog/activation_map/");
+    /// Reveal txid -> inscription awaiting its activation tx
+    pub static ref PROG_DEFERRED_INSCRIPTIONS: Table<Txid, Json<DeferredInscription>> = Table::new("/prog/deferred/");
+}
+
+/// Serializable representation of a deferred inscription awaiting activation.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct DeferredInscription {
+    /// Raw inscription content bytes (the brc20-prog JSON)
+    pub content: Vec<u8>,
+    /// Inscription entry bytes (serialized)
+    pub entry_bytes: Vec<u8>,
+    /// The inscription's reveal txid (for looking up the activation mapping)
+    pub reveal_txid: Vec<u8>,
+    /// Height the inscription was first seen
+    pub height: u32,
+    /// Pre-computed sender EVM address (20 bytes)
+    /// Stored because the sender derivation needs the reveal block, which
+    /// won't be available when the deferred inscription is re-executed
+    /// in the activation block context.
+    pub sender: Vec<u8>,
+}
@@ -7,6 +7,7 @@ use wasm_bindgen_test::wasm_bindgen_test as test;
 use crate::prog_indexer::ProgrammableBrc20Indexer;
 use crate::view;
 use crate::proto::CallRequest;
+use crate::tables::PROG_ACTIVATION_MAP;
 use shrew_test_helpers::state::clear;
 use shrew_test_helpers::blocks::{create_coinbase_transaction, create_block_with_txs};
 use shrew_test_helpers::transactions::{create_inscription_transaction, create_activation_transaction, create_mock_outpoint};
@@ -16,7 +17,6 @@ use shrew_evm::tables::{INSCRIPTION_ID_TO_CONTRACT_ADDRESS, EVM_STORAGE};
 use shrew_ord::tables::TXID_TO_RAW_TX;
 use revm::primitives::{Address, U256};
 use revm::Database;
-use metashrew_support::index_pointer::KeyValuePointer;
 use bitcoin::Amount;
 
 const FRBTC_BYTECODE: &str = include_str!("fixtures/frbtc_bytecode.hex");
@@ -31,9 +31,7 @@ fn deploy_frbtc_at(height: u32) -> Address {
     let mut prog = ProgrammableBrc20Indexer::new();
     prog.index_block(&block, height);
     let inscription_id = shrew_support::inscription::InscriptionId::new(tx.txid(), 0);
-    let addr_bytes = INSCRIPTION_ID_TO_CONTRACT_ADDRESS.select(&inscription_id.to_bytes()).get();
-    assert!(!addr_bytes.is_empty(), "FrBTC should deploy");
-    Address::from_slice(&addr_bytes)
+    INSCRIPTION_ID_TO_CONTRACT_ADDRESS.get(&inscription_id).expect("FrBTC should deploy")
 }
 
 fn view_call(to: &Address, calldata: &[u8]) -> crate::proto::CallResponse {
@@ -70,17 +68,9 @@ fn test_activation_mapping_stored() {
     prog.index_block(&block, height2);
 
     // Check activation mapping
-    let ptr = metashrew_core::index_pointer::IndexPointer::from_keyword("/prog/activation_map/")
-        .select(&reveal_txid[..].to_vec());
-    let stored = ptr.get();
-    assert!(!stored.is_empty(), "Activation mapping should be stored");
-    assert_eq!(stored.len(), 32, "Activation mapping should be 32 bytes (txid)");
-
-    // The stored bytes should be the activation txid in LE (internal) order
-    let stored_bytes: Vec<u8> = stored.to_vec();
-    let expected_bytes: Vec<u8> = activation_txid[..].to_vec();
-    assert_eq!(stored_bytes, expected_bytes,
-        "Stored activation txid should match activation_tx.compute_txid() in LE order");
+    let stored = PROG_ACTIVATION_MAP.get(&reveal_txid).expect("Activation mapping should be stored");
+    assert_eq!(stored, activation_txid,
+        "Stored activation txid should match activation_tx.compute_txid()");
 }
 
 /// Step 2: Verify the activation txid is in TXID_TO_RAW_TX
@@ -105,13 +95,11 @@ fn test_activation_tx_in_raw_tx_table() {
     index_ord_block(&block, height2).unwrap();
 
     // The ord indexer should have stored all txs including the activation tx
-    let activation_raw = TXID_TO_RAW_TX.select(&activation_txid[..].to_vec()).get();
-    assert!(!activation_raw.is_empty(),
+    assert!(TXID_TO_RAW_TX.contains(&activation_txid),
         "Activation tx should be in TXID_TO_RAW_TX (stored by ord indexer)");
 
     // Also check the reveal tx is stored
-    let reveal_raw = TXID_TO_RAW_TX.select(&reveal_txid[..].to_vec()).get();
-    assert!(!reveal_raw.is_empty(),
+    assert!(TXID_TO_RAW_TX.contains(&reveal_txid),
         "Reveal tx should be in TXID_TO_RAW_TX");
 }
 
@@ -171,48 +159,24 @@ fn test_wrap_mints_with_activation_tx() {
 
     if supply == U256::ZERO {
         // Debug: check the activation mapping
-        let map_ptr = metashrew_core::index_pointer::IndexPointer::from_keyword("/prog/activation_map/")
-            .select(&reveal_txid[..].to_vec());
-        let stored_activation = map_ptr.get();
-        let has_mapping = !stored_activation.is_empty();
-
-        // Check if the stored activation txid matches what's in TXID_TO_RAW_TX
-        let activation_in_raw = if stored_activation.len() == 32 {
-            !TXID_TO_RAW_TX.select(&stored_activation.to_vec()).get().is_empty()
-        } else {
-            false
-        };
-
-        // Check what resolve_op_return_tx_id would return
-        // It reads the mapping, reverses bytes LE→BE, and returns B256
-        let reversed_stored: Vec<u8> = if stored_activation.len() == 32 {
-            let mut b = stored_activation.to_vec();
-            b.reverse();
-            b
-        } else {
-            vec![]
-        };
-
-        // The precompile receives this BE value from Solidity, reverses to LE, and looks up
-        // So it should look up the ORIGINAL stored bytes (before reversal)
-        let precompile_lookup_key = stored_activation.to_vec();
-        let precompile_finds_tx = !TXID_TO_RAW_TX.select(&precompile_lookup_key).get().is_empty();
+        let stored_activation = PROG_ACTIVATION_MAP.get(&reveal_txid);
+
+        // Check if the stored activation txid is in TXID_TO_RAW_TX. The
+        // precompile receives the BE txid from Solidity and reverses it back
+        // to this internal order for the lookup.
+        let activation_in_raw = stored_activation
+            .map(|txid| TXID_TO_RAW_TX.contains(&txid))
+            .unwrap_or(false);
 
         panic!(
             "totalSupply is 0 — wrap() failed to mint!\n\
              Debug info:\n\
              - has_activation_mapping: {}\n\
-             - stored_activation_len: {}\n\
              - activation_in_TXID_TO_RAW_TX: {}\n\
-             - precompile_would_find_tx: {}\n\
-             - stored_hex: {}\n\
-             - reversed_hex: {}",
-            has_mapping,
-            stored_activation.len(),
+             - stored_activation: {:?}",
+            stored_activation.is_some(),
             activation_in_raw,
-            precompile_finds_tx,
-            hex::encode(&stored_activation.to_vec()),
-            hex::encode(&reversed_stored),
+            stored_activation,
         );
     }
 
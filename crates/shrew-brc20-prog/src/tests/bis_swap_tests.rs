@@ -38,8 +38,7 @@ fn deploy(bytecode_hex: &str, height: u32) -> Option<Address> {
     let mut prog = ProgrammableBrc20Indexer::new();
     prog.index_block(&block, height);
     let id = shrew_support::inscription::InscriptionId::new(tx.txid(), 0);
-    let addr = INSCRIPTION_ID_TO_CONTRACT_ADDRESS.select(&id.to_bytes()).get();
-    if addr.is_empty() { None } else { Some(Address::from_slice(&addr)) }
+    INSCRIPTION_ID_TO_CONTRACT_ADDRESS.get(&id)
 }
 
 fn call_contract(contract: &Address, calldata_hex: &str, height: u32) {
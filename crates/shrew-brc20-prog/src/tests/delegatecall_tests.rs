@@ -19,7 +19,6 @@ use shrew_evm::database::MetashrewDB;
 use shrew_evm::tables::INSCRIPTION_ID_TO_CONTRACT_ADDRESS;
 use revm::primitives::{Address, U256};
 use revm::Database;
-use metashrew_support::index_pointer::KeyValuePointer;
 
 use shrew_test_helpers::transactions::create_mock_outpoint;
 use std::sync::atomic::{AtomicU32, Ordering};
@@ -38,8 +37,7 @@ fn deploy_contract(bytecode_hex: &str, height: u32) -> Option<Address> {
     let mut prog = ProgrammableBrc20Indexer::new();
     prog.index_block(&block, height);
     let inscription_id = shrew_support::inscription::InscriptionId::new(tx.txid(), 0);
-    let addr_bytes = INSCRIPTION_ID_TO_CONTRACT_ADDRESS.select(&inscription_id.to_bytes()).get();
-    if addr_bytes.is_empty() { None } else { Some(Address::from_slice(&addr_bytes)) }
+    INSCRIPTION_ID_TO_CONTRACT_ADDRESS.get(&inscription_id)
 }
 
 fn view_call(to: &Address, calldata: &[u8]) -> crate::proto::CallResponse {
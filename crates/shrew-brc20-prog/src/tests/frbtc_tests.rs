@@ -15,7 +15,6 @@ use shrew_evm::database::MetashrewDB;
 use shrew_evm::tables::{INSCRIPTION_ID_TO_CONTRACT_ADDRESS, EVM_STORAGE, EVM_ACCOUNTS};
 use revm::primitives::{Address, U256};
 use revm::Database;
-use metashrew_support::index_pointer::KeyValuePointer;
 
 use shrew_test_helpers::transactions::create_activation_transaction;
 use bitcoin::Amount;
@@ -43,12 +42,7 @@ fn deploy_frbtc(height: u32) -> Option<Address> {
     prog.index_block(&block, height);
 
     let inscription_id = shrew_support::inscription::InscriptionId::new(tx.txid(), 0);
-    let addr_bytes = INSCRIPTION_ID_TO_CONTRACT_ADDRESS.select(&inscription_id.to_bytes()).get();
-    if addr_bytes.is_empty() {
-        None
-    } else {
-        Some(Address::from_slice(&addr_bytes))
-    }
+    INSCRIPTION_ID_TO_CONTRACT_ADDRESS.get(&inscription_id)
 }
 
 fn view_call(to: &Address, calldata: &[u8]) -> crate::proto::CallResponse {
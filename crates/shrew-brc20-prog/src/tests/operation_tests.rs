@@ -5,7 +5,6 @@ use shrew_test_helpers::blocks::{create_coinbase_transaction, create_block_with_
 use shrew_test_helpers::transactions::create_inscription_transaction;
 use shrew_test_helpers::indexing::index_ord_block;
 use shrew_evm::tables::{CONTRACT_ADDRESS_TO_INSCRIPTION_ID, INSCRIPTION_ID_TO_CONTRACT_ADDRESS};
-use metashrew_support::index_pointer::KeyValuePointer;
 use shrew_ord::tables::GLOBAL_SEQUENCE_COUNTER;
 
 #[test]
@@ -39,8 +38,7 @@ fn test_prog_non_prog_inscription_ignored() {
 
     // No contracts should have been deployed - sequence counter should show 1 inscription
     // but no prog contracts in EVM tables
-    let seq_bytes = GLOBAL_SEQUENCE_COUNTER.get();
-    assert!(!seq_bytes.is_empty(), "Inscription should exist");
+    assert!(GLOBAL_SEQUENCE_COUNTER.get().is_some(), "Inscription should exist");
 }
 
 #[test]
@@ -62,24 +60,15 @@ fn test_prog_deploy_stores_contract() {
     // from the inscription id to a contract address.
     let inscription_txid = tx.txid();
     let inscription_id = shrew_support::inscription::InscriptionId::new(inscription_txid, 0);
-    let inscription_id_bytes = inscription_id.to_bytes();
 
-    let contract_addr = INSCRIPTION_ID_TO_CONTRACT_ADDRESS.select(&inscription_id_bytes).get();
-    assert!(
-        !contract_addr.is_empty(),
-        "Deploy should store contract address for inscription id"
-    );
-    assert_eq!(contract_addr.len(), 20, "Contract address should be 20 bytes");
+    let contract_addr = INSCRIPTION_ID_TO_CONTRACT_ADDRESS.get(&inscription_id)
+        .expect("Deploy should store contract address for inscription id");
 
     // Reverse mapping should also exist
-    let reverse_id = CONTRACT_ADDRESS_TO_INSCRIPTION_ID.select(&contract_addr.to_vec()).get();
-    assert!(
-        !reverse_id.is_empty(),
-        "Reverse mapping (contract address -> inscription id) should exist"
-    );
+    let reverse_id = CONTRACT_ADDRESS_TO_INSCRIPTION_ID.get(&contract_addr)
+        .expect("Reverse mapping (contract address -> inscription id) should exist");
     assert_eq!(
-        reverse_id.as_ref(),
-        inscription_id_bytes.as_slice(),
+        reverse_id, inscription_id,
         "Reverse mapping should point back to same inscription id"
     );
 }
@@ -113,9 +102,8 @@ fn test_prog_wrong_protocol_ignored() {
 
     // No contract should have been created for a brc-20 inscription
     let inscription_id = shrew_support::inscription::InscriptionId::new(tx.txid(), 0);
-    let contract_addr = INSCRIPTION_ID_TO_CONTRACT_ADDRESS.select(&inscription_id.to_bytes()).get();
     assert!(
-        contract_addr.is_empty(),
+        !INSCRIPTION_ID_TO_CONTRACT_ADDRESS.contains(&inscription_id),
         "brc-20 inscription should not produce a prog contract"
     );
 }
@@ -136,11 +124,9 @@ fn test_prog_deploy_with_0x_prefix() {
 
     let inscription_txid = tx.txid();
     let inscription_id = shrew_support::inscription::InscriptionId::new(inscription_txid, 0);
-    let inscription_id_bytes = inscription_id.to_bytes();
 
-    let contract_addr = INSCRIPTION_ID_TO_CONTRACT_ADDRESS.select(&inscription_id_bytes).get();
     assert!(
-        !contract_addr.is_empty(),
+        INSCRIPTION_ID_TO_CONTRACT_ADDRESS.contains(&inscription_id),
         "Deploy with 0x-prefixed bytecode should store contract address"
     );
 }
@@ -15,7 +15,6 @@ use shrew_evm::database::MetashrewDB;
 use shrew_evm::tables::{INSCRIPTION_ID_TO_CONTRACT_ADDRESS, EVM_STORAGE};
 use revm::primitives::{Address, U256};
 use revm::Database;
-use metashrew_support::index_pointer::KeyValuePointer;
 
 /// Helper: wrap runtime bytecode in a simple constructor.
 /// Constructor: PUSH1 <len>, DUP1, PUSH1 <offset>, PUSH1 0, CODECOPY, PUSH1 0, RETURN
@@ -41,12 +40,7 @@ fn deploy_contract(bytecode_hex: &str, height: u32) -> Option<Address> {
     prog.index_block(&block, height);
 
     let inscription_id = shrew_support::inscription::InscriptionId::new(tx.txid(), 0);
-    let addr_bytes = INSCRIPTION_ID_TO_CONTRACT_ADDRESS.select(&inscription_id.to_bytes()).get();
-    if addr_bytes.is_empty() {
-        None
-    } else {
-        Some(Address::from_slice(&addr_bytes))
-    }
+    INSCRIPTION_ID_TO_CONTRACT_ADDRESS.get(&inscription_id)
 }
 
 /// Helper: make a view call to a contract.
@@ -205,11 +199,8 @@ fn test_view_call_deploy_then_read_storage() {
     let addr = addr.unwrap();
 
     // Verify storage was committed by reading raw table
-    let mut key = addr.to_vec();
-    key.extend_from_slice(&U256::ZERO.to_be_bytes::<32>());
-    let stored = EVM_STORAGE.select(&key).get();
-    assert!(!stored.is_empty(), "Storage slot 0 should be committed by indexer");
-    let stored_value = U256::from_be_slice(&stored);
+    let stored_value = EVM_STORAGE.get(&(addr, U256::ZERO))
+        .expect("Storage slot 0 should be committed by indexer");
     assert_eq!(stored_value, U256::from(0x42), "Stored value should be 0x42");
 
     // Also verify the account exists via MetashrewDB
@@ -1,3 +1,4 @@
+use crate::error::ProgError;
 use crate::proto::{CallRequest, CallResponse};
 use shrew_evm::database::MetashrewDB;
 use shrew_evm::ShrewPrecompiles;
@@ -8,7 +9,7 @@ use revm::context::{Context, TxEnv, BlockEnv, CfgEnv, Journal, FrameStack, Evm};
 use revm::handler::instructions::EthInstructions;
 use revm::handler::EthFrame;
 use revm::interpreter::interpreter::EthInterpreter;
-use revm::ExecuteEvm;
+use revm::{Database, ExecuteEvm};
 use shrew_support::constants::BRC20_PROG_MAX_CALL_GAS;
 
 type Ctx = Context<BlockEnv, TxEnv, CfgEnv, MetashrewDB, Journal<MetashrewDB>, ()>;
@@ -58,7 +59,7 @@ fn build_view_evm() -> ViewEvm {
 }
 
 /// Execute a read-only EVM call (eth_call style)
-pub fn call(request: &CallRequest) -> Result<CallResponse, String> {
+pub fn call(request: &CallRequest) -> Result<CallResponse, ProgError> {
     let mut response = CallResponse::default();
 
     if request.to.len() != 20 {
@@ -111,3 +112,52 @@ pub fn call(request: &CallRequest) -> Result<CallResponse, String> {
 
     Ok(response)
 }
+
+/// Storage slot `slot` of the account at `address`
+pub fn storage_at(request: &serde_json::Value) -> Result<serde_json::Value, ProgError> {
+    let address = address_field(request)?;
+    let slot_hex = request["slot"].as_str().unwrap_or("0x0");
+    let slot_bytes = hex::decode(slot_hex.strip_prefix("0x").unwrap_or(slot_hex))
+        .map_err(|e| ProgError::InvalidField { field: "slot", reason: e.to_string() })?;
+    let mut slot_arr = [0u8; 32];
+    let start = 32usize.saturating_sub(slot_bytes.len());
+    slot_arr[start..].copy_from_slice(&slot_bytes[..slot_bytes.len().min(32)]);
+    let slot = U256::from_be_bytes(slot_arr);
+
+    let value = MetashrewDB.storage(address, slot).map_err(|e| ProgError::Database(e.to_string()))?;
+    Ok(serde_json::json!({"value": format!("0x{}", hex::encode(value.to_be_bytes::<32>()))}))
+}
+
+/// Code size, nonce and code hash of the account at `address`
+pub fn code_at(request: &serde_json::Value) -> Result<serde_json::Value, ProgError> {
+    let address = address_field(request)?;
+    match MetashrewDB.basic(address).map_err(|e| ProgError::Database(e.to_string()))? {
+        Some(info) => {
+            let code_size = info.code.as_ref().map(|c| c.len()).unwrap_or(0);
+            Ok(serde_json::json!({
+                "code_size": code_size,
+                "nonce": info.nonce,
+                "has_code": code_size > 0,
+                "code_hash": format!("0x{}", hex::encode(info.code_hash.as_slice())),
+                "code_is_none": info.code.is_none(),
+            }))
+        }
+        None => Ok(serde_json::json!({
+            "code_size": 0,
+            "nonce": 0,
+            "has_code": false,
+            "code_hash": "none",
+            "exists": false,
+        })),
+    }
+}
+
+fn address_field(request: &serde_json::Value) -> Result<Address, ProgError> {
+    let addr_hex = request["address"].as_str().unwrap_or("");
+    let addr_bytes = hex::decode(addr_hex.strip_prefix("0x").unwrap_or(addr_hex))
+        .map_err(|e| ProgError::InvalidField { field: "address", reason: e.to_string() })?;
+    if addr_bytes.len() != 20 {
+        return Err(ProgError::InvalidField { field: "address", reason: "must be 20 bytes".to_string() });
+    }
+    Ok(Address::from_slice(&addr_bytes))
+}
@@ -1,16 +1,19 @@
 use serde::{Deserialize, Serialize};
 use anyhow::Result;
+use crate::event_hash::{self, BlockEventHashes, EventHasher, format_amount};
+use crate::ledger;
+use crate::rules::Brc20Rules;
+use crate::proto::Brc20Event;
 use crate::tables::*;
-use shrew_support::inscription::{InscriptionEntry, InscriptionId};
-use shrew_support::utils::get_address_from_txout;
-use shrew_support::constants::{BRC20_SELF_MINT_ENABLE_HEIGHT, BRC20_PROG_PHASE_ONE_HEIGHT, BRC20_PREDEPLOY_ACTIVATION_HEIGHT};
+use shrew_support::inscription::{InscriptionId, SatPoint};
+use shrew_ord::entries;
+use shrew_ord::history::{self, InscriptionTransfer};
 use shrew_ord::tables::{
-    INSCRIPTION_ID_TO_SEQUENCE, SEQUENCE_TO_INSCRIPTION_ENTRY,
-    OUTPOINT_TO_INSCRIPTIONS, INSCRIPTION_CONTENT,
+    INSCRIPTION_ID_TO_SEQUENCE,
+    OUTPOINT_TO_INSCRIPTIONS, InscriptionContentTable,
 };
-use bitcoin_hashes::Hash;
-use bitcoin::{Block, Network, Transaction};
-use metashrew_support::index_pointer::KeyValuePointer;
+use bitcoin::{Block, Network, Transaction, Txid};
+use shrew_support::diagnostics::{self, BlockDiagnostics};
 use std::str::FromStr;
 
 /// Maximum representable BRC-20 amount: (2^64 - 1) * 10^18
@@ -42,6 +45,52 @@ pub enum TransferDestination {
     SentAsFee,
 }
 
+/// Why a well-formed BRC-20 operation had no effect
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rejection {
+    /// Deploy of a ticker that already exists
+    AlreadyDeployed,
+    /// Mint of a ticker that was never deployed
+    UnknownTicker,
+    ZeroAmount,
+    /// Mint above the ticker's `lim`
+    MintLimitExceeded,
+    /// Mint after the whole supply was minted
+    SupplyExhausted,
+    /// Transfer of more than the owner's available balance
+    InsufficientBalance,
+    /// Self-mint mint whose parent is not the deploy inscription
+    InvalidSelfMintParent,
+    /// Inscription whose sat was paid as fee by its reveal
+    InscribedAsFee,
+}
+
+impl Rejection {
+    /// Stable name, used as the key of rejection counters
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Rejection::AlreadyDeployed => "already_deployed",
+            Rejection::UnknownTicker => "unknown_ticker",
+            Rejection::ZeroAmount => "zero_amount",
+            Rejection::MintLimitExceeded => "mint_limit_exceeded",
+            Rejection::SupplyExhausted => "supply_exhausted",
+            Rejection::InsufficientBalance => "insufficient_balance",
+            Rejection::InvalidSelfMintParent => "invalid_self_mint_parent",
+            Rejection::InscribedAsFee => "inscribed_as_fee",
+        }
+    }
+}
+
+/// Effect of an operation on the ledger
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// The operation took effect on `amount`: the max supply of a deploy, the
+    /// minted amount after clamping to the remaining supply, or the amount
+    /// made transferable
+    Applied { amount: u128 },
+    Rejected(Rejection),
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Brc20Operation {
     Deploy {
@@ -100,22 +149,81 @@ pub struct TransferInfo {
     pub sender: String,
 }
 
-pub struct Brc20Indexer;
+/// Counters, events and event hash of the block being processed, saved once
+/// it is done
+#[derive(Default)]
+struct BlockRecord {
+    counts: BlockDiagnostics,
+    events: Vec<(InscriptionId, Brc20Event)>,
+    hasher: EventHasher,
+}
+
+impl BlockRecord {
+    fn record(&mut self, inscription_id: InscriptionId, event: Brc20Event) {
+        match event.rejection.as_str() {
+            "" => self.counts.brc20_accepted += 1,
+            reason => self.counts.reject(reason),
+        }
+        self.events.push((inscription_id, event));
+    }
+
+    /// Add an applied operation to the block event hash
+    fn hash(&mut self, event: &event_hash::Brc20Event) {
+        self.hasher.add_event(event);
+    }
+
+    /// Save the block at `height`. The cumulative hash chains from the
+    /// previous block's, or from an empty hash at `activation_height`.
+    fn save(self, height: u32, activation_height: u32) {
+        let block_hash = self.hasher.compute_block_hash();
+        let previous = height.checked_sub(1).and_then(|previous| BRC20_EVENT_HASHES.get(&previous));
+        let complete = previous.as_ref().map_or(height == activation_height, |previous| previous.complete);
+        let last_cumulative = previous.map(|hashes| hashes.cumulative_hash).unwrap_or_default();
+        let cumulative_hash = EventHasher::compute_cumulative_hash(&last_cumulative, &block_hash);
+        BRC20_EVENT_HASHES.set(&height, &BlockEventHashes { block_hash, cumulative_hash, complete });
+
+        if !self.events.is_empty() {
+            let events: Vec<Brc20Event> = self.events.iter().map(|(_, event)| event.clone()).collect();
+            BRC20_BLOCK_EVENTS.set(&height, &events);
+        }
+        for (inscription_id, event) in self.events {
+            let mut events = BRC20_INSCRIPTION_EVENTS.get(&inscription_id).unwrap_or_default();
+            events.push(event);
+            BRC20_INSCRIPTION_EVENTS.set(&inscription_id, &events);
+        }
+        diagnostics::add(height, "brc20", self.counts);
+    }
+}
+
+pub struct Brc20Indexer {
+    rules: Brc20Rules,
+}
 
 impl Brc20Indexer {
-    pub fn new() -> Self { Self }
+    pub fn new() -> Self { Self::with_rules(Brc20Rules::default()) }
+
+    pub fn with_rules(rules: Brc20Rules) -> Self { Self { rules } }
 
     /// Process an entire block for BRC20 operations.
-    /// OPI ordering: inscriptions first, then transfers (spending).
+    /// OPI ordering: inscriptions first, then transfers (spending). Every
+    /// operation, applied or rejected, is recorded as an event in that order,
+    /// and the applied ones are hashed into the OPI v3 event hashes.
     pub fn process_block(&self, block: &Block, height: u32) {
+        if !self.rules.is_active(height) {
+            diagnostics::add(height, "brc20", BlockDiagnostics::default());
+            return;
+        }
+        crate::layout::migrate(height);
         let network = Network::Bitcoin;
+        let mut record = BlockRecord::default();
         for tx in &block.txdata {
             // Process inscriptions FIRST (deploy/mint/transfer-inscribe)
-            self.process_brc20_inscriptions(tx, network, height);
+            self.process_brc20_inscriptions(tx, height, &mut record);
 
             // Then process transfer claims (spending transferable inscriptions)
-            self.process_brc20_transfers(tx, network, height);
+            self.process_brc20_transfers(tx, network, height, &mut record);
         }
+        record.save(height, self.rules.activation_height);
     }
 
     /// Validate content-type per OPI rules:
@@ -127,22 +235,14 @@ impl Brc20Indexer {
             || ct == "application/json" || ct.starts_with("application/json;")
     }
 
-    fn process_brc20_inscriptions(&self, tx: &Transaction, network: Network, height: u32) {
+    fn process_brc20_inscriptions(&self, tx: &Transaction, height: u32, record: &mut BlockRecord) {
         for (input_idx, _input) in tx.input.iter().enumerate() {
             let inscription_id = InscriptionId::new(tx.compute_txid(), input_idx as u32);
-            let seq_bytes = INSCRIPTION_ID_TO_SEQUENCE.select(&inscription_id.to_bytes()).get();
-            if seq_bytes.is_empty() { continue; }
-
-            let entry_bytes = SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq_bytes).get();
-            if entry_bytes.is_empty() { continue; }
-
-            let entry = match InscriptionEntry::from_bytes(&entry_bytes) {
-                Ok(e) => e,
-                Err(_) => continue,
-            };
+            let Some(sequence) = INSCRIPTION_ID_TO_SEQUENCE.get(&inscription_id) else { continue };
+            let Some(entry) = entries::load_or_report(sequence, height) else { continue };
 
             // Skip cursed inscriptions (OPI: cursed_for_brc20 check)
-            if entry.number < 0 { continue; }
+            if self.rules.is_cursed(&entry) { continue; }
 
             // Validate content-type strictly per OPI rules
             let _content_type = match &entry.content_type {
@@ -151,93 +251,216 @@ impl Brc20Indexer {
             };
 
             let inscription_id_str = inscription_id.to_string();
-            let content_bytes = INSCRIPTION_CONTENT.select(&inscription_id_str.as_bytes().to_vec()).get();
+            let content_bytes = InscriptionContentTable::new().get(&inscription_id).unwrap_or_default();
             if content_bytes.is_empty() { continue; }
 
             if let Some(operation) = self.parse_operation(&content_bytes, height) {
-                // For self-mint mints, validate that the parent inscription matches the deploy inscription
-                if let Brc20Operation::Mint { ref ticker, .. } = operation {
-                    let ticker_lower = ticker.to_lowercase();
-                    if let Some(ticker_data) = Brc20Tickers::new().get(&ticker_lower) {
-                        if let Ok(ticker_entry) = serde_json::from_slice::<Ticker>(&ticker_data) {
-                            if ticker_entry.is_self_mint {
-                                // Self-mint mint requires parent inscription ID == deploy inscription ID
-                                let deploy_id = InscriptionId::from_str(&ticker_entry.deploy_inscription_id).ok();
-                                let has_valid_parent = match (&entry.parent, &deploy_id) {
-                                    (Some(parent), Some(deploy)) => parent == deploy,
-                                    _ => false,
-                                };
-                                if !has_valid_parent {
-                                    continue; // Skip: self-mint mint without valid parent
-                                }
-                            }
-                        }
+                let owner = Self::genesis_owner(tx, &entry.satpoint);
+                let outcome = if !Self::has_valid_self_mint_parent(&operation, entry.parent.as_ref()) {
+                    Outcome::Rejected(Rejection::InvalidSelfMintParent)
+                } else if let Some(owner) = &owner {
+                    let Ok(outcome) = self.process_operation(&operation, &inscription_id_str, owner) else { continue };
+                    outcome
+                } else {
+                    Outcome::Rejected(Rejection::InscribedAsFee)
+                };
+                if let (Outcome::Applied { amount }, Some(owner)) = (outcome, &owner) {
+                    let original_ticker = Self::original_ticker(&content_bytes);
+                    if let Some(event) = Self::inscribe_hash_event(&operation, &inscription_id_str, owner, original_ticker, amount, entry.parent.as_ref()) {
+                        record.hash(&event);
                     }
                 }
-
-                if let Some(first_output) = tx.output.get(0) {
-                    if let Some(address) = get_address_from_txout(first_output, network) {
-                        let _ = self.process_operation(&operation, &inscription_id_str, &address.to_string());
-                    }
+                match Self::inscribe_event(&operation, &inscription_id_str, owner.as_deref().unwrap_or_default(), height, outcome) {
+                    Some(event) => record.record(inscription_id, event),
+                    // Predeploys are not BRC-20 events
+                    None => record.counts.brc20_accepted += 1,
                 }
             }
         }
     }
 
-    fn process_brc20_transfers(&self, tx: &Transaction, network: Network, height: u32) {
-        // Check if inscription was spent as fee:
-        // An inscription is "sent as fee" when the total output value is less than
-        // the total input value and the inscription's output index doesn't exist.
-        // For simplicity, we detect this when there are no outputs or the inscription
-        // has no corresponding output.
-        let has_outputs = !tx.output.is_empty();
+    /// Owner an inscription was revealed to: the pkscript of the output its
+    /// genesis satpoint is in, OP_RETURN included as in OPI. `None` if it was
+    /// inscribed as fee.
+    fn genesis_owner(tx: &Transaction, satpoint: &SatPoint) -> Option<String> {
+        if satpoint.outpoint.is_null() {
+            return None;
+        }
+        tx.output.get(satpoint.outpoint.vout as usize).map(|output| ledger::pkscript_key(&output.script_pubkey))
+    }
+
+    /// Self-mint mints require a parent inscription equal to the deploy inscription
+    fn has_valid_self_mint_parent(operation: &Brc20Operation, parent: Option<&InscriptionId>) -> bool {
+        let Brc20Operation::Mint { ticker, .. } = operation else { return true };
+        let Some(ticker_entry) = BRC20_TICKERS.get(&ticker.to_lowercase()) else { return true };
+        if !ticker_entry.is_self_mint {
+            return true;
+        }
+        let deploy_id = InscriptionId::from_str(&ticker_entry.deploy_inscription_id).ok();
+        matches!((parent, &deploy_id), (Some(parent), Some(deploy)) if parent == deploy)
+    }
+
+    /// Event of an operation inscribed to `owner`; `None` for a predeploy
+    fn inscribe_event(operation: &Brc20Operation, inscription_id: &str, owner: &str, height: u32, outcome: Outcome) -> Option<Brc20Event> {
+        let (event_type, ticker, requested, decimals) = match operation {
+            Brc20Operation::Deploy { ticker, max_supply, decimals, .. } => ("deploy-inscribe", ticker, *max_supply, *decimals),
+            Brc20Operation::Mint { ticker, amount } => ("mint-inscribe", ticker, *amount, Self::ticker_decimals(ticker)),
+            Brc20Operation::Transfer { ticker, amount } => ("transfer-inscribe", ticker, *amount, Self::ticker_decimals(ticker)),
+            Brc20Operation::Predeploy { .. } => return None,
+        };
+        let (amount, rejection) = match outcome {
+            Outcome::Applied { amount } => (amount, ""),
+            Outcome::Rejected(rejection) => (requested, rejection.as_str()),
+        };
+        Some(Brc20Event {
+            event_type: event_type.to_string(),
+            ticker: ticker.to_lowercase(),
+            amount: format_amount(amount, decimals),
+            from: String::new(),
+            to: owner.to_string(),
+            inscription_id: inscription_id.to_string(),
+            block_height: height,
+            rejection: rejection.to_string(),
+        })
+    }
+
+    /// OPI v3 hash event of an operation applied on `amount`; `None` for a
+    /// predeploy
+    fn inscribe_hash_event(
+        operation: &Brc20Operation,
+        inscription_id: &str,
+        owner: &str,
+        original_ticker: Option<String>,
+        amount: u128,
+        parent: Option<&InscriptionId>,
+    ) -> Option<event_hash::Brc20Event> {
+        let inscription_id = inscription_id.to_string();
+        let owner = owner.to_string();
+        Some(match operation {
+            Brc20Operation::Deploy { ticker, max_supply, limit_per_mint, decimals, self_mint, .. } => event_hash::Brc20Event::DeployInscribe {
+                inscription_id,
+                deployer_pkscript: owner,
+                ticker_lowercase: ticker.clone(),
+                ticker_original: original_ticker.unwrap_or_else(|| ticker.clone()),
+                max_supply: format_amount(*max_supply, *decimals),
+                decimals: *decimals,
+                limit_per_mint: format_amount(*limit_per_mint, *decimals),
+                is_self_mint: *self_mint,
+            },
+            Brc20Operation::Mint { ticker, .. } => event_hash::Brc20Event::MintInscribe {
+                inscription_id,
+                minter_pkscript: owner,
+                ticker_lowercase: ticker.clone(),
+                ticker_original: original_ticker.unwrap_or_else(|| ticker.clone()),
+                amount: format_amount(amount, Self::ticker_decimals(ticker)),
+                parent_id: parent.map(|parent| parent.to_string()),
+            },
+            Brc20Operation::Transfer { ticker, .. } => event_hash::Brc20Event::TransferInscribe {
+                inscription_id,
+                source_pkscript: owner,
+                ticker_lowercase: ticker.clone(),
+                ticker_original: original_ticker.unwrap_or_else(|| ticker.clone()),
+                amount: format_amount(amount, Self::ticker_decimals(ticker)),
+            },
+            Brc20Operation::Predeploy { .. } => return None,
+        })
+    }
+
+    /// OPI v3 hash event of a transfer-transfer. An inscription sent as fee
+    /// has no recipient pkscript.
+    fn transfer_hash_event(transfer_info: &TransferInfo, destination: &TransferDestination, pkscript: &str, inscription_id: &InscriptionId) -> event_hash::Brc20Event {
+        let content = InscriptionContentTable::new().get(inscription_id).unwrap_or_default();
+        let sent_pkscript = match destination {
+            TransferDestination::SentAsFee => String::new(),
+            _ => pkscript.to_string(),
+        };
+        event_hash::Brc20Event::TransferTransfer {
+            inscription_id: inscription_id.to_string(),
+            source_pkscript: transfer_info.sender.clone(),
+            sent_pkscript,
+            ticker_lowercase: transfer_info.ticker.clone(),
+            ticker_original: Self::original_ticker(&content).unwrap_or_else(|| transfer_info.ticker.clone()),
+            amount: format_amount(transfer_info.amount, Self::ticker_decimals(&transfer_info.ticker)),
+        }
+    }
+
+    /// The `tick` of an inscription as written, before lowercasing. OPI event
+    /// hashes include it.
+    fn original_ticker(content: &[u8]) -> Option<String> {
+        let json: serde_json::Value = serde_json::from_slice(content).ok()?;
+        json.get("tick")?.as_str().map(str::to_string)
+    }
+
+    /// Event of a transfer inscription sent to `destination`, whose output
+    /// has `pkscript`
+    fn transfer_event(transfer_info: &TransferInfo, destination: &TransferDestination, pkscript: &str, inscription_id: &str, height: u32) -> Brc20Event {
+        let from = transfer_info.sender.clone();
+        let to = match destination {
+            TransferDestination::SentAsFee => from.clone(),
+            _ => pkscript.to_string(),
+        };
+        Brc20Event {
+            event_type: "transfer-transfer".to_string(),
+            ticker: transfer_info.ticker.clone(),
+            amount: format_amount(transfer_info.amount, Self::ticker_decimals(&transfer_info.ticker)),
+            from,
+            to,
+            inscription_id: inscription_id.to_string(),
+            block_height: height,
+            rejection: String::new(),
+        }
+    }
+
+    /// Decimals of a deployed ticker; 18 for one that was never deployed
+    fn ticker_decimals(ticker: &str) -> u8 {
+        BRC20_TICKERS.get(&ticker.to_lowercase()).map_or(18, |ticker| ticker.decimals)
+    }
 
+    fn process_brc20_transfers(&self, tx: &Transaction, network: Network, height: u32, record: &mut BlockRecord) {
+        let txid = tx.compute_txid();
         for input in &tx.input {
-            let outpoint_bytes = input.previous_output.txid.as_byte_array()
-                .iter().chain(input.previous_output.vout.to_le_bytes().iter()).copied().collect::<Vec<u8>>();
-            let inscription_sequences = OUTPOINT_TO_INSCRIPTIONS.select(&outpoint_bytes).get_list();
+            let inscription_sequences = OUTPOINT_TO_INSCRIPTIONS.get_list(&input.previous_output);
             if inscription_sequences.is_empty() { continue; }
 
-            for seq_bytes in inscription_sequences {
-                let entry_bytes = SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq_bytes).get();
-                if entry_bytes.is_empty() { continue; }
-                if let Ok(entry) = InscriptionEntry::from_bytes(&entry_bytes) {
+            for sequence in inscription_sequences {
+                // Where this transaction moved the inscription, as located by
+                // shrew-ord from the sat it is on
+                let Some(transfer) = Self::moved_by(sequence, txid) else { continue };
+                if let Some(entry) = entries::load_or_report(sequence, height) {
                     let inscription_id_str = entry.id.to_string();
 
                     // Double-claim prevention: check if inscription is still transferable
-                    let transfer_info_bytes = match Brc20TransferableInscriptions::new().get(&inscription_id_str) {
-                        Some(data) => data,
+                    let transfer_info = match BRC20_TRANSFERABLE_INSCRIPTIONS.get(&inscription_id_str) {
+                        Some(info) => info,
                         None => continue, // Already claimed or never existed
                     };
-                    let transfer_info = match serde_json::from_slice::<TransferInfo>(&transfer_info_bytes) {
-                        Ok(info) => info,
-                        Err(_) => continue,
-                    };
+                    // Transfers inscribed by an older version name the sender
+                    // by address; one on another network cannot be claimed
+                    let Ok(sender) = ledger::stored_owner_key(&transfer_info.sender) else { continue };
+                    let transfer_info = TransferInfo { sender, ..transfer_info };
 
                     // Delete transferable inscription FIRST to prevent double-claim
-                    Brc20TransferableInscriptions::new().delete(&inscription_id_str);
-
-                    if !has_outputs {
-                        // No outputs at all — sent as fee
-                        let _ = self.resolve_transfer(TransferDestination::SentAsFee, &transfer_info, height);
-                        continue;
-                    }
-
-                    if let Some(first_output) = tx.output.get(0) {
-                        let pkscript_hex = hex::encode(first_output.script_pubkey.as_bytes());
-
-                        // Classify destination from the output pkscript
-                        let destination = Self::classify_destination(&pkscript_hex, false);
-                        let _ = self.resolve_transfer(destination, &transfer_info, height);
-                    } else {
-                        // No first output (shouldn't happen if has_outputs, but handle gracefully)
-                        let _ = self.resolve_transfer(TransferDestination::SentAsFee, &transfer_info, height);
+                    BRC20_TRANSFERABLE_INSCRIPTIONS.delete(&inscription_id_str);
+                    BRC20_OWNER_TRANSFERABLES.delete(&transfer_info.sender, &inscription_id_str);
+
+                    let pkscript_hex = hex::encode(&transfer.owner_script);
+                    let destination = Self::classify_destination(&pkscript_hex, transfer.spent_as_fee);
+                    if self.resolve_transfer(destination.clone(), &transfer_info, height).is_ok() {
+                        record.hash(&Self::transfer_hash_event(&transfer_info, &destination, &pkscript_hex, &entry.id));
+                        let event = Self::transfer_event(&transfer_info, &destination, &pkscript_hex, &inscription_id_str, height);
+                        record.record(entry.id, event);
                     }
                 }
             }
         }
     }
 
+    /// The move of inscription `sequence` by transaction `txid`, if it moved
+    /// it: the output its sat landed in, or the fee
+    fn moved_by(sequence: u32, txid: Txid) -> Option<InscriptionTransfer> {
+        history::load(sequence).into_iter().rev().find(|transfer| transfer.txid == txid)
+    }
+
     /// Parse a BRC-20 amount string into 18-decimal fixed-point u128.
     ///
     /// Matches OPI `get_amount_value()`:
@@ -351,7 +574,7 @@ impl Brc20Indexer {
         if op == "predeploy" {
             let hash = json.get("hash")?.as_str()?.to_string();
             if hash.is_empty() { return None; }
-            if height < BRC20_PREDEPLOY_ACTIVATION_HEIGHT { return None; }
+            if !self.rules.allows_predeploy(height) { return None; }
             return Some(Brc20Operation::Predeploy { hash });
         }
 
@@ -362,15 +585,7 @@ impl Brc20Indexer {
         let ticker_byte_len = raw_ticker.as_bytes().len();
 
         // Height-based validation for extended tickers
-        match ticker_byte_len {
-            5 => {
-                if height < BRC20_SELF_MINT_ENABLE_HEIGHT { return None; }
-            }
-            6 => {
-                if height < BRC20_PROG_PHASE_ONE_HEIGHT { return None; }
-            }
-            _ => {} // 4-byte tickers always allowed
-        }
+        if !self.rules.allows_ticker(ticker_byte_len, height) { return None; }
 
         match op {
             "deploy" => {
@@ -434,8 +649,7 @@ impl Brc20Indexer {
             "mint" => {
                 let amt_str = json.get("amt")?.as_str()?;
                 // Look up ticker's decimals for precision validation
-                let ticker_decimals = Brc20Tickers::new().get(&ticker)
-                    .and_then(|d| serde_json::from_slice::<Ticker>(&d).ok())
+                let ticker_decimals = BRC20_TICKERS.get(&ticker)
                     .map(|t| t.decimals)
                     .unwrap_or(18); // If ticker not deployed yet, allow max (will fail later anyway)
                 let amount = Self::parse_amount(amt_str, ticker_decimals)?;
@@ -445,8 +659,7 @@ impl Brc20Indexer {
             "transfer" => {
                 let amt_str = json.get("amt")?.as_str()?;
                 // Look up ticker's decimals for precision validation
-                let ticker_decimals = Brc20Tickers::new().get(&ticker)
-                    .and_then(|d| serde_json::from_slice::<Ticker>(&d).ok())
+                let ticker_decimals = BRC20_TICKERS.get(&ticker)
                     .map(|t| t.decimals)
                     .unwrap_or(18);
                 let amount = Self::parse_amount(amt_str, ticker_decimals)?;
@@ -457,21 +670,24 @@ impl Brc20Indexer {
         }
     }
 
-    pub fn process_operation(&self, operation: &Brc20Operation, inscription_id: &str, owner: &str) -> Result<()> {
-        match operation {
+    /// Apply `operation` inscribed by `owner`, a ledger key (see [`ledger`]).
+    /// A valid operation that the current state does not allow is
+    /// [`Outcome::Rejected`] and changes nothing.
+    pub fn process_operation(&self, operation: &Brc20Operation, inscription_id: &str, owner: &str) -> Result<Outcome> {
+        ledger::migrate(owner);
+        let amount = match operation {
             Brc20Operation::Predeploy { hash } => {
                 // Store the predeploy inscription: maps inscription_id -> (hash, pkscript)
-                let predeploy_data = serde_json::to_vec(&PredeployInfo {
+                BRC20_PREDEPLOYS.set(inscription_id, &PredeployInfo {
                     hash: hash.clone(),
                     deployer_pkscript: owner.to_string(),
-                })?;
-                Brc20Predeploys::new().set(inscription_id, &predeploy_data);
+                });
+                0
             }
             Brc20Operation::Deploy { ticker, max_supply, limit_per_mint, decimals, self_mint, .. } => {
                 // Normalize ticker to lowercase for storage/lookup
                 let ticker = ticker.to_lowercase();
-                let tickers_table = Brc20Tickers::new();
-                if tickers_table.get(&ticker).is_some() { return Ok(()); }
+                if BRC20_TICKERS.contains(&ticker) { return Ok(Outcome::Rejected(Rejection::AlreadyDeployed)); }
                 let new_ticker = Ticker {
                     name: ticker.clone(), max_supply: *max_supply, current_supply: 0,
                     limit_per_mint: *limit_per_mint, decimals: *decimals,
@@ -479,54 +695,50 @@ impl Brc20Indexer {
                     is_self_mint: *self_mint,
                     burned_supply: 0,
                 };
-                tickers_table.set(&ticker, &serde_json::to_vec(&new_ticker)?);
+                BRC20_TICKERS.set(&ticker, &new_ticker);
+                *max_supply
             }
             Brc20Operation::Mint { ticker, amount } => {
                 let ticker = ticker.to_lowercase();
                 // Reject zero amount
-                if *amount == 0 { return Ok(()); }
-                let tickers_table = Brc20Tickers::new();
-                if let Some(ticker_data) = tickers_table.get(&ticker) {
-                    let mut ticker_entry: Ticker = serde_json::from_slice(&ticker_data)?;
-                    if *amount > ticker_entry.limit_per_mint { return Ok(()); }
-                    if ticker_entry.current_supply >= ticker_entry.max_supply { return Ok(()); }
-
-                    // Clamp amount to remaining supply (OPI partial mint behavior)
-                    let remaining = ticker_entry.max_supply - ticker_entry.current_supply;
-                    let mint_amount = (*amount).min(remaining);
-
-                    ticker_entry.current_supply += mint_amount;
-                    tickers_table.set(&ticker, &serde_json::to_vec(&ticker_entry)?);
-                    let balances_table = Brc20Balances::new();
-                    let mut balance = balances_table.get(owner, &ticker)
-                        .and_then(|d| serde_json::from_slice(&d).ok())
-                        .unwrap_or_else(|| Balance::new(ticker.clone()));
-                    balance.total_balance += mint_amount;
-                    balance.available_balance += mint_amount;
-                    balances_table.set(owner, &ticker, &serde_json::to_vec(&balance)?);
-                }
+                if *amount == 0 { return Ok(Outcome::Rejected(Rejection::ZeroAmount)); }
+                let Some(mut ticker_entry) = BRC20_TICKERS.get(&ticker) else {
+                    return Ok(Outcome::Rejected(Rejection::UnknownTicker));
+                };
+                if *amount > ticker_entry.limit_per_mint { return Ok(Outcome::Rejected(Rejection::MintLimitExceeded)); }
+                if ticker_entry.current_supply >= ticker_entry.max_supply { return Ok(Outcome::Rejected(Rejection::SupplyExhausted)); }
+
+                // Clamp amount to remaining supply (OPI partial mint behavior)
+                let remaining = ticker_entry.max_supply - ticker_entry.current_supply;
+                let mint_amount = (*amount).min(remaining);
+
+                ticker_entry.current_supply += mint_amount;
+                BRC20_TICKERS.set(&ticker, &ticker_entry);
+                let mut balance = BRC20_BALANCES.get(owner, &ticker)
+                    .unwrap_or_else(|| Balance::new(ticker.clone()));
+                balance.total_balance += mint_amount;
+                balance.available_balance += mint_amount;
+                ledger::set_balance(owner, &ticker, &balance);
+                mint_amount
             }
             Brc20Operation::Transfer { ticker, amount } => {
                 let ticker = ticker.to_lowercase();
                 // Reject zero amount
-                if *amount == 0 { return Ok(()); }
-                let balances_table = Brc20Balances::new();
-                let balance_data = match balances_table.get(owner, &ticker) {
-                    Some(data) => data,
-                    None => return Ok(()),
-                };
-                let mut balance: Balance = match serde_json::from_slice(&balance_data) {
-                    Ok(b) => b,
-                    Err(_) => return Ok(()),
+                if *amount == 0 { return Ok(Outcome::Rejected(Rejection::ZeroAmount)); }
+                let mut balance = match BRC20_BALANCES.get(owner, &ticker) {
+                    Some(balance) => balance,
+                    None => return Ok(Outcome::Rejected(Rejection::InsufficientBalance)),
                 };
-                if balance.available_balance < *amount { return Ok(()); }
+                if balance.available_balance < *amount { return Ok(Outcome::Rejected(Rejection::InsufficientBalance)); }
                 balance.available_balance -= amount;
-                balances_table.set(owner, &ticker, &serde_json::to_vec(&balance)?);
+                ledger::set_balance(owner, &ticker, &balance);
                 let transfer_info = TransferInfo { ticker: ticker.clone(), amount: *amount, sender: owner.to_string() };
-                Brc20TransferableInscriptions::new().set(inscription_id, &serde_json::to_vec(&transfer_info)?);
+                BRC20_TRANSFERABLE_INSCRIPTIONS.set(inscription_id, &transfer_info);
+                BRC20_OWNER_TRANSFERABLES.set(owner, inscription_id, &true);
+                *amount
             }
-        }
-        Ok(())
+        };
+        Ok(Outcome::Applied { amount })
     }
 
     /// Claim a transfer — simple wallet-to-wallet (backwards-compatible).
@@ -542,73 +754,67 @@ impl Brc20Indexer {
     /// - Brc20ProgDeposit: BRC20-PROG OP_RETURN, tokens deposited to prog module
     /// - SentAsFee: inscription spent as tx fee, tokens returned to sender
     pub fn resolve_transfer(&self, destination: TransferDestination, transfer_info: &TransferInfo, height: u32) -> Result<()> {
-        let balances_table = Brc20Balances::new();
-        let tickers_table = Brc20Tickers::new();
+        // Transfers inscribed by an older version name the sender by address
+        let transfer_info = &TransferInfo { sender: ledger::stored_owner_key(&transfer_info.sender)?, ..transfer_info.clone() };
         let ticker = &transfer_info.ticker;
+        BRC20_TRANSFER_COUNTS.increment(ticker);
+        ledger::migrate(&transfer_info.sender);
+        if let TransferDestination::Wallet(new_owner) = &destination {
+            ledger::migrate(new_owner);
+        }
 
         match destination {
             TransferDestination::Wallet(ref new_owner) => {
                 // Add to recipient's balance
-                let mut new_owner_balance = balances_table.get(new_owner, ticker)
-                    .and_then(|d| serde_json::from_slice(&d).ok())
+                let mut new_owner_balance = BRC20_BALANCES.get(new_owner, ticker)
                     .unwrap_or_else(|| Balance::new(ticker.clone()));
                 new_owner_balance.total_balance += transfer_info.amount;
                 new_owner_balance.available_balance += transfer_info.amount;
-                balances_table.set(new_owner, ticker, &serde_json::to_vec(&new_owner_balance)?);
+                ledger::set_balance(new_owner, ticker, &new_owner_balance);
 
                 // Deduct from sender's total_balance (available was already reduced at inscribe)
-                if let Some(sender_data) = balances_table.get(&transfer_info.sender, ticker) {
-                    let mut sender_balance: Balance = serde_json::from_slice(&sender_data)?;
+                if let Some(mut sender_balance) = BRC20_BALANCES.get(&transfer_info.sender, ticker) {
                     sender_balance.total_balance -= transfer_info.amount;
-                    balances_table.set(&transfer_info.sender, ticker, &serde_json::to_vec(&sender_balance)?);
+                    ledger::set_balance(&transfer_info.sender, ticker, &sender_balance);
                 }
             }
             TransferDestination::Burn => {
                 // OP_RETURN: reduce sender's total_balance, increment ticker's burned_supply
-                if let Some(sender_data) = balances_table.get(&transfer_info.sender, ticker) {
-                    let mut sender_balance: Balance = serde_json::from_slice(&sender_data)?;
+                if let Some(mut sender_balance) = BRC20_BALANCES.get(&transfer_info.sender, ticker) {
                     sender_balance.total_balance -= transfer_info.amount;
-                    balances_table.set(&transfer_info.sender, ticker, &serde_json::to_vec(&sender_balance)?);
+                    ledger::set_balance(&transfer_info.sender, ticker, &sender_balance);
                 }
-                if let Some(ticker_data) = tickers_table.get(ticker) {
-                    let mut ticker_entry: Ticker = serde_json::from_slice(&ticker_data)?;
+                if let Some(mut ticker_entry) = BRC20_TICKERS.get(ticker) {
                     ticker_entry.burned_supply += transfer_info.amount;
-                    tickers_table.set(ticker, &serde_json::to_vec(&ticker_entry)?);
+                    BRC20_TICKERS.set(ticker, &ticker_entry);
                 }
             }
             TransferDestination::Brc20ProgDeposit => {
                 // BRC20-PROG OP_RETURN: phase-gated deposit
                 // Before phase 1 or for tickers < 6 bytes before phase 2: treat as burn
-                let ticker_len = ticker.as_bytes().len();
-                let should_burn = height < BRC20_PROG_PHASE_ONE_HEIGHT
-                    || (ticker_len < 6 && height < BRC20_PROG_ALL_TICKERS_HEIGHT);
-
-                if should_burn {
+                if !self.rules.allows_prog_deposit(ticker.as_bytes().len(), height) {
                     // Burn: same as OP_RETURN
-                    if let Some(sender_data) = balances_table.get(&transfer_info.sender, ticker) {
-                        let mut sender_balance: Balance = serde_json::from_slice(&sender_data)?;
+                    if let Some(mut sender_balance) = BRC20_BALANCES.get(&transfer_info.sender, ticker) {
                         sender_balance.total_balance -= transfer_info.amount;
-                        balances_table.set(&transfer_info.sender, ticker, &serde_json::to_vec(&sender_balance)?);
+                        ledger::set_balance(&transfer_info.sender, ticker, &sender_balance);
                     }
-                    if let Some(ticker_data) = tickers_table.get(ticker) {
-                        let mut ticker_entry: Ticker = serde_json::from_slice(&ticker_data)?;
+                    if let Some(mut ticker_entry) = BRC20_TICKERS.get(ticker) {
                         ticker_entry.burned_supply += transfer_info.amount;
-                        tickers_table.set(ticker, &serde_json::to_vec(&ticker_entry)?);
+                        BRC20_TICKERS.set(ticker, &ticker_entry);
                     }
                 } else {
                     // Deposit to BRC20-PROG: move tokens to the prog address balance
-                    let mut prog_balance = balances_table.get(BRC20_PROG_OP_RETURN_PKSCRIPT, ticker)
-                        .and_then(|d| serde_json::from_slice(&d).ok())
+                    ledger::migrate(BRC20_PROG_OP_RETURN_PKSCRIPT);
+                    let mut prog_balance = BRC20_BALANCES.get(BRC20_PROG_OP_RETURN_PKSCRIPT, ticker)
                         .unwrap_or_else(|| Balance::new(ticker.clone()));
                     prog_balance.total_balance += transfer_info.amount;
                     prog_balance.available_balance += transfer_info.amount;
-                    balances_table.set(BRC20_PROG_OP_RETURN_PKSCRIPT, ticker, &serde_json::to_vec(&prog_balance)?);
+                    ledger::set_balance(BRC20_PROG_OP_RETURN_PKSCRIPT, ticker, &prog_balance);
 
                     // Deduct from sender
-                    if let Some(sender_data) = balances_table.get(&transfer_info.sender, ticker) {
-                        let mut sender_balance: Balance = serde_json::from_slice(&sender_data)?;
+                    if let Some(mut sender_balance) = BRC20_BALANCES.get(&transfer_info.sender, ticker) {
                         sender_balance.total_balance -= transfer_info.amount;
-                        balances_table.set(&transfer_info.sender, ticker, &serde_json::to_vec(&sender_balance)?);
+                        ledger::set_balance(&transfer_info.sender, ticker, &sender_balance);
                     }
 
                     // Record deposit event for prog indexer to process
@@ -621,10 +827,9 @@ impl Brc20Indexer {
             }
             TransferDestination::SentAsFee => {
                 // Inscription spent as fee: return tokens to sender's available_balance
-                if let Some(sender_data) = balances_table.get(&transfer_info.sender, ticker) {
-                    let mut sender_balance: Balance = serde_json::from_slice(&sender_data)?;
+                if let Some(mut sender_balance) = BRC20_BALANCES.get(&transfer_info.sender, ticker) {
                     sender_balance.available_balance += transfer_info.amount;
-                    balances_table.set(&transfer_info.sender, ticker, &serde_json::to_vec(&sender_balance)?);
+                    ledger::set_balance(&transfer_info.sender, ticker, &sender_balance);
                 }
                 // Note: total_balance is unchanged (it was never deducted at inscribe,
                 // only available_balance was reduced)
@@ -667,7 +872,7 @@ impl Brc20Indexer {
         predeploy_parent_id: Option<&str>,
         deploy_height: u32,
         predeploy_height: u32,
-    ) -> Result<()> {
+    ) -> Result<Outcome> {
         let (ticker, salt) = match operation {
             Brc20Operation::Deploy { ticker, salt, .. } => {
                 (ticker.clone(), salt.clone().ok_or_else(|| anyhow::anyhow!("6-byte deploy requires salt"))?)
@@ -685,9 +890,8 @@ impl Brc20Indexer {
         }
 
         // Fetch predeploy info
-        let predeploy_data = Brc20Predeploys::new().get(parent_id)
+        let predeploy_info = BRC20_PREDEPLOYS.get(parent_id)
             .ok_or_else(|| anyhow::anyhow!("Predeploy inscription not found: {}", parent_id))?;
-        let predeploy_info: PredeployInfo = serde_json::from_slice(&predeploy_data)?;
 
         // Rule 2: Hash verification
         let expected_hash = compute_predeploy_hash(&ticker, &salt, &predeploy_info.deployer_pkscript)
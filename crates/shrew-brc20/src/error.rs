@@ -0,0 +1,46 @@
+//! Errors of the BRC-20 views.
+
+use shrew_support::error::{CodedError, ErrorCode};
+use shrew_support::pagination::PaginationError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Brc20Error {
+    /// The request leaves out a field the view needs
+    #[error("request must specify {0}")]
+    MissingField(&'static str),
+    /// A request field is present but malformed
+    #[error("invalid {field}: {reason}")]
+    InvalidField { field: &'static str, reason: String },
+    /// The block has not been indexed yet
+    #[error("block {0} is not indexed")]
+    BlockNotIndexed(u32),
+    #[error("ticker {0:?} is not deployed")]
+    UnknownTicker(String),
+    #[error("inscription {0} is not indexed")]
+    UnknownInscription(String),
+    #[error(transparent)]
+    Pagination(#[from] PaginationError),
+    /// A stored value could not be encoded into the response
+    #[error("failed to encode response: {0}")]
+    Encode(#[from] serde_json::Error),
+}
+
+impl Brc20Error {
+    pub(crate) fn invalid_txid(error: impl std::fmt::Display) -> Self {
+        Brc20Error::InvalidField { field: "txid", reason: error.to_string() }
+    }
+}
+
+impl CodedError for Brc20Error {
+    fn code(&self) -> ErrorCode {
+        match self {
+            Brc20Error::MissingField(_) | Brc20Error::InvalidField { .. } | Brc20Error::Pagination(_) => {
+                ErrorCode::InvalidArgument
+            }
+            Brc20Error::BlockNotIndexed(_) | Brc20Error::UnknownTicker(_) | Brc20Error::UnknownInscription(_) => {
+                ErrorCode::NotFound
+            }
+            Brc20Error::Encode(_) => ErrorCode::Internal,
+        }
+    }
+}
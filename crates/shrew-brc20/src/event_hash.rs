@@ -3,6 +3,7 @@
 ///! Implements the OPI event hash format for verifying BRC-20 indexer state.
 ///! See: https://github.com/bestinslot-xyz/OPI#brc-20-indexer--api
 
+use serde::{Deserialize, Serialize};
 use sha2::{Sha256, Digest};
 
 const EVENT_SEPARATOR: char = '|';
@@ -93,6 +94,7 @@ impl Brc20Event {
 }
 
 /// Computes per-block and cumulative event hashes per OPI v3.
+#[derive(Default)]
 pub struct EventHasher {
     events: Vec<String>,
 }
@@ -125,6 +127,21 @@ impl EventHasher {
     }
 }
 
+/// Event hashes stored for an indexed block
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BlockEventHashes {
+    /// Hash of the block's events; empty if the block has none
+    pub block_hash: String,
+    /// Hash chaining the block hashes of every block up to this one
+    pub cumulative_hash: String,
+    /// Whether the chain reaches back to the activation height. A store
+    /// upgraded from a layout without event hashes, or one that skipped a
+    /// block, restarts the chain from an empty hash, so its cumulative hashes
+    /// differ from OPI's.
+    #[serde(default)]
+    pub complete: bool,
+}
+
 /// Format a fixed-point u128 amount (scaled by 10^18) as a decimal string
 /// with the given number of decimal places.
 ///
@@ -0,0 +1,92 @@
+//! Migration of BRC-20 state written with the original table layout.
+//!
+//! The original layout stored each ticker under its lowercase name without
+//! listing the names, and each pending transfer under its inscription id
+//! without indexing it by owner. [`migrate`] walks ord's inscriptions in the
+//! first block the indexer processes after the upgrade:
+//!
+//! - the deploy inscription of each stored ticker lists the ticker in
+//!   [`BRC20_TICKERS`], in deploy order. Their number is recorded in
+//!   [`BRC20_ORIGINAL_TICKERS`]: only those tickers can have balances under
+//!   the original keys (see [`ledger`](crate::ledger)).
+//! - each pending transfer is listed under its sender in
+//!   [`BRC20_OWNER_TRANSFERABLES`].
+//!
+//! The original layout recorded no per-inscription events, so a store that
+//! held tickers notes the upgrade height in [`BRC20_EVENTS_SINCE`]; transfer
+//! inscriptions revealed earlier have an unknown status once claimed.
+
+use crate::brc20::{Brc20Indexer, Ticker};
+use crate::ledger;
+use crate::tables::{
+    BRC20_EVENTS_SINCE, BRC20_LAYOUT, BRC20_ORIGINAL_TICKERS, BRC20_OWNER_TRANSFERABLES, BRC20_TICKERS,
+    BRC20_TRANSFERABLE_INSCRIPTIONS,
+};
+use shrew_ord::entries;
+use shrew_ord::tables::{InscriptionContentTable, GLOBAL_SEQUENCE_COUNTER};
+use shrew_support::inscription::InscriptionEntry;
+
+/// Layout version of the BRC-20 tables written by this indexer; 0 is the
+/// original layout
+pub const LAYOUT_VERSION: u8 = 1;
+
+/// Outcome of a layout migration
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LayoutMigrationReport {
+    pub from_version: u8,
+    pub to_version: u8,
+    /// Tickers added to the ticker list
+    pub tickers: u32,
+    /// Pending transfers listed under their sender
+    pub transferables: u32,
+}
+
+/// Layout version of the store; 0 means the original layout
+pub fn layout_version() -> u8 {
+    BRC20_LAYOUT.get().unwrap_or(0)
+}
+
+/// Bring BRC-20 state in the original layout up to date. Runs before the
+/// block's own operations, after ord has indexed the block.
+pub fn migrate(height: u32) -> Option<LayoutMigrationReport> {
+    let from_version = layout_version();
+    if from_version >= LAYOUT_VERSION {
+        return None;
+    }
+
+    let mut report = LayoutMigrationReport { from_version, to_version: LAYOUT_VERSION, ..Default::default() };
+    let max_sequence = GLOBAL_SEQUENCE_COUNTER.get().unwrap_or(0);
+    for sequence in 1..=max_sequence {
+        let Some(entry) = entries::load_or_report(sequence, height) else { continue };
+        if let Some((name, ticker)) = deployed_ticker(&entry) {
+            BRC20_TICKERS.set(&name, &ticker);
+            report.tickers += 1;
+        }
+        let inscription_id = entry.id.to_string();
+        let sender = BRC20_TRANSFERABLE_INSCRIPTIONS.get(&inscription_id)
+            .and_then(|transfer_info| ledger::stored_owner_key(&transfer_info.sender).ok());
+        if let Some(sender) = sender {
+            BRC20_OWNER_TRANSFERABLES.set(&sender, &inscription_id, &true);
+            report.transferables += 1;
+        }
+    }
+    BRC20_ORIGINAL_TICKERS.set(&(BRC20_TICKERS.keys().len() as u32));
+    if report.tickers > 0 {
+        BRC20_EVENTS_SINCE.set(&height);
+    }
+
+    BRC20_LAYOUT.set(&LAYOUT_VERSION);
+    Some(report)
+}
+
+/// The stored ticker `entry` is the deploy inscription of, by lowercase name
+fn deployed_ticker(entry: &InscriptionEntry) -> Option<(String, Ticker)> {
+    if !entry.content_type.as_deref().is_some_and(Brc20Indexer::is_valid_brc20_content_type) {
+        return None;
+    }
+    let content = InscriptionContentTable::new().get(&entry.id)?;
+    let json: serde_json::Value = serde_json::from_slice(&content).ok()?;
+    let name = json.get("tick")?.as_str()?.to_lowercase();
+    let ticker = BRC20_TICKERS.get(&name)?;
+    (ticker.deploy_inscription_id == entry.id.to_string()).then_some((name, ticker))
+}
@@ -0,0 +1,187 @@
+//! Owner keys of the BRC-20 ledger.
+//!
+//! Balances and transferable inscriptions are keyed by the owner's pkscript in
+//! lowercase hex, as in OPI, so every script can hold tokens whether or not it
+//! has an address. The original layout stored each balance under the raw key
+//! `{owner}:{ticker}` and listed neither owners nor keys. The owner was the
+//! mainnet address for deploys, mints and inscribed transfers, but the hex
+//! pkscript for received transfers and BRC20-prog deposits, so one owner can
+//! have rows under both. Those balances are found by trying both forms with
+//! every ticker deployed before the upgrade (see [`BRC20_ORIGINAL_TICKERS`]):
+//! [`migrate`] folds them into the pkscript key the first time the indexer
+//! touches the owner, and [`balance`] adds them up for owners not touched
+//! since.
+//!
+//! Every balance write goes through [`set_balance`], which keeps the holders
+//! of each ticker, the owners with a nonzero total balance, and their count.
+//! The original layout has no list of owners to backfill them from, so on a
+//! store upgraded from it the holder index only covers owners touched since
+//! the upgrade. Such a store must be reindexed for complete holders.
+
+use crate::brc20::{Balance, TransferInfo};
+use crate::error::Brc20Error;
+use crate::tables::{
+    BRC20_BALANCES, BRC20_HOLDERS, BRC20_HOLDER_COUNTS, BRC20_MIGRATED_OWNERS, BRC20_ORIGINAL_TICKERS,
+    BRC20_OWNER_TRANSFERABLES, BRC20_TICKERS, BRC20_TRANSFERABLE_INSCRIPTIONS, LEGACY_BRC20_BALANCES,
+};
+use bitcoin::address::NetworkUnchecked;
+use bitcoin::{Address, Network, Script, ScriptBuf};
+use shrew_support::constants::NETWORK;
+use std::str::FromStr;
+
+/// Ledger key of the owner of `script`
+pub fn pkscript_key(script: &Script) -> String {
+    hex::encode(script.as_bytes())
+}
+
+/// Ledger key of an owner given either as an address on the indexed network
+/// or as a hex pkscript
+pub fn owner_key(owner: &str) -> Result<String, Brc20Error> {
+    if let Ok(address) = Address::from_str(owner) {
+        return address_key(address);
+    }
+    match hex::decode(owner) {
+        Ok(script) if !script.is_empty() => Ok(hex::encode(script)),
+        _ => Err(Brc20Error::InvalidField {
+            field: "address",
+            reason: format!("{:?} is neither an address nor a hex pkscript", owner),
+        }),
+    }
+}
+
+/// Ledger key of an owner stored by an older version, which may be an address
+/// string. Anything else is already a ledger key and is returned unchanged.
+pub fn stored_owner_key(owner: &str) -> Result<String, Brc20Error> {
+    match Address::from_str(owner) {
+        Ok(address) => address_key(address),
+        Err(_) => Ok(owner.to_string()),
+    }
+}
+
+fn address_key(address: Address<NetworkUnchecked>) -> Result<String, Brc20Error> {
+    let address = address.require_network(NETWORK).map_err(|error| Brc20Error::InvalidField {
+        field: "address",
+        reason: error.to_string(),
+    })?;
+    Ok(pkscript_key(&address.script_pubkey()))
+}
+
+/// The address string the original layout used as the ledger key of `pkscript`
+fn legacy_key(pkscript: &str) -> Option<String> {
+    let script = ScriptBuf::from_bytes(hex::decode(pkscript).ok()?);
+    Address::from_script(&script, Network::Bitcoin).ok().map(|address| address.to_string())
+}
+
+/// Raw key of `ticker`'s balance under `legacy`, an owner address or pkscript
+fn legacy_balance_key(legacy: &str, ticker: &str) -> String {
+    format!("{}:{}", legacy, ticker)
+}
+
+/// Sum of `ticker`'s balances the original layout holds under `legacy_keys`
+fn legacy_balance(legacy_keys: &[String], ticker: &str) -> Option<Balance> {
+    legacy_keys.iter()
+        .filter_map(|legacy| LEGACY_BRC20_BALANCES.get(&legacy_balance_key(legacy, ticker)))
+        .reduce(|mut sum, balance| {
+            sum.total_balance += balance.total_balance;
+            sum.available_balance += balance.available_balance;
+            sum
+        })
+}
+
+/// Tickers that may have balances in the original layout
+fn original_tickers() -> Vec<String> {
+    let tickers = BRC20_TICKERS.keys();
+    match BRC20_ORIGINAL_TICKERS.get() {
+        Some(count) => tickers.into_iter().take(count as usize).collect(),
+        None => tickers,
+    }
+}
+
+/// The owners under which the original layout may still hold balances of
+/// `owner`: its address, if it has one, and its pkscript. Empty once they
+/// have been migrated.
+fn unmigrated_legacy_keys(owner: &str) -> Vec<String> {
+    if BRC20_MIGRATED_OWNERS.contains(owner) {
+        return Vec::new();
+    }
+    legacy_key(owner).into_iter().chain([owner.to_string()]).collect()
+}
+
+/// Move the balances the original layout keyed by `owner`'s address or
+/// pkscript onto `owner`. Call before reading or writing any of `owner`'s
+/// balances.
+pub fn migrate(owner: &str) {
+    let legacy_keys = unmigrated_legacy_keys(owner);
+    if legacy_keys.is_empty() {
+        return;
+    }
+    for ticker in original_tickers() {
+        let Some(legacy_balance) = legacy_balance(&legacy_keys, &ticker) else { continue };
+        let mut balance = BRC20_BALANCES.get(owner, &ticker).unwrap_or_else(|| Balance::new(ticker.clone()));
+        balance.total_balance += legacy_balance.total_balance;
+        balance.available_balance += legacy_balance.available_balance;
+        set_balance(owner, &ticker, &balance);
+        for legacy in &legacy_keys {
+            LEGACY_BRC20_BALANCES.delete(&legacy_balance_key(legacy, &ticker));
+        }
+    }
+    BRC20_MIGRATED_OWNERS.set(owner, &true);
+}
+
+/// Store `owner`'s balance in `ticker`, counting the owner as a holder of
+/// `ticker` while its total balance is nonzero
+pub fn set_balance(owner: &str, ticker: &str, balance: &Balance) {
+    let held = BRC20_BALANCES.get(owner, ticker).is_some_and(|previous| previous.total_balance > 0);
+    match (held, balance.total_balance > 0) {
+        (false, true) => { BRC20_HOLDER_COUNTS.increment(ticker); }
+        (true, false) => {
+            let holders = BRC20_HOLDER_COUNTS.get(ticker).unwrap_or(0);
+            BRC20_HOLDER_COUNTS.set(ticker, &holders.saturating_sub(1));
+        }
+        _ => {}
+    }
+    if balance.total_balance > 0 {
+        BRC20_HOLDERS.set(ticker, owner, &balance.total_balance);
+    } else {
+        BRC20_HOLDERS.delete(ticker, owner);
+    }
+    BRC20_BALANCES.set(owner, ticker, balance);
+}
+
+/// Balance of `owner` in `ticker`, including any not yet migrated
+pub fn balance(owner: &str, ticker: &str) -> Option<Balance> {
+    let current = BRC20_BALANCES.get(owner, ticker);
+    let legacy = legacy_balance(&unmigrated_legacy_keys(owner), &ticker.to_lowercase());
+    match (current, legacy) {
+        (Some(mut balance), Some(legacy)) => {
+            balance.total_balance += legacy.total_balance;
+            balance.available_balance += legacy.available_balance;
+            Some(balance)
+        }
+        (current, legacy) => current.or(legacy),
+    }
+}
+
+/// Every balance `owner` has had, including any not yet migrated, by ticker
+pub fn balances(owner: &str) -> Vec<Balance> {
+    let mut tickers = BRC20_BALANCES.keys(owner);
+    let legacy_keys = unmigrated_legacy_keys(owner);
+    if !legacy_keys.is_empty() {
+        tickers.extend(original_tickers().into_iter()
+            .filter(|ticker| legacy_keys.iter().any(|legacy| LEGACY_BRC20_BALANCES.contains(&legacy_balance_key(legacy, ticker)))));
+    }
+    tickers.sort();
+    tickers.dedup();
+    tickers.iter().filter_map(|ticker| balance(owner, ticker)).collect()
+}
+
+/// Unclaimed transfer inscriptions of `owner`, by inscription id, in the order
+/// they were listed
+pub fn transferables(owner: &str) -> Vec<(String, TransferInfo)> {
+    BRC20_OWNER_TRANSFERABLES.entries(owner).into_iter()
+        .filter_map(|(inscription_id, _)| {
+            let transfer_info = BRC20_TRANSFERABLE_INSCRIPTIONS.get(&inscription_id)?;
+            Some((inscription_id, transfer_info))
+        })
+        .collect()
+}
@@ -1,9 +1,13 @@
 #[cfg(feature = "entrypoint")]
-use bitcoin::{Block, consensus::deserialize};
-use serde_json::{from_slice, to_vec};
+use shrew_support::diagnostics;
+use shrew_support::wire::serve;
 
 pub mod brc20;
 pub mod event_hash;
+pub mod error;
+pub mod layout;
+pub mod ledger;
+pub mod rules;
 pub mod tables;
 pub mod view;
 
@@ -11,17 +15,18 @@ pub mod proto {
     include!(concat!(env!("OUT_DIR"), "/shrew_brc20.rs"));
 }
 
-pub use brc20::{Brc20Indexer, Brc20Operation, Ticker, Balance, TransferInfo, PredeployInfo, compute_predeploy_hash};
+pub use rules::Brc20Rules;
+pub use brc20::{Brc20Indexer, Brc20Operation, Outcome, Rejection, Ticker, Balance, TransferInfo, PredeployInfo, compute_predeploy_hash};
 
 
 #[cfg(feature = "entrypoint")]
 #[metashrew_core::main]
 fn main_logic(height: u32, block_data: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
-    if let Ok(block) = deserialize::<Block>(block_data) {
+    if let Some(block) = diagnostics::decode_block(height, block_data) {
         // First run inscription indexer
-        let mut inscription_indexer = shrew_ord::indexer::InscriptionIndexer::new();
-        let _ = inscription_indexer.load_state();
-        let _ = inscription_indexer.index_block(&block, height);
+        if !shrew_ord::indexer::InscriptionIndexer::index_or_report(&block, height) {
+            return Ok(());
+        }
 
         // Then process BRC20 operations from inscribed content
         let brc20_indexer = Brc20Indexer::new();
@@ -32,14 +37,47 @@ fn main_logic(height: u32, block_data: &[u8]) -> Result<(), Box<dyn std::error::
 
 #[metashrew_core::view]
 pub fn getbalance(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetBalanceRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_balance(&req)?)?)
+    serve(input, view::get_balance)
 }
 
 #[metashrew_core::view]
 pub fn getbrc20events(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetBrc20EventsRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_brc20_events(&req)?)?)
+    serve(input, view::get_brc20_events)
+}
+
+#[metashrew_core::view]
+pub fn geteventhash(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    serve(input, view::get_event_hash)
+}
+
+#[metashrew_core::view]
+pub fn getbrc20ticker(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    serve(input, view::get_brc20_ticker)
+}
+
+#[metashrew_core::view]
+pub fn getbrc20tickers(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    serve(input, view::get_brc20_tickers)
+}
+
+#[metashrew_core::view]
+pub fn getbrc20holders(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    serve(input, view::get_brc20_holders)
+}
+
+#[metashrew_core::view]
+pub fn getbrc20portfolio(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    serve(input, view::get_brc20_portfolio)
+}
+
+#[metashrew_core::view]
+pub fn getbrc20transfers(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    serve(input, view::get_brc20_transfers)
+}
+
+#[metashrew_core::view]
+pub fn getbrc20transferstatus(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    serve(input, view::get_brc20_transfer_status)
 }
 
 #[cfg(test)]
@@ -0,0 +1,100 @@
+//! Height-gated BRC-20 rules.
+//!
+//! Every activation height the indexer checks lives in [`Brc20Rules`], so the
+//! only difference between mainnet and regtest is which parameters the
+//! indexer is built with. The `regtest` feature selects the regtest ones.
+
+use crate::brc20::BRC20_PROG_ALL_TICKERS_HEIGHT;
+use bitcoin::Network;
+use shrew_support::constants::{
+    BRC20_ACTIVATION_HEIGHT, BRC20_PREDEPLOY_ACTIVATION_HEIGHT, BRC20_PROG_PHASE_ONE_HEIGHT,
+    BRC20_SELF_MINT_ENABLE_HEIGHT, NETWORK,
+};
+use shrew_support::inscription::{Charm, InscriptionEntry};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Brc20Rules {
+    /// First height whose inscriptions and transfers are indexed
+    pub activation_height: u32,
+    /// 5-byte self-mint tickers
+    pub self_mint_height: u32,
+    /// `predeploy` inscriptions
+    pub predeploy_height: u32,
+    /// 6-byte tickers, and BRC20-PROG deposits of them
+    pub prog_phase_one_height: u32,
+    /// BRC20-PROG deposits of every ticker; burned before
+    pub prog_all_tickers_height: u32,
+}
+
+impl Brc20Rules {
+    pub fn mainnet() -> Self {
+        Self {
+            activation_height: BRC20_ACTIVATION_HEIGHT,
+            self_mint_height: BRC20_SELF_MINT_ENABLE_HEIGHT,
+            predeploy_height: BRC20_PREDEPLOY_ACTIVATION_HEIGHT,
+            prog_phase_one_height: BRC20_PROG_PHASE_ONE_HEIGHT,
+            prog_all_tickers_height: BRC20_PROG_ALL_TICKERS_HEIGHT,
+        }
+    }
+
+    /// Every rule active from the genesis block
+    pub fn regtest() -> Self {
+        Self {
+            activation_height: 0,
+            self_mint_height: 0,
+            predeploy_height: 0,
+            prog_phase_one_height: 0,
+            prog_all_tickers_height: 0,
+        }
+    }
+
+    /// Rules of `network`. Only mainnet and regtest heights are known; other
+    /// networks use the mainnet ones.
+    pub fn for_network(network: Network) -> Self {
+        match network {
+            Network::Regtest => Self::regtest(),
+            _ => Self::mainnet(),
+        }
+    }
+
+    pub fn is_active(&self, height: u32) -> bool {
+        height >= self.activation_height
+    }
+
+    /// Whether a ticker of `length` bytes can be deployed, minted or
+    /// transferred at `height`
+    pub fn allows_ticker(&self, length: usize, height: u32) -> bool {
+        match length {
+            5 => height >= self.self_mint_height,
+            6 => height >= self.prog_phase_one_height,
+            _ => true,
+        }
+    }
+
+    pub fn allows_predeploy(&self, height: u32) -> bool {
+        height >= self.predeploy_height
+    }
+
+    /// Whether a BRC20-PROG deposit of a ticker of `length` bytes reaches the
+    /// module at `height` rather than being burned
+    pub fn allows_prog_deposit(&self, length: usize, height: u32) -> bool {
+        height >= self.prog_phase_one_height && (length >= 6 || height >= self.prog_all_tickers_height)
+    }
+
+    /// As in OPI, BRC-20 ignores cursed inscriptions even after the jubilee,
+    /// when ord stops numbering them negatively and marks them vindicated
+    /// instead.
+    pub fn is_cursed(&self, entry: &InscriptionEntry) -> bool {
+        entry.number < 0 || entry.has_charm(Charm::Vindicated)
+    }
+}
+
+impl Default for Brc20Rules {
+    fn default() -> Self {
+        if cfg!(feature = "regtest") {
+            Self::for_network(Network::Regtest)
+        } else {
+            Self::for_network(NETWORK)
+        }
+    }
+}
@@ -1,16 +1,49 @@
-use metashrew_core::index_pointer::IndexPointer;
-use metashrew_support::index_pointer::KeyValuePointer;
-use std::sync::Arc;
+use crate::brc20::{Balance, PredeployInfo, Ticker, TransferInfo};
+use crate::event_hash::BlockEventHashes;
+use crate::proto::Brc20Event;
+use shrew_support::inscription::InscriptionId;
+use shrew_support::table::{self, Json, PrefixTable, Slot, Table};
 
 lazy_static::lazy_static! {
-    pub static ref BRC20_TICKERS: IndexPointer = IndexPointer::from_keyword("/brc20/tickers/");
-    pub static ref BRC20_BALANCES: IndexPointer = IndexPointer::from_keyword("/brc20/balances/");
-    pub static ref BRC20_EVENTS: IndexPointer = IndexPointer::from_keyword("/brc20/events/");
-    pub static ref BRC20_TRANSFERABLE_INSCRIPTIONS: IndexPointer = IndexPointer::from_keyword("/brc20/transferable/");
+    /// Listed in deploy order. Tickers stored before the list existed are
+    /// listed by `layout::migrate`.
+    pub static ref BRC20_TICKERS: Table<table::Ticker, Json<Ticker>> = Table::indexed("/brc20/tickers/");
+    /// Ticker -> owner -> total balance, for owners with a nonzero total.
+    /// Kept by `ledger::set_balance`; complete only on a store indexed from
+    /// scratch.
+    pub static ref BRC20_HOLDERS: PrefixTable<table::Ticker, str, u128> = PrefixTable::new("/brc20/holders/");
+    /// Owners with a nonzero total balance, kept by `ledger::set_balance`
+    pub static ref BRC20_HOLDER_COUNTS: Table<table::Ticker, u64> = Table::new("/brc20/holder_count/");
+    /// Transfer inscriptions claimed, whatever their destination
+    pub static ref BRC20_TRANSFER_COUNTS: Table<table::Ticker, u64> = Table::new("/brc20/transfer_count/");
+    /// Owner -> ticker -> balance
+    pub static ref BRC20_BALANCES: PrefixTable<str, table::Ticker, Json<Balance>> = PrefixTable::new("/brc20/balances/");
+    /// `{address}:{ticker}` -> balance, as the original layout stored it under
+    /// the same keyword. Read and emptied by `ledger::migrate`.
+    pub static ref LEGACY_BRC20_BALANCES: Table<str, Json<Balance>> = Table::new("/brc20/balances/");
+    /// How many of the first `BRC20_TICKERS` keys were deployed before the
+    /// store left the original layout, set by `layout::migrate`. Unset, every
+    /// ticker is tried.
+    pub static ref BRC20_ORIGINAL_TICKERS: Slot<u32> = Slot::new("/brc20/original_ticker_count");
+    /// Owners whose balances in the original layout have been migrated
+    pub static ref BRC20_MIGRATED_OWNERS: Table<str, bool> = Table::new("/brc20/migrated_owners/");
+    pub static ref BRC20_INSCRIPTION_EVENTS: Table<InscriptionId, Json<Vec<Brc20Event>>> = Table::new("/brc20/events/inscription/");
+    pub static ref BRC20_BLOCK_EVENTS: Table<u32, Json<Vec<Brc20Event>>> = Table::new("/brc20/events/height/");
+    /// OPI v3 block and cumulative event hashes of every indexed block
+    pub static ref BRC20_EVENT_HASHES: Table<u32, Json<BlockEventHashes>> = Table::new("/brc20/event_hashes/");
+    /// Inscribed transfers awaiting their claim, keyed like the ledger by inscription id string
+    pub static ref BRC20_TRANSFERABLE_INSCRIPTIONS: Table<str, Json<TransferInfo>> = Table::new("/brc20/transferable/");
+    /// Owner -> inscription id of its unclaimed transfer inscriptions.
+    /// Transfers stored before the index existed are listed by `layout::migrate`.
+    pub static ref BRC20_OWNER_TRANSFERABLES: PrefixTable<str, str, bool> = PrefixTable::new("/brc20/transferable_by_owner/");
     /// Pending BRC20-PROG deposit events. Written by BRC-20 indexer, consumed by prog indexer.
-    /// Key: height (u32 LE), Value: JSON array of DepositEvent
-    pub static ref BRC20_PROG_PENDING_DEPOSITS: IndexPointer = IndexPointer::from_keyword("/brc20/prog_deposits/");
-    pub static ref BRC20_PREDEPLOYS: IndexPointer = IndexPointer::from_keyword("/brc20/predeploys/");
+    pub static ref BRC20_PROG_PENDING_DEPOSITS: Table<u32, Json<Vec<DepositEvent>>> = Table::new("/brc20/prog_deposits/");
+    pub static ref BRC20_PREDEPLOYS: Table<str, Json<PredeployInfo>> = Table::new("/brc20/predeploys/");
+    /// Layout version of the BRC-20 tables, see `layout`
+    pub static ref BRC20_LAYOUT: Slot<u8> = Slot::new("/brc20/layout");
+    /// First height whose BRC-20 events are recorded, on a store upgraded
+    /// from the original layout. Unset on a store indexed from scratch.
+    pub static ref BRC20_EVENTS_SINCE: Slot<u32> = Slot::new("/brc20/events_since");
 }
 
 /// A pending BRC20-PROG deposit event. Recorded when tokens are sent to the
@@ -29,112 +62,18 @@ impl Brc20ProgDeposits {
 
     /// Append a deposit event for the given block height.
     pub fn push(&self, height: u32, event: &DepositEvent) {
-        let key = height.to_le_bytes().to_vec();
-        let pointer = BRC20_PROG_PENDING_DEPOSITS.select(&key);
-        let existing = pointer.get();
-        let mut events: Vec<DepositEvent> = if existing.is_empty() {
-            Vec::new()
-        } else {
-            serde_json::from_slice(&existing).unwrap_or_default()
-        };
+        let mut events = self.get(height);
         events.push(event.clone());
-        let mut pointer = BRC20_PROG_PENDING_DEPOSITS.select(&key);
-        pointer.set(Arc::new(serde_json::to_vec(&events).unwrap_or_default()));
+        BRC20_PROG_PENDING_DEPOSITS.set(&height, &events);
     }
 
     /// Get all pending deposit events for a block height.
     pub fn get(&self, height: u32) -> Vec<DepositEvent> {
-        let key = height.to_le_bytes().to_vec();
-        let pointer = BRC20_PROG_PENDING_DEPOSITS.select(&key);
-        let result = pointer.get();
-        if result.is_empty() {
-            Vec::new()
-        } else {
-            serde_json::from_slice(&result).unwrap_or_default()
-        }
+        BRC20_PROG_PENDING_DEPOSITS.get(&height).unwrap_or_default()
     }
 
     /// Clear deposit events for a block height (after processing).
     pub fn clear(&self, height: u32) {
-        let key = height.to_le_bytes().to_vec();
-        let mut pointer = BRC20_PROG_PENDING_DEPOSITS.select(&key);
-        pointer.set(Arc::new(vec![]));
-    }
-}
-
-pub struct Brc20Tickers;
-pub struct Brc20Balances;
-pub struct Brc20EventsTable;
-pub struct Brc20Predeploys;
-pub struct Brc20TransferableInscriptions;
-
-impl Brc20Tickers {
-    pub fn new() -> Self { Self }
-    pub fn get(&self, ticker: &str) -> Option<Vec<u8>> {
-        let pointer = BRC20_TICKERS.select(&ticker.as_bytes().to_vec());
-        let result = pointer.get();
-        if result.is_empty() { None } else { Some((*result).clone()) }
-    }
-    pub fn set(&self, ticker: &str, data: &[u8]) {
-        let mut pointer = BRC20_TICKERS.select(&ticker.as_bytes().to_vec());
-        pointer.set(Arc::new(data.to_vec()));
-    }
-}
-
-impl Brc20Balances {
-    pub fn new() -> Self { Self }
-    pub fn get(&self, address: &str, ticker: &str) -> Option<Vec<u8>> {
-        let key = format!("{}:{}", address, ticker);
-        let pointer = BRC20_BALANCES.select(&key.as_bytes().to_vec());
-        let result = pointer.get();
-        if result.is_empty() { None } else { Some((*result).clone()) }
-    }
-    pub fn set(&self, address: &str, ticker: &str, data: &[u8]) {
-        let key = format!("{}:{}", address, ticker);
-        let mut pointer = BRC20_BALANCES.select(&key.as_bytes().to_vec());
-        pointer.set(Arc::new(data.to_vec()));
-    }
-}
-
-impl Brc20TransferableInscriptions {
-    pub fn new() -> Self { Self }
-    pub fn get(&self, inscription_id: &str) -> Option<Vec<u8>> {
-        let pointer = BRC20_TRANSFERABLE_INSCRIPTIONS.select(&inscription_id.as_bytes().to_vec());
-        let result = pointer.get();
-        if result.is_empty() { None } else { Some((*result).clone()) }
-    }
-    pub fn set(&self, inscription_id: &str, data: &[u8]) {
-        let mut pointer = BRC20_TRANSFERABLE_INSCRIPTIONS.select(&inscription_id.as_bytes().to_vec());
-        pointer.set(Arc::new(data.to_vec()));
-    }
-    pub fn delete(&self, inscription_id: &str) {
-        let mut pointer = BRC20_TRANSFERABLE_INSCRIPTIONS.select(&inscription_id.as_bytes().to_vec());
-        pointer.set(Arc::new(vec![]));
-    }
-}
-
-impl Brc20EventsTable {
-    pub fn new() -> Self { Self }
-    pub fn get(&self, tx_id: &str) -> Option<Vec<u8>> {
-        let pointer = BRC20_EVENTS.select(&tx_id.as_bytes().to_vec());
-        let result = pointer.get();
-        if result.is_empty() { None } else { Some((*result).clone()) }
-    }
-    pub fn set(&self, tx_id: &str, data: &[u8]) {
-        let mut pointer = BRC20_EVENTS.select(&tx_id.as_bytes().to_vec());
-        pointer.set(Arc::new(data.to_vec()));
-    }
-}
-
-impl Brc20Predeploys {
-    pub fn new() -> Self { Self }
-    pub fn get(&self, inscription_id: &str) -> Option<Vec<u8>> {
-        let pointer = BRC20_PREDEPLOYS.select(&inscription_id.as_bytes().to_vec());
-        let result = pointer.get();
-        if result.is_empty() { None } else { Some((*result).clone()) }
-    }
-    pub fn set(&self, inscription_id: &str, data: &[u8]) {
-        let mut pointer = BRC20_PREDEPLOYS.select(&inscription_id.as_bytes().to_vec());
-        pointer.set(Arc::new(data.to_vec()));
+        BRC20_PROG_PENDING_DEPOSITS.delete(&height);
     }
 }
@@ -6,7 +6,7 @@
 
 use wasm_bindgen_test::wasm_bindgen_test as test;
 use crate::brc20::{Brc20Indexer, Brc20Operation, Ticker, Balance, TransferInfo};
-use crate::tables::{Brc20Tickers, Brc20Balances, Brc20TransferableInscriptions};
+use crate::tables::{BRC20_TICKERS, BRC20_BALANCES, BRC20_TRANSFERABLE_INSCRIPTIONS};
 use shrew_test_helpers::state::clear;
 use shrew_test_helpers::assertions::{assert_brc20_balance, assert_brc20_supply};
 
@@ -331,3 +331,48 @@ fn test_format_deploy_self_mint_event() {
         "Self-mint deploy should have is_self_mint=true"
     );
 }
+
+// ============================================================================
+// TEST 11: Fixed vectors — first block, then an empty block
+// ============================================================================
+
+#[wasm_bindgen_test]
+fn test_event_hash_fixed_vectors() {
+    // No events: empty block hash, and the first cumulative hash is sha256("")
+    let empty = EventHasher::new().compute_block_hash();
+    assert_eq!(empty, "");
+    assert_eq!(
+        EventHasher::compute_cumulative_hash("", &empty),
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+
+    let mut hasher = EventHasher::new();
+    hasher.add_event(&Brc20Event::DeployInscribe {
+        inscription_id: format!("{}i0", "11".repeat(32)),
+        deployer_pkscript: format!("5120{}", "22".repeat(32)),
+        ticker_lowercase: "ordi".to_string(),
+        ticker_original: "ORDI".to_string(),
+        max_supply: "21000000.000000000000000000".to_string(),
+        decimals: 18,
+        limit_per_mint: "1000.000000000000000000".to_string(),
+        is_self_mint: false,
+    });
+    hasher.add_event(&Brc20Event::MintInscribe {
+        inscription_id: format!("{}i0", "33".repeat(32)),
+        minter_pkscript: format!("0014{}", "44".repeat(20)),
+        ticker_lowercase: "ordi".to_string(),
+        ticker_original: "ordi".to_string(),
+        amount: "1000.000000000000000000".to_string(),
+        parent_id: None,
+    });
+    let block_hash = hasher.compute_block_hash();
+    assert_eq!(block_hash, "08d42e46b58721ae78722e400ee71f8005b5208fc8deb5402cfb41b50bb135bc");
+
+    // First block with events, then an empty block chained onto it
+    let first = EventHasher::compute_cumulative_hash("", &block_hash);
+    assert_eq!(first, "8c02c976cb9eae41680e341af87b17745a67ff19ae28c9bc6d9503d42b675882");
+    assert_eq!(
+        EventHasher::compute_cumulative_hash(&first, &empty),
+        "ce0af61c97129615c7ef6be78bb84bcd2b46f8c4c8a74e4d437e946680759171"
+    );
+}
@@ -0,0 +1,160 @@
+use wasm_bindgen_test::wasm_bindgen_test as test;
+use crate::ledger;
+use crate::error::Brc20Error;
+use crate::proto::{get_brc20_events_request, GetBrc20EventsRequest, GetEventHashRequest, InscriptionId as ProtoInscriptionId};
+use crate::tables::{BRC20_BLOCK_EVENTS, BRC20_INSCRIPTION_EVENTS};
+use crate::view;
+use bitcoin_hashes::Hash;
+use sha2::{Digest, Sha256};
+use shrew_support::diagnostics;
+use shrew_support::InscriptionId;
+use shrew_test_helpers::blocks::*;
+use shrew_test_helpers::brc20::*;
+use shrew_test_helpers::indexing::index_ord_block;
+use shrew_test_helpers::state::{clear, get_test_address};
+use shrew_test_helpers::transactions::create_mock_outpoint;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn sha256_hex(input: &str) -> String {
+    format!("{:x}", Sha256::digest(input.as_bytes()))
+}
+
+fn mint(ticker: &str, amount: &str, owner: &bitcoin::Address, commit: u32) -> bitcoin::Transaction {
+    inscribe("mint", ticker, &[("amt", amount)], owner, commit)
+}
+
+#[wasm_bindgen_test]
+fn test_events_are_recorded_per_block_in_order() {
+    clear();
+    let alice = get_test_address(1);
+    let alice_key = ledger::pkscript_key(&alice.script_pubkey());
+
+    let (deploy_block, deploy_tx) = create_brc20_deploy_block("evnt", "1000", "100");
+    index_block(&deploy_block, 0);
+
+    let block = create_block_with_txs(vec![
+        create_coinbase_transaction(1),
+        mint("evnt", "60", &alice, 1),
+        mint("evnt", "200", &alice, 2),
+        mint("none", "1", &alice, 3),
+    ]);
+    index_block(&block, 1);
+
+    let deploy = BRC20_BLOCK_EVENTS.get(&0).unwrap();
+    assert_eq!(deploy.len(), 1);
+    assert_eq!(deploy[0].event_type, "deploy-inscribe");
+    assert_eq!(deploy[0].amount, "1000.000000000000000000");
+    assert_eq!(deploy[0].inscription_id, InscriptionId::new(deploy_tx.compute_txid(), 0).to_string());
+
+    let events = BRC20_BLOCK_EVENTS.get(&1).unwrap();
+    let summary: Vec<(&str, &str, &str)> = events.iter()
+        .map(|event| (event.ticker.as_str(), event.amount.as_str(), event.rejection.as_str()))
+        .collect();
+    assert_eq!(summary, vec![
+        ("evnt", "60.000000000000000000", ""),
+        ("evnt", "200.000000000000000000", "mint_limit_exceeded"),
+        ("none", "1.000000000000000000", "unknown_ticker"),
+    ]);
+    assert!(events.iter().all(|event| event.event_type == "mint-inscribe" && event.to == alice_key && event.block_height == 1));
+
+    let counts = diagnostics::block(1);
+    assert_eq!(counts.brc20_accepted, 1);
+    assert_eq!(counts.brc20_rejected.get("mint_limit_exceeded"), Some(&1));
+
+    // The view pages through the same events
+    let response = view::get_brc20_events(&GetBrc20EventsRequest {
+        query: Some(get_brc20_events_request::Query::BlockHeight(1)),
+        pagination: None,
+    }).unwrap();
+    assert_eq!(response.events, events);
+}
+
+#[wasm_bindgen_test]
+fn test_transfer_events_are_recorded_per_inscription() {
+    clear();
+    let alice = get_test_address(1);
+    let bob = get_test_address(2);
+    let alice_key = ledger::pkscript_key(&alice.script_pubkey());
+    let bob_key = ledger::pkscript_key(&bob.script_pubkey());
+
+    let (deploy_block, _) = create_brc20_deploy_block("evnt", "1000", "100");
+    index_block(&deploy_block, 0);
+    index_block(&create_block_with_txs(vec![create_coinbase_transaction(1), mint("evnt", "100", &alice, 1)]), 1);
+    let (inscribe_block, inscribe_tx) = create_brc20_transfer_inscribe_block("evnt", "40", &alice, &create_mock_outpoint(2).txid);
+    index_block(&inscribe_block, 2);
+    let (claim_block, _) = create_brc20_transfer_claim_block(&inscribe_tx, &bob);
+    index_block(&claim_block, 3);
+
+    let inscription_id = InscriptionId::new(inscribe_tx.compute_txid(), 0);
+    let events = BRC20_INSCRIPTION_EVENTS.get(&inscription_id).unwrap();
+    assert_eq!(events.len(), 2);
+    assert_eq!((events[0].event_type.as_str(), events[0].to.as_str(), events[0].block_height), ("transfer-inscribe", alice_key.as_str(), 2));
+    assert_eq!(events[1].event_type, "transfer-transfer");
+    assert_eq!(events[1].from, alice_key);
+    assert_eq!(events[1].to, bob_key);
+    assert_eq!(events[1].amount, "40.000000000000000000");
+    assert_eq!(events[1].block_height, 3);
+    assert_eq!(BRC20_BLOCK_EVENTS.get(&3).unwrap(), vec![events[1].clone()]);
+
+    let response = view::get_brc20_events(&GetBrc20EventsRequest {
+        query: Some(get_brc20_events_request::Query::InscriptionId(ProtoInscriptionId {
+            txid: inscribe_tx.compute_txid().to_byte_array().to_vec(),
+            index: 0,
+        })),
+        pagination: None,
+    }).unwrap();
+    assert_eq!(response.events, events);
+}
+
+#[wasm_bindgen_test]
+fn test_event_hashes_chain_applied_events() {
+    clear();
+    let alice = get_test_address(1);
+    let alice_key = ledger::pkscript_key(&alice.script_pubkey());
+
+    let (deploy_block, deploy_tx) = create_brc20_deploy_block("EvNt", "1000", "100");
+    index_block(&deploy_block, 0);
+    let mint_tx = mint("EVNT", "60", &alice, 1);
+    let block = create_block_with_txs(vec![create_coinbase_transaction(1), mint_tx.clone(), mint("evnt", "200", &alice, 2)]);
+    index_block(&block, 1);
+    index_block(&create_block_with_coinbase_tx(2), 2);
+
+    // Rejected operations are stored as events but not hashed
+    let deploy_event = format!(
+        "deploy-inscribe;{}i0;{};evnt;EvNt;1000.000000000000000000;18;100.000000000000000000;false",
+        deploy_tx.compute_txid(), ledger::pkscript_key(&deploy_tx.output[0].script_pubkey),
+    );
+    let mint_event = format!("mint-inscribe;{}i0;{};evnt;EVNT;60.000000000000000000;", mint_tx.compute_txid(), alice_key);
+    let block_hashes = [sha256_hex(&deploy_event), sha256_hex(&mint_event), String::new()];
+    let mut cumulative = String::new();
+    for (height, block_hash) in block_hashes.into_iter().enumerate() {
+        cumulative = sha256_hex(&format!("{}{}", cumulative, block_hash));
+        let response = view::get_event_hash(&GetEventHashRequest { height: height as u32 }).unwrap();
+        assert_eq!(response.block_hash, block_hash);
+        assert_eq!(response.cumulative_hash, cumulative);
+        assert!(response.complete);
+    }
+
+    assert!(matches!(view::get_event_hash(&GetEventHashRequest { height: 3 }), Err(Brc20Error::BlockNotIndexed(3))));
+}
+
+#[wasm_bindgen_test]
+fn test_event_hash_chain_is_incomplete_after_a_missing_block() {
+    // An empty activation block chains from the empty hash: sha256("")
+    clear();
+    index_block(&create_block_with_coinbase_tx(0), 0);
+    let first = view::get_event_hash(&GetEventHashRequest { height: 0 }).unwrap();
+    assert_eq!(first.block_hash, "");
+    assert_eq!(first.cumulative_hash, "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+    assert!(first.complete);
+
+    // A store upgraded from a layout without event hashes has none for the
+    // blocks before the upgrade, so its chain cannot match OPI's
+    clear();
+    index_ord_block(&create_block_with_coinbase_tx(0), 0).unwrap();
+    index_block(&create_block_with_coinbase_tx(1), 1);
+    index_block(&create_block_with_coinbase_tx(2), 2);
+    for height in [1, 2] {
+        assert!(!view::get_event_hash(&GetEventHashRequest { height }).unwrap().complete);
+    }
+}
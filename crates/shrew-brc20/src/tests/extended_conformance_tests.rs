@@ -5,8 +5,8 @@
 ///! Organized by category: parsing, amounts, deploy, mint, transfer, claim.
 
 use wasm_bindgen_test::wasm_bindgen_test as test;
-use crate::brc20::{Brc20Indexer, Brc20Operation, Ticker, Balance, TransferInfo, MAX_AMOUNT};
-use crate::tables::{Brc20Tickers, Brc20Balances, Brc20TransferableInscriptions};
+use crate::brc20::{Brc20Indexer, Brc20Operation, TransferInfo, MAX_AMOUNT};
+use crate::tables::{BRC20_TICKERS, BRC20_BALANCES, BRC20_TRANSFERABLE_INSCRIPTIONS};
 use shrew_test_helpers::state::clear;
 use shrew_test_helpers::assertions::{assert_brc20_balance, assert_brc20_supply};
 
@@ -584,7 +584,7 @@ fn test_mint_after_supply_exhausted_rejected() {
     indexer.process_operation(&mint2, "mint2_0i0", "bc1qb").unwrap();
 
     assert_brc20_supply("ordi", 100 * SCALE);
-    let balance = Brc20Balances::new().get("bc1qb", "ordi");
+    let balance = BRC20_BALANCES.get("bc1qb", "ordi");
     assert!(balance.is_none(), "No balance should exist after supply exhausted");
 }
 
@@ -634,7 +634,7 @@ fn test_transfer_exact_available_balance() {
     indexer.process_operation(&transfer, "xfer_0i0", "bc1qsender").unwrap();
 
     assert_brc20_balance("bc1qsender", "ordi", 0, 1000 * SCALE);
-    let transferable = Brc20TransferableInscriptions::new().get("xfer_0i0");
+    let transferable = BRC20_TRANSFERABLE_INSCRIPTIONS.get("xfer_0i0");
     assert!(transferable.is_some(), "Transfer of exact available balance should succeed");
 }
 
@@ -659,7 +659,7 @@ fn test_transfer_one_over_available_rejected() {
     indexer.process_operation(&transfer, "xfer_0i0", "bc1qsender").unwrap();
 
     assert_brc20_balance("bc1qsender", "ordi", 1000 * SCALE, 1000 * SCALE);
-    let transferable = Brc20TransferableInscriptions::new().get("xfer_0i0");
+    let transferable = BRC20_TRANSFERABLE_INSCRIPTIONS.get("xfer_0i0");
     assert!(transferable.is_none(), "Transfer over available should be rejected");
 }
 
@@ -681,7 +681,7 @@ fn test_transfer_no_balance_ignored() {
     let transfer = Brc20Operation::Transfer { ticker: "ordi".to_string(), amount: 100 * SCALE };
     indexer.process_operation(&transfer, "xfer_0i0", "bc1qnobalance").unwrap();
 
-    let transferable = Brc20TransferableInscriptions::new().get("xfer_0i0");
+    let transferable = BRC20_TRANSFERABLE_INSCRIPTIONS.get("xfer_0i0");
     assert!(transferable.is_none(), "Transfer with no balance should be ignored");
 }
 
@@ -694,7 +694,7 @@ fn test_transfer_nonexistent_ticker_ignored() {
     let transfer = Brc20Operation::Transfer { ticker: "fake".to_string(), amount: 100 * SCALE };
     indexer.process_operation(&transfer, "xfer_0i0", "bc1qsender").unwrap();
 
-    let transferable = Brc20TransferableInscriptions::new().get("xfer_0i0");
+    let transferable = BRC20_TRANSFERABLE_INSCRIPTIONS.get("xfer_0i0");
     assert!(transferable.is_none(), "Transfer of undeployed ticker should be ignored");
 }
 
@@ -725,8 +725,8 @@ fn test_multiple_transfers_before_claim() {
     assert_brc20_balance("bc1qsender", "ordi", 300 * SCALE, 1000 * SCALE);
 
     // Both transferable inscriptions should exist
-    assert!(Brc20TransferableInscriptions::new().get("xfer1_0i0").is_some());
-    assert!(Brc20TransferableInscriptions::new().get("xfer2_0i0").is_some());
+    assert!(BRC20_TRANSFERABLE_INSCRIPTIONS.get("xfer1_0i0").is_some());
+    assert!(BRC20_TRANSFERABLE_INSCRIPTIONS.get("xfer2_0i0").is_some());
 }
 
 #[test]
@@ -754,7 +754,7 @@ fn test_transfer_exceeds_available_after_pending() {
     indexer.process_operation(&xfer2, "xfer2_0i0", "bc1qsender").unwrap();
 
     assert_brc20_balance("bc1qsender", "ordi", 200 * SCALE, 1000 * SCALE);
-    assert!(Brc20TransferableInscriptions::new().get("xfer2_0i0").is_none(),
+    assert!(BRC20_TRANSFERABLE_INSCRIPTIONS.get("xfer2_0i0").is_none(),
         "Second transfer exceeding available should be rejected");
 }
 
@@ -975,8 +975,7 @@ fn test_deploy_duplicate_ticker_ignored() {
     };
     indexer.process_operation(&deploy2, "second_0i0", "bc1qb").unwrap();
 
-    let data = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").unwrap();
     assert_eq!(ticker.max_supply, 21_000_000 * SCALE, "First deploy should win");
     assert_eq!(ticker.decimals, 18, "First deploy's decimals should persist");
     assert_eq!(ticker.deploy_inscription_id, "first_0i0");
@@ -1009,8 +1008,7 @@ fn test_deploy_case_insensitive_dedup_via_process() {
     };
     indexer.process_operation(&deploy2, "second_0i0", "bc1qb").unwrap();
 
-    let data = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").unwrap();
     assert_eq!(ticker.max_supply, 21_000_000 * SCALE);
 }
 
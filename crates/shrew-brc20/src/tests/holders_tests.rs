@@ -0,0 +1,111 @@
+use wasm_bindgen_test::wasm_bindgen_test as test;
+use crate::error::Brc20Error;
+use crate::ledger;
+use crate::proto::{GetBrc20HoldersRequest, GetBrc20TickerRequest};
+use crate::tables::BRC20_HOLDERS;
+use crate::view;
+use bitcoin::{Address, Transaction};
+use shrew_support::constants::NETWORK;
+use shrew_support::pagination::PaginationRequest;
+use shrew_test_helpers::blocks::*;
+use shrew_test_helpers::brc20::*;
+use shrew_test_helpers::state::{clear, get_test_address};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn inscribe_hold(op: &str, amount: &str, owner: &Address, commit: u32) -> Transaction {
+    let fields: &[(&str, &str)] = match op {
+        "deploy" => &[("max", "21000"), ("lim", "1000")],
+        _ => &[("amt", amount)],
+    };
+    inscribe(op, "hold", fields, owner, commit)
+}
+
+fn pkscript(address: &Address) -> String {
+    ledger::pkscript_key(&address.script_pubkey())
+}
+
+fn holders(pagination: Option<PaginationRequest>) -> Vec<(String, String, String)> {
+    let request = GetBrc20HoldersRequest { ticker: "HOLD".to_string(), pagination };
+    view::get_brc20_holders(&request).unwrap().holders.into_iter()
+        .map(|holder| (holder.owner, holder.total_balance, holder.available_balance))
+        .collect()
+}
+
+#[wasm_bindgen_test]
+fn test_holders_are_ordered_by_total_balance() {
+    clear();
+    let (alice, bob, carol) = (get_test_address(1), get_test_address(2), get_test_address(3));
+    index_block(&create_block_with_txs(vec![create_coinbase_transaction(0), inscribe_hold("deploy", "", &alice, 20)]), 0);
+    index_block(&create_block_with_txs(vec![
+        create_coinbase_transaction(1),
+        inscribe_hold("mint", "300", &alice, 1),
+        inscribe_hold("mint", "500", &bob, 2),
+        inscribe_hold("mint", "100", &carol, 3),
+    ]), 1);
+    // carol sends everything to bob; alice's inscribed transfer keeps her total
+    let carol_transfer = inscribe_hold("transfer", "100", &carol, 4);
+    index_block(&create_block_with_txs(vec![
+        create_coinbase_transaction(2),
+        carol_transfer.clone(),
+        inscribe_hold("transfer", "100", &alice, 5),
+    ]), 2);
+    let (claim_block, _) = create_brc20_transfer_claim_block(&carol_transfer, &bob);
+    index_block(&claim_block, 3);
+
+    assert_eq!(holders(None), [
+        (pkscript(&bob), "600.000000000000000000".to_string(), "600.000000000000000000".to_string()),
+        (pkscript(&alice), "300.000000000000000000".to_string(), "200.000000000000000000".to_string()),
+    ]);
+    assert!(!BRC20_HOLDERS.contains("hold", &pkscript(&carol)));
+    let ticker = view::get_brc20_ticker(&GetBrc20TickerRequest { ticker: "hold".to_string() }).unwrap().ticker.unwrap();
+    assert_eq!(ticker.holders, 2);
+
+    let request = GetBrc20HoldersRequest {
+        ticker: "hold".to_string(),
+        pagination: Some(PaginationRequest { limit: 1, page: 1, ..Default::default() }),
+    };
+    let response = view::get_brc20_holders(&request).unwrap();
+    let holder = &response.holders[0];
+    assert_eq!(holder.owner, pkscript(&alice));
+    let address = Address::from_script(&alice.script_pubkey(), NETWORK).unwrap();
+    assert_eq!(holder.address, address.to_string());
+    let pagination = response.pagination.unwrap();
+    assert_eq!((response.holders.len(), pagination.total, pagination.more), (1, 2, false));
+}
+
+#[wasm_bindgen_test]
+fn test_holder_pages_resume_after_the_last_holder() {
+    clear();
+    let (alice, bob, dave) = (get_test_address(1), get_test_address(2), get_test_address(4));
+    index_block(&create_block_with_txs(vec![create_coinbase_transaction(0), inscribe_hold("deploy", "", &alice, 20)]), 0);
+    index_block(&create_block_with_txs(vec![
+        create_coinbase_transaction(1),
+        inscribe_hold("mint", "300", &alice, 1),
+        inscribe_hold("mint", "500", &bob, 2),
+    ]), 1);
+
+    let first = view::get_brc20_holders(&GetBrc20HoldersRequest {
+        ticker: "hold".to_string(),
+        pagination: Some(PaginationRequest { limit: 1, ..Default::default() }),
+    }).unwrap();
+    assert_eq!(first.holders[0].owner, pkscript(&bob));
+    let cursor = first.pagination.unwrap().next_cursor;
+    assert!(!cursor.is_empty());
+
+    // A holder ranked above the cursor does not shift the next page
+    index_block(&create_block_with_txs(vec![create_coinbase_transaction(2), inscribe_hold("mint", "1000", &dave, 3)]), 2);
+    let second = view::get_brc20_holders(&GetBrc20HoldersRequest {
+        ticker: "hold".to_string(),
+        pagination: Some(PaginationRequest { limit: 1, cursor, ..Default::default() }),
+    }).unwrap();
+    assert_eq!(second.holders[0].owner, pkscript(&alice));
+    let pagination = second.pagination.unwrap();
+    assert_eq!((pagination.total, pagination.more), (3, false));
+}
+
+#[wasm_bindgen_test]
+fn test_holders_of_unknown_ticker_is_not_found() {
+    clear();
+    let request = GetBrc20HoldersRequest { ticker: "none".to_string(), pagination: None };
+    assert!(matches!(view::get_brc20_holders(&request), Err(Brc20Error::UnknownTicker(_))));
+}
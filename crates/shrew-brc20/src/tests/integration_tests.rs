@@ -12,11 +12,11 @@
 
 use wasm_bindgen_test::wasm_bindgen_test as test;
 use crate::brc20::{
-    Brc20Indexer, Brc20Operation, Ticker, Balance, TransferInfo,
+    Brc20Indexer, Brc20Operation, TransferInfo,
     TransferDestination, MAX_AMOUNT,
     OP_RETURN_PKSCRIPT, BRC20_PROG_OP_RETURN_PKSCRIPT,
 };
-use crate::tables::{Brc20Tickers, Brc20Balances, Brc20TransferableInscriptions};
+use crate::tables::{BRC20_TICKERS, BRC20_BALANCES, BRC20_TRANSFERABLE_INSCRIPTIONS};
 use shrew_test_helpers::state::clear;
 use shrew_test_helpers::assertions::{assert_brc20_balance, assert_brc20_supply};
 
@@ -118,7 +118,7 @@ fn test_double_claim_prevented_via_delete() {
     indexer.process_operation(&transfer, "xfer_0i0", "bc1qsender").unwrap();
 
     // Verify transfer inscription exists
-    assert!(Brc20TransferableInscriptions::new().get("xfer_0i0").is_some());
+    assert!(BRC20_TRANSFERABLE_INSCRIPTIONS.get("xfer_0i0").is_some());
 
     // First claim
     let info = TransferInfo {
@@ -129,15 +129,15 @@ fn test_double_claim_prevented_via_delete() {
     indexer.claim_transfer("bc1qbob", &info).unwrap();
 
     // Delete the transferable inscription (as process_brc20_transfers does)
-    Brc20TransferableInscriptions::new().delete("xfer_0i0");
+    BRC20_TRANSFERABLE_INSCRIPTIONS.delete("xfer_0i0");
 
     // Verify it's gone
-    assert!(Brc20TransferableInscriptions::new().get("xfer_0i0").is_none(),
+    assert!(BRC20_TRANSFERABLE_INSCRIPTIONS.get("xfer_0i0").is_none(),
         "Transferable inscription should be deleted after claim");
 
     // Attempting to get the transfer info again would return None
     // This is how process_brc20_transfers prevents double-claim
-    let second_lookup = Brc20TransferableInscriptions::new().get("xfer_0i0");
+    let second_lookup = BRC20_TRANSFERABLE_INSCRIPTIONS.get("xfer_0i0");
     assert!(second_lookup.is_none(), "Second lookup should return None — double-claim prevented");
 
     // Balances should reflect only ONE claim
@@ -182,8 +182,7 @@ fn test_double_resolve_without_delete_duplicates_tokens() {
         TransferDestination::Wallet("bc1qbob".to_string()), &info, 840000).unwrap();
 
     // Bob has 800 (duplicated!) — this is the bug we prevent with delete-first
-    let bob_data = Brc20Balances::new().get("bc1qbob", "ordi").unwrap();
-    let bob_balance: Balance = serde_json::from_slice(&bob_data).unwrap();
+    let bob_balance = BRC20_BALANCES.get("bc1qbob", "ordi").unwrap();
     assert_eq!(bob_balance.total_balance, 800 * SCALE,
         "Without delete protection, tokens are duplicated — this test documents the bug");
 
@@ -349,8 +348,7 @@ fn test_resolve_via_classify_op_return_burn() {
     indexer.resolve_transfer(dest, &info, 840000).unwrap();
 
     assert_brc20_balance("bc1qsender", "ordi", 600 * SCALE, 600 * SCALE);
-    let data = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").unwrap();
     assert_eq!(ticker.burned_supply, 400 * SCALE);
 }
 
@@ -401,8 +399,7 @@ fn test_resolve_via_classify_prog_deposit() {
     indexer.resolve_transfer(dest, &info, 912690).unwrap();
 
     assert_brc20_balance("bc1qsender", "abcdef", 600 * SCALE, 600 * SCALE);
-    let prog_data = Brc20Balances::new().get(BRC20_PROG_OP_RETURN_PKSCRIPT, "abcdef").unwrap();
-    let prog_balance: Balance = serde_json::from_slice(&prog_data).unwrap();
+    let prog_balance = BRC20_BALANCES.get(BRC20_PROG_OP_RETURN_PKSCRIPT, "abcdef").unwrap();
     assert_eq!(prog_balance.total_balance, 400 * SCALE);
 }
 
@@ -430,28 +427,28 @@ fn test_multi_destination_sequence() {
     // Alice sends 1000 to Bob (wallet)
     let xfer1 = Brc20Operation::Transfer { ticker: "ordi".to_string(), amount: 1000 * SCALE };
     indexer.process_operation(&xfer1, "x1_0i0", "bc1qalice").unwrap();
-    Brc20TransferableInscriptions::new().delete("x1_0i0");
+    BRC20_TRANSFERABLE_INSCRIPTIONS.delete("x1_0i0");
     let info1 = TransferInfo { ticker: "ordi".to_string(), amount: 1000 * SCALE, sender: "bc1qalice".to_string() };
     indexer.resolve_transfer(TransferDestination::Wallet("bc1qbob".to_string()), &info1, 840000).unwrap();
 
     // Alice burns 500 (OP_RETURN)
     let xfer2 = Brc20Operation::Transfer { ticker: "ordi".to_string(), amount: 500 * SCALE };
     indexer.process_operation(&xfer2, "x2_0i0", "bc1qalice").unwrap();
-    Brc20TransferableInscriptions::new().delete("x2_0i0");
+    BRC20_TRANSFERABLE_INSCRIPTIONS.delete("x2_0i0");
     let info2 = TransferInfo { ticker: "ordi".to_string(), amount: 500 * SCALE, sender: "bc1qalice".to_string() };
     indexer.resolve_transfer(TransferDestination::Burn, &info2, 840000).unwrap();
 
     // Alice loses 200 as fee (returned)
     let xfer3 = Brc20Operation::Transfer { ticker: "ordi".to_string(), amount: 200 * SCALE };
     indexer.process_operation(&xfer3, "x3_0i0", "bc1qalice").unwrap();
-    Brc20TransferableInscriptions::new().delete("x3_0i0");
+    BRC20_TRANSFERABLE_INSCRIPTIONS.delete("x3_0i0");
     let info3 = TransferInfo { ticker: "ordi".to_string(), amount: 200 * SCALE, sender: "bc1qalice".to_string() };
     indexer.resolve_transfer(TransferDestination::SentAsFee, &info3, 840000).unwrap();
 
     // Alice sends 300 to Charlie (wallet)
     let xfer4 = Brc20Operation::Transfer { ticker: "ordi".to_string(), amount: 300 * SCALE };
     indexer.process_operation(&xfer4, "x4_0i0", "bc1qalice").unwrap();
-    Brc20TransferableInscriptions::new().delete("x4_0i0");
+    BRC20_TRANSFERABLE_INSCRIPTIONS.delete("x4_0i0");
     let info4 = TransferInfo { ticker: "ordi".to_string(), amount: 300 * SCALE, sender: "bc1qalice".to_string() };
     indexer.resolve_transfer(TransferDestination::Wallet("bc1qcharlie".to_string()), &info4, 840000).unwrap();
 
@@ -469,8 +466,7 @@ fn test_multi_destination_sequence() {
     assert_brc20_balance("bc1qbob", "ordi", 1000 * SCALE, 1000 * SCALE);
     assert_brc20_balance("bc1qcharlie", "ordi", 300 * SCALE, 300 * SCALE);
 
-    let data = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").unwrap();
     assert_eq!(ticker.burned_supply, 500 * SCALE);
     assert_eq!(ticker.current_supply, 5000 * SCALE);
 
@@ -518,12 +514,9 @@ fn test_conservation_check() {
     indexer.resolve_transfer(TransferDestination::SentAsFee, &info3, 840000).unwrap();
 
     // Read final state
-    let alice_data = Brc20Balances::new().get("bc1qalice", "ordi").unwrap();
-    let alice: Balance = serde_json::from_slice(&alice_data).unwrap();
-    let bob_data = Brc20Balances::new().get("bc1qbob", "ordi").unwrap();
-    let bob: Balance = serde_json::from_slice(&bob_data).unwrap();
-    let ticker_data = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&ticker_data).unwrap();
+    let alice = BRC20_BALANCES.get("bc1qalice", "ordi").unwrap();
+    let bob = BRC20_BALANCES.get("bc1qbob", "ordi").unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").unwrap();
 
     // Conservation: all live balances + burned = minted
     let total_live = alice.total_balance + bob.total_balance;
@@ -574,17 +567,17 @@ fn test_ordering_documented() {
     indexer.process_operation(&xfer, "xfer_0i0", "bc1qalice").unwrap();
 
     // Verify the transferable inscription was created
-    assert!(Brc20TransferableInscriptions::new().get("xfer_0i0").is_some(),
+    assert!(BRC20_TRANSFERABLE_INSCRIPTIONS.get("xfer_0i0").is_some(),
         "Transfer inscription should exist after inscribe phase");
 
     // Step 2 (transfers second): claim the transfer
     let info = TransferInfo {
         ticker: "ordi".to_string(), amount: 400 * SCALE, sender: "bc1qalice".to_string(),
     };
-    Brc20TransferableInscriptions::new().delete("xfer_0i0");
+    BRC20_TRANSFERABLE_INSCRIPTIONS.delete("xfer_0i0");
     indexer.resolve_transfer(TransferDestination::Wallet("bc1qbob".to_string()), &info, 840000).unwrap();
 
-    assert!(Brc20TransferableInscriptions::new().get("xfer_0i0").is_none(),
+    assert!(BRC20_TRANSFERABLE_INSCRIPTIONS.get("xfer_0i0").is_none(),
         "Transfer inscription should be consumed after transfer phase");
 
     assert_brc20_balance("bc1qalice", "ordi", 600 * SCALE, 600 * SCALE);
@@ -0,0 +1,205 @@
+use wasm_bindgen_test::wasm_bindgen_test as test;
+use crate::brc20::{Balance, Brc20Indexer, Brc20Operation, TransferInfo, TransferDestination, BRC20_PROG_OP_RETURN_PKSCRIPT};
+use crate::ledger;
+use crate::proto::GetBalanceRequest;
+use crate::error::Brc20Error;
+use crate::tables::{BRC20_BALANCES, LEGACY_BRC20_BALANCES};
+use crate::view;
+use bitcoin::{Address, Amount, Network, OutPoint, ScriptBuf, Transaction, TxIn, TxOut};
+use shrew_ord::ord_inscriptions::Inscription;
+use shrew_test_helpers::blocks::{create_block_with_txs, create_coinbase_transaction};
+use shrew_test_helpers::brc20::*;
+use shrew_test_helpers::state::{clear, get_test_address, view_address};
+use shrew_test_helpers::transactions::{create_mock_outpoint, create_test_transaction};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const SCALE: u128 = 1_000_000_000_000_000_000u128; // 10^18
+
+fn pkscript(address: &Address) -> String {
+    ledger::pkscript_key(&address.script_pubkey())
+}
+
+/// The address the original layout keyed the balances of `address` by
+fn legacy_key(address: &Address) -> String {
+    Address::from_script(&address.script_pubkey(), Network::Bitcoin).unwrap().to_string()
+}
+
+#[wasm_bindgen_test]
+fn test_owner_key_accepts_address_or_pkscript() {
+    let address = get_test_address(1);
+    let key = pkscript(&address);
+    assert_eq!(ledger::owner_key(&view_address(&address)).ok(), Some(key.clone()));
+    assert_eq!(ledger::owner_key(&key.to_uppercase()).ok(), Some(key.clone()));
+    assert_eq!(ledger::stored_owner_key(&legacy_key(&address)).ok(), Some(key.clone()));
+    assert_eq!(ledger::stored_owner_key(&key).ok(), Some(key));
+    for owner in ["not an owner", ""] {
+        assert!(matches!(ledger::owner_key(owner), Err(Brc20Error::InvalidField { field: "address", .. })));
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_owner_key_rejects_addresses_of_another_network() {
+    // The indexer runs on mainnet; the test addresses are regtest ones
+    let address = get_test_address(1).to_string();
+    assert!(matches!(ledger::owner_key(&address), Err(Brc20Error::InvalidField { field: "address", .. })));
+    assert!(matches!(ledger::stored_owner_key(&address), Err(Brc20Error::InvalidField { field: "address", .. })));
+}
+
+#[wasm_bindgen_test]
+fn test_mints_and_received_transfers_share_the_pkscript_key() {
+    clear();
+    let alice = get_test_address(1);
+    let bob = get_test_address(2);
+
+    // Every reveal spends a fresh commit output so none is a reinscription
+    let (deploy_block, _) = create_brc20_deploy_block("ledg", "21000", "1000");
+    index_block(&deploy_block, 0);
+    let (mint_block, _) = create_brc20_mint_block("ledg", "500", &alice, &create_mock_outpoint(1).txid);
+    index_block(&mint_block, 1);
+    let (inscribe_block, inscribe_tx) = create_brc20_transfer_inscribe_block("ledg", "100", &alice, &create_mock_outpoint(2).txid);
+    index_block(&inscribe_block, 2);
+    let (claim_block, _) = create_brc20_transfer_claim_block(&inscribe_tx, &bob);
+    index_block(&claim_block, 3);
+    let (mint_block, _) = create_brc20_mint_block("ledg", "50", &bob, &create_mock_outpoint(3).txid);
+    index_block(&mint_block, 4);
+
+    let alice_balance = BRC20_BALANCES.get(&pkscript(&alice), "ledg").unwrap();
+    assert_eq!(alice_balance.total_balance, 400 * SCALE);
+    let bob_balance = BRC20_BALANCES.get(&pkscript(&bob), "ledg").unwrap();
+    assert_eq!(bob_balance.total_balance, 150 * SCALE);
+    assert_eq!(bob_balance.available_balance, 150 * SCALE);
+    assert!(BRC20_BALANCES.get(&bob.to_string(), "ledg").is_none());
+
+    // The view finds the balance by address and by pkscript
+    for owner in [view_address(&bob), pkscript(&bob)] {
+        let response = view::get_balance(&GetBalanceRequest { address: owner, ticker: "ledg".to_string() }).unwrap();
+        let balance: Balance = serde_json::from_str(&response.balance).unwrap();
+        assert_eq!(balance.total_balance, 150 * SCALE);
+    }
+    assert!(view::get_balance(&GetBalanceRequest { address: "nobody".to_string(), ticker: "ledg".to_string() }).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_legacy_address_balances_are_migrated() {
+    clear();
+    let indexer = Brc20Indexer::new();
+    let owner = get_test_address(3);
+    let key = pkscript(&owner);
+    let legacy = legacy_key(&owner);
+
+    let deploy = Brc20Operation::Deploy {
+        ticker: "migr".to_string(),
+        max_supply: 21_000 * SCALE,
+        limit_per_mint: 1_000 * SCALE,
+        decimals: 18,
+        self_mint: false,
+        salt: None,
+    };
+    indexer.process_operation(&deploy, "deploy_0i0", &pkscript(&get_test_address(5))).unwrap();
+
+    // State left by an older version: a balance in the original layout, a
+    // received transfer under the pkscript, and a transfer inscribed by address
+    set_original_layout_balance(&legacy, "migr", 300 * SCALE, 200 * SCALE);
+    BRC20_BALANCES.set(&key, "migr", &Balance { ticker: "migr".to_string(), total_balance: 50 * SCALE, available_balance: 50 * SCALE });
+    let pending = TransferInfo { ticker: "migr".to_string(), amount: 100 * SCALE, sender: legacy.clone() };
+
+    // Views add up both keys before the owner is touched
+    let merged = ledger::balance(&key, "migr").unwrap();
+    assert_eq!((merged.total_balance, merged.available_balance), (350 * SCALE, 250 * SCALE));
+
+    // The first operation of the owner moves the legacy balance
+    let mint = Brc20Operation::Mint { ticker: "migr".to_string(), amount: 10 * SCALE };
+    indexer.process_operation(&mint, "mint_0i0", &key).unwrap();
+    assert!(!LEGACY_BRC20_BALANCES.contains(&format!("{}:migr", legacy)));
+    let balance = BRC20_BALANCES.get(&key, "migr").unwrap();
+    assert_eq!((balance.total_balance, balance.available_balance), (360 * SCALE, 260 * SCALE));
+
+    // A transfer inscribed under the address debits the pkscript key
+    let recipient = pkscript(&get_test_address(4));
+    indexer.resolve_transfer(TransferDestination::Wallet(recipient.clone()), &pending, 5).unwrap();
+    let balance = BRC20_BALANCES.get(&key, "migr").unwrap();
+    assert_eq!(balance.total_balance, 260 * SCALE);
+    assert_eq!(BRC20_BALANCES.get(&recipient, "migr").unwrap().total_balance, 100 * SCALE);
+}
+
+#[wasm_bindgen_test]
+fn test_legacy_pkscript_balances_are_migrated() {
+    clear();
+    let indexer = Brc20Indexer::new();
+    let owner = get_test_address(3);
+    let key = pkscript(&owner);
+
+    let deploy = Brc20Operation::Deploy {
+        ticker: "recv".to_string(),
+        max_supply: 21_000 * SCALE,
+        limit_per_mint: 1_000 * SCALE,
+        decimals: 18,
+        self_mint: false,
+        salt: None,
+    };
+    indexer.process_operation(&deploy, "deploy_0i0", &pkscript(&get_test_address(5))).unwrap();
+
+    // The original layout credited minted balances under the address, but
+    // received transfers and BRC20-prog deposits under the hex pkscript
+    set_original_layout_balance(&legacy_key(&owner), "recv", 100 * SCALE, 80 * SCALE);
+    set_original_layout_balance(&key, "recv", 40 * SCALE, 40 * SCALE);
+    set_original_layout_balance(BRC20_PROG_OP_RETURN_PKSCRIPT, "recv", 7 * SCALE, 7 * SCALE);
+
+    let merged = ledger::balance(&key, "recv").unwrap();
+    assert_eq!((merged.total_balance, merged.available_balance), (140 * SCALE, 120 * SCALE));
+    assert_eq!(ledger::balances(&key).len(), 1);
+    assert_eq!(ledger::balance(BRC20_PROG_OP_RETURN_PKSCRIPT, "recv").unwrap().total_balance, 7 * SCALE);
+
+    // The received balance survives the owner's first operation
+    let mint = Brc20Operation::Mint { ticker: "recv".to_string(), amount: 10 * SCALE };
+    indexer.process_operation(&mint, "mint_0i0", &key).unwrap();
+    assert!(!LEGACY_BRC20_BALANCES.contains(&format!("{}:recv", key)));
+    let balance = BRC20_BALANCES.get(&key, "recv").unwrap();
+    assert_eq!((balance.total_balance, balance.available_balance), (150 * SCALE, 130 * SCALE));
+
+    ledger::migrate(BRC20_PROG_OP_RETURN_PKSCRIPT);
+    assert_eq!(BRC20_BALANCES.get(BRC20_PROG_OP_RETURN_PKSCRIPT, "recv").unwrap().total_balance, 7 * SCALE);
+    assert!(!LEGACY_BRC20_BALANCES.contains(&format!("{}:recv", BRC20_PROG_OP_RETURN_PKSCRIPT)));
+}
+
+/// Mint envelope, optionally pointing at a sat of the reveal's outputs
+fn mint_envelope(ticker: &str, amount: &str, pointer: Option<u64>) -> bitcoin::Witness {
+    Inscription {
+        content_type: Some(b"text/plain".to_vec()),
+        body: Some(create_brc20_json("mint", ticker, &[("amt", amount)])),
+        pointer: pointer.map(|pointer| pointer.to_le_bytes().to_vec()),
+        ..Default::default()
+    }.to_witness()
+}
+
+/// Reveal of `envelope`, spending `commit`, into 10,000 sat outputs to `outputs`
+fn reveal(commit: OutPoint, envelope: bitcoin::Witness, outputs: &[ScriptBuf]) -> Transaction {
+    let mut tx = create_test_transaction();
+    tx.input = vec![TxIn { previous_output: commit, witness: envelope, ..Default::default() }];
+    tx.output = outputs.iter()
+        .map(|script_pubkey| TxOut { value: Amount::from_sat(10_000), script_pubkey: script_pubkey.clone() })
+        .collect();
+    tx
+}
+
+#[wasm_bindgen_test]
+fn test_inscribe_operations_belong_to_the_output_they_land_in() {
+    clear();
+    let alice = get_test_address(1);
+    let bob = get_test_address(2);
+    let (deploy_block, _) = create_brc20_deploy_block("btch", "21000", "1000");
+    index_block(&deploy_block, 0);
+
+    let outputs = [alice.script_pubkey(), bob.script_pubkey()];
+    let to_alice = reveal(create_mock_outpoint(10), mint_envelope("btch", "100", None), &outputs);
+    // The pointer names the first sat of bob's output
+    let to_bob = reveal(create_mock_outpoint(11), mint_envelope("btch", "200", Some(10_000)), &outputs);
+    // A mint revealed into an OP_RETURN output is credited to that pkscript
+    let burn = reveal(create_mock_outpoint(12), mint_envelope("btch", "300", None), &[ScriptBuf::from_bytes(vec![0x6a])]);
+    index_block(&create_block_with_txs(vec![create_coinbase_transaction(1), to_alice, to_bob, burn]), 1);
+
+    let balance = |owner: &str| BRC20_BALANCES.get(owner, "btch").map_or(0, |balance| balance.total_balance);
+    assert_eq!(balance(&pkscript(&alice)), 100 * SCALE);
+    assert_eq!(balance(&pkscript(&bob)), 200 * SCALE);
+    assert_eq!(balance("6a"), 300 * SCALE);
+}
@@ -1,6 +1,6 @@
 use wasm_bindgen_test::wasm_bindgen_test as test;
 use crate::brc20::{Brc20Indexer, Brc20Operation, TransferInfo};
-use crate::tables::{Brc20Tickers, Brc20Balances};
+use crate::tables::{BRC20_TICKERS, BRC20_BALANCES};
 use shrew_test_helpers::state::clear;
 use shrew_test_helpers::assertions::{assert_brc20_balance, assert_brc20_supply};
 use wasm_bindgen_test::wasm_bindgen_test;
@@ -65,7 +65,7 @@ fn test_deploy_mint_transfer_lifecycle() {
 
     deploy(&indexer, "ordi", 21_000_000 * SCALE, 1000 * SCALE, "deploy_0i0");
 
-    let ticker_data = Brc20Tickers::new().get("ordi");
+    let ticker_data = BRC20_TICKERS.get("ordi");
     assert!(ticker_data.is_some(), "Ticker should exist after deploy");
 
     mint(&indexer, "ordi", 1000 * SCALE, "bc1qalice", "mint_0i0");
@@ -114,7 +114,7 @@ fn test_mint_to_max_supply() {
     mint(&indexer, "tiny", 1 * SCALE, "bc1qminter2", "mint2_0i0");
 
     assert_brc20_supply("tiny", 1000 * SCALE);
-    let balance = Brc20Balances::new().get("bc1qminter2", "tiny");
+    let balance = BRC20_BALANCES.get("bc1qminter2", "tiny");
     assert!(balance.is_none(), "Mint beyond max supply should be rejected");
 }
 
@@ -136,7 +136,7 @@ fn test_multiple_tickers() {
     assert_brc20_balance("bc1qalice", "pepe", 5000 * SCALE, 5000 * SCALE);
     assert_brc20_balance("bc1qbob", "ordi", 800 * SCALE, 800 * SCALE);
 
-    let bob_pepe = Brc20Balances::new().get("bc1qbob", "pepe");
+    let bob_pepe = BRC20_BALANCES.get("bc1qbob", "pepe");
     assert!(bob_pepe.is_none(), "Bob should have no pepe tokens");
 }
 
@@ -9,3 +9,10 @@ mod integration_tests;
 mod decimal_precision_tests;
 mod event_hash_tests;
 mod predeploy_tests;
+mod ledger_tests;
+mod events_tests;
+mod rules_tests;
+mod ticker_tests;
+mod holders_tests;
+mod portfolio_tests;
+mod transfer_status_tests;
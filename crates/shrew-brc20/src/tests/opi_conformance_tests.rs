@@ -7,8 +7,8 @@
 ///! After the u64->u128 type migration, amount assertions will be updated.
 
 use wasm_bindgen_test::wasm_bindgen_test as test;
-use crate::brc20::{Brc20Indexer, Brc20Operation, Ticker, Balance};
-use crate::tables::{Brc20Tickers, Brc20Balances, Brc20TransferableInscriptions};
+use crate::brc20::{Brc20Indexer, Brc20Operation};
+use crate::tables::{BRC20_TICKERS, BRC20_BALANCES, BRC20_TRANSFERABLE_INSCRIPTIONS};
 use shrew_test_helpers::state::clear;
 use wasm_bindgen_test::wasm_bindgen_test;
 
@@ -87,16 +87,14 @@ fn test_ticker_case_insensitive_deploy_dedup() {
 
     // Only the first deploy's params should be stored
     // The ticker should be stored under the lowercase key
-    let data = Brc20Tickers::new().get("ordi").expect("'ordi' should exist");
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").expect("'ordi' should exist");
     assert_eq!(ticker.max_supply, 21_000_000, "First deploy should win (case-insensitive)");
 
     // "ORDI" as a separate ticker should NOT exist
-    // If the implementation normalizes to lowercase, then Brc20Tickers::get("ORDI")
+    // If the implementation normalizes to lowercase, then BRC20_TICKERS.get("ORDI")
     // either returns None (no separate entry) or returns the same as "ordi" (normalized lookup).
     // Either way, there must not be a separate ticker with max_supply=99_999_999.
-    if let Some(upper_data) = Brc20Tickers::new().get("ORDI") {
-        let upper_ticker: Ticker = serde_json::from_slice(&upper_data).unwrap();
+    if let Some(upper_ticker) = BRC20_TICKERS.get("ORDI") {
         // If ORDI returns data, it must be the SAME ticker (the ordi deploy), not the second deploy
         assert_eq!(upper_ticker.max_supply, 21_000_000,
             "'ORDI' lookup must not create a separate ticker — should resolve to 'ordi'");
@@ -128,8 +126,7 @@ fn test_ticker_case_insensitive_mint() {
     indexer.process_operation(&mint, "mint_0i0", "bc1qminter").unwrap();
 
     // Supply of "ordi" should have increased
-    let data = Brc20Tickers::new().get("ordi").expect("'ordi' should exist");
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").expect("'ordi' should exist");
     assert_eq!(ticker.current_supply, 500,
         "Minting 'ORDI' should increase 'ordi' supply (case-insensitive)");
 }
@@ -228,14 +225,12 @@ fn test_partial_mint_clamps_to_remaining() {
     let mint2 = Brc20Operation::Mint { ticker: "ordi".to_string(), amount: 800 };
     indexer.process_operation(&mint2, "mint2_0i0", "bc1qminter2").unwrap();
 
-    let data = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").unwrap();
     assert_eq!(ticker.current_supply, 1000,
         "Supply should be clamped to max (1000), not remain at 800");
 
-    let balance_data = Brc20Balances::new().get("bc1qminter2", "ordi")
+    let balance = BRC20_BALANCES.get("bc1qminter2", "ordi")
         .expect("minter2 should have a balance from partial mint");
-    let balance: Balance = serde_json::from_slice(&balance_data).unwrap();
     assert_eq!(balance.available_balance, 200,
         "minter2 should receive 200 (clamped remainder)");
 }
@@ -262,11 +257,10 @@ fn test_reject_zero_mint_amount() {
     let mint = Brc20Operation::Mint { ticker: "ordi".to_string(), amount: 0 };
     indexer.process_operation(&mint, "mint_0i0", "bc1qminter").unwrap();
 
-    let data = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").unwrap();
     assert_eq!(ticker.current_supply, 0, "Zero amount mint should be rejected");
 
-    let balance = Brc20Balances::new().get("bc1qminter", "ordi");
+    let balance = BRC20_BALANCES.get("bc1qminter", "ordi");
     assert!(balance.is_none(), "No balance should exist for zero mint");
 }
 
@@ -291,7 +285,7 @@ fn test_reject_zero_transfer_amount() {
     let transfer = Brc20Operation::Transfer { ticker: "ordi".to_string(), amount: 0 };
     indexer.process_operation(&transfer, "xfer_0i0", "bc1qsender").unwrap();
 
-    let transferable = Brc20TransferableInscriptions::new().get("xfer_0i0");
+    let transferable = BRC20_TRANSFERABLE_INSCRIPTIONS.get("xfer_0i0");
     assert!(transferable.is_none(), "Zero amount transfer should be rejected");
 }
 
@@ -414,8 +408,7 @@ fn test_regression_deploy_first_wins() {
     indexer.process_operation(&op1, "first_0i0", "bc1qa").unwrap();
     indexer.process_operation(&op2, "second_0i0", "bc1qb").unwrap();
 
-    let data = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").unwrap();
     assert_eq!(ticker.max_supply, 21_000_000, "First deploy should win");
 }
 
@@ -436,8 +429,7 @@ fn test_regression_mint_exceeds_limit_rejected() {
     let mint = Brc20Operation::Mint { ticker: "ordi".to_string(), amount: 1001 };
     indexer.process_operation(&mint, "mint_0i0", "bc1qminter").unwrap();
 
-    let data = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").unwrap();
     assert_eq!(ticker.current_supply, 0, "Mint exceeding limit should be rejected");
 }
 
@@ -464,11 +456,10 @@ fn test_regression_transfer_insufficient_balance_rejected() {
     indexer.process_operation(&transfer, "xfer_0i0", "bc1qsender").unwrap();
 
     // Balance should be unchanged
-    let bal_data = Brc20Balances::new().get("bc1qsender", "ordi").unwrap();
-    let balance: Balance = serde_json::from_slice(&bal_data).unwrap();
+    let balance = BRC20_BALANCES.get("bc1qsender", "ordi").unwrap();
     assert_eq!(balance.available_balance, 100);
     assert_eq!(balance.total_balance, 100);
 
-    let transferable = Brc20TransferableInscriptions::new().get("xfer_0i0");
+    let transferable = BRC20_TRANSFERABLE_INSCRIPTIONS.get("xfer_0i0");
     assert!(transferable.is_none(), "Transfer should not be recorded");
 }
@@ -0,0 +1,80 @@
+use wasm_bindgen_test::wasm_bindgen_test as test;
+use crate::error::Brc20Error;
+use crate::ledger;
+use crate::proto::{Brc20PortfolioResponse, GetBrc20PortfolioRequest};
+use crate::view;
+use bitcoin::{Address, Network};
+use shrew_support::InscriptionId;
+use shrew_test_helpers::blocks::*;
+use shrew_test_helpers::brc20::*;
+use shrew_test_helpers::indexing::index_ord_block;
+use shrew_test_helpers::state::{clear, get_test_address, view_address};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn portfolio(owner: &str) -> Brc20PortfolioResponse {
+    view::get_brc20_portfolio(&GetBrc20PortfolioRequest { address: owner.to_string() }).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn test_portfolio_lists_held_tickers_and_open_transfers() {
+    clear();
+    let alice = get_test_address(1);
+    let bob = get_test_address(2);
+    index_block(&create_block_with_txs(vec![
+        create_coinbase_transaction(0),
+        inscribe("deploy", "aaaa", &[("max", "21000"), ("lim", "1000")], &alice, 20),
+        inscribe("deploy", "bbbb", &[("max", "21000"), ("lim", "1000"), ("dec", "2")], &alice, 21),
+    ]), 0);
+    index_block(&create_block_with_txs(vec![
+        create_coinbase_transaction(1),
+        inscribe("mint", "aaaa", &[("amt", "300")], &alice, 1),
+        inscribe("mint", "bbbb", &[("amt", "50.5")], &alice, 2),
+    ]), 1);
+    // One transfer stays open; the other moves all of alice's bbbb to bob
+    let open = inscribe("transfer", "aaaa", &[("amt", "100")], &alice, 3);
+    let sent = inscribe("transfer", "bbbb", &[("amt", "50.5")], &alice, 4);
+    index_block(&create_block_with_txs(vec![create_coinbase_transaction(2), open.clone(), sent.clone()]), 2);
+    let (claim_block, _) = create_brc20_transfer_claim_block(&sent, &bob);
+    index_block(&claim_block, 3);
+
+    let response = portfolio(&view_address(&alice));
+    assert_eq!(response.balances.len(), 1);
+    let balance = &response.balances[0];
+    assert_eq!(balance.ticker, "aaaa");
+    assert_eq!(balance.total_balance, "300.000000000000000000");
+    assert_eq!(balance.available_balance, "200.000000000000000000");
+    assert_eq!(balance.transferable_balance, "100.000000000000000000");
+    assert_eq!(response.transfers.len(), 1);
+    let transfer = &response.transfers[0];
+    assert_eq!(transfer.inscription_id, InscriptionId::new(open.compute_txid(), 0).to_string());
+    assert_eq!((transfer.ticker.as_str(), transfer.amount.as_str()), ("aaaa", "100.000000000000000000"));
+
+    let response = portfolio(&ledger::pkscript_key(&bob.script_pubkey()));
+    assert_eq!(response.balances.len(), 1);
+    assert_eq!((response.balances[0].ticker.as_str(), response.balances[0].total_balance.as_str()), ("bbbb", "50.50"));
+    assert!(response.transfers.is_empty());
+}
+
+#[wasm_bindgen_test]
+fn test_portfolio_includes_balances_not_yet_migrated() {
+    clear();
+    let owner = get_test_address(3);
+    let deploy = inscribe("deploy", "migr", &[("max", "21000"), ("lim", "1000")], &get_test_address(4), 20);
+    index_ord_block(&create_block_with_txs(vec![create_coinbase_transaction(0), deploy.clone()]), 0).unwrap();
+    let scale = 10u128.pow(18);
+    set_original_layout_ticker("migr", &InscriptionId::new(deploy.compute_txid(), 0).to_string(), 21_000 * scale, 5 * scale);
+    let legacy = Address::from_script(&owner.script_pubkey(), Network::Bitcoin).unwrap().to_string();
+    set_original_layout_balance(&legacy, "migr", 5 * scale, 5 * scale);
+    index_block(&create_block_with_txs(vec![create_coinbase_transaction(1)]), 1);
+
+    let response = portfolio(&view_address(&owner));
+    assert_eq!(response.balances.len(), 1);
+    assert_eq!(response.balances[0].total_balance, "5.000000000000000000");
+}
+
+#[wasm_bindgen_test]
+fn test_portfolio_rejects_an_unknown_owner_format() {
+    clear();
+    let request = GetBrc20PortfolioRequest { address: "not an owner".to_string() };
+    assert!(matches!(view::get_brc20_portfolio(&request), Err(Brc20Error::InvalidField { field: "address", .. })));
+}
@@ -9,7 +9,7 @@
 ///! - Hash format: double sha256 of (ticker_utf8 + salt_hex_decoded + pkscript_hex_decoded)
 
 use crate::brc20::{Brc20Indexer, Brc20Operation};
-use crate::tables::Brc20Tickers;
+use crate::tables::BRC20_TICKERS;
 use shrew_test_helpers::state::clear;
 use wasm_bindgen_test::wasm_bindgen_test;
 
@@ -187,7 +187,7 @@ fn test_6byte_deploy_requires_valid_predeploy() {
     assert!(result.is_ok(), "6-byte deploy with valid predeploy should succeed");
 
     // Verify ticker was created
-    let data = Brc20Tickers::new().get("ticker");
+    let data = BRC20_TICKERS.get("ticker");
     assert!(data.is_some(), "Ticker should be deployed");
 }
 
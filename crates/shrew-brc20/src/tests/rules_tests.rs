@@ -0,0 +1,81 @@
+use wasm_bindgen_test::wasm_bindgen_test as test;
+use crate::brc20::Brc20Indexer;
+use crate::rules::Brc20Rules;
+use crate::tables::{BRC20_BALANCES, BRC20_TICKERS};
+use crate::ledger;
+use bitcoin::{Network, OutPoint, TxIn};
+use shrew_ord::tables::OUTPOINT_TO_VALUE;
+use shrew_support::constants::{BRC20_ACTIVATION_HEIGHT, BRC20_SELF_MINT_ENABLE_HEIGHT, JUBILEE_HEIGHT};
+use shrew_test_helpers::blocks::{create_block_with_txs, create_coinbase_transaction};
+use shrew_test_helpers::brc20::*;
+use shrew_test_helpers::indexing::index_ord_block;
+use shrew_test_helpers::state::{clear, get_test_address};
+use shrew_test_helpers::transactions::{create_inscription_transaction_to_address, create_mock_outpoint};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn index_block(indexer: &Brc20Indexer, block: &bitcoin::Block, height: u32) {
+    index_ord_block(block, height).unwrap();
+    indexer.process_block(block, height);
+}
+
+#[wasm_bindgen_test]
+fn test_rules_come_from_the_network() {
+    assert_eq!(Brc20Rules::for_network(Network::Bitcoin), Brc20Rules::mainnet());
+    assert_eq!(Brc20Rules::for_network(Network::Regtest), Brc20Rules::regtest());
+
+    let mainnet = Brc20Rules::mainnet();
+    assert!(!mainnet.is_active(BRC20_ACTIVATION_HEIGHT - 1));
+    assert!(mainnet.is_active(BRC20_ACTIVATION_HEIGHT));
+    assert!(!mainnet.allows_ticker(5, BRC20_SELF_MINT_ENABLE_HEIGHT - 1));
+    assert!(mainnet.allows_ticker(4, 0));
+
+    let regtest = Brc20Rules::regtest();
+    assert!(regtest.is_active(0) && regtest.allows_ticker(5, 0) && regtest.allows_ticker(6, 0) && regtest.allows_predeploy(0));
+    assert!(regtest.allows_prog_deposit(4, 0));
+}
+
+#[wasm_bindgen_test]
+fn test_operations_before_activation_are_ignored() {
+    clear();
+    let indexer = Brc20Indexer::new();
+    let (early, _) = create_brc20_deploy_block("erly", "21000", "1000");
+    index_block(&indexer, &early, BRC20_ACTIVATION_HEIGHT - 1);
+    assert!(BRC20_TICKERS.get("erly").is_none());
+
+    let deploy = create_inscription_transaction_to_address(
+        &create_brc20_json("deploy", "ontm", &[("max", "21000"), ("lim", "1000")]),
+        "text/plain",
+        Some(create_mock_outpoint(1)),
+        &get_test_address(1),
+    );
+    let block = create_block_with_txs(vec![create_coinbase_transaction(BRC20_ACTIVATION_HEIGHT), deploy]);
+    index_block(&indexer, &block, BRC20_ACTIVATION_HEIGHT);
+    assert!(BRC20_TICKERS.get("ontm").is_some());
+}
+
+#[wasm_bindgen_test]
+fn test_vindicated_inscriptions_are_cursed_for_brc20() {
+    clear();
+    let indexer = Brc20Indexer::new();
+    let alice = get_test_address(1);
+    let (deploy_block, _) = create_brc20_deploy_block("vind", "21000", "1000");
+    index_block(&indexer, &deploy_block, JUBILEE_HEIGHT);
+
+    // After the jubilee, ord blesses an envelope on the second input but
+    // marks it vindicated
+    let mint = |amount: &str, commit: u32| create_inscription_transaction_to_address(
+        &create_brc20_json("mint", "vind", &[("amt", amount)]),
+        "text/plain",
+        Some(create_mock_outpoint(commit)),
+        &alice,
+    );
+    let mut vindicated = mint("200", 2);
+    let funding = OutPoint::new(create_mock_outpoint(3).txid, 5);
+    OUTPOINT_TO_VALUE.set(&funding, &10_000);
+    vindicated.input.insert(0, TxIn { previous_output: funding, ..Default::default() });
+    let block = create_block_with_txs(vec![create_coinbase_transaction(JUBILEE_HEIGHT + 1), mint("100", 1), vindicated]);
+    index_block(&indexer, &block, JUBILEE_HEIGHT + 1);
+
+    let balance = BRC20_BALANCES.get(&ledger::pkscript_key(&alice.script_pubkey()), "vind").unwrap();
+    assert_eq!(balance.total_balance, 100 * 1_000_000_000_000_000_000u128);
+}
@@ -7,8 +7,8 @@
 ///! - Self-mint specific deploy/mint rules
 
 use wasm_bindgen_test::wasm_bindgen_test as test;
-use crate::brc20::{Brc20Indexer, Brc20Operation, Ticker, MAX_AMOUNT};
-use crate::tables::Brc20Tickers;
+use crate::brc20::{Brc20Indexer, Brc20Operation, MAX_AMOUNT};
+use crate::tables::BRC20_TICKERS;
 use shrew_test_helpers::state::clear;
 use wasm_bindgen_test::wasm_bindgen_test;
 
@@ -115,8 +115,7 @@ fn test_self_mint_flag_stored_in_ticker() {
     };
     indexer.process_operation(&op, "deploy_0i0", "bc1qdeployer").unwrap();
 
-    let data = Brc20Tickers::new().get("abcde").expect("Ticker should exist");
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("abcde").expect("Ticker should exist");
     assert!(ticker.is_self_mint, "is_self_mint should be true in stored ticker");
 }
 
@@ -134,8 +133,7 @@ fn test_regular_4byte_ticker_not_self_mint() {
     };
     indexer.process_operation(&op, "deploy_0i0", "bc1qdeployer").unwrap();
 
-    let data = Brc20Tickers::new().get("ordi").expect("Ticker should exist");
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").expect("Ticker should exist");
     assert!(!ticker.is_self_mint, "4-byte ticker should not be self-mint");
 }
 
@@ -229,8 +227,7 @@ fn test_self_mint_ticker_mint_via_process_operation() {
     indexer.process_operation(&mint, "mint_0i0", "bc1qminter").unwrap();
 
     // Verify ticker data
-    let data = Brc20Tickers::new().get("abcde").expect("Ticker should exist");
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("abcde").expect("Ticker should exist");
     assert!(ticker.is_self_mint);
     assert_eq!(ticker.current_supply, 50 * SCALE);
 }
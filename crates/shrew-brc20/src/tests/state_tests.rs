@@ -1,6 +1,6 @@
 use wasm_bindgen_test::wasm_bindgen_test as test;
 use crate::brc20::{Brc20Indexer, Brc20Operation, Ticker, TransferInfo};
-use crate::tables::{Brc20Tickers, Brc20Balances, Brc20TransferableInscriptions};
+use crate::tables::{BRC20_TICKERS, BRC20_BALANCES, BRC20_TRANSFERABLE_INSCRIPTIONS};
 use shrew_test_helpers::state::clear;
 use shrew_test_helpers::assertions::{assert_brc20_balance, assert_brc20_supply};
 use wasm_bindgen_test::wasm_bindgen_test;
@@ -25,9 +25,9 @@ fn test_deploy_creates_ticker() {
     };
     indexer.process_operation(&op, "fake_inscription_id_0i0", "bc1qtest").unwrap();
 
-    let data = Brc20Tickers::new().get("ordi");
+    let data = BRC20_TICKERS.get("ordi");
     assert!(data.is_some(), "Ticker 'ordi' should exist after deploy");
-    let ticker: Ticker = serde_json::from_slice(&data.unwrap()).unwrap();
+    let ticker: Ticker = data.unwrap();
     assert_eq!(ticker.name, "ordi");
     assert_eq!(ticker.max_supply, 21_000_000 * SCALE);
     assert_eq!(ticker.limit_per_mint, 1000 * SCALE);
@@ -59,8 +59,7 @@ fn test_deploy_first_wins() {
     indexer.process_operation(&op1, "first_deploy_0i0", "bc1qfirst").unwrap();
     indexer.process_operation(&op2, "second_deploy_0i0", "bc1qsecond").unwrap();
 
-    let data = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").unwrap();
     assert_eq!(ticker.max_supply, 21_000_000 * SCALE);
     assert_eq!(ticker.deploy_inscription_id, "first_deploy_0i0");
 }
@@ -92,9 +91,9 @@ fn test_deploy_case_insensitive() {
     indexer.process_operation(&op_upper, "upper_0i0", "bc1qb").unwrap();
 
     // Only the first deploy should exist
-    let data = Brc20Tickers::new().get("ordi");
+    let data = BRC20_TICKERS.get("ordi");
     assert!(data.is_some(), "'ordi' ticker should exist");
-    let ticker: Ticker = serde_json::from_slice(&data.unwrap()).unwrap();
+    let ticker: Ticker = data.unwrap();
     assert_eq!(ticker.max_supply, 21_000_000 * SCALE, "First deploy should win");
 }
 
@@ -169,7 +168,7 @@ fn test_mint_exceeds_limit_rejected() {
     indexer.process_operation(&mint, "mint_0i0", "bc1qminter").unwrap();
 
     assert_brc20_supply("ordi", 0);
-    let balance = Brc20Balances::new().get("bc1qminter", "ordi");
+    let balance = BRC20_BALANCES.get("bc1qminter", "ordi");
     assert!(balance.is_none(), "Balance should not exist for rejected mint");
 }
 
@@ -210,9 +209,9 @@ fn test_mint_nonexistent_ticker_ignored() {
     };
     indexer.process_operation(&mint, "mint_0i0", "bc1qminter").unwrap();
 
-    let ticker_data = Brc20Tickers::new().get("fake");
+    let ticker_data = BRC20_TICKERS.get("fake");
     assert!(ticker_data.is_none(), "Non-existent ticker should not appear after mint");
-    let balance_data = Brc20Balances::new().get("bc1qminter", "fake");
+    let balance_data = BRC20_BALANCES.get("bc1qminter", "fake");
     assert!(balance_data.is_none(), "Balance should not exist for non-deployed ticker mint");
 }
 
@@ -242,7 +241,7 @@ fn test_transfer_inscribe_reduces_available() {
 
     assert_brc20_balance("bc1qsender", "ordi", 600 * SCALE, 1000 * SCALE);
 
-    let transferable = Brc20TransferableInscriptions::new().get("transfer_0i0");
+    let transferable = BRC20_TRANSFERABLE_INSCRIPTIONS.get("transfer_0i0");
     assert!(transferable.is_some(), "Transferable inscription should be recorded");
 }
 
@@ -268,7 +267,7 @@ fn test_transfer_inscribe_insufficient_balance_ignored() {
 
     assert_brc20_balance("bc1qsender", "ordi", 100 * SCALE, 100 * SCALE);
 
-    let transferable = Brc20TransferableInscriptions::new().get("transfer_0i0");
+    let transferable = BRC20_TRANSFERABLE_INSCRIPTIONS.get("transfer_0i0");
     assert!(transferable.is_none(), "Transfer should not be recorded when balance insufficient");
 }
 
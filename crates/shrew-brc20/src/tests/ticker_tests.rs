@@ -0,0 +1,153 @@
+use wasm_bindgen_test::wasm_bindgen_test as test;
+use crate::error::Brc20Error;
+use crate::ledger;
+use crate::proto::{Brc20Ticker, Brc20TickerOrder, GetBrc20TickerRequest, GetBrc20TickersRequest};
+use crate::tables::BRC20_ORIGINAL_TICKERS;
+use crate::view;
+use shrew_support::pagination::PaginationRequest;
+use shrew_support::InscriptionId;
+use shrew_test_helpers::blocks::*;
+use shrew_test_helpers::brc20::*;
+use shrew_test_helpers::indexing::index_ord_block;
+use shrew_test_helpers::state::{clear, get_test_address};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn ticker(name: &str) -> Brc20Ticker {
+    view::get_brc20_ticker(&GetBrc20TickerRequest { ticker: name.to_string() }).unwrap().ticker.unwrap()
+}
+
+fn tickers(order: Brc20TickerOrder) -> Vec<String> {
+    let request = GetBrc20TickersRequest { order: order as i32, pagination: None };
+    view::get_brc20_tickers(&request).unwrap().tickers.into_iter().map(|ticker| ticker.ticker).collect()
+}
+
+/// `aaaa` is deployed first but ends up with one holder and 10% minted;
+/// `bbbb` has two holders and 90% minted
+fn index_two_tickers() {
+    let deployer = get_test_address(0);
+    let alice = get_test_address(1);
+    let bob = get_test_address(2);
+    index_block(&create_block_with_txs(vec![
+        create_coinbase_transaction(0),
+        inscribe("deploy", "aaaa", &[("max", "1000"), ("lim", "1000")], &deployer, 20),
+        inscribe("deploy", "bbbb", &[("max", "100"), ("lim", "100")], &deployer, 21),
+    ]), 0);
+    index_block(&create_block_with_txs(vec![
+        create_coinbase_transaction(1),
+        inscribe("mint", "aaaa", &[("amt", "100")], &alice, 1),
+        inscribe("mint", "bbbb", &[("amt", "80")], &alice, 2),
+        inscribe("mint", "bbbb", &[("amt", "10")], &bob, 3),
+    ]), 1);
+    // alice sends all of her aaaa to bob
+    let inscribe_tx = inscribe("transfer", "aaaa", &[("amt", "100")], &alice, 4);
+    index_block(&create_block_with_txs(vec![create_coinbase_transaction(2), inscribe_tx.clone()]), 2);
+    let (claim_block, _) = create_brc20_transfer_claim_block(&inscribe_tx, &bob);
+    index_block(&claim_block, 3);
+}
+
+#[wasm_bindgen_test]
+fn test_ticker_info_reports_supply_deploy_and_activity() {
+    clear();
+    index_two_tickers();
+
+    let aaaa = ticker("AAAA");
+    assert_eq!(aaaa.ticker, "aaaa");
+    assert_eq!(aaaa.max_supply, "1000.000000000000000000");
+    assert_eq!(aaaa.current_supply, "100.000000000000000000");
+    assert_eq!(aaaa.remaining_supply, "900.000000000000000000");
+    assert_eq!(aaaa.limit_per_mint, "1000.000000000000000000");
+    assert_eq!(aaaa.burned_supply, "0.000000000000000000");
+    assert_eq!(aaaa.decimals, 18);
+    assert!(!aaaa.self_mint);
+    assert_eq!(aaaa.deploy_height, 0);
+    assert_eq!(aaaa.deployer, ledger::pkscript_key(&get_test_address(0).script_pubkey()));
+    assert_eq!(aaaa.mint_progress, "10.00");
+    // alice's balance went to zero when her transfer was claimed
+    assert_eq!((aaaa.holders, aaaa.transfers), (1, 1));
+
+    let bbbb = ticker("bbbb");
+    assert_eq!(bbbb.mint_progress, "90.00");
+    assert_eq!((bbbb.holders, bbbb.transfers), (2, 0));
+}
+
+#[wasm_bindgen_test]
+fn test_ticker_info_of_unknown_ticker_is_not_found() {
+    clear();
+    let error = view::get_brc20_ticker(&GetBrc20TickerRequest { ticker: "none".to_string() }).unwrap_err();
+    assert!(matches!(error, Brc20Error::UnknownTicker(ticker) if ticker == "none"));
+}
+
+#[wasm_bindgen_test]
+fn test_ticker_list_sorts_and_paginates() {
+    clear();
+    index_two_tickers();
+
+    assert_eq!(tickers(Brc20TickerOrder::Deploy), ["aaaa", "bbbb"]);
+    assert_eq!(tickers(Brc20TickerOrder::Holders), ["bbbb", "aaaa"]);
+    assert_eq!(tickers(Brc20TickerOrder::Progress), ["bbbb", "aaaa"]);
+
+    let request = GetBrc20TickersRequest {
+        order: Brc20TickerOrder::Deploy as i32,
+        pagination: Some(PaginationRequest { limit: 1, page: 1, ..Default::default() }),
+    };
+    let response = view::get_brc20_tickers(&request).unwrap();
+    assert_eq!(response.tickers.len(), 1);
+    assert_eq!(response.tickers[0].ticker, "bbbb");
+    let pagination = response.pagination.unwrap();
+    assert_eq!((pagination.total, pagination.more), (2, false));
+}
+
+#[wasm_bindgen_test]
+fn test_ticker_pages_resume_after_the_last_ticker() {
+    clear();
+    index_two_tickers();
+    // Same number of holders as bbbb: ties go to the earlier deploy
+    index_block(&create_block_with_txs(vec![
+        create_coinbase_transaction(4),
+        inscribe("deploy", "cccc", &[("max", "1000"), ("lim", "1000")], &get_test_address(0), 22),
+    ]), 4);
+    index_block(&create_block_with_txs(vec![
+        create_coinbase_transaction(5),
+        inscribe("mint", "cccc", &[("amt", "1")], &get_test_address(1), 5),
+        inscribe("mint", "cccc", &[("amt", "1")], &get_test_address(2), 6),
+    ]), 5);
+
+    let mut listed = Vec::new();
+    let mut cursor = String::new();
+    loop {
+        let request = GetBrc20TickersRequest {
+            order: Brc20TickerOrder::Holders as i32,
+            pagination: Some(PaginationRequest { limit: 1, cursor: cursor.clone(), ..Default::default() }),
+        };
+        let response = view::get_brc20_tickers(&request).unwrap();
+        listed.extend(response.tickers.into_iter().map(|ticker| ticker.ticker));
+        cursor = response.pagination.unwrap().next_cursor;
+        if cursor.is_empty() {
+            break;
+        }
+    }
+    assert_eq!(listed, ["bbbb", "cccc", "aaaa"]);
+}
+
+#[wasm_bindgen_test]
+fn test_tickers_stored_before_the_list_existed_are_listed() {
+    clear();
+    let deployer = get_test_address(0);
+    let deploy = inscribe("deploy", "olds", &[("max", "1000"), ("lim", "1000")], &deployer, 20);
+    index_ord_block(&create_block_with_txs(vec![create_coinbase_transaction(0), deploy.clone()]), 0).unwrap();
+    let scale = 10u128.pow(18);
+    set_original_layout_ticker("olds", &InscriptionId::new(deploy.compute_txid(), 0).to_string(), 1000 * scale, 200 * scale);
+
+    // The first block after the upgrade lists it; later deploys follow it
+    index_block(&create_block_with_txs(vec![
+        create_coinbase_transaction(1),
+        inscribe("deploy", "news", &[("max", "1000"), ("lim", "1000")], &deployer, 21),
+    ]), 1);
+    assert_eq!(tickers(Brc20TickerOrder::Deploy), ["olds", "news"]);
+    assert_eq!(BRC20_ORIGINAL_TICKERS.get(), Some(1));
+
+    let olds = ticker("olds");
+    assert_eq!(olds.current_supply, "200.000000000000000000");
+    assert_eq!(olds.deploy_height, 0);
+    assert_eq!(olds.deployer, ledger::pkscript_key(&deployer.script_pubkey()));
+}
@@ -10,11 +10,11 @@
 
 use wasm_bindgen_test::wasm_bindgen_test as test;
 use crate::brc20::{
-    Brc20Indexer, Brc20Operation, Ticker, Balance, TransferInfo,
+    Brc20Indexer, Brc20Operation, TransferInfo,
     TransferDestination, MAX_AMOUNT,
     OP_RETURN_PKSCRIPT, BRC20_PROG_OP_RETURN_PKSCRIPT, BRC20_PROG_ALL_TICKERS_HEIGHT,
 };
-use crate::tables::{Brc20Tickers, Brc20Balances, Brc20TransferableInscriptions};
+use crate::tables::{BRC20_TICKERS, BRC20_BALANCES, BRC20_TRANSFERABLE_INSCRIPTIONS};
 use shrew_test_helpers::state::clear;
 use shrew_test_helpers::assertions::{assert_brc20_balance, assert_brc20_supply};
 
@@ -125,8 +125,7 @@ fn test_wallet_transfer_preserves_supply() {
     // Supply unchanged
     assert_brc20_supply("ordi", 1000 * SCALE);
     // No burned supply
-    let data = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").unwrap();
     assert_eq!(ticker.burned_supply, 0);
 }
 
@@ -176,8 +175,7 @@ fn test_burn_increments_burned_supply() {
     let info = transfer_info("ordi", 400 * SCALE, "bc1qsender");
     indexer.resolve_transfer(TransferDestination::Burn, &info, 840000).unwrap();
 
-    let data = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").unwrap();
     assert_eq!(ticker.burned_supply, 400 * SCALE, "burned_supply should increase by burned amount");
     assert_eq!(ticker.current_supply, 1000 * SCALE, "current_supply unchanged (tokens were minted)");
 }
@@ -193,7 +191,7 @@ fn test_burn_does_not_add_to_any_recipient() {
     indexer.resolve_transfer(TransferDestination::Burn, &info, 840000).unwrap();
 
     // No one receives the burned tokens
-    let op_return_balance = Brc20Balances::new().get("6a", "ordi");
+    let op_return_balance = BRC20_BALANCES.get("6a", "ordi");
     assert!(op_return_balance.is_none(), "No balance should exist at OP_RETURN address");
 }
 
@@ -212,8 +210,7 @@ fn test_burn_multiple_times_accumulates() {
     let info2 = transfer_info("ordi", 200 * SCALE, "bc1qsender");
     indexer.resolve_transfer(TransferDestination::Burn, &info2, 840000).unwrap();
 
-    let data = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").unwrap();
     assert_eq!(ticker.burned_supply, 400 * SCALE, "burned_supply should accumulate");
     assert_brc20_balance("bc1qsender", "ordi", 600 * SCALE, 600 * SCALE);
 }
@@ -230,8 +227,7 @@ fn test_burn_all_tokens() {
 
     assert_brc20_balance("bc1qsender", "ordi", 0, 0);
 
-    let data = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").unwrap();
     assert_eq!(ticker.burned_supply, 1000 * SCALE);
 }
 
@@ -266,8 +262,7 @@ fn test_sent_as_fee_no_burn() {
     let info = transfer_info("ordi", 400 * SCALE, "bc1qsender");
     indexer.resolve_transfer(TransferDestination::SentAsFee, &info, 840000).unwrap();
 
-    let data = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").unwrap();
     assert_eq!(ticker.burned_supply, 0, "No tokens should be burned on fee return");
 }
 
@@ -295,7 +290,7 @@ fn test_sent_as_fee_no_recipient_created() {
     indexer.resolve_transfer(TransferDestination::SentAsFee, &info, 840000).unwrap();
 
     // No external recipient should have tokens
-    let any_balance = Brc20Balances::new().get("bc1qrecipient", "ordi");
+    let any_balance = BRC20_BALANCES.get("bc1qrecipient", "ordi");
     assert!(any_balance.is_none(), "No recipient should receive tokens on fee return");
 }
 
@@ -315,12 +310,11 @@ fn test_brc20_prog_deposit_before_phase1_burns() {
     indexer.resolve_transfer(TransferDestination::Brc20ProgDeposit, &info, 912689).unwrap();
 
     // Tokens burned
-    let data = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").unwrap();
     assert_eq!(ticker.burned_supply, 400 * SCALE, "Should burn before phase 1");
 
     // No deposit balance
-    let prog_balance = Brc20Balances::new().get(BRC20_PROG_OP_RETURN_PKSCRIPT, "ordi");
+    let prog_balance = BRC20_BALANCES.get(BRC20_PROG_OP_RETURN_PKSCRIPT, "ordi");
     assert!(prog_balance.is_none(), "No prog deposit before phase 1");
 }
 
@@ -336,8 +330,7 @@ fn test_brc20_prog_deposit_4byte_ticker_after_phase1_burns() {
     // After phase 1 but 4-byte ticker (< 6 bytes) and before phase 2: should burn
     indexer.resolve_transfer(TransferDestination::Brc20ProgDeposit, &info, 912690).unwrap();
 
-    let data = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").unwrap();
     assert_eq!(ticker.burned_supply, 400 * SCALE,
         "4-byte ticker should burn even after phase 1 (before phase 2)");
 }
@@ -366,14 +359,12 @@ fn test_brc20_prog_deposit_6byte_ticker_after_phase1_deposits() {
     indexer.resolve_transfer(TransferDestination::Brc20ProgDeposit, &info, 912690).unwrap();
 
     // Tokens deposited, not burned
-    let data = Brc20Tickers::new().get("abcdef").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("abcdef").unwrap();
     assert_eq!(ticker.burned_supply, 0, "6-byte ticker should deposit, not burn");
 
     // Prog address should have balance
-    let prog_data = Brc20Balances::new().get(BRC20_PROG_OP_RETURN_PKSCRIPT, "abcdef")
+    let prog_balance = BRC20_BALANCES.get(BRC20_PROG_OP_RETURN_PKSCRIPT, "abcdef")
         .expect("Prog deposit balance should exist");
-    let prog_balance: Balance = serde_json::from_slice(&prog_data).unwrap();
     assert_eq!(prog_balance.total_balance, 400 * SCALE);
     assert_eq!(prog_balance.available_balance, 400 * SCALE);
 
@@ -407,8 +398,7 @@ fn test_brc20_prog_deposit_accumulates() {
     let info2 = transfer_info("abcdef", 300 * SCALE, "bc1qsender");
     indexer.resolve_transfer(TransferDestination::Brc20ProgDeposit, &info2, 912690).unwrap();
 
-    let prog_data = Brc20Balances::new().get(BRC20_PROG_OP_RETURN_PKSCRIPT, "abcdef").unwrap();
-    let prog_balance: Balance = serde_json::from_slice(&prog_data).unwrap();
+    let prog_balance = BRC20_BALANCES.get(BRC20_PROG_OP_RETURN_PKSCRIPT, "abcdef").unwrap();
     assert_eq!(prog_balance.total_balance, 500 * SCALE, "Deposits should accumulate");
 
     assert_brc20_balance("bc1qsender", "abcdef", 500 * SCALE, 500 * SCALE);
@@ -437,8 +427,7 @@ fn test_mix_wallet_and_burn() {
     assert_brc20_balance("bc1qsender", "ordi", 500 * SCALE, 500 * SCALE);
     assert_brc20_balance("bc1qbob", "ordi", 300 * SCALE, 300 * SCALE);
 
-    let data = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").unwrap();
     assert_eq!(ticker.burned_supply, 200 * SCALE);
     assert_eq!(ticker.current_supply, 1000 * SCALE); // minted supply unchanged
 }
@@ -469,8 +458,7 @@ fn test_mix_wallet_burn_fee() {
     assert_brc20_balance("bc1qsender", "ordi", 500 * SCALE, 500 * SCALE);
     assert_brc20_balance("bc1qbob", "ordi", 200 * SCALE, 200 * SCALE);
 
-    let data = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").unwrap();
     assert_eq!(ticker.burned_supply, 300 * SCALE);
 }
 
@@ -485,8 +473,7 @@ fn test_burned_supply_default_zero_on_existing_ticker() {
     setup_sender(&indexer, "ordi", 1000 * SCALE, "bc1qsender");
 
     // Ticker created without explicit burned_supply — serde default should be 0
-    let data = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").unwrap();
     assert_eq!(ticker.burned_supply, 0, "burned_supply should default to 0");
 }
 
@@ -502,8 +489,7 @@ fn test_burned_supply_serialization_roundtrip() {
     indexer.resolve_transfer(TransferDestination::Burn, &info, 840000).unwrap();
 
     // Re-read and verify
-    let data = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").unwrap();
     assert_eq!(ticker.burned_supply, 100 * SCALE);
 
     // Burn more and verify accumulation persists through serialization
@@ -511,8 +497,7 @@ fn test_burned_supply_serialization_roundtrip() {
     let info2 = transfer_info("ordi", 50 * SCALE, "bc1qsender");
     indexer.resolve_transfer(TransferDestination::Burn, &info2, 840000).unwrap();
 
-    let data2 = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker2: Ticker = serde_json::from_slice(&data2).unwrap();
+    let ticker2 = BRC20_TICKERS.get("ordi").unwrap();
     assert_eq!(ticker2.burned_supply, 150 * SCALE);
 }
 
@@ -616,8 +601,7 @@ fn test_full_lifecycle_inscribe_classify_resolve_burn() {
     indexer.resolve_transfer(dest, &info, 840000).unwrap();
 
     assert_brc20_balance("bc1qsender", "ordi", 500 * SCALE, 500 * SCALE);
-    let data = Brc20Tickers::new().get("ordi").unwrap();
-    let ticker: Ticker = serde_json::from_slice(&data).unwrap();
+    let ticker = BRC20_TICKERS.get("ordi").unwrap();
     assert_eq!(ticker.burned_supply, 500 * SCALE);
 }
 
@@ -664,8 +648,7 @@ fn test_full_lifecycle_inscribe_classify_resolve_prog_deposit() {
     indexer.resolve_transfer(dest, &info, 912690).unwrap();
 
     assert_brc20_balance("bc1qsender", "abcdef", 500 * SCALE, 500 * SCALE);
-    let prog_data = Brc20Balances::new().get(BRC20_PROG_OP_RETURN_PKSCRIPT, "abcdef").unwrap();
-    let prog_balance: Balance = serde_json::from_slice(&prog_data).unwrap();
+    let prog_balance = BRC20_BALANCES.get(BRC20_PROG_OP_RETURN_PKSCRIPT, "abcdef").unwrap();
     assert_eq!(prog_balance.total_balance, 500 * SCALE);
 }
 
@@ -765,3 +748,92 @@ fn test_brc20_prog_deposit_events_clear() {
     deposits.clear(100);
     assert_eq!(deposits.get(100).len(), 0, "Clear should remove all events for height");
 }
+
+// ============================================================================
+// Destinations located by sat flow through shrew-ord
+// ============================================================================
+
+use crate::ledger;
+use bitcoin::{Address, Amount, OutPoint, Sequence, ScriptBuf, Transaction, TxIn, TxOut, Witness};
+use bitcoin::transaction::Version;
+use shrew_test_helpers::blocks::{create_block_with_txs, create_coinbase_transaction};
+use shrew_test_helpers::brc20::{create_brc20_deploy_block, create_brc20_mint_block, create_brc20_transfer_inscribe_block, index_block};
+use shrew_test_helpers::state::get_test_address;
+use shrew_test_helpers::transactions::create_mock_outpoint;
+
+fn balance(owner: &Address, ticker: &str) -> (u128, u128) {
+    let balance = BRC20_BALANCES.get(&ledger::pkscript_key(&owner.script_pubkey()), ticker).unwrap_or_else(|| crate::brc20::Balance::new(ticker.to_string()));
+    (balance.total_balance, balance.available_balance)
+}
+
+/// Transaction spending `inputs` in order into outputs of 10,000 sats each
+fn spend(inputs: &[OutPoint], outputs: &[&Address]) -> Transaction {
+    Transaction {
+        version: Version(2),
+        lock_time: bitcoin::absolute::LockTime::ZERO,
+        input: inputs.iter().map(|previous_output| TxIn {
+            previous_output: *previous_output,
+            script_sig: ScriptBuf::new(),
+            sequence: Sequence::MAX,
+            witness: Witness::new(),
+        }).collect(),
+        output: outputs.iter().map(|address| TxOut {
+            value: Amount::from_sat(10000),
+            script_pubkey: address.script_pubkey(),
+        }).collect(),
+    }
+}
+
+/// Deploy `dest`, mint 1000 to `owner` and inscribe one transfer per amount,
+/// each in its own block starting at height 2. Returns the transfer reveals.
+fn setup_transfers(owner: &Address, amounts: &[&str]) -> Vec<Transaction> {
+    let (deploy_block, _) = create_brc20_deploy_block("dest", "21000", "1000");
+    index_block(&deploy_block, 0);
+    let (mint_block, _) = create_brc20_mint_block("dest", "1000", owner, &create_mock_outpoint(1).txid);
+    index_block(&mint_block, 1);
+    amounts.iter().enumerate().map(|(i, amount)| {
+        let (block, tx) = create_brc20_transfer_inscribe_block("dest", amount, owner, &create_mock_outpoint(10 + i as u32).txid);
+        index_block(&block, 2 + i as u32);
+        tx
+    }).collect()
+}
+
+#[test]
+fn test_transfers_follow_their_sats_to_each_output() {
+    clear();
+    let alice = get_test_address(1);
+    let bob = get_test_address(2);
+    let carol = get_test_address(3);
+    let transfers = setup_transfers(&alice, &["100", "200"]);
+
+    // The second transfer's sat is at offset 10,000, which is the second output
+    let claim = spend(
+        &[OutPoint::new(transfers[0].compute_txid(), 0), OutPoint::new(transfers[1].compute_txid(), 0)],
+        &[&bob, &carol],
+    );
+    index_block(&create_block_with_txs(vec![create_coinbase_transaction(4), claim]), 4);
+
+    assert_eq!(balance(&alice, "dest"), (700 * SCALE, 700 * SCALE));
+    assert_eq!(balance(&bob, "dest"), (100 * SCALE, 100 * SCALE));
+    assert_eq!(balance(&carol, "dest"), (200 * SCALE, 200 * SCALE));
+}
+
+#[test]
+fn test_transfer_whose_sat_lands_in_the_fee_returns_to_sender() {
+    clear();
+    let alice = get_test_address(1);
+    let bob = get_test_address(2);
+    let transfers = setup_transfers(&alice, &["100", "50"]);
+
+    // Two 10,000 sat inputs into a single 10,000 sat output: the first
+    // transfer reaches bob, the second is paid as fee
+    let claim = spend(
+        &[OutPoint::new(transfers[0].compute_txid(), 0), OutPoint::new(transfers[1].compute_txid(), 0)],
+        &[&bob],
+    );
+    index_block(&create_block_with_txs(vec![create_coinbase_transaction(4), claim]), 4);
+
+    assert_eq!(balance(&alice, "dest"), (900 * SCALE, 900 * SCALE));
+    assert_eq!(balance(&bob, "dest"), (100 * SCALE, 100 * SCALE));
+    assert!(BRC20_TRANSFERABLE_INSCRIPTIONS.get(&format!("{}i0", transfers[1].compute_txid())).is_none());
+}
@@ -0,0 +1,158 @@
+use wasm_bindgen_test::wasm_bindgen_test as test;
+use crate::brc20::TransferInfo;
+use crate::error::Brc20Error;
+use crate::ledger;
+use crate::proto::{
+    Brc20TransferState, Brc20TransferStatusResponse, GetBrc20TransferStatusRequest, GetBrc20TransfersRequest,
+    InscriptionId as ProtoInscriptionId,
+};
+use crate::tables::BRC20_TRANSFERABLE_INSCRIPTIONS;
+use crate::view;
+use bitcoin::{Address, Network, Transaction};
+use bitcoin_hashes::Hash;
+use shrew_support::pagination::PaginationRequest;
+use shrew_support::InscriptionId;
+use shrew_test_helpers::blocks::*;
+use shrew_test_helpers::brc20::*;
+use shrew_test_helpers::indexing::index_ord_block;
+use shrew_test_helpers::state::{clear, get_test_address, view_address};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn inscribe_tsts(op: &str, amount: &str, owner: &Address, commit: u32) -> Transaction {
+    let fields: &[(&str, &str)] = match op {
+        "deploy" => &[("max", "21000"), ("lim", "1000")],
+        _ => &[("amt", amount)],
+    };
+    inscribe(op, "tsts", fields, owner, commit)
+}
+
+fn status(tx: &Transaction) -> Brc20TransferStatusResponse {
+    let inscription_id = ProtoInscriptionId { txid: tx.compute_txid().to_byte_array().to_vec(), index: 0 };
+    view::get_brc20_transfer_status(&GetBrc20TransferStatusRequest { inscription_id: Some(inscription_id) }).unwrap()
+}
+
+fn open_transfers(owner: &Address, pagination: Option<PaginationRequest>) -> Vec<String> {
+    let request = GetBrc20TransfersRequest { address: view_address(owner), pagination };
+    view::get_brc20_transfers(&request).unwrap().transfers.into_iter()
+        .map(|transfer| transfer.inscription_id)
+        .collect()
+}
+
+fn id(tx: &Transaction) -> String {
+    InscriptionId::new(tx.compute_txid(), 0).to_string()
+}
+
+#[wasm_bindgen_test]
+fn test_transfer_status_follows_the_inscription() {
+    clear();
+    let alice = get_test_address(1);
+    let bob = get_test_address(2);
+    let mint = inscribe_tsts("mint", "300", &alice, 1);
+    index_block(&create_block_with_txs(vec![create_coinbase_transaction(0), inscribe_tsts("deploy", "", &alice, 20)]), 0);
+    index_block(&create_block_with_txs(vec![create_coinbase_transaction(1), mint.clone()]), 1);
+    let transfer = inscribe_tsts("transfer", "100", &alice, 2);
+    let overdrawn = inscribe_tsts("transfer", "1000", &alice, 3);
+    index_block(&create_block_with_txs(vec![create_coinbase_transaction(2), transfer.clone(), overdrawn.clone()]), 2);
+
+    let response = status(&transfer);
+    assert_eq!(response.state(), Brc20TransferState::Valid);
+    assert_eq!((response.ticker.as_str(), response.amount.as_str()), ("tsts", "100.000000000000000000"));
+    assert_eq!(response.owner, ledger::pkscript_key(&alice.script_pubkey()));
+    assert_eq!(open_transfers(&alice, None), [id(&transfer)]);
+
+    let (claim_block, _) = create_brc20_transfer_claim_block(&transfer, &bob);
+    index_block(&claim_block, 3);
+    let response = status(&transfer);
+    assert_eq!(response.state(), Brc20TransferState::Used);
+    assert_eq!(response.used_height, 3);
+    assert_eq!(response.used_to, ledger::pkscript_key(&bob.script_pubkey()));
+    assert_eq!(response.amount, "100.000000000000000000");
+    assert!(open_transfers(&alice, None).is_empty());
+
+    let response = status(&overdrawn);
+    assert_eq!(response.state(), Brc20TransferState::Invalid);
+    assert_eq!(response.reason, "insufficient_balance");
+    assert_eq!(response.amount, "1000.000000000000000000");
+
+    let response = status(&mint);
+    assert_eq!(response.state(), Brc20TransferState::Invalid);
+    assert_eq!(response.reason, "not_a_transfer");
+}
+
+#[wasm_bindgen_test]
+fn test_open_transfer_pages_resume_after_the_last_id() {
+    clear();
+    let alice = get_test_address(1);
+    index_block(&create_block_with_txs(vec![create_coinbase_transaction(0), inscribe_tsts("deploy", "", &alice, 20)]), 0);
+    index_block(&create_block_with_txs(vec![create_coinbase_transaction(1), inscribe_tsts("mint", "300", &alice, 1)]), 1);
+    let transfers = [
+        inscribe_tsts("transfer", "100", &alice, 2),
+        inscribe_tsts("transfer", "50", &alice, 3),
+        inscribe_tsts("transfer", "25", &alice, 4),
+    ];
+    let mut block = vec![create_coinbase_transaction(2)];
+    block.extend(transfers.iter().cloned());
+    index_block(&create_block_with_txs(block), 2);
+
+    let mut ids: Vec<String> = transfers.iter().map(id).collect();
+    ids.sort();
+    assert_eq!(open_transfers(&alice, None), ids);
+
+    let request = GetBrc20TransfersRequest {
+        address: view_address(&alice),
+        pagination: Some(PaginationRequest { limit: 1, ..Default::default() }),
+    };
+    let response = view::get_brc20_transfers(&request).unwrap();
+    assert_eq!(response.transfers[0].inscription_id, ids[0]);
+    let cursor = response.pagination.unwrap().next_cursor;
+
+    // Claiming the transfer already listed does not shift the next page
+    let claimed = transfers.iter().find(|tx| id(tx) == ids[0]).unwrap();
+    let (claim_block, _) = create_brc20_transfer_claim_block(claimed, &get_test_address(2));
+    index_block(&claim_block, 3);
+    let page = PaginationRequest { limit: 1, cursor, ..Default::default() };
+    assert_eq!(open_transfers(&alice, Some(page)), [ids[1].clone()]);
+}
+
+#[wasm_bindgen_test]
+fn test_transfers_stored_before_the_events_existed() {
+    clear();
+    let alice = get_test_address(1);
+    let deploy = inscribe_tsts("deploy", "", &alice, 20);
+    let pending = inscribe_tsts("transfer", "100", &alice, 1);
+    let claimed = inscribe_tsts("transfer", "50", &alice, 2);
+    index_ord_block(&create_block_with_txs(vec![
+        create_coinbase_transaction(0), deploy.clone(), pending.clone(), claimed.clone(),
+    ]), 0).unwrap();
+
+    // The original layout kept the pending transfer under its id and the
+    // sender's mainnet address, and nothing for the claimed one
+    let scale = 10u128.pow(18);
+    set_original_layout_ticker("tsts", &id(&deploy), 21_000 * scale, 1_000 * scale);
+    let sender = Address::from_script(&alice.script_pubkey(), Network::Bitcoin).unwrap().to_string();
+    BRC20_TRANSFERABLE_INSCRIPTIONS.set(&id(&pending), &TransferInfo { ticker: "tsts".to_string(), amount: 100 * scale, sender });
+    index_block(&create_block_with_txs(vec![create_coinbase_transaction(1)]), 1);
+
+    assert_eq!(open_transfers(&alice, None), [id(&pending)]);
+    let response = status(&pending);
+    assert_eq!(response.state(), Brc20TransferState::Valid);
+    assert_eq!(response.owner, ledger::pkscript_key(&alice.script_pubkey()));
+
+    // Used or rejected before events were recorded: not reported invalid
+    let response = status(&claimed);
+    assert_eq!(response.state(), Brc20TransferState::Unknown);
+    assert_eq!((response.ticker.as_str(), response.amount.as_str()), ("tsts", "50.000000000000000000"));
+    let response = status(&deploy);
+    assert_eq!((response.state(), response.reason.as_str()), (Brc20TransferState::Invalid, "not_a_transfer"));
+}
+
+#[wasm_bindgen_test]
+fn test_transfer_status_of_unknown_inscription_is_not_found() {
+    clear();
+    let request = GetBrc20TransferStatusRequest {
+        inscription_id: Some(ProtoInscriptionId { txid: vec![7; 32], index: 0 }),
+    };
+    assert!(matches!(view::get_brc20_transfer_status(&request), Err(Brc20Error::UnknownInscription(_))));
+    let request = GetBrc20TransferStatusRequest { inscription_id: None };
+    assert!(matches!(view::get_brc20_transfer_status(&request), Err(Brc20Error::MissingField("inscription_id"))));
+}
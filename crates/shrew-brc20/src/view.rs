@@ -1,41 +1,318 @@
 use crate::tables::*;
 use crate::proto::{
-    GetBalanceRequest, BalanceResponse, GetBrc20EventsRequest, Brc20EventsResponse, Brc20Event,
-    get_brc20_events_request,
+    GetBalanceRequest, BalanceResponse, GetBrc20EventsRequest, Brc20EventsResponse,
+    get_brc20_events_request, GetEventHashRequest, EventHashResponse,
+    GetBrc20TickerRequest, Brc20TickerResponse, GetBrc20TickersRequest, Brc20TickersResponse,
+    Brc20Ticker, Brc20TickerOrder, GetBrc20HoldersRequest, Brc20HoldersResponse, Brc20Holder,
+    GetBrc20PortfolioRequest, Brc20PortfolioResponse, Brc20PortfolioBalance, Brc20TransferInscription,
+    GetBrc20TransfersRequest, Brc20TransfersResponse, GetBrc20TransferStatusRequest,
+    Brc20TransferStatusResponse, Brc20TransferState, InscriptionId as ProtoInscriptionId,
 };
-use shrew_support::InscriptionId;
-use bitcoin::Txid;
+use crate::brc20::{Brc20Indexer, Brc20Operation, Ticker, TransferInfo};
+use crate::error::Brc20Error;
+use crate::event_hash::format_amount;
+use crate::ledger;
+use crate::rules::Brc20Rules;
+use shrew_ord::entries;
+use shrew_ord::tables::{
+    InscriptionContentTable, INSCRIPTION_ID_TO_SEQUENCE, OUTPOINT_TO_SCRIPT, TXID_TO_COMPACT_TX, TXID_TO_RAW_TX,
+};
+use shrew_support::{InscriptionEntry, InscriptionId};
+use shrew_support::pagination::{paginate, paginate_by_key};
+use bitcoin::consensus::deserialize;
+use bitcoin::{Address, OutPoint, Script, Transaction, Txid};
 use bitcoin_hashes::Hash;
-use metashrew_support::index_pointer::KeyValuePointer;
+use shrew_support::constants::NETWORK;
+use std::str::FromStr;
 
-pub fn get_balance(request: &GetBalanceRequest) -> Result<BalanceResponse, String> {
+pub fn get_balance(request: &GetBalanceRequest) -> Result<BalanceResponse, Brc20Error> {
+    let owner = ledger::owner_key(&request.address)?;
     let mut response = BalanceResponse::default();
-    let balance_bytes = BRC20_BALANCES.select(&format!("{}:{}", request.address, request.ticker).as_bytes().to_vec()).get();
-    if !balance_bytes.is_empty() {
-        response.balance = String::from_utf8(balance_bytes.to_vec()).unwrap_or_default();
+    if let Some(balance) = ledger::balance(&owner, &request.ticker) {
+        response.balance = serde_json::to_string(&balance)?;
+    }
+    Ok(response)
+}
+
+pub fn get_brc20_portfolio(request: &GetBrc20PortfolioRequest) -> Result<Brc20PortfolioResponse, Brc20Error> {
+    let owner = ledger::owner_key(&request.address)?;
+    let balances = ledger::balances(&owner).into_iter()
+        .filter(|balance| balance.total_balance > 0)
+        .map(|balance| {
+            let decimals = ticker_decimals(&balance.ticker);
+            Brc20PortfolioBalance {
+                total_balance: format_amount(balance.total_balance, decimals),
+                available_balance: format_amount(balance.available_balance, decimals),
+                transferable_balance: format_amount(balance.total_balance.saturating_sub(balance.available_balance), decimals),
+                ticker: balance.ticker,
+            }
+        })
+        .collect();
+    let transfers = ledger::transferables(&owner).into_iter()
+        .map(|(inscription_id, transfer_info)| transfer_inscription(inscription_id, transfer_info))
+        .collect();
+    Ok(Brc20PortfolioResponse { balances, transfers })
+}
+
+pub fn get_brc20_transfers(request: &GetBrc20TransfersRequest) -> Result<Brc20TransfersResponse, Brc20Error> {
+    let owner = ledger::owner_key(&request.address)?;
+    // Claimed transfers leave the list, so pages resume after the last id
+    let transferables = ledger::transferables(&owner);
+    let (page, pagination) = paginate_by_key(transferables, |(inscription_id, _)| inscription_id.clone(), request.pagination.as_ref())?;
+    Ok(Brc20TransfersResponse {
+        transfers: page.into_iter()
+            .map(|(inscription_id, transfer_info)| transfer_inscription(inscription_id, transfer_info))
+            .collect(),
+        pagination: Some(pagination),
+    })
+}
+
+/// Whether a transfer inscription can still move its amount. Used and
+/// rejected transfers are told apart by the events recorded for them.
+pub fn get_brc20_transfer_status(request: &GetBrc20TransferStatusRequest) -> Result<Brc20TransferStatusResponse, Brc20Error> {
+    let proto_id = request.inscription_id.as_ref().ok_or(Brc20Error::MissingField("inscription_id"))?;
+    let inscription_id = inscription_id_of(proto_id)?;
+    let sequence = INSCRIPTION_ID_TO_SEQUENCE.get(&inscription_id)
+        .ok_or_else(|| Brc20Error::UnknownInscription(inscription_id.to_string()))?;
+    let mut response = Brc20TransferStatusResponse::default();
+
+    if let Some(transfer_info) = BRC20_TRANSFERABLE_INSCRIPTIONS.get(&inscription_id.to_string()) {
+        response.set_state(Brc20TransferState::Valid);
+        response.amount = format_amount(transfer_info.amount, ticker_decimals(&transfer_info.ticker));
+        response.owner = ledger::stored_owner_key(&transfer_info.sender)?;
+        response.ticker = transfer_info.ticker;
+        return Ok(response);
+    }
+
+    let events = BRC20_INSCRIPTION_EVENTS.get(&inscription_id).unwrap_or_default();
+    let inscribed = events.iter().find(|event| event.event_type == "transfer-inscribe");
+    let claimed = events.iter().find(|event| event.event_type == "transfer-transfer");
+    match (inscribed, claimed) {
+        (Some(inscribed), _) if !inscribed.rejection.is_empty() => {
+            response.reason = inscribed.rejection.clone();
+        }
+        (_, Some(claimed)) => {
+            response.set_state(Brc20TransferState::Used);
+            response.used_height = claimed.block_height;
+            response.used_to = claimed.to.clone();
+        }
+        _ => {
+            let rules = Brc20Rules::default();
+            match entries::load(sequence).ok().flatten() {
+                Some(entry) if rules.is_cursed(&entry) => response.reason = "cursed".to_string(),
+                Some(entry) => match unrecorded_transfer(&entry, rules) {
+                    Some((ticker, amount)) => {
+                        response.set_state(Brc20TransferState::Unknown);
+                        response.amount = format_amount(amount, ticker_decimals(&ticker));
+                        response.ticker = ticker;
+                    }
+                    None => response.reason = "not_a_transfer".to_string(),
+                },
+                None => response.reason = "not_a_transfer".to_string(),
+            }
+            return Ok(response);
+        }
+    }
+    if let Some(inscribed) = inscribed {
+        response.ticker = inscribed.ticker.clone();
+        response.amount = inscribed.amount.clone();
+        response.owner = inscribed.to.clone();
     }
     Ok(response)
 }
 
-pub fn get_brc20_events(request: &GetBrc20EventsRequest) -> Result<Brc20EventsResponse, String> {
-    let mut response = Brc20EventsResponse::default();
-    let query = request.query.as_ref().ok_or("Request must specify a query")?;
-    let events_bytes = match query {
+pub fn get_brc20_events(request: &GetBrc20EventsRequest) -> Result<Brc20EventsResponse, Brc20Error> {
+    let query = request.query.as_ref().ok_or(Brc20Error::MissingField("query"))?;
+    let events = match query {
         get_brc20_events_request::Query::InscriptionId(proto_id) => {
-            let inscription_id = InscriptionId {
-                txid: Txid::from_slice(&proto_id.txid).map_err(|e| e.to_string())?,
-                index: proto_id.index,
-            };
-            BRC20_EVENTS.select(&inscription_id.to_string().as_bytes().to_vec()).get()
+            BRC20_INSCRIPTION_EVENTS.get(&inscription_id_of(proto_id)?)
         }
         get_brc20_events_request::Query::BlockHeight(height) => {
-            BRC20_EVENTS.select(&height.to_le_bytes().to_vec()).get()
+            BRC20_BLOCK_EVENTS.get(height)
         }
     };
-    if !events_bytes.is_empty() {
-        if let Ok(events) = serde_json::from_slice::<Vec<Brc20Event>>(&events_bytes) {
-            response.events = events;
-        }
+    let (events, pagination) = paginate(events.unwrap_or_default(), request.pagination.as_ref())?;
+    Ok(Brc20EventsResponse { events, pagination: Some(pagination) })
+}
+
+pub fn get_event_hash(request: &GetEventHashRequest) -> Result<EventHashResponse, Brc20Error> {
+    let hashes = BRC20_EVENT_HASHES.get(&request.height).ok_or(Brc20Error::BlockNotIndexed(request.height))?;
+    Ok(EventHashResponse {
+        height: request.height,
+        block_hash: hashes.block_hash,
+        cumulative_hash: hashes.cumulative_hash,
+        complete: hashes.complete,
+    })
+}
+
+pub fn get_brc20_ticker(request: &GetBrc20TickerRequest) -> Result<Brc20TickerResponse, Brc20Error> {
+    let ticker = BRC20_TICKERS.get(&request.ticker).ok_or_else(|| Brc20Error::UnknownTicker(request.ticker.clone()))?;
+    let genesis = deploy_genesis(&ticker);
+    Ok(Brc20TickerResponse { ticker: Some(ticker_info(ticker, genesis)) })
+}
+
+pub fn get_brc20_tickers(request: &GetBrc20TickersRequest) -> Result<Brc20TickersResponse, Brc20Error> {
+    let order = request.order();
+    // Ticker names are listed in the order they were deployed
+    let tickers: Vec<_> = BRC20_TICKERS.entries().into_iter().enumerate()
+        .map(|(position, (_, ticker))| (ticker_key(order, position as u32, &ticker), ticker))
+        .collect();
+    let (page, pagination) = paginate_by_key(tickers, |(key, _)| key.clone(), request.pagination.as_ref())?;
+    Ok(Brc20TickersResponse {
+        tickers: page.into_iter()
+            .map(|(_, ticker)| {
+                let genesis = deploy_genesis(&ticker);
+                ticker_info(ticker, genesis)
+            })
+            .collect(),
+        pagination: Some(pagination),
+    })
+}
+
+pub fn get_brc20_holders(request: &GetBrc20HoldersRequest) -> Result<Brc20HoldersResponse, Brc20Error> {
+    let ticker = BRC20_TICKERS.get(&request.ticker).ok_or_else(|| Brc20Error::UnknownTicker(request.ticker.clone()))?;
+    let holders = BRC20_HOLDERS.entries(&ticker.name);
+    let (page, pagination) = paginate_by_key(holders, holder_key, request.pagination.as_ref())?;
+    let amount = |value: u128| format_amount(value, ticker.decimals);
+    let holders = page.into_iter()
+        .map(|(owner, total)| {
+            let available = BRC20_BALANCES.get(&owner, &ticker.name).map_or(0, |balance| balance.available_balance);
+            Brc20Holder {
+                address: address_of(&owner),
+                total_balance: amount(total),
+                available_balance: amount(available),
+                owner,
+            }
+        })
+        .collect();
+    Ok(Brc20HoldersResponse { holders, pagination: Some(pagination) })
+}
+
+/// Sort key of a ticker deployed at `position` of the ticker list: its rank in
+/// `order`, highest first, then its deploy position
+fn ticker_key(order: Brc20TickerOrder, position: u32, ticker: &Ticker) -> Vec<u8> {
+    let rank = match order {
+        Brc20TickerOrder::Deploy => 0,
+        Brc20TickerOrder::Holders => holders(&ticker.name) as u128,
+        Brc20TickerOrder::Progress => mint_progress(ticker),
+    };
+    let mut key = (u128::MAX - rank).to_be_bytes().to_vec();
+    key.extend_from_slice(&position.to_be_bytes());
+    key
+}
+
+/// Sort key of a holder, ordering by total balance descending, then by owner
+fn holder_key((owner, total): &(String, u128)) -> Vec<u8> {
+    let mut key = (u128::MAX - total).to_be_bytes().to_vec();
+    key.extend_from_slice(owner.as_bytes());
+    key
+}
+
+fn inscription_id_of(proto_id: &ProtoInscriptionId) -> Result<InscriptionId, Brc20Error> {
+    Ok(InscriptionId {
+        txid: Txid::from_slice(&proto_id.txid).map_err(Brc20Error::invalid_txid)?,
+        index: proto_id.index,
+    })
+}
+
+/// Ticker and amount of `entry` if it is a transfer inscription revealed
+/// before the store recorded BRC-20 events
+fn unrecorded_transfer(entry: &InscriptionEntry, rules: Brc20Rules) -> Option<(String, u128)> {
+    if !BRC20_EVENTS_SINCE.get().is_some_and(|since| entry.height < since) {
+        return None;
+    }
+    let content = InscriptionContentTable::new().get(&entry.id)?;
+    match Brc20Indexer::with_rules(rules).parse_operation(&content, entry.height)? {
+        Brc20Operation::Transfer { ticker, amount } => Some((ticker, amount)),
+        _ => None,
+    }
+}
+
+fn transfer_inscription(inscription_id: String, transfer_info: TransferInfo) -> Brc20TransferInscription {
+    Brc20TransferInscription {
+        inscription_id,
+        amount: format_amount(transfer_info.amount, ticker_decimals(&transfer_info.ticker)),
+        ticker: transfer_info.ticker,
+    }
+}
+
+fn ticker_decimals(ticker: &str) -> u8 {
+    BRC20_TICKERS.get(ticker).map_or(18, |ticker| ticker.decimals)
+}
+
+/// Address of a ledger key on the indexed network, empty if the pkscript has none
+fn address_of(owner: &str) -> String {
+    hex::decode(owner).ok()
+        .and_then(|script| Address::from_script(Script::from_bytes(&script), NETWORK).ok())
+        .map(|address| address.to_string())
+        .unwrap_or_default()
+}
+
+/// Where a ticker's deploy inscription was revealed
+struct DeployGenesis {
+    height: u32,
+    /// pkscript of the output the deploy inscription was revealed to
+    deployer: Vec<u8>,
+}
+
+/// Genesis of `ticker`'s deploy inscription, read from its entry and genesis
+/// output. `None` if ord does not know the deploy inscription.
+fn deploy_genesis(ticker: &Ticker) -> Option<DeployGenesis> {
+    let inscription_id = InscriptionId::from_str(&ticker.deploy_inscription_id).ok()?;
+    let sequence = INSCRIPTION_ID_TO_SEQUENCE.get(&inscription_id)?;
+    let entry = entries::load(sequence).ok().flatten()?;
+    Some(DeployGenesis {
+        height: entry.height,
+        deployer: output_script(&entry.satpoint.outpoint).unwrap_or_default(),
+    })
+}
+
+/// Script of `outpoint`, from the script shrew-ord keeps for every output
+/// under relevant-only retention, else from its transaction if it was kept
+fn output_script(outpoint: &OutPoint) -> Option<Vec<u8>> {
+    if outpoint.is_null() {
+        return None;
+    }
+    if let Some(script) = OUTPOINT_TO_SCRIPT.get(outpoint) {
+        return Some(script);
+    }
+    let raw = TXID_TO_RAW_TX.get(&outpoint.txid).or_else(|| TXID_TO_COMPACT_TX.get(&outpoint.txid))?;
+    let tx: Transaction = deserialize(&raw).ok()?;
+    tx.output.get(outpoint.vout as usize).map(|output| output.script_pubkey.to_bytes())
+}
+
+fn holders(ticker: &str) -> u64 {
+    BRC20_HOLDER_COUNTS.get(ticker).unwrap_or(0)
+}
+
+/// current_supply as a share of max_supply, in basis points
+fn mint_progress(ticker: &Ticker) -> u128 {
+    if ticker.max_supply == 0 {
+        return 0;
+    }
+    match ticker.current_supply.checked_mul(10_000) {
+        Some(scaled) => scaled / ticker.max_supply,
+        None => ticker.current_supply / (ticker.max_supply / 10_000),
+    }
+}
+
+fn ticker_info(ticker: Ticker, genesis: Option<DeployGenesis>) -> Brc20Ticker {
+    let amount = |value: u128| format_amount(value, ticker.decimals);
+    let progress = mint_progress(&ticker);
+    Brc20Ticker {
+        max_supply: amount(ticker.max_supply),
+        current_supply: amount(ticker.current_supply),
+        remaining_supply: amount(ticker.max_supply.saturating_sub(ticker.current_supply)),
+        limit_per_mint: amount(ticker.limit_per_mint),
+        burned_supply: amount(ticker.burned_supply),
+        decimals: ticker.decimals as u32,
+        self_mint: ticker.is_self_mint,
+        deploy_height: genesis.as_ref().map_or(0, |genesis| genesis.height),
+        deployer: genesis.map(|genesis| hex::encode(genesis.deployer)).unwrap_or_default(),
+        mint_progress: format!("{}.{:02}", progress / 100, progress % 100),
+        holders: holders(&ticker.name),
+        transfers: BRC20_TRANSFER_COUNTS.get(&ticker.name).unwrap_or(0),
+        deploy_inscription_id: ticker.deploy_inscription_id,
+        ticker: ticker.name,
     }
-    Ok(response)
 }
@@ -1,5 +1,6 @@
 use crate::tables::*;
 use shrew_ord::tables::HEIGHT_TO_BLOCK_HASH;
+use bitcoin_hashes::Hash;
 use revm::primitives::{Address, B256, U256};
 use revm::state::{Account, AccountInfo, Bytecode};
 use revm::{Database, DatabaseCommit};
@@ -30,58 +31,34 @@ impl Database for MetashrewDB {
     type Error = MetashrewError;
 
     fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
-        let pointer = EVM_ACCOUNTS.select(&address.to_vec());
-        let result = pointer.get();
-        if result.is_empty() {
-            Ok(None)
-        } else {
-            let mut account_info: AccountInfo = match bincode::deserialize(&result) {
-                Ok(info) => info,
-                Err(_) => return Ok(None),
-            };
-            // Load contract code from storage (matching canonical brc20-prog behavior).
-            // AccountInfo deserialized from bincode may not include the code field,
-            // so we must look it up by code_hash.
-            if account_info.code.is_none() || account_info.code.as_ref().map_or(false, |c| c.is_empty()) {
-                account_info.code = Some(
-                    self.code_by_hash(account_info.code_hash)
-                        .unwrap_or(Bytecode::new())
-                );
-            }
-            Ok(Some(account_info))
+        let Some(mut account_info) = EVM_ACCOUNTS.get(&address) else { return Ok(None) };
+        // Load contract code from storage (matching canonical brc20-prog behavior).
+        // AccountInfo deserialized from bincode may not include the code field,
+        // so we must look it up by code_hash.
+        if account_info.code.is_none() || account_info.code.as_ref().map_or(false, |c| c.is_empty()) {
+            account_info.code = Some(
+                self.code_by_hash(account_info.code_hash)
+                    .unwrap_or(Bytecode::new())
+            );
         }
+        Ok(Some(account_info))
     }
 
     fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
-        let pointer = CODE_HASH_TO_BYTECODE.select(&code_hash.to_vec());
-        let result = pointer.get();
-        if result.is_empty() {
-            Ok(Bytecode::new())
-        } else {
-            Ok(Bytecode::new_raw((*result).clone().into()))
+        match CODE_HASH_TO_BYTECODE.get(&code_hash.0) {
+            Some(bytecode) => Ok(Bytecode::new_raw(bytecode.into())),
+            None => Ok(Bytecode::new()),
         }
     }
 
     fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
-        let mut key = address.to_vec();
-        key.extend_from_slice(&index.to_be_bytes::<32>());
-        let pointer = EVM_STORAGE.select(&key);
-        let result = pointer.get();
-        if result.is_empty() {
-            Ok(U256::ZERO)
-        } else {
-            Ok(U256::from_be_slice(&result))
-        }
+        Ok(EVM_STORAGE.get(&(address, index)).unwrap_or(U256::ZERO))
     }
 
     fn block_hash(&mut self, number: u64) -> Result<B256, Self::Error> {
-        let height_bytes = (number as u32).to_le_bytes().to_vec();
-        let pointer = HEIGHT_TO_BLOCK_HASH.select(&height_bytes);
-        let result = pointer.get();
-        if result.is_empty() {
-            Ok(B256::ZERO)
-        } else {
-            Ok(B256::from_slice(&result))
+        match HEIGHT_TO_BLOCK_HASH.get(&(number as u32)) {
+            Some(hash) => Ok(B256::from_slice(hash.as_byte_array())),
+            None => Ok(B256::ZERO),
         }
     }
 }
@@ -126,19 +103,17 @@ impl DatabaseCommit for MetashrewDB {
             }
 
             if account.is_selfdestructed() {
-                EVM_ACCOUNTS.select(&address.to_vec()).set(Arc::new(vec![]));
+                EVM_ACCOUNTS.delete(&address);
                 continue;
             }
 
-            let account_info_bytes = bincode::serialize(&account.info).unwrap();
-            EVM_ACCOUNTS.select(&address.to_vec()).set(Arc::new(account_info_bytes));
+            EVM_ACCOUNTS.set(&address, &account.info);
 
             // Only store code for newly created accounts
             if account.is_created() {
                 if let Some(bytecode) = &account.info.code {
                     if !bytecode.is_empty() {
-                        CODE_HASH_TO_BYTECODE.select(&account.info.code_hash.to_vec())
-                            .set(Arc::new(bytecode.bytes().to_vec()));
+                        CODE_HASH_TO_BYTECODE.set(&account.info.code_hash.0, &bytecode.bytes().to_vec());
                     }
                 }
             }
@@ -148,11 +123,7 @@ impl DatabaseCommit for MetashrewDB {
                 if !value.is_changed() {
                     continue;
                 }
-                let mut key = address.to_vec();
-                key.extend_from_slice(&index.to_be_bytes::<32>());
-                EVM_STORAGE.select(&key).set(
-                    Arc::new(value.present_value().to_be_bytes::<32>().to_vec())
-                );
+                EVM_STORAGE.set(&(address, index), &value.present_value());
             }
         }
     }
@@ -15,11 +15,10 @@ use bitcoin::blockdata::script::Builder;
 use bitcoin::consensus::{deserialize, Decodable};
 use bitcoin::key::UntweakedPublicKey;
 use bitcoin::taproot::TaprootBuilder;
-use bitcoin::{Amount, Network, ScriptBuf, Transaction, Witness};
+use bitcoin::{Amount, Network, OutPoint, ScriptBuf, Transaction, TxOut, Txid, Witness};
 use bitcoin_hashes::Hash;
-use metashrew_support::index_pointer::KeyValuePointer;
 use revm::primitives::{Address, B256};
-use shrew_ord::tables::{TXID_TO_RAW_TX, TXID_TO_BLOCK_HEIGHT};
+use shrew_ord::tables::{OUTPOINT_TO_SCRIPT, OUTPOINT_TO_VALUE, TXID_TO_BLOCK_HEIGHT, TXID_TO_COMPACT_TX, TXID_TO_RAW_TX};
 
 /// Precompile address for BIP322 verify
 pub const PRECOMPILE_BIP322: Address = address_from_low_byte(0xFE);
@@ -100,21 +99,30 @@ pub struct PrecompileResult {
 // Helper: look up a transaction by txid bytes (32 bytes, internal byte order)
 // ============================================================================
 
-/// Look up a raw transaction from the TXID_TO_RAW_TX table.
+/// Look up a transaction from TXID_TO_RAW_TX, falling back to the
+/// witness-stripped copy in TXID_TO_COMPACT_TX.
 /// Returns the deserialized Transaction and its block height.
 fn lookup_tx(txid_bytes: &[u8]) -> Option<(Transaction, u32)> {
-    let raw = TXID_TO_RAW_TX.select(&txid_bytes.to_vec()).get();
-    if raw.is_empty() { return None; }
-    let height_bytes = TXID_TO_BLOCK_HEIGHT.select(&txid_bytes.to_vec()).get();
-    let height = if height_bytes.len() >= 4 {
-        u32::from_le_bytes(height_bytes[..4].try_into().unwrap_or([0; 4]))
-    } else {
-        0
-    };
+    let txid = Txid::from_slice(txid_bytes).ok()?;
+    let raw = TXID_TO_RAW_TX.get(&txid).or_else(|| TXID_TO_COMPACT_TX.get(&txid))?;
+    let height = TXID_TO_BLOCK_HEIGHT.get(&txid).unwrap_or(0);
     let tx: Transaction = deserialize(&raw).ok()?;
     Some((tx, height))
 }
 
+/// Look up the output spent by `outpoint`, from its transaction if it was
+/// kept, else from the value and script shrew-ord records for every output
+/// under relevant-only retention.
+fn lookup_output(outpoint: &OutPoint) -> Option<TxOut> {
+    if let Some((tx, _)) = lookup_tx(outpoint.txid.as_byte_array()) {
+        return tx.output.get(outpoint.vout as usize).cloned();
+    }
+    Some(TxOut {
+        value: Amount::from_sat(OUTPOINT_TO_VALUE.get(outpoint)?),
+        script_pubkey: ScriptBuf::from_bytes(OUTPOINT_TO_SCRIPT.get(outpoint)?),
+    })
+}
+
 // ============================================================================
 // Helper: ABI encoding utilities
 // ============================================================================
@@ -345,15 +353,10 @@ pub fn btc_tx_details(input: &[u8], gas_limit: u64, current_height: u32) -> Prec
             vin_txids.push(prev_txid_be);
             vin_vouts.push(prev_vout as u64);
 
-            // Look up the previous transaction to get scriptPubKey and value
-            if let Some((prev_tx, _)) = lookup_tx(prev_txid.as_byte_array()) {
-                if let Some(prev_output) = prev_tx.output.get(prev_vout as usize) {
-                    vin_scripts.push(prev_output.script_pubkey.as_bytes().to_vec());
-                    vin_values.push(prev_output.value.to_sat());
-                } else {
-                    vin_scripts.push(vec![]);
-                    vin_values.push(0);
-                }
+            // Look up the previous output to get scriptPubKey and value
+            if let Some(prev_output) = lookup_output(&txin.previous_output) {
+                vin_scripts.push(prev_output.script_pubkey.as_bytes().to_vec());
+                vin_values.push(prev_output.value.to_sat());
             } else {
                 vin_scripts.push(vec![]);
                 vin_values.push(0);
@@ -508,13 +511,9 @@ pub fn last_sat_location(input: &[u8], gas_limit: u64) -> PrecompileResult {
         let prev_txid = txin.previous_output.txid;
         let prev_vout = txin.previous_output.vout;
 
-        let prev_output_value = if let Some((prev_tx, _)) = lookup_tx(prev_txid.as_byte_array()) {
-            if let Some(prev_out) = prev_tx.output.get(prev_vout as usize) {
-                old_pkscript = prev_out.script_pubkey.as_bytes().to_vec();
-                prev_out.value.to_sat()
-            } else {
-                0
-            }
+        let prev_output_value = if let Some(prev_out) = lookup_output(&txin.previous_output) {
+            old_pkscript = prev_out.script_pubkey.as_bytes().to_vec();
+            prev_out.value.to_sat()
         } else {
             0
         };
@@ -1,10 +1,79 @@
-use metashrew_core::index_pointer::IndexPointer;
-use metashrew_support::index_pointer::KeyValuePointer;
+use revm::primitives::{Address, U256};
+use revm::state::AccountInfo;
+use shrew_support::inscription::InscriptionId;
+use shrew_support::table::{Bincode, KeyCodec, Table, ValueCodec};
 
 lazy_static::lazy_static! {
-    pub static ref EVM_ACCOUNTS: IndexPointer = IndexPointer::from_keyword("/prog/accounts/");
-    pub static ref EVM_STORAGE: IndexPointer = IndexPointer::from_keyword("/prog/storage/");
-    pub static ref CODE_HASH_TO_BYTECODE: IndexPointer = IndexPointer::from_keyword("/prog/code_hash_to_bytecode/");
-    pub static ref CONTRACT_ADDRESS_TO_INSCRIPTION_ID: IndexPointer = IndexPointer::from_keyword("/prog/contract_to_id/");
-    pub static ref INSCRIPTION_ID_TO_CONTRACT_ADDRESS: IndexPointer = IndexPointer::from_keyword("/prog/id_to_contract/");
+    pub static ref EVM_ACCOUNTS: Table<EvmAddress, Bincode<AccountInfo>> = Table::new("/prog/accounts/");
+    pub static ref EVM_STORAGE: Table<StorageSlot, Word> = Table::new("/prog/storage/");
+    pub static ref CODE_HASH_TO_BYTECODE: Table<[u8; 32], Vec<u8>> = Table::new("/prog/code_hash_to_bytecode/");
+    pub static ref CONTRACT_ADDRESS_TO_INSCRIPTION_ID: Table<EvmAddress, InscriptionId> = Table::new("/prog/contract_to_id/");
+    pub static ref INSCRIPTION_ID_TO_CONTRACT_ADDRESS: Table<InscriptionId, EvmAddress> = Table::new("/prog/id_to_contract/");
+}
+
+/// 20-byte EVM account address
+pub struct EvmAddress;
+
+/// Contract storage slot: the account address followed by the slot index as
+/// a 32-byte big-endian word
+pub struct StorageSlot;
+
+/// 32-byte big-endian EVM word
+pub struct Word;
+
+fn address_from_bytes(bytes: &[u8]) -> Option<Address> {
+    (bytes.len() == 20).then(|| Address::from_slice(bytes))
+}
+
+impl KeyCodec for EvmAddress {
+    type Key = Address;
+
+    fn encode_key(key: &Address) -> Vec<u8> {
+        key.to_vec()
+    }
+
+    fn decode_key(bytes: &[u8]) -> Option<Address> {
+        address_from_bytes(bytes)
+    }
+}
+
+impl ValueCodec for EvmAddress {
+    type Value = Address;
+
+    fn encode_value(value: &Address) -> Vec<u8> {
+        value.to_vec()
+    }
+
+    fn decode_value(bytes: &[u8]) -> Option<Address> {
+        address_from_bytes(bytes)
+    }
+}
+
+impl KeyCodec for StorageSlot {
+    type Key = (Address, U256);
+
+    fn encode_key((address, index): &(Address, U256)) -> Vec<u8> {
+        let mut key = address.to_vec();
+        key.extend_from_slice(&index.to_be_bytes::<32>());
+        key
+    }
+
+    fn decode_key(bytes: &[u8]) -> Option<(Address, U256)> {
+        if bytes.len() != 52 {
+            return None;
+        }
+        Some((Address::from_slice(&bytes[..20]), U256::from_be_slice(&bytes[20..])))
+    }
+}
+
+impl ValueCodec for Word {
+    type Value = U256;
+
+    fn encode_value(value: &U256) -> Vec<u8> {
+        value.to_be_bytes::<32>().to_vec()
+    }
+
+    fn decode_value(bytes: &[u8]) -> Option<U256> {
+        (bytes.len() == 32).then(|| U256::from_be_slice(bytes))
+    }
 }
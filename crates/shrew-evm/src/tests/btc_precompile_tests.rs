@@ -11,18 +11,26 @@ use bitcoin::{
     Amount, ScriptBuf, absolute::LockTime,
 };
 use bitcoin_hashes::Hash;
-use metashrew_support::index_pointer::KeyValuePointer;
 use revm::primitives::B256;
-use shrew_ord::tables::{TXID_TO_RAW_TX, TXID_TO_BLOCK_HEIGHT};
+use shrew_ord::tables::{TXID_TO_RAW_TX, TXID_TO_COMPACT_TX, TXID_TO_BLOCK_HEIGHT, OUTPOINT_TO_VALUE, OUTPOINT_TO_SCRIPT};
 use shrew_test_helpers::state::clear;
-use std::sync::Arc;
 
 /// Store a synthetic transaction in the indexed tables
 fn store_tx(tx: &Transaction, height: u32) {
-    let txid_bytes = tx.compute_txid().as_byte_array().to_vec();
-    let raw = serialize(tx);
-    TXID_TO_RAW_TX.select(&txid_bytes).set(Arc::new(raw));
-    TXID_TO_BLOCK_HEIGHT.select(&txid_bytes).set(Arc::new(height.to_le_bytes().to_vec()));
+    let txid = tx.compute_txid();
+    TXID_TO_RAW_TX.set(&txid, &serialize(tx));
+    TXID_TO_BLOCK_HEIGHT.set(&txid, &height);
+}
+
+/// Store a synthetic transaction in witness-stripped form, as `TxRetention::Compact` does
+fn store_compact_tx(tx: &Transaction, height: u32) {
+    let txid = tx.compute_txid();
+    let mut compact = tx.clone();
+    for input in &mut compact.input {
+        input.witness = Witness::new();
+    }
+    TXID_TO_COMPACT_TX.set(&txid, &serialize(&compact));
+    TXID_TO_BLOCK_HEIGHT.set(&txid, &height);
 }
 
 /// Build a simple test transaction with specified inputs and outputs
@@ -151,6 +159,58 @@ fn test_tx_details_single_input_single_output() {
     assert_eq!(block_height, 840001, "Block height should match");
 }
 
+#[test]
+fn test_tx_details_reads_compact_form() {
+    clear();
+    let op_return_txid = B256::ZERO;
+
+    let parent = build_tx(vec![(Txid::all_zeros(), 0)], vec![(p2tr_script(), 10_000_000)]);
+    let mut child = build_tx(vec![(parent.compute_txid(), 0)], vec![(p2wpkh_script(), 9_658_000)]);
+    child.input[0].witness = Witness::from_slice(&[vec![0xab; 64]]);
+
+    let input = build_tx_details_input(&child.compute_txid());
+
+    store_tx(&parent, 840000);
+    store_tx(&child, 840001);
+    let full = execute_precompile(&PRECOMPILE_TX_DETAILS, &input, 2_000_000, op_return_txid, 840001).unwrap();
+    assert!(full.success);
+
+    clear();
+    store_compact_tx(&parent, 840000);
+    store_compact_tx(&child, 840001);
+    assert!(!TXID_TO_RAW_TX.contains(&child.compute_txid()));
+    let compact = execute_precompile(&PRECOMPILE_TX_DETAILS, &input, 2_000_000, op_return_txid, 840001).unwrap();
+
+    assert!(compact.success, "Compact transactions should satisfy tx details lookups");
+    assert_eq!(compact.gas_used, full.gas_used);
+    assert_eq!(compact.output, full.output, "Witness data is not part of the precompile output");
+}
+
+#[test]
+fn test_tx_details_reads_outputs_of_dropped_parents() {
+    clear();
+    let op_return_txid = B256::ZERO;
+
+    let parent = build_tx(vec![(Txid::all_zeros(), 0)], vec![(p2tr_script(), 10_000_000)]);
+    let child = build_tx(vec![(parent.compute_txid(), 0)], vec![(p2wpkh_script(), 9_658_000)]);
+    let input = build_tx_details_input(&child.compute_txid());
+
+    store_tx(&parent, 840000);
+    store_tx(&child, 840001);
+    let full = execute_precompile(&PRECOMPILE_TX_DETAILS, &input, 2_000_000, op_return_txid, 840001).unwrap();
+
+    // Relevant retention keeps the child, but only the outputs of its parent
+    clear();
+    store_tx(&child, 840001);
+    let spent = OutPoint { txid: parent.compute_txid(), vout: 0 };
+    OUTPOINT_TO_VALUE.set(&spent, &10_000_000);
+    OUTPOINT_TO_SCRIPT.set(&spent, &p2tr_script().to_bytes());
+    let relevant = execute_precompile(&PRECOMPILE_TX_DETAILS, &input, 2_000_000, op_return_txid, 840001).unwrap();
+
+    assert!(relevant.success);
+    assert_eq!(relevant.output, full.output, "Inputs are described from the recorded outputs");
+}
+
 #[test]
 fn test_tx_details_multi_input() {
     clear();
@@ -0,0 +1,66 @@
+//! Galleries and parent-based collections.
+//!
+//! A collection is keyed by the sequence number of the inscription that
+//! defines it. Parents collect their children (already listed in
+//! `SEQUENCE_TO_CHILDREN`), and an inscription whose properties carry a
+//! gallery collects the listed items in `GALLERY_TO_ITEMS`. Trait counts are
+//! aggregated per collection in `COLLECTIONS`: a child contributes the traits
+//! from its own properties, a gallery item the traits the gallery assigns to
+//! it.
+
+use crate::properties::{Attributes, Properties};
+use crate::tables::*;
+use shrew_support::inscription::InscriptionId;
+
+/// One trait value and the number of collection members carrying it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraitCount {
+    pub name: String,
+    pub value: String,
+    pub count: u64,
+}
+
+/// Index a new inscription's gallery, and count its traits towards its parent's collection
+pub fn record_inscription(sequence: u32, parent_sequence: Option<u32>, properties: &Properties) {
+    if let Some(parent_sequence) = parent_sequence {
+        record_traits(parent_sequence, &properties.attributes);
+    }
+    for item in &properties.gallery {
+        GALLERY_TO_ITEMS.append(&sequence, &item.id);
+        INSCRIPTION_TO_GALLERIES.append(&item.id, &sequence);
+        record_traits(sequence, &item.attributes);
+    }
+}
+
+/// Item ids of a gallery, in the order they were listed
+pub fn gallery_items(sequence: u32) -> Vec<InscriptionId> {
+    GALLERY_TO_ITEMS.get_list(&sequence)
+}
+
+/// Sequence numbers of the galleries listing `id`, oldest first
+pub fn galleries(id: &InscriptionId) -> Vec<u32> {
+    INSCRIPTION_TO_GALLERIES.get_list(id)
+}
+
+/// Aggregate trait counts of a collection, in first-seen order
+pub fn trait_counts(sequence: u32) -> Vec<TraitCount> {
+    COLLECTIONS.entries(&sequence)
+        .into_iter()
+        .filter_map(|(key, count)| {
+            let split = key.iter().position(|byte| *byte == 0)?;
+            Some(TraitCount {
+                name: String::from_utf8_lossy(&key[..split]).into_owned(),
+                value: String::from_utf8_lossy(&key[split + 1..]).into_owned(),
+                count,
+            })
+        })
+        .collect()
+}
+
+fn record_traits(sequence: u32, attributes: &Attributes) {
+    // NUL separates name from value in the key, so names containing it are not counted
+    for (name, value) in attributes.traits.iter().filter(|(name, _)| !name.contains('\0')) {
+        let key = [name.as_bytes(), &[0], value.to_string().as_bytes()].concat();
+        COLLECTIONS.increment(&sequence, &key);
+    }
+}
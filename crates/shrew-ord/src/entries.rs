@@ -0,0 +1,96 @@
+//! Access to stored inscription entries, and their schema migration.
+//!
+//! Entries are stored under `SEQUENCE_TO_INSCRIPTION_ENTRY` in the versioned
+//! encoding of [`InscriptionEntry::to_bytes`]. Entries written before that
+//! encoding existed are rewritten in place by [`migrate`], which the indexer
+//! runs in the first block it indexes after an upgrade.
+//!
+//! A stored entry that cannot be decoded is never silently skipped: views
+//! return the error, and indexers record it in `ENTRY_DECODE_FAILURES` under
+//! the height at which it was first seen.
+
+use crate::tables::*;
+use serde::{Deserialize, Serialize};
+use shrew_support::inscription::{EntryDecodeError, InscriptionEntry, ENTRY_SCHEMA_VERSION};
+
+/// A stored entry that could not be decoded
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecodeFailure {
+    pub sequence: u32,
+    pub error: String,
+}
+
+/// Outcome of a schema migration
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MigrationReport {
+    pub from_version: u8,
+    pub to_version: u8,
+    pub migrated: u32,
+    /// Entries left untouched because they could not be decoded, also
+    /// recorded in `ENTRY_DECODE_FAILURES` at the migration height
+    pub failed: Vec<DecodeFailure>,
+}
+
+/// Entry stored for `sequence`, `None` if there is none
+pub fn load(sequence: u32) -> Result<Option<InscriptionEntry>, EntryDecodeError> {
+    SEQUENCE_TO_INSCRIPTION_ENTRY.get(&sequence)
+        .map(|bytes| InscriptionEntry::from_bytes(&bytes))
+        .transpose()
+}
+
+/// Entry stored for `sequence` for use while indexing `height`. A decode
+/// failure is recorded against the height and the entry treated as missing.
+pub fn load_or_report(sequence: u32, height: u32) -> Option<InscriptionEntry> {
+    load(sequence).unwrap_or_else(|error| {
+        record_failure(height, &DecodeFailure { sequence, error: error.to_string() });
+        None
+    })
+}
+
+/// Decode failures first seen while indexing `height`
+pub fn failures(height: u32) -> Vec<DecodeFailure> {
+    ENTRY_DECODE_FAILURES.get_list(&height)
+}
+
+/// Schema version of the stored entries; 0 means the unversioned bincode layout
+pub fn schema_version() -> u8 {
+    INSCRIPTION_ENTRY_SCHEMA.get().unwrap_or(0)
+}
+
+/// Rewrite every stored entry in the current schema, if it is not already
+pub fn migrate(height: u32) -> Option<MigrationReport> {
+    let from_version = schema_version();
+    if from_version == ENTRY_SCHEMA_VERSION {
+        return None;
+    }
+
+    let mut report = MigrationReport { from_version, to_version: ENTRY_SCHEMA_VERSION, ..Default::default() };
+    let max_sequence = GLOBAL_SEQUENCE_COUNTER.get().unwrap_or(0);
+    for sequence in 1..=max_sequence {
+        let Some(bytes) = SEQUENCE_TO_INSCRIPTION_ENTRY.get(&sequence) else { continue };
+        match InscriptionEntry::from_legacy_bytes(&bytes) {
+            Ok(entry) => {
+                SEQUENCE_TO_INSCRIPTION_ENTRY.set(&sequence, &entry.to_bytes());
+                report.migrated += 1;
+            }
+            Err(error) => {
+                let failure = DecodeFailure { sequence, error: error.to_string() };
+                record_failure(height, &failure);
+                report.failed.push(failure);
+            }
+        }
+    }
+
+    INSCRIPTION_ENTRY_SCHEMA.set(&ENTRY_SCHEMA_VERSION);
+    Some(report)
+}
+
+/// Record a failure at the first height it is seen. Protocol indexers rescan
+/// entries every block, so an undecodable entry is reported once, not per scan.
+fn record_failure(height: u32, failure: &DecodeFailure) {
+    if FAILED_ENTRY_SEQUENCES.contains(&failure.sequence) {
+        return;
+    }
+    FAILED_ENTRY_SEQUENCES.set(&failure.sequence, &height);
+    ENTRY_DECODE_FAILURES.append(&height, failure);
+}
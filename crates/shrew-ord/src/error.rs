@@ -0,0 +1,50 @@
+//! Errors of the inscription indexer and its views.
+
+use crate::envelope::ParseError;
+use bitcoin::Txid;
+use shrew_support::error::{CodedError, ErrorCode};
+use shrew_support::inscription::{EntryDecodeError, InscriptionId};
+use shrew_support::pagination::PaginationError;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum OrdError {
+    /// The request leaves out a field the view needs
+    #[error("request must specify {0}")]
+    MissingField(&'static str),
+    /// A request field is present but malformed
+    #[error("invalid {field}: {reason}")]
+    InvalidField { field: &'static str, reason: String },
+    #[error(transparent)]
+    Pagination(#[from] PaginationError),
+    /// A stored inscription entry could not be decoded
+    #[error("inscription entry {sequence}: {source}")]
+    EntryDecode { sequence: u32, source: EntryDecodeError },
+    /// The inscription envelopes of a transaction could not be parsed
+    #[error("envelopes of {txid}: {source}")]
+    Envelope { txid: Txid, source: ParseError },
+    /// A transaction reveals an inscription that is already indexed, which
+    /// means the block was indexed twice
+    #[error("inscription {0} is already indexed")]
+    DuplicateInscription(InscriptionId),
+    #[error("{0} is not implemented")]
+    NotImplemented(&'static str),
+}
+
+impl OrdError {
+    pub(crate) fn invalid_txid(error: impl std::fmt::Display) -> Self {
+        OrdError::InvalidField { field: "txid", reason: error.to_string() }
+    }
+}
+
+impl CodedError for OrdError {
+    fn code(&self) -> ErrorCode {
+        match self {
+            OrdError::MissingField(_) | OrdError::InvalidField { .. } | OrdError::Pagination(_) => {
+                ErrorCode::InvalidArgument
+            }
+            OrdError::EntryDecode { .. } | OrdError::Envelope { .. } => ErrorCode::DecodeFailure,
+            OrdError::DuplicateInscription(_) => ErrorCode::Internal,
+            OrdError::NotImplemented(_) => ErrorCode::NotImplemented,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Per-inscription location history.
+//!
+//! Every inscription gets one record at genesis and one more each time the
+//! output holding it is spent, appended in order under its sequence number in
+//! `SEQUENCE_TO_HISTORY`.
+
+use crate::tables::SEQUENCE_TO_HISTORY;
+use bitcoin::Txid;
+use serde::{Deserialize, Serialize};
+use shrew_support::inscription::SatPoint;
+
+/// One step in an inscription's history
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InscriptionTransfer {
+    /// Where the inscription landed. Inscriptions spent as fee are recorded at
+    /// the null outpoint, with the offset of the sat within the fees paid.
+    pub satpoint: SatPoint,
+    /// Reveal transaction for the genesis record, spending transaction afterwards
+    pub txid: Txid,
+    pub height: u32,
+    /// script_pubkey of the output holding the inscription, empty if spent as fee
+    pub owner_script: Vec<u8>,
+    /// Landed in an OP_RETURN output
+    pub burned: bool,
+    pub spent_as_fee: bool,
+}
+
+pub fn append(sequence: u32, transfer: &InscriptionTransfer) {
+    SEQUENCE_TO_HISTORY.append(&sequence, transfer);
+}
+
+/// Full history of an inscription, oldest first
+pub fn load(sequence: u32) -> Vec<InscriptionTransfer> {
+    SEQUENCE_TO_HISTORY.get_list(&sequence)
+}
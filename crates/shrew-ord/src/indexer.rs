@@ -1,12 +1,44 @@
 use shrew_support::inscription::{Charm, InscriptionEntry, InscriptionId, Rarity, SatPoint};
 use crate::envelope::{parse_inscriptions_from_transaction, Envelope};
+use crate::error::OrdError;
+use crate::history::{self, InscriptionTransfer};
+use crate::stats::BlockStats;
 use crate::tables::*;
-use bitcoin::{Block, OutPoint, Transaction, Txid, Network};
+use bitcoin::{Block, OutPoint, Transaction, Txid, Network, Witness};
 use bitcoin::consensus::serialize;
 use bitcoin_hashes::Hash;
-use metashrew_support::index_pointer::KeyValuePointer;
+use sha2::{Digest, Sha256};
+use shrew_support::diagnostics;
 use std::collections::HashMap;
-use std::sync::Arc;
+
+/// Which transactions are kept for the BRC20-prog precompiles
+/// (`btc_tx_details`, `last_sat_location`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxRetention {
+    /// Every transaction, fully serialized into TXID_TO_RAW_TX
+    All,
+    /// Only transactions that create inscriptions, spend an inscribed output
+    /// or activate a BRC20-prog inscription, which covers all BRC-20 and prog
+    /// activity. The script of every output is kept in OUTPOINT_TO_SCRIPT so
+    /// the inputs of a kept transaction can still be described; precompile
+    /// lookups of other txids fail.
+    Relevant,
+    /// Every transaction, without witness data, in TXID_TO_COMPACT_TX
+    Compact,
+}
+
+impl Default for TxRetention {
+    /// Selected by feature; `tx-retention-relevant` wins if both are enabled
+    fn default() -> Self {
+        if cfg!(feature = "tx-retention-relevant") {
+            TxRetention::Relevant
+        } else if cfg!(feature = "tx-retention-compact") {
+            TxRetention::Compact
+        } else {
+            TxRetention::All
+        }
+    }
+}
 
 /// Main indexer for processing Bitcoin blocks and extracting inscriptions
 pub struct InscriptionIndexer {
@@ -18,6 +50,7 @@ pub struct InscriptionIndexer {
     pub blessed_counter: i32,
     pub cursed_counter: i32,
     pub jubilee_height: u32,
+    pub tx_retention: TxRetention,
 }
 
 impl InscriptionIndexer {
@@ -31,76 +64,94 @@ impl InscriptionIndexer {
             blessed_counter: 0,
             cursed_counter: 0,
             jubilee_height: shrew_support::constants::JUBILEE_HEIGHT,
+            tx_retention: TxRetention::default(),
         }
     }
 
-    pub fn load_state(&mut self) -> Result<(), IndexError> {
-        let seq_bytes = GLOBAL_SEQUENCE_COUNTER.get();
-        if !seq_bytes.is_empty() {
-            self.sequence_counter = u32::from_le_bytes(
-                seq_bytes[..4].try_into().map_err(|_| IndexError::InvalidData)?,
-            );
-        }
-        let blessed_bytes = BLESSED_INSCRIPTION_COUNTER.get();
-        if !blessed_bytes.is_empty() {
-            self.blessed_counter = i32::from_le_bytes(
-                blessed_bytes[..4].try_into().map_err(|_| IndexError::InvalidData)?,
-            );
-        }
-        let cursed_bytes = CURSED_INSCRIPTION_COUNTER.get();
-        if !cursed_bytes.is_empty() {
-            self.cursed_counter = i32::from_le_bytes(
-                cursed_bytes[..4].try_into().map_err(|_| IndexError::InvalidData)?,
-            );
-        }
+    pub fn load_state(&mut self) -> Result<(), OrdError> {
+        self.sequence_counter = GLOBAL_SEQUENCE_COUNTER.get().unwrap_or(0);
+        self.blessed_counter = BLESSED_INSCRIPTION_COUNTER.get().unwrap_or(0);
+        self.cursed_counter = CURSED_INSCRIPTION_COUNTER.get().unwrap_or(0);
         Ok(())
     }
 
-    pub fn save_state(&self) -> Result<(), IndexError> {
-        GLOBAL_SEQUENCE_COUNTER.clone().set(Arc::new(self.sequence_counter.to_le_bytes().to_vec()));
-        BLESSED_INSCRIPTION_COUNTER.clone().set(Arc::new(self.blessed_counter.to_le_bytes().to_vec()));
-        CURSED_INSCRIPTION_COUNTER.clone().set(Arc::new(self.cursed_counter.to_le_bytes().to_vec()));
+    pub fn save_state(&self) -> Result<(), OrdError> {
+        GLOBAL_SEQUENCE_COUNTER.set(&self.sequence_counter);
+        BLESSED_INSCRIPTION_COUNTER.set(&self.blessed_counter);
+        CURSED_INSCRIPTION_COUNTER.set(&self.cursed_counter);
         Ok(())
     }
 
-    pub fn index_block(&mut self, block: &Block, height: u32) -> Result<BlockIndexResult, IndexError> {
+    /// Index a block from an entrypoint. An error is recorded in the block's
+    /// diagnostics instead of returned. Returns whether the block was indexed:
+    /// the protocols after ord read its tables, so they skip a block it failed.
+    pub fn index_or_report(block: &Block, height: u32) -> bool {
+        let mut indexer = Self::new();
+        match indexer.load_state().and_then(|()| indexer.index_block(block, height)) {
+            Ok(_) => true,
+            Err(error) => {
+                diagnostics::record(height, "ord", &error);
+                false
+            }
+        }
+    }
+
+    pub fn index_block(&mut self, block: &Block, height: u32) -> Result<BlockIndexResult, OrdError> {
         self.height = height;
         self.block_hash = block.block_hash();
         self.block_time = block.header.time;
+        crate::entries::migrate(height);
+        crate::layout::migrate(height);
 
-        HEIGHT_TO_BLOCK_HASH.select(&height.to_le_bytes().to_vec()).set(Arc::new(self.block_hash.as_byte_array().to_vec()));
-        BLOCK_HASH_TO_HEIGHT.select(&self.block_hash.as_byte_array().to_vec()).set(Arc::new(height.to_le_bytes().to_vec()));
+        HEIGHT_TO_BLOCK_HASH.set(&height, &self.block_hash);
+        BLOCK_HASH_TO_HEIGHT.set(&self.block_hash, &height);
 
-        // Index all transactions for BRC20-prog precompile lookups
-        for tx in &block.txdata {
-            let txid_bytes = tx.compute_txid().as_byte_array().to_vec();
-            let raw_tx = serialize(tx);
-            TXID_TO_RAW_TX.select(&txid_bytes).set(Arc::new(raw_tx));
-            TXID_TO_BLOCK_HEIGHT.select(&txid_bytes).set(Arc::new(height.to_le_bytes().to_vec()));
+        let mut result = BlockIndexResult::new(height, self.block_hash);
+        // The counters are saved even if a transaction fails, so the
+        // inscriptions stored before it keep their numbers
+        let indexed = self.index_transactions(block, &mut result);
+        self.save_state()?;
+        indexed?;
+
+        for entry in &result.inscriptions {
+            HEIGHT_TO_INSCRIPTIONS.append(&height, &entry.id);
         }
 
-        let mut result = BlockIndexResult::new(height, self.block_hash);
-        let mut sat_ranges = SatRanges::new();
+        let mut block_stats = BlockStats::default();
+        for entry in &result.inscriptions {
+            block_stats.record(entry);
+            crate::stats::record_inscription(entry);
+        }
+        block_stats.blessed_total = self.blessed_counter as u64;
+        block_stats.cursed_total = self.cursed_counter.unsigned_abs() as u64;
+        block_stats.unknown_fees = result.unknown_fees as u64;
+        block_stats.save(height);
+
+        diagnostics::add(height, "ord", diagnostics::BlockDiagnostics {
+            envelopes: result.envelopes as u64,
+            inscriptions_created: result.inscriptions.len() as u64,
+            inscriptions_transferred: result.transfers as u64,
+            ..Default::default()
+        });
+        Ok(result)
+    }
 
+    fn index_transactions(&mut self, block: &Block, result: &mut BlockIndexResult) -> Result<(), OrdError> {
+        let mut sat_ranges = SatRanges::new();
         for (tx_index, tx) in block.txdata.iter().enumerate() {
             sat_ranges.process_transaction(tx, tx_index == 0)?;
         }
 
         for (tx_index, tx) in block.txdata.iter().enumerate() {
+            record_output_values(tx);
+            if tx_index > 0 {
+                result.transfers += self.track_transfers(tx);
+            }
             let tx_result = self.index_transaction(tx, tx_index, &sat_ranges)?;
+            self.retain_transaction(tx, !tx_result.inscriptions.is_empty());
             result.merge(tx_result);
         }
-
-        if !result.inscriptions.is_empty() {
-            let inscription_ids: Vec<_> = result.inscriptions.iter().map(|e| e.id.to_bytes()).collect();
-            for (i, inscription_id) in inscription_ids.iter().enumerate() {
-                let key = format!("{}:{}", height, i);
-                HEIGHT_TO_INSCRIPTIONS.select(&key.as_bytes().to_vec()).set(Arc::new(inscription_id.clone()));
-            }
-        }
-
-        self.save_state()?;
-        Ok(result)
+        Ok(())
     }
 
     fn index_transaction(
@@ -108,18 +159,26 @@ impl InscriptionIndexer {
         tx: &Transaction,
         tx_index: usize,
         sat_ranges: &SatRanges,
-    ) -> Result<TransactionIndexResult, IndexError> {
-        let mut result = TransactionIndexResult::new(tx.compute_txid());
+    ) -> Result<TransactionIndexResult, OrdError> {
+        let txid = tx.compute_txid();
+        let mut result = TransactionIndexResult::new(txid);
 
         let envelopes = parse_inscriptions_from_transaction(tx)
-            .map_err(|_| IndexError::ParseError)?;
+            .map_err(|source| OrdError::Envelope { txid, source })?;
 
+        result.envelopes = envelopes.len();
         if envelopes.is_empty() {
             return Ok(result);
         }
 
-        for envelope in envelopes {
-            let inscription_result = self.process_inscription_envelope(tx, tx_index, &envelope, sat_ranges)?;
+        let fee = reveal_fee(tx, envelopes.len());
+        if fee.is_none() {
+            result.unknown_fees = envelopes.len();
+        }
+
+        // As in ord, the id index counts envelopes across the whole transaction
+        for (id_counter, envelope) in envelopes.iter().enumerate() {
+            let inscription_result = self.process_inscription_envelope(tx, tx_index, id_counter as u32, envelope, fee.unwrap_or(0), sat_ranges)?;
             result.merge(inscription_result);
         }
 
@@ -130,13 +189,15 @@ impl InscriptionIndexer {
         &mut self,
         tx: &Transaction,
         tx_index: usize,
+        id_counter: u32,
         envelope: &Envelope,
+        fee: u64,
         sat_ranges: &SatRanges,
-    ) -> Result<InscriptionIndexResult, IndexError> {
-        let inscription_id = InscriptionId::new(tx.compute_txid(), envelope.input as u32);
+    ) -> Result<InscriptionIndexResult, OrdError> {
+        let inscription_id = InscriptionId::new(tx.compute_txid(), id_counter);
 
-        if !INSCRIPTION_ID_TO_SEQUENCE.select(&inscription_id.to_bytes()).get().is_empty() {
-            return Err(IndexError::DuplicateInscription);
+        if INSCRIPTION_ID_TO_SEQUENCE.contains(&inscription_id) {
+            return Err(OrdError::DuplicateInscription(inscription_id));
         }
 
         // Detect reinscription: check if the input's previous_output already has inscriptions
@@ -145,6 +206,10 @@ impl InscriptionIndexer {
         let is_cursed = envelope.payload.is_cursed()
             || self.is_cursed_by_context(envelope, tx_index)
             || is_reinscription;
+        let genesis = match self.genesis_offset(tx, envelope) {
+            Some(offset) => self.locate(tx, inscription_id.txid, offset),
+            None => self.unbound(inscription_id.txid),
+        };
         let number = if is_cursed && self.height < self.jubilee_height {
             self.cursed_counter -= 1;
             self.cursed_counter
@@ -155,11 +220,10 @@ impl InscriptionIndexer {
 
         self.sequence_counter += 1;
         let sequence = self.sequence_counter;
-        let satpoint = self.calculate_satpoint(tx, envelope, sat_ranges)?;
 
         let mut entry = InscriptionEntry::new(
-            inscription_id.clone(), number, sequence, satpoint.clone(),
-            self.height, self.calculate_fee(tx), self.block_time,
+            inscription_id.clone(), number, sequence, genesis.satpoint.clone(),
+            self.height, fee, self.block_time,
         );
 
         if let Some(content_type) = envelope.payload.content_type() { entry.content_type = Some(content_type); }
@@ -168,8 +232,10 @@ impl InscriptionIndexer {
         if let Some(parent_id) = envelope.payload.parent_id() { entry.parent = Some(parent_id); }
         if let Some(delegate_id) = envelope.payload.delegate_id() { entry.delegate = Some(delegate_id); }
         if let Some(pointer) = envelope.payload.pointer_value() { entry.pointer = Some(pointer); }
+        if let Some(rune) = envelope.payload.rune_value() { entry.rune = Some(rune); }
+        if let Some(body) = &envelope.payload.body { entry.content_hash = Some(Sha256::digest(body).into()); }
 
-        if let Some(sat) = self.calculate_sat_number(&satpoint, sat_ranges) {
+        if let Some(sat) = self.calculate_sat_number(&genesis.satpoint, sat_ranges) {
             entry.sat = Some(sat);
             let rarity = Rarity::from_sat(sat);
             match rarity {
@@ -189,9 +255,11 @@ impl InscriptionIndexer {
                 entry.set_charm(Charm::Vindicated);
             }
         }
-        if envelope.payload.body.is_none() { entry.set_charm(Charm::Unbound); }
+        let unlocated = genesis.satpoint.outpoint.is_null() && !genesis.spent_as_fee;
+        if envelope.payload.body.is_none() || unlocated { entry.set_charm(Charm::Unbound); }
 
         self.store_inscription(&entry, envelope)?;
+        history::append(sequence, &genesis);
 
         Ok(InscriptionIndexResult {
             inscription: entry,
@@ -199,90 +267,257 @@ impl InscriptionIndexer {
         })
     }
 
-    fn store_inscription(&self, entry: &InscriptionEntry, envelope: &Envelope) -> Result<(), IndexError> {
-        let id_bytes = entry.id.to_bytes();
-        let sequence_bytes = entry.sequence.to_le_bytes().to_vec();
-        let entry_bytes = entry.to_bytes();
+    fn store_inscription(&self, entry: &InscriptionEntry, envelope: &Envelope) -> Result<(), OrdError> {
+        let sequence = entry.sequence;
 
-        INSCRIPTION_ID_TO_SEQUENCE.select(&id_bytes).set(Arc::new(sequence_bytes.clone()));
-        SEQUENCE_TO_INSCRIPTION_ENTRY.select(&sequence_bytes).set(Arc::new(entry_bytes));
-        INSCRIPTION_NUMBER_TO_SEQUENCE.select(&entry.number.to_le_bytes().to_vec()).set(Arc::new(sequence_bytes.clone()));
-        SEQUENCE_TO_SATPOINT.select(&sequence_bytes).set(Arc::new(entry.satpoint.to_bytes()));
+        INSCRIPTION_ID_TO_SEQUENCE.set(&entry.id, &sequence);
+        SEQUENCE_TO_INSCRIPTION_ENTRY.set(&sequence, &entry.to_bytes());
+        INSCRIPTION_NUMBER_TO_SEQUENCE.set(&entry.number, &sequence);
+        SEQUENCE_TO_SATPOINT.set(&sequence, &entry.satpoint);
 
         if let Some(sat) = entry.sat {
-            SAT_TO_SEQUENCE.select(&sat.to_le_bytes().to_vec()).set(Arc::new(sequence_bytes.clone()));
-            INSCRIPTION_TO_SAT.select(&sequence_bytes).set(Arc::new(sat.to_le_bytes().to_vec()));
+            SAT_TO_SEQUENCE.set(&sat, &sequence);
+            INSCRIPTION_TO_SAT.set(&sequence, &sat);
         }
 
-        let outpoint_bytes = entry.satpoint.outpoint.txid.as_byte_array()
-            .iter().chain(entry.satpoint.outpoint.vout.to_le_bytes().iter()).copied().collect::<Vec<u8>>();
-        OUTPOINT_TO_INSCRIPTIONS.select(&outpoint_bytes).append(Arc::new(sequence_bytes.clone()));
+        // Inscribed as fee: no output holds it
+        if !entry.satpoint.outpoint.is_null() {
+            OUTPOINT_TO_INSCRIPTIONS.append(&entry.satpoint.outpoint, &sequence);
+        }
 
+        let mut parent_sequence = None;
         if let Some(parent_id) = &entry.parent {
-            let parent_id_bytes = parent_id.to_bytes();
-            let parent_seq_bytes = INSCRIPTION_ID_TO_SEQUENCE.select(&parent_id_bytes).get();
-            if !parent_seq_bytes.is_empty() {
-                SEQUENCE_TO_CHILDREN.select(&parent_seq_bytes).append(Arc::new(sequence_bytes.clone()));
-                SEQUENCE_TO_PARENTS.select(&sequence_bytes).append(Arc::new(parent_seq_bytes.to_vec()));
+            if let Some(parent) = INSCRIPTION_ID_TO_SEQUENCE.get(parent_id) {
+                SEQUENCE_TO_CHILDREN.append(&parent, &sequence);
+                SEQUENCE_TO_PARENTS.append(&sequence, &parent);
+                parent_sequence = Some(parent);
             }
         }
+        if let Some(properties) = envelope.payload.properties() {
+            crate::collections::record_inscription(sequence, parent_sequence, &properties);
+        }
 
         if let Some(content_type) = &entry.content_type {
-            CONTENT_TYPE_TO_INSCRIPTIONS.select(&content_type.as_bytes().to_vec()).append(Arc::new(sequence_bytes.clone()));
+            CONTENT_TYPE_TO_INSCRIPTIONS.append(content_type, &sequence);
         }
         if let Some(metaprotocol) = &entry.metaprotocol {
-            METAPROTOCOL_TO_INSCRIPTIONS.select(&metaprotocol.as_bytes().to_vec()).append(Arc::new(sequence_bytes.clone()));
+            METAPROTOCOL_TO_INSCRIPTIONS.append(metaprotocol, &sequence);
         }
 
-        let txid_bytes = entry.id.txid.as_byte_array();
-        TXID_TO_INSCRIPTIONS.select(&txid_bytes.to_vec()).append(Arc::new(sequence_bytes.clone()));
-        INSCRIPTION_TO_TXID.select(&sequence_bytes).set(Arc::new(txid_bytes.to_vec()));
+        TXID_TO_INSCRIPTIONS.append(&entry.id.txid, &sequence);
+        INSCRIPTION_TO_TXID.set(&sequence, &entry.id.txid);
 
         if let Some(body) = &envelope.payload.body {
-            let inscription_id_str = format!("{}i{}", entry.id.txid, entry.id.index);
-            INSCRIPTION_CONTENT.select(&inscription_id_str.as_bytes().to_vec()).set(Arc::new(body.to_vec()));
+            let hash = InscriptionContentTable::new().set(&entry.id, body);
+            CONTENT_HASH_TO_INSCRIPTIONS.append(&hash, &sequence);
         }
         if let Some(metadata) = &envelope.payload.metadata {
-            let inscription_id_str = format!("{}i{}", entry.id.txid, entry.id.index);
-            INSCRIPTION_METADATA.select(&inscription_id_str.as_bytes().to_vec()).set(Arc::new(metadata.to_vec()));
+            INSCRIPTION_METADATA.set(&entry.id, &metadata.to_vec());
         }
 
         Ok(())
     }
 
+    /// Move inscriptions sitting on the outputs spent by `tx` to the output
+    /// their sat flows into, first-in-first-out as in ord. Inscriptions behind
+    /// an input whose value is not indexed stay where they are, since the
+    /// offset of their sat is unknown. Returns the number of inscriptions moved.
+    fn track_transfers(&self, tx: &Transaction) -> usize {
+        let inscribed: Vec<(usize, Vec<u32>)> = tx.input.iter().enumerate()
+            .map(|(i, input)| (i, OUTPOINT_TO_INSCRIPTIONS.get_list(&input.previous_output)))
+            .filter(|(_, sequences)| !sequences.is_empty())
+            .collect();
+        if inscribed.is_empty() {
+            return 0;
+        }
+
+        let mut input_offset = 0u64;
+        let mut next_input = 0;
+        let mut moves = Vec::new();
+        'inputs: for (input_index, sequences) in inscribed {
+            for input in &tx.input[next_input..input_index] {
+                let Some(value) = output_value(&input.previous_output) else { break 'inputs };
+                input_offset += value;
+            }
+            next_input = input_index;
+
+            let spent = tx.input[input_index].previous_output;
+            for sequence in sequences {
+                let Some(current) = SEQUENCE_TO_SATPOINT.get(&sequence) else { continue };
+                if current.outpoint == spent {
+                    moves.push((sequence, input_offset + current.offset));
+                }
+            }
+        }
+
+        let txid = tx.compute_txid();
+        for &(sequence, offset) in &moves {
+            let transfer = self.locate(tx, txid, offset);
+            SEQUENCE_TO_SATPOINT.set(&sequence, &transfer.satpoint);
+            if !transfer.spent_as_fee {
+                OUTPOINT_TO_INSCRIPTIONS.append(&transfer.satpoint.outpoint, &sequence);
+            }
+            history::append(sequence, &transfer);
+        }
+        moves.len()
+    }
+
+    /// Resolve a sat offset, counted across all outputs of `tx`, to the output
+    /// it lands in. Offsets past the last output were paid as fee.
+    fn locate(&self, tx: &Transaction, txid: Txid, offset: u64) -> InscriptionTransfer {
+        let mut output_start = 0u64;
+        for (vout, output) in tx.output.iter().enumerate() {
+            let output_end = output_start + output.value.to_sat();
+            if offset < output_end {
+                return self.transfer_to(tx, SatPoint::new(OutPoint { txid, vout: vout as u32 }, offset - output_start));
+            }
+            output_start = output_end;
+        }
+        InscriptionTransfer {
+            satpoint: SatPoint::new(OutPoint::null(), offset - output_start),
+            txid,
+            height: self.height,
+            owner_script: Vec::new(),
+            burned: false,
+            spent_as_fee: true,
+        }
+    }
+
+    /// Genesis record of an inscription whose sat cannot be located, as for
+    /// an envelope behind an input whose value is not indexed. Like ord's
+    /// unbound inscriptions it sits at the null outpoint, not as fee.
+    fn unbound(&self, txid: Txid) -> InscriptionTransfer {
+        InscriptionTransfer {
+            satpoint: SatPoint::new(OutPoint::null(), 0),
+            txid,
+            height: self.height,
+            owner_script: Vec::new(),
+            burned: false,
+            spent_as_fee: false,
+        }
+    }
+
+    /// History record for an inscription located at an output of `tx`
+    fn transfer_to(&self, tx: &Transaction, satpoint: SatPoint) -> InscriptionTransfer {
+        let script_pubkey = tx.output.get(satpoint.outpoint.vout as usize).map(|output| &output.script_pubkey);
+        InscriptionTransfer {
+            txid: satpoint.outpoint.txid,
+            height: self.height,
+            owner_script: script_pubkey.map(|script| script.to_bytes()).unwrap_or_default(),
+            burned: script_pubkey.is_some_and(|script| script.is_op_return()),
+            spent_as_fee: false,
+            satpoint,
+        }
+    }
+
+    /// Store a transaction for BRC20-prog precompile lookups according to `tx_retention`
+    fn retain_transaction(&self, tx: &Transaction, inscribes: bool) {
+        let txid = tx.compute_txid();
+        match self.tx_retention {
+            TxRetention::All => {
+                TXID_TO_RAW_TX.set(&txid, &serialize(tx));
+            }
+            TxRetention::Relevant => {
+                for (vout, output) in tx.output.iter().enumerate() {
+                    OUTPOINT_TO_SCRIPT.set(&OutPoint { txid, vout: vout as u32 }, &output.script_pubkey.to_bytes());
+                }
+                if !inscribes && !self.spends_inscription(tx) && !is_prog_activation(tx) {
+                    return;
+                }
+                TXID_TO_RAW_TX.set(&txid, &serialize(tx));
+            }
+            TxRetention::Compact => {
+                let mut compact = tx.clone();
+                for input in &mut compact.input {
+                    input.witness = Witness::new();
+                }
+                TXID_TO_COMPACT_TX.set(&txid, &serialize(&compact));
+            }
+        }
+        TXID_TO_BLOCK_HEIGHT.set(&txid, &self.height);
+    }
+
+    /// Whether any input of the transaction spends an output carrying inscriptions
+    fn spends_inscription(&self, tx: &Transaction) -> bool {
+        tx.input.iter().any(|input| OUTPOINT_TO_INSCRIPTIONS.length(&input.previous_output) > 0)
+    }
+
     /// Detect if this inscription is a reinscription: the input's previous_output
     /// already has one or more inscriptions on it.
     fn is_reinscription(&self, tx: &Transaction, envelope: &Envelope) -> bool {
         if envelope.input >= tx.input.len() {
             return false;
         }
-        let prev_outpoint = &tx.input[envelope.input].previous_output;
-        let outpoint_bytes: Vec<u8> = prev_outpoint.txid.as_byte_array()
-            .iter()
-            .chain(prev_outpoint.vout.to_le_bytes().iter())
-            .copied()
-            .collect();
-        let existing = OUTPOINT_TO_INSCRIPTIONS.select(&outpoint_bytes).get_list();
-        !existing.is_empty()
+        OUTPOINT_TO_INSCRIPTIONS.length(&tx.input[envelope.input].previous_output) > 0
     }
 
-    fn is_cursed_by_context(&self, _envelope: &Envelope, tx_index: usize) -> bool {
+    /// Curses that depend on where the envelope sits rather than on its fields:
+    /// coinbase, not in the first input, not the first envelope of its input,
+    /// and ord's `pushnum` / `stutter` encodings.
+    fn is_cursed_by_context(&self, envelope: &Envelope, tx_index: usize) -> bool {
         tx_index == 0
+            || envelope.input != 0
+            || envelope.offset != 0
+            || envelope.pushnum
+            || envelope.stutter
     }
 
-    fn calculate_satpoint(&self, tx: &Transaction, envelope: &Envelope, _sat_ranges: &SatRanges) -> Result<SatPoint, IndexError> {
-        let vout = envelope.payload.pointer_value().unwrap_or(0) as u32;
-        let outpoint = OutPoint { txid: tx.compute_txid(), vout };
-        Ok(SatPoint::new(outpoint, 0))
+    /// Sat offset, counted across all outputs of `tx`, that `envelope`
+    /// inscribes: its pointer if that is within the outputs, as in ord, else
+    /// the first sat of the input carrying it. `None` if the value of an input
+    /// ahead of it is not indexed.
+    fn genesis_offset(&self, tx: &Transaction, envelope: &Envelope) -> Option<u64> {
+        let total_output: u64 = tx.output.iter().map(|output| output.value.to_sat()).sum();
+        if let Some(pointer) = envelope.payload.pointer_value().filter(|pointer| *pointer < total_output) {
+            return Some(pointer);
+        }
+        tx.input.iter().take(envelope.input)
+            .map(|input| output_value(&input.previous_output))
+            .sum()
     }
 
     fn calculate_sat_number(&self, _satpoint: &SatPoint, _sat_ranges: &SatRanges) -> Option<u64> {
         None
     }
+}
+
+/// The fee paid by `tx`, split evenly across its `envelopes` as ord does.
+/// `None` if the value of one of its inputs was never indexed.
+fn reveal_fee(tx: &Transaction, envelopes: usize) -> Option<u64> {
+    if tx.is_coinbase() {
+        return Some(0);
+    }
+    let input_value = tx.input.iter()
+        .map(|input| output_value(&input.previous_output))
+        .sum::<Option<u64>>()?;
+    let output_value: u64 = tx.output.iter().map(|output| output.value.to_sat()).sum();
+    Some(input_value.saturating_sub(output_value) / envelopes as u64)
+}
+
+/// Whether output 0 of `tx` is the `BRC20PROG` OP_RETURN that activates a
+/// BRC20-prog inscription
+fn is_prog_activation(tx: &Transaction) -> bool {
+    tx.output.first().is_some_and(|output| output.script_pubkey.as_bytes().windows(9).any(|window| window == b"BRC20PROG"))
+}
+
+/// Record the value of each output of `tx`, so the sat flow of the
+/// transactions spending them does not depend on `TxRetention`
+fn record_output_values(tx: &Transaction) {
+    let txid = tx.compute_txid();
+    for (vout, output) in tx.output.iter().enumerate() {
+        OUTPOINT_TO_VALUE.set(&OutPoint { txid, vout: vout as u32 }, &output.value.to_sat());
+    }
+}
 
-    fn calculate_fee(&self, _tx: &Transaction) -> u64 {
-        0
+/// Value of an output indexed earlier. Outputs created before values were
+/// recorded are read back from the retained transaction, if there is one.
+fn output_value(outpoint: &OutPoint) -> Option<u64> {
+    if let Some(value) = OUTPOINT_TO_VALUE.get(outpoint) {
+        return Some(value);
     }
+    let raw = TXID_TO_RAW_TX.get(&outpoint.txid).or_else(|| TXID_TO_COMPACT_TX.get(&outpoint.txid))?;
+    let tx: Transaction = bitcoin::consensus::deserialize(&raw).ok()?;
+    tx.output.get(outpoint.vout as usize).map(|output| output.value.to_sat())
 }
 
 /// Sat range tracking for transactions
@@ -293,7 +528,7 @@ pub struct SatRanges {
 impl SatRanges {
     pub fn new() -> Self { Self { ranges: HashMap::new() } }
 
-    pub fn process_transaction(&mut self, tx: &Transaction, _is_coinbase: bool) -> Result<(), IndexError> {
+    pub fn process_transaction(&mut self, tx: &Transaction, _is_coinbase: bool) -> Result<(), OrdError> {
         for (vout, _output) in tx.output.iter().enumerate() {
             let outpoint = OutPoint { txid: tx.compute_txid(), vout: vout as u32 };
             self.ranges.insert(outpoint, (0, 0));
@@ -312,13 +547,21 @@ pub struct BlockIndexResult {
     pub block_hash: bitcoin::BlockHash,
     pub inscriptions: Vec<InscriptionEntry>,
     pub transactions_processed: usize,
+    /// Envelopes parsed, including those that did not become inscriptions
+    pub envelopes: usize,
+    /// Existing inscriptions moved by the block's transactions
+    pub transfers: usize,
+    /// Inscriptions created with a fee of 0 because it could not be computed
+    pub unknown_fees: usize,
 }
 
 impl BlockIndexResult {
     pub fn new(height: u32, block_hash: bitcoin::BlockHash) -> Self {
-        Self { height, block_hash, inscriptions: Vec::new(), transactions_processed: 0 }
+        Self { height, block_hash, inscriptions: Vec::new(), transactions_processed: 0, envelopes: 0, transfers: 0, unknown_fees: 0 }
     }
     pub fn merge(&mut self, tx_result: TransactionIndexResult) {
+        self.envelopes += tx_result.envelopes;
+        self.unknown_fees += tx_result.unknown_fees;
         self.inscriptions.extend(tx_result.inscriptions);
         self.transactions_processed += 1;
     }
@@ -328,10 +571,12 @@ impl BlockIndexResult {
 pub struct TransactionIndexResult {
     pub txid: Txid,
     pub inscriptions: Vec<InscriptionEntry>,
+    pub envelopes: usize,
+    pub unknown_fees: usize,
 }
 
 impl TransactionIndexResult {
-    pub fn new(txid: Txid) -> Self { Self { txid, inscriptions: Vec::new() } }
+    pub fn new(txid: Txid) -> Self { Self { txid, inscriptions: Vec::new(), envelopes: 0, unknown_fees: 0 } }
     pub fn merge(&mut self, inscription_result: InscriptionIndexResult) {
         self.inscriptions.push(inscription_result.inscription);
     }
@@ -342,26 +587,3 @@ pub struct InscriptionIndexResult {
     pub inscription: InscriptionEntry,
     pub envelope: Envelope,
 }
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum IndexError {
-    InvalidData,
-    ParseError,
-    DuplicateInscription,
-    InvalidInput,
-    DatabaseError,
-}
-
-impl std::fmt::Display for IndexError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            IndexError::InvalidData => write!(f, "Invalid data"),
-            IndexError::ParseError => write!(f, "Parse error"),
-            IndexError::DuplicateInscription => write!(f, "Duplicate inscription"),
-            IndexError::InvalidInput => write!(f, "Invalid input"),
-            IndexError::DatabaseError => write!(f, "Database error"),
-        }
-    }
-}
-
-impl std::error::Error for IndexError {}
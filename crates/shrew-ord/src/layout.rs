@@ -0,0 +1,94 @@
+//! Migration of stores written with the original table layout.
+//!
+//! The original layout keyed three ord tables differently under the same
+//! keywords they use now:
+//!
+//! - `/inscriptions/content/` held each body whole, keyed by the inscription
+//!   id string (`{txid}i{index}`). Bodies now live in the content hash tables.
+//! - `/inscriptions/metadata/` was keyed by the inscription id string. It is
+//!   now keyed by the 36-byte inscription id.
+//! - `/inscriptions/height_to_list/` held one id per `{height}:{index}` string
+//!   key. It is now an append list keyed by the height.
+//!
+//! [`migrate`] moves every legacy record to its current key in the first block
+//! the indexer indexes after an upgrade, and deletes the legacy copy. Until
+//! then [`InscriptionContentTable`] and the metadata view fall back to the
+//! legacy keys, so views keep serving inscriptions indexed before the upgrade.
+
+use crate::entries;
+use crate::tables::*;
+use shrew_support::inscription::InscriptionId;
+use std::collections::HashSet;
+
+/// Version of the table layout written by this indexer; 0 is the original layout
+pub const LAYOUT_VERSION: u8 = 1;
+
+/// Outcome of a layout migration
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LayoutMigrationReport {
+    pub from_version: u8,
+    pub to_version: u8,
+    /// Bodies moved into the content hash tables
+    pub bodies: u32,
+    /// Metadata moved to the binary inscription id key
+    pub metadata: u32,
+    /// Ids moved into the per-height inscription lists
+    pub height_entries: u32,
+}
+
+/// Layout version of the store; 0 means the original layout
+pub fn layout_version() -> u8 {
+    STORE_LAYOUT.get().unwrap_or(0)
+}
+
+/// Metadata of `inscription_id`, including metadata still in the original layout
+pub fn metadata(inscription_id: &InscriptionId) -> Option<Vec<u8>> {
+    INSCRIPTION_METADATA.get(inscription_id).or_else(|| LEGACY_INSCRIPTION_METADATA.get(&inscription_id.to_string()))
+}
+
+/// Move every record still in the original layout to its current table.
+/// Runs after [`entries::migrate`], since it reads the stored entries.
+pub fn migrate(height: u32) -> Option<LayoutMigrationReport> {
+    let from_version = layout_version();
+    if from_version >= LAYOUT_VERSION {
+        return None;
+    }
+
+    let mut report = LayoutMigrationReport { from_version, to_version: LAYOUT_VERSION, ..Default::default() };
+    let mut heights = HashSet::new();
+    let max_sequence = GLOBAL_SEQUENCE_COUNTER.get().unwrap_or(0);
+    for sequence in 1..=max_sequence {
+        let Some(entry) = entries::load_or_report(sequence, height) else { continue };
+        let legacy_id = entry.id.to_string();
+        if let Some(body) = LEGACY_INSCRIPTION_CONTENT.get(&legacy_id) {
+            let hash = InscriptionContentTable::new().set(&entry.id, &body);
+            CONTENT_HASH_TO_INSCRIPTIONS.append(&hash, &sequence);
+            LEGACY_INSCRIPTION_CONTENT.delete(&legacy_id);
+            report.bodies += 1;
+        }
+        if let Some(metadata) = LEGACY_INSCRIPTION_METADATA.get(&legacy_id) {
+            INSCRIPTION_METADATA.set(&entry.id, &metadata);
+            LEGACY_INSCRIPTION_METADATA.delete(&legacy_id);
+            report.metadata += 1;
+        }
+        if heights.insert(entry.height) {
+            report.height_entries += migrate_height(entry.height);
+        }
+    }
+
+    STORE_LAYOUT.set(&LAYOUT_VERSION);
+    Some(report)
+}
+
+/// Move the legacy `{height}:{index}` list of `height` onto its append list,
+/// in index order
+fn migrate_height(height: u32) -> u32 {
+    let mut index = 0;
+    loop {
+        let key = format!("{}:{}", height, index);
+        let Some(inscription_id) = LEGACY_HEIGHT_TO_INSCRIPTIONS.get(&key) else { return index };
+        HEIGHT_TO_INSCRIPTIONS.append(&height, &inscription_id);
+        LEGACY_HEIGHT_TO_INSCRIPTIONS.delete(&key);
+        index += 1;
+    }
+}
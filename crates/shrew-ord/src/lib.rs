@@ -1,13 +1,20 @@
 #[cfg(feature = "entrypoint")]
-use bitcoin::{Block, consensus::deserialize};
-use serde_json::{from_slice, to_vec};
+use shrew_support::diagnostics;
+use shrew_support::wire::serve;
 
 pub mod tables;
+pub mod collections;
+pub mod entries;
 pub mod envelope;
+pub mod error;
+pub mod history;
 pub mod indexer;
+pub mod layout;
 pub mod view;
 pub mod message;
 pub mod ord_inscriptions;
+pub mod properties;
+pub mod stats;
 
 pub mod proto {
     include!(concat!(env!("OUT_DIR"), "/shrew_ord.rs"));
@@ -22,120 +29,135 @@ pub use view::*;
 #[cfg(feature = "entrypoint")]
 #[metashrew_core::main]
 fn main_logic(height: u32, block_data: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
-    if let Ok(block) = deserialize::<Block>(block_data) {
-        let mut idx = indexer::InscriptionIndexer::new();
-        let _ = idx.load_state();
-        let _ = idx.index_block(&block, height);
+    if let Some(block) = diagnostics::decode_block(height, block_data) {
+        indexer::InscriptionIndexer::index_or_report(&block, height);
     }
     Ok(())
 }
 
 #[metashrew_core::view]
 pub fn getinscription(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetInscriptionRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_inscription(&req)?)?)
+    serve(input, view::get_inscription)
 }
 
 #[metashrew_core::view]
 pub fn getinscriptions(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetInscriptionsRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_inscriptions(&req)?)?)
+    serve(input, view::get_inscriptions)
 }
 
 #[metashrew_core::view]
 pub fn getchildren(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetChildrenRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_children(&req)?)?)
+    serve(input, view::get_children)
 }
 
 #[metashrew_core::view]
 pub fn getparents(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetParentsRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_parents(&req)?)?)
+    serve(input, view::get_parents)
 }
 
 #[metashrew_core::view]
 pub fn getcontent(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetContentRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_content(&req)?)?)
+    serve(input, view::get_content)
+}
+
+#[metashrew_core::view]
+pub fn getcontentcopies(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    serve(input, view::get_content_copies)
+}
+
+#[metashrew_core::view]
+pub fn getinscriptionhistory(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    serve(input, view::get_inscription_history)
+}
+
+#[metashrew_core::view]
+pub fn getinscriptionstats(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    serve(input, view::get_inscription_stats)
+}
+
+#[metashrew_core::view]
+pub fn getcollectionmembers(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    serve(input, view::get_collection_members)
+}
+
+#[metashrew_core::view]
+pub fn getgalleries(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    serve(input, view::get_galleries)
+}
+
+#[metashrew_core::view]
+pub fn getcollectiontraits(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    serve(input, view::get_collection_traits)
 }
 
 #[metashrew_core::view]
 pub fn getmetadata(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetMetadataRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_metadata(&req)?)?)
+    serve(input, view::get_metadata)
 }
 
 #[metashrew_core::view]
 pub fn getsat(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetSatRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_sat(&req)?)?)
+    serve(input, view::get_sat)
 }
 
 #[metashrew_core::view]
 pub fn getsatinscriptions(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetSatInscriptionsRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_sat_inscriptions(&req)?)?)
+    serve(input, view::get_sat_inscriptions)
 }
 
 #[metashrew_core::view]
 pub fn getsatinscription(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetSatInscriptionRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_sat_inscription(&req)?)?)
+    serve(input, view::get_sat_inscription)
 }
 
 #[metashrew_core::view]
 pub fn getchildinscriptions(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetChildInscriptionsRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_child_inscriptions(&req)?)?)
+    serve(input, view::get_child_inscriptions)
 }
 
 #[metashrew_core::view]
 pub fn getparentinscriptions(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetParentInscriptionsRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_parent_inscriptions(&req)?)?)
+    serve(input, view::get_parent_inscriptions)
 }
 
 #[metashrew_core::view]
 pub fn getundelegatedcontent(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetUndelegatedContentRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_undelegated_content(&req)?)?)
+    serve(input, view::get_undelegated_content)
 }
 
 #[metashrew_core::view]
 pub fn getutxo(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetUtxoRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_utxo(&req)?)?)
+    serve(input, view::get_utxo)
 }
 
 #[metashrew_core::view]
 pub fn getblockhash(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetBlockHashRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_block_hash(&req)?)?)
+    serve(input, view::get_block_hash)
 }
 
 #[metashrew_core::view]
 pub fn getblockheight(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetBlockHeightRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_block_height(&req)?)?)
+    serve(input, view::get_block_height)
 }
 
 #[metashrew_core::view]
 pub fn getblocktime(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetBlockTimeRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_block_time(&req)?)?)
+    serve(input, view::get_block_time)
 }
 
 #[metashrew_core::view]
 pub fn getblockinfo(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetBlockInfoRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_block_info(&req)?)?)
+    serve(input, view::get_block_info)
+}
+
+#[metashrew_core::view]
+pub fn getblockdiagnostics(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    serve(input, view::get_block_diagnostics)
 }
 
 #[metashrew_core::view]
 pub fn gettransaction(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetTransactionRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_tx(&req)?)?)
+    serve(input, view::get_tx)
 }
 
 #[cfg(test)]
@@ -1,4 +1,6 @@
 use prost::{Message, DecodeError};
+use serde::{de::DeserializeOwned, Serialize};
+use shrew_support::wire::{self, WireFormat};
 
 pub struct InscriptionMessageContext;
 
@@ -6,6 +8,22 @@ impl InscriptionMessageContext {
     pub fn new() -> Self { Self }
     pub fn serialize<T: Message>(message: &T) -> Vec<u8> { message.encode_to_vec() }
     pub fn deserialize<T: Message + Default>(bytes: &[u8]) -> Result<T, DecodeError> { Message::decode(bytes) }
+
+    /// Decode a view input as JSON or, when it starts with the protobuf marker, as binary protobuf
+    pub fn decode_request<T>(input: &[u8]) -> Result<(T, WireFormat), Box<dyn std::error::Error>>
+    where
+        T: Message + Default + DeserializeOwned,
+    {
+        wire::decode_request(input)
+    }
+
+    /// Encode a view response in the format the request arrived in
+    pub fn encode_response<T>(response: &T, format: WireFormat) -> Result<Vec<u8>, Box<dyn std::error::Error>>
+    where
+        T: Message + Serialize,
+    {
+        wire::encode_response(response, format)
+    }
 }
 
 impl Default for InscriptionMessageContext {
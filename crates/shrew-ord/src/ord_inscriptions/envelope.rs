@@ -3,13 +3,24 @@
 //! Ported from ord/src/inscriptions/envelope.rs
 
 use super::Inscription;
-use bitcoin::Transaction;
+use bitcoin::blockdata::{
+    opcodes,
+    script::{
+        self,
+        Instruction::{self, Op, PushBytes},
+        Instructions,
+    },
+};
+use bitcoin::{Script, Transaction, Witness};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
+use std::iter::Peekable;
 
 pub const PROTOCOL_ID: [u8; 3] = *b"ord";
 pub const BODY_TAG: [u8; 0] = [];
 
+type Result<T> = std::result::Result<T, script::Error>;
+
 pub type RawEnvelope = Envelope<Vec<Vec<u8>>>;
 pub type ParsedEnvelope = Envelope<Inscription>;
 
@@ -97,9 +108,118 @@ impl ParsedEnvelope {
 }
 
 impl RawEnvelope {
-    pub fn from_transaction(_transaction: &Transaction) -> Vec<Self> {
-        // Simplified implementation - we mainly need this for the constants and types
-        // The full parsing implementation would go here if needed
-        Vec::new()
+    pub fn from_transaction(transaction: &Transaction) -> Vec<Self> {
+        let mut envelopes = Vec::new();
+
+        for (i, input) in transaction.input.iter().enumerate() {
+            if let Some(tapscript) = unversioned_leaf_script_from_witness(&input.witness) {
+                if let Ok(input_envelopes) = Self::from_tapscript(tapscript, i) {
+                    envelopes.extend(input_envelopes);
+                }
+            }
+        }
+
+        envelopes
+    }
+
+    fn from_tapscript(tapscript: &Script, input: usize) -> Result<Vec<Self>> {
+        let mut envelopes = Vec::new();
+
+        let mut instructions = tapscript.instructions().peekable();
+
+        let mut stuttered = false;
+        while let Some(instruction) = instructions.next().transpose()? {
+            if instruction == PushBytes((&[]).into()) {
+                let (stutter, envelope) =
+                    Self::from_instructions(&mut instructions, input, envelopes.len(), stuttered)?;
+                if let Some(envelope) = envelope {
+                    envelopes.push(envelope);
+                } else {
+                    stuttered = stutter;
+                }
+            }
+        }
+
+        Ok(envelopes)
+    }
+
+    fn accept(instructions: &mut Peekable<Instructions>, instruction: Instruction) -> Result<bool> {
+        if instructions.peek() == Some(&Ok(instruction)) {
+            instructions.next().transpose()?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
     }
-}
\ No newline at end of file
+
+    fn from_instructions(
+        instructions: &mut Peekable<Instructions>,
+        input: usize,
+        offset: usize,
+        stutter: bool,
+    ) -> Result<(bool, Option<Self>)> {
+        if !Self::accept(instructions, Op(opcodes::all::OP_IF))? {
+            let stutter = instructions.peek() == Some(&Ok(PushBytes((&[]).into())));
+            return Ok((stutter, None));
+        }
+
+        if !Self::accept(instructions, PushBytes((&PROTOCOL_ID).into()))? {
+            let stutter = instructions.peek() == Some(&Ok(PushBytes((&[]).into())));
+            return Ok((stutter, None));
+        }
+
+        let mut pushnum = false;
+
+        let mut payload = Vec::new();
+
+        loop {
+            match instructions.next().transpose()? {
+                None => return Ok((false, None)),
+                Some(Op(opcodes::all::OP_ENDIF)) => {
+                    return Ok((
+                        false,
+                        Some(Envelope {
+                            input: input.try_into().unwrap(),
+                            offset: offset.try_into().unwrap(),
+                            payload,
+                            pushnum,
+                            stutter,
+                        }),
+                    ));
+                }
+                Some(Op(opcodes::all::OP_PUSHNUM_NEG1)) => {
+                    pushnum = true;
+                    payload.push(vec![0x81]);
+                }
+                Some(Op(opcode)) if pushnum_value(opcode).is_some() => {
+                    pushnum = true;
+                    payload.push(vec![pushnum_value(opcode).unwrap()]);
+                }
+                Some(PushBytes(push)) => {
+                    payload.push(push.as_bytes().to_vec());
+                }
+                Some(_) => return Ok((false, None)),
+            }
+        }
+    }
+}
+
+/// Value pushed by `OP_PUSHNUM_1` through `OP_PUSHNUM_16`
+fn pushnum_value(opcode: opcodes::Opcode) -> Option<u8> {
+    let byte = opcode.to_u8();
+    let first = opcodes::all::OP_PUSHNUM_1.to_u8();
+    let last = opcodes::all::OP_PUSHNUM_16.to_u8();
+    if (first..=last).contains(&byte) {
+        Some(byte - first + 1)
+    } else {
+        None
+    }
+}
+
+/// The leaf script of a script-path spend: the second-to-last witness
+/// element, after removing an annex if one is present. Key-path spends and
+/// empty witnesses have no tapscript and can never carry an envelope.
+fn unversioned_leaf_script_from_witness(witness: &Witness) -> Option<&Script> {
+    #[allow(deprecated)]
+    witness.tapscript()
+}
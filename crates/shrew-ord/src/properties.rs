@@ -0,0 +1,135 @@
+//! Decoding of ord's `properties` tag (tag 17).
+//!
+//! The tag holds a CBOR map with integer keys, as written by ord:
+//!
+//! ```text
+//! { 0: [ { 0: <item id>, 1: <attributes> }, ... ],   // gallery
+//!   1: { 0: <title>, 1: { <trait>: <value>, ... } } } // attributes
+//! ```
+//!
+//! Gallery item ids use ord's compact form: the txid bytes followed by the
+//! little-endian index with trailing zero bytes removed. Malformed fields are
+//! skipped rather than rejecting the whole tag, matching ord.
+
+use bitcoin::Txid;
+use bitcoin_hashes::Hash;
+use ciborium::value::Value;
+use shrew_support::inscription::InscriptionId;
+use std::fmt;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Properties {
+    pub attributes: Attributes,
+    pub gallery: Vec<GalleryItem>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Attributes {
+    pub title: Option<String>,
+    pub traits: Vec<(String, TraitValue)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GalleryItem {
+    pub id: InscriptionId,
+    pub attributes: Attributes,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraitValue {
+    Bool(bool),
+    Integer(i128),
+    Null,
+    String(String),
+}
+
+impl fmt::Display for TraitValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TraitValue::Bool(value) => write!(f, "{}", value),
+            TraitValue::Integer(value) => write!(f, "{}", value),
+            TraitValue::Null => write!(f, "null"),
+            TraitValue::String(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl Properties {
+    /// Decode the raw tag value, `None` if it is not a CBOR map
+    pub fn from_cbor(bytes: &[u8]) -> Option<Self> {
+        let value: Value = ciborium::de::from_reader(bytes).ok()?;
+        let fields = value.as_map()?;
+
+        let gallery = field(fields, 0)
+            .and_then(Value::as_array)
+            .map(|items| items.iter().filter_map(GalleryItem::from_value).collect())
+            .unwrap_or_default();
+        let attributes = field(fields, 1).map(Attributes::from_value).unwrap_or_default();
+
+        Some(Self { attributes, gallery })
+    }
+}
+
+impl Attributes {
+    fn from_value(value: &Value) -> Self {
+        let Some(fields) = value.as_map() else { return Self::default() };
+        let title = field(fields, 0).and_then(Value::as_text).map(str::to_string);
+        let traits = field(fields, 1)
+            .and_then(Value::as_map)
+            .map(|traits| {
+                traits.iter()
+                    .filter_map(|(name, value)| Some((name.as_text()?.to_string(), TraitValue::from_value(value)?)))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self { title, traits }
+    }
+}
+
+impl GalleryItem {
+    fn from_value(value: &Value) -> Option<Self> {
+        let fields = value.as_map()?;
+        let id = field(fields, 0).and_then(Value::as_bytes).and_then(|bytes| inscription_id_from_value(bytes))?;
+        let attributes = field(fields, 1).map(Attributes::from_value).unwrap_or_default();
+        Some(Self { id, attributes })
+    }
+}
+
+impl TraitValue {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Bool(value) => Some(TraitValue::Bool(*value)),
+            Value::Integer(value) => Some(TraitValue::Integer(i128::from(*value))),
+            Value::Null => Some(TraitValue::Null),
+            Value::Text(value) => Some(TraitValue::String(value.clone())),
+            _ => None,
+        }
+    }
+}
+
+/// Value stored under an integer key of a CBOR map
+fn field(fields: &[(Value, Value)], key: i128) -> Option<&Value> {
+    fields.iter()
+        .find(|(k, _)| matches!(k, Value::Integer(k) if i128::from(*k) == key))
+        .map(|(_, v)| v)
+}
+
+/// Parse ord's compact inscription id encoding
+pub fn inscription_id_from_value(bytes: &[u8]) -> Option<InscriptionId> {
+    if bytes.len() < 32 || bytes.len() > 36 {
+        return None;
+    }
+    let txid = Txid::from_slice(&bytes[..32]).ok()?;
+    let mut index = [0u8; 4];
+    index[..bytes.len() - 32].copy_from_slice(&bytes[32..]);
+    Some(InscriptionId::new(txid, u32::from_le_bytes(index)))
+}
+
+/// ord's compact inscription id encoding
+pub fn inscription_id_to_value(id: &InscriptionId) -> Vec<u8> {
+    let mut bytes = id.txid.as_byte_array().to_vec();
+    let index = id.index.to_le_bytes();
+    let len = index.iter().rposition(|byte| *byte != 0).map_or(0, |i| i + 1);
+    bytes.extend_from_slice(&index[..len]);
+    bytes
+}
@@ -0,0 +1,101 @@
+//! Incrementally maintained inscription statistics.
+//!
+//! Counts by content type, metaprotocol and charm are bumped as each
+//! inscription is indexed, and one [`BlockStats`] record is written per block,
+//! so the `getinscriptionstats` view never has to walk
+//! `SEQUENCE_TO_INSCRIPTION_ENTRY`.
+
+use crate::tables::*;
+use shrew_support::inscription::{Charm, InscriptionEntry};
+use shrew_support::table::ValueCodec;
+
+/// Inscription activity in a single block, plus the running blessed and
+/// cursed totals as of the end of that block
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BlockStats {
+    pub inscriptions: u64,
+    pub total_bytes: u64,
+    pub total_fees: u64,
+    pub blessed_total: u64,
+    pub cursed_total: u64,
+    /// Inscriptions left out of `total_fees` because the value of one of
+    /// their reveal's inputs was never indexed
+    pub unknown_fees: u64,
+}
+
+impl BlockStats {
+    const ENCODED_LEN: usize = 48;
+    /// Records written before `unknown_fees` was counted
+    const LEGACY_ENCODED_LEN: usize = 40;
+
+    pub fn record(&mut self, entry: &InscriptionEntry) {
+        self.inscriptions += 1;
+        self.total_bytes += entry.content_length.unwrap_or(0);
+        self.total_fees += entry.fee;
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::ENCODED_LEN);
+        for value in [self.inscriptions, self.total_bytes, self.total_fees, self.blessed_total, self.cursed_total, self.unknown_fees] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::ENCODED_LEN && bytes.len() != Self::LEGACY_ENCODED_LEN {
+            return None;
+        }
+        let field = |i: usize| u64::from_le_bytes(bytes[i * 8..i * 8 + 8].try_into().unwrap());
+        Some(Self {
+            inscriptions: field(0),
+            total_bytes: field(1),
+            total_fees: field(2),
+            blessed_total: field(3),
+            cursed_total: field(4),
+            unknown_fees: if bytes.len() == Self::ENCODED_LEN { field(5) } else { 0 },
+        })
+    }
+
+    /// Stats written for `height`, if that block has been indexed
+    pub fn load(height: u32) -> Option<Self> {
+        HEIGHT_TO_INSCRIPTION_STATS.get(&height)
+    }
+
+    pub fn save(&self, height: u32) {
+        HEIGHT_TO_INSCRIPTION_STATS.set(&height, self);
+        INSCRIPTION_STATS_HEIGHT.set(&height);
+    }
+}
+
+impl ValueCodec for BlockStats {
+    type Value = BlockStats;
+
+    fn encode_value(value: &BlockStats) -> Vec<u8> {
+        value.to_bytes()
+    }
+
+    fn decode_value(bytes: &[u8]) -> Option<BlockStats> {
+        BlockStats::from_bytes(bytes)
+    }
+}
+
+/// Last height for which stats were written
+pub fn stats_height() -> Option<u32> {
+    INSCRIPTION_STATS_HEIGHT.get()
+}
+
+/// Bump the content type, metaprotocol and charm counters for a new inscription
+pub fn record_inscription(entry: &InscriptionEntry) {
+    if let Some(content_type) = &entry.content_type {
+        CONTENT_TYPE_COUNTS.increment(content_type);
+    }
+    if let Some(metaprotocol) = &entry.metaprotocol {
+        METAPROTOCOL_COUNTS.increment(metaprotocol);
+    }
+    for charm in Charm::all() {
+        if entry.has_charm(*charm) {
+            CHARM_COUNTS.increment(&(*charm as u16));
+        }
+    }
+}
@@ -1,122 +1,193 @@
-use metashrew_core::index_pointer::IndexPointer;
-use metashrew_support::index_pointer::KeyValuePointer;
+use crate::entries::DecodeFailure;
+use crate::history::InscriptionTransfer;
+use crate::stats::BlockStats;
+use bitcoin::{BlockHash, OutPoint, Script, ScriptBuf, Txid};
+use sha2::{Digest, Sha256};
+use shrew_support::inscription::{InscriptionId, SatPoint};
+use shrew_support::rune::RuneId;
+use shrew_support::table::{Bincode, ListTable, PrefixTable, Slot, Table};
 
 lazy_static::lazy_static! {
     // Core mappings
-    pub static ref INSCRIPTION_ID_TO_SEQUENCE: IndexPointer = IndexPointer::from_keyword("/inscriptions/id_to_seq/");
-    pub static ref SEQUENCE_TO_INSCRIPTION_ENTRY: IndexPointer = IndexPointer::from_keyword("/inscriptions/seq_to_entry/");
-    pub static ref INSCRIPTION_NUMBER_TO_SEQUENCE: IndexPointer = IndexPointer::from_keyword("/inscriptions/num_to_seq/");
+    pub static ref INSCRIPTION_ID_TO_SEQUENCE: Table<InscriptionId, u32> = Table::new("/inscriptions/id_to_seq/");
+    /// Versioned `InscriptionEntry` encoding; read through `entries`
+    pub static ref SEQUENCE_TO_INSCRIPTION_ENTRY: Table<u32, Vec<u8>> = Table::new("/inscriptions/seq_to_entry/");
+    pub static ref INSCRIPTION_NUMBER_TO_SEQUENCE: Table<i32, u32> = Table::new("/inscriptions/num_to_seq/");
+    /// Schema version of the stored entries (absent before versioning)
+    pub static ref INSCRIPTION_ENTRY_SCHEMA: Slot<u8> = Slot::new("/inscriptions/entry_schema");
+    /// Entries that failed to decode, listed under the height they were first seen
+    /// at, and that height keyed by sequence
+    pub static ref ENTRY_DECODE_FAILURES: ListTable<u32, Bincode<DecodeFailure>> = ListTable::new("/inscriptions/entry_decode_failures/");
+    pub static ref FAILED_ENTRY_SEQUENCES: Table<u32, u32> = Table::new("/inscriptions/failed_entries/");
 
     // Location tracking
-    pub static ref SEQUENCE_TO_SATPOINT: IndexPointer = IndexPointer::from_keyword("/inscriptions/seq_to_satpoint/");
-    pub static ref SAT_TO_SEQUENCE: IndexPointer = IndexPointer::from_keyword("/inscriptions/sat_to_seq/");
-    pub static ref OUTPOINT_TO_INSCRIPTIONS: IndexPointer = IndexPointer::from_keyword("/inscriptions/outpoint_to_list/");
+    /// Current satpoint of each sequence (the entry itself keeps the genesis satpoint)
+    pub static ref SEQUENCE_TO_SATPOINT: Table<u32, SatPoint> = Table::new("/inscriptions/seq_to_satpoint/");
+    pub static ref SAT_TO_SEQUENCE: Table<u64, u32> = Table::new("/inscriptions/sat_to_seq/");
+    /// Sequences that have ever been located at an outpoint. Spent outpoints keep
+    /// their lists so later protocols in the same block still see what was spent.
+    pub static ref OUTPOINT_TO_INSCRIPTIONS: ListTable<OutPoint, u32> = ListTable::new("/inscriptions/outpoint_to_list/");
+    /// Location history per sequence, genesis first
+    pub static ref SEQUENCE_TO_HISTORY: ListTable<u32, Bincode<InscriptionTransfer>> = ListTable::new("/inscriptions/seq_to_history/");
 
     // Hierarchical relationships
-    pub static ref SEQUENCE_TO_CHILDREN: IndexPointer = IndexPointer::from_keyword("/inscriptions/seq_to_children/");
-    pub static ref SEQUENCE_TO_PARENTS: IndexPointer = IndexPointer::from_keyword("/inscriptions/seq_to_parents/");
+    pub static ref SEQUENCE_TO_CHILDREN: ListTable<u32, u32> = ListTable::new("/inscriptions/seq_to_children/");
+    pub static ref SEQUENCE_TO_PARENTS: ListTable<u32, u32> = ListTable::new("/inscriptions/seq_to_parents/");
 
     // Block and height indexing
-    pub static ref HEIGHT_TO_INSCRIPTIONS: IndexPointer = IndexPointer::from_keyword("/inscriptions/height_to_list/");
-    pub static ref HEIGHT_TO_BLOCK_HASH: IndexPointer = IndexPointer::from_keyword("/inscriptions/height_to_hash/");
-    pub static ref BLOCK_HASH_TO_HEIGHT: IndexPointer = IndexPointer::from_keyword("/inscriptions/hash_to_height/");
+    pub static ref HEIGHT_TO_INSCRIPTIONS: ListTable<u32, InscriptionId> = ListTable::new("/inscriptions/height_to_list/");
+    pub static ref HEIGHT_TO_BLOCK_HASH: Table<u32, BlockHash> = Table::new("/inscriptions/height_to_hash/");
+    pub static ref BLOCK_HASH_TO_HEIGHT: Table<BlockHash, u32> = Table::new("/inscriptions/hash_to_height/");
 
     // Content and metadata indexing
-    pub static ref CONTENT_TYPE_TO_INSCRIPTIONS: IndexPointer = IndexPointer::from_keyword("/inscriptions/content_type/");
-    pub static ref METAPROTOCOL_TO_INSCRIPTIONS: IndexPointer = IndexPointer::from_keyword("/inscriptions/metaprotocol/");
+    pub static ref CONTENT_TYPE_TO_INSCRIPTIONS: ListTable<str, u32> = ListTable::new("/inscriptions/content_type/");
+    pub static ref METAPROTOCOL_TO_INSCRIPTIONS: ListTable<str, u32> = ListTable::new("/inscriptions/metaprotocol/");
 
     // Statistics and counters
-    pub static ref GLOBAL_SEQUENCE_COUNTER: IndexPointer = IndexPointer::from_keyword("/inscriptions/counters/sequence");
-    pub static ref BLESSED_INSCRIPTION_COUNTER: IndexPointer = IndexPointer::from_keyword("/inscriptions/counters/blessed");
-    pub static ref CURSED_INSCRIPTION_COUNTER: IndexPointer = IndexPointer::from_keyword("/inscriptions/counters/cursed");
+    pub static ref GLOBAL_SEQUENCE_COUNTER: Slot<u32> = Slot::new("/inscriptions/counters/sequence");
+    pub static ref BLESSED_INSCRIPTION_COUNTER: Slot<i32> = Slot::new("/inscriptions/counters/blessed");
+    pub static ref CURSED_INSCRIPTION_COUNTER: Slot<i32> = Slot::new("/inscriptions/counters/cursed");
+    /// Inscription counts by content type / metaprotocol / charm, the first two
+    /// listing their keys in first-seen order
+    pub static ref CONTENT_TYPE_COUNTS: Table<str, u64> = Table::indexed("/inscriptions/stats/content_type_count/");
+    pub static ref METAPROTOCOL_COUNTS: Table<str, u64> = Table::indexed("/inscriptions/stats/metaprotocol_count/");
+    pub static ref CHARM_COUNTS: Table<u16, u64> = Table::new("/inscriptions/stats/charm_count/");
+    /// Per-block `BlockStats`, and the last height written
+    pub static ref HEIGHT_TO_INSCRIPTION_STATS: Table<u32, BlockStats> = Table::new("/inscriptions/stats/height/");
+    pub static ref INSCRIPTION_STATS_HEIGHT: Slot<u32> = Slot::new("/inscriptions/stats/tip");
 
     // Special collections
-    pub static ref HOME_INSCRIPTIONS: IndexPointer = IndexPointer::from_keyword("/inscriptions/home/");
-    pub static ref COLLECTIONS: IndexPointer = IndexPointer::from_keyword("/inscriptions/collections/");
+    pub static ref HOME_INSCRIPTIONS: ListTable<(), InscriptionId> = ListTable::new("/inscriptions/home/");
+    /// Per-collection trait counts keyed by the defining sequence, then by
+    /// trait name and value separated by NUL (see `collections`)
+    pub static ref COLLECTIONS: PrefixTable<u32, [u8], u64> = PrefixTable::new("/inscriptions/collections/");
+    /// Gallery sequence -> item inscription ids, and inscription id -> gallery sequences
+    pub static ref GALLERY_TO_ITEMS: ListTable<u32, InscriptionId> = ListTable::new("/inscriptions/gallery_to_items/");
+    pub static ref INSCRIPTION_TO_GALLERIES: ListTable<InscriptionId, u32> = ListTable::new("/inscriptions/inscription_to_galleries/");
 
     // Sat tracking
-    pub static ref SAT_TO_INSCRIPTIONS: IndexPointer = IndexPointer::from_keyword("/inscriptions/sat_to_inscriptions/");
-    pub static ref INSCRIPTION_TO_SAT: IndexPointer = IndexPointer::from_keyword("/inscriptions/inscription_to_sat/");
+    pub static ref SAT_TO_INSCRIPTIONS: ListTable<u64, u32> = ListTable::new("/inscriptions/sat_to_inscriptions/");
+    pub static ref INSCRIPTION_TO_SAT: Table<u32, u64> = Table::new("/inscriptions/inscription_to_sat/");
 
     // Transaction tracking
-    pub static ref TXID_TO_INSCRIPTIONS: IndexPointer = IndexPointer::from_keyword("/inscriptions/txid_to_inscriptions/");
-    pub static ref INSCRIPTION_TO_TXID: IndexPointer = IndexPointer::from_keyword("/inscriptions/inscription_to_txid/");
+    pub static ref TXID_TO_INSCRIPTIONS: ListTable<Txid, u32> = ListTable::new("/inscriptions/txid_to_inscriptions/");
+    pub static ref INSCRIPTION_TO_TXID: Table<u32, Txid> = Table::new("/inscriptions/inscription_to_txid/");
 
     // Address tracking
-    pub static ref ADDRESS_TO_INSCRIPTIONS: IndexPointer = IndexPointer::from_keyword("/inscriptions/address_to_inscriptions/");
-    pub static ref INSCRIPTION_TO_ADDRESS: IndexPointer = IndexPointer::from_keyword("/inscriptions/inscription_to_address/");
-
-    // Rune tracking
-    pub static ref RUNE_TO_INSCRIPTIONS: IndexPointer = IndexPointer::from_keyword("/inscriptions/rune_to_inscriptions/");
-    pub static ref INSCRIPTION_TO_RUNE: IndexPointer = IndexPointer::from_keyword("/inscriptions/inscription_to_rune/");
-
-    // Content storage
-    pub static ref INSCRIPTION_CONTENT: IndexPointer = IndexPointer::from_keyword("/inscriptions/content/");
-    pub static ref INSCRIPTION_METADATA: IndexPointer = IndexPointer::from_keyword("/inscriptions/metadata/");
+    pub static ref ADDRESS_TO_INSCRIPTIONS: ListTable<Script, u32> = ListTable::new("/inscriptions/address_to_inscriptions/");
+    pub static ref INSCRIPTION_TO_ADDRESS: Table<u32, ScriptBuf> = Table::new("/inscriptions/inscription_to_address/");
+
+    // Rune tracking: written by shrew-runes when a rune is etched. As in ord, a rune
+    // is linked to the etching transaction's first inscription; the one whose rune
+    // tag names it is indexed separately.
+    pub static ref RUNE_TO_INSCRIPTIONS: Table<RuneId, u32> = Table::new("/inscriptions/rune_to_inscriptions/");
+    pub static ref INSCRIPTION_TO_RUNE: Table<u32, RuneId> = Table::new("/inscriptions/inscription_to_rune/");
+    pub static ref RUNE_TO_TAGGED_INSCRIPTION: Table<RuneId, u32> = Table::new("/inscriptions/rune_to_tagged_inscription/");
+
+    // Content storage: bodies are stored once per sha256 hash, in chunks (see CONTENT_CHUNK_SIZE),
+    // and shared by identical inscriptions
+    pub static ref CONTENT_HASH_TO_SIZE: Table<[u8; 32], u64> = Table::new("/inscriptions/content_size/");
+    pub static ref CONTENT_HASH_TO_BODY: Table<([u8; 32], u32), Vec<u8>> = Table::new("/inscriptions/content_by_hash/");
+    pub static ref INSCRIPTION_TO_CONTENT_HASH: Table<InscriptionId, [u8; 32]> = Table::new("/inscriptions/content_hash/");
+    pub static ref CONTENT_HASH_TO_INSCRIPTIONS: ListTable<[u8; 32], u32> = ListTable::new("/inscriptions/hash_to_inscriptions/");
+    pub static ref INSCRIPTION_METADATA: Table<InscriptionId, Vec<u8>> = Table::new("/inscriptions/metadata/");
+    /// Whole bodies keyed by inscription id string, as the original layout
+    /// stored them. Read as a fallback until `layout::migrate` empties it.
+    pub static ref LEGACY_INSCRIPTION_CONTENT: Table<str, Vec<u8>> = Table::new("/inscriptions/content/");
+    /// Metadata keyed by inscription id string, and block inscription lists
+    /// keyed by `{height}:{index}`, as the original layout stored them.
+    /// Emptied by `layout::migrate`.
+    pub static ref LEGACY_INSCRIPTION_METADATA: Table<str, Vec<u8>> = Table::new("/inscriptions/metadata/");
+    pub static ref LEGACY_HEIGHT_TO_INSCRIPTIONS: Table<str, InscriptionId> = Table::new("/inscriptions/height_to_list/");
+    /// Table layout version of the store (absent in the original layout)
+    pub static ref STORE_LAYOUT: Slot<u8> = Slot::new("/inscriptions/layout");
 
     // Delegation tracking
-    pub static ref DELEGATE_TO_INSCRIPTIONS: IndexPointer = IndexPointer::from_keyword("/inscriptions/delegate_to_inscriptions/");
-    pub static ref INSCRIPTION_TO_DELEGATE: IndexPointer = IndexPointer::from_keyword("/inscriptions/inscription_to_delegate/");
+    pub static ref DELEGATE_TO_INSCRIPTIONS: ListTable<InscriptionId, u32> = ListTable::new("/inscriptions/delegate_to_inscriptions/");
+    pub static ref INSCRIPTION_TO_DELEGATE: Table<u32, InscriptionId> = Table::new("/inscriptions/inscription_to_delegate/");
 
     // Raw transaction storage (for BRC20-prog precompiles)
-    pub static ref TXID_TO_RAW_TX: IndexPointer = IndexPointer::from_keyword("/transactions/txid_to_raw/");
-    pub static ref TXID_TO_BLOCK_HEIGHT: IndexPointer = IndexPointer::from_keyword("/transactions/txid_to_height/");
+    pub static ref TXID_TO_RAW_TX: Table<Txid, Vec<u8>> = Table::new("/transactions/txid_to_raw/");
+    pub static ref TXID_TO_BLOCK_HEIGHT: Table<Txid, u32> = Table::new("/transactions/txid_to_height/");
+    /// Value of every transaction output, whatever the retention policy, so
+    /// sat flow never depends on which transactions were kept
+    pub static ref OUTPOINT_TO_VALUE: Table<OutPoint, u64> = Table::new("/transactions/outpoint_to_value/");
+    /// Script of every transaction output under `TxRetention::Relevant`, so
+    /// the precompiles can describe the inputs of a kept transaction whose
+    /// parents were dropped
+    pub static ref OUTPOINT_TO_SCRIPT: Table<OutPoint, Vec<u8>> = Table::new("/transactions/outpoint_to_script/");
+    /// Witness-stripped transactions, written instead of TXID_TO_RAW_TX under `TxRetention::Compact`
+    pub static ref TXID_TO_COMPACT_TX: Table<Txid, Vec<u8>> = Table::new("/transactions/txid_to_compact/");
 }
 
-/// Table wrapper structs for easier access
+/// Inscription bodies, stored by content hash
 pub struct InscriptionContentTable;
-pub struct InscriptionContentTypeTable;
-pub struct InscriptionMetadataTable;
-pub struct InscriptionParentTable;
-pub struct InscriptionNumberTable;
+
+/// Size of each stored body chunk. Bodies are written as chunks keyed by
+/// content hash and chunk index, with their total size in CONTENT_HASH_TO_SIZE.
+pub const CONTENT_CHUNK_SIZE: usize = 64 * 1024;
 
 impl InscriptionContentTable {
     pub fn new() -> Self { Self }
-    pub fn get(&self, inscription_id: &str) -> Option<Vec<u8>> {
-        let pointer = INSCRIPTION_CONTENT.select(&inscription_id.as_bytes().to_vec());
-        let result = pointer.get();
-        if result.is_empty() { None } else { Some((*result).clone()) }
+    pub fn get(&self, inscription_id: &InscriptionId) -> Option<Vec<u8>> {
+        let (content, _) = self.get_range(inscription_id, 0, None)?;
+        if content.is_empty() { None } else { Some(content) }
     }
-    pub fn set(&self, inscription_id: &str, content: &[u8]) {
-        let mut pointer = INSCRIPTION_CONTENT.select(&inscription_id.as_bytes().to_vec());
-        pointer.set(std::sync::Arc::new(content.to_vec()));
+    /// Read `length` bytes (or the rest of the body) starting at `offset`,
+    /// returning them together with the total body size.
+    pub fn get_range(&self, inscription_id: &InscriptionId, offset: u64, length: Option<u64>) -> Option<(Vec<u8>, u64)> {
+        let Some(hash) = INSCRIPTION_TO_CONTENT_HASH.get(inscription_id) else {
+            let body = LEGACY_INSCRIPTION_CONTENT.get(&inscription_id.to_string())?;
+            let total = body.len() as u64;
+            let (start, end) = byte_range(total, offset, length);
+            return Some((body[start as usize..end as usize].to_vec(), total));
+        };
+        let total = self.size_by_hash(&hash)?;
+        let (start, end) = byte_range(total, offset, length);
+        let mut content = Vec::with_capacity((end - start) as usize);
+        let chunk_size = CONTENT_CHUNK_SIZE as u64;
+        let mut position = start;
+        while position < end {
+            let chunk_index = (position / chunk_size) as u32;
+            let chunk = CONTENT_HASH_TO_BODY.get(&(hash, chunk_index)).unwrap_or_default();
+            let chunk_start = (position % chunk_size) as usize;
+            let chunk_end = ((end - chunk_index as u64 * chunk_size).min(chunk_size) as usize).min(chunk.len());
+            if chunk_start >= chunk_end { break; }
+            content.extend_from_slice(&chunk[chunk_start..chunk_end]);
+            position += (chunk_end - chunk_start) as u64;
+        }
+        Some((content, total))
     }
-}
-
-impl InscriptionContentTypeTable {
-    pub fn new() -> Self { Self }
-    pub fn get(&self, inscription_id: &str) -> Option<Vec<u8>> {
-        let content_type_table = IndexPointer::from_keyword("/inscriptions/content_types/");
-        let pointer = content_type_table.select(&inscription_id.as_bytes().to_vec());
-        let result = pointer.get();
-        if result.is_empty() { None } else { Some((*result).clone()) }
+    /// Record the body of an inscription, writing the bytes only if no other
+    /// inscription already stored an identical body.
+    pub fn set(&self, inscription_id: &InscriptionId, content: &[u8]) -> [u8; 32] {
+        let hash: [u8; 32] = Sha256::digest(content).into();
+        if self.size_by_hash(&hash).is_none() {
+            for (chunk_index, chunk) in content.chunks(CONTENT_CHUNK_SIZE).enumerate() {
+                CONTENT_HASH_TO_BODY.set(&(hash, chunk_index as u32), &chunk.to_vec());
+            }
+            CONTENT_HASH_TO_SIZE.set(&hash, &(content.len() as u64));
+        }
+        INSCRIPTION_TO_CONTENT_HASH.set(inscription_id, &hash);
+        hash
     }
-}
-
-impl InscriptionMetadataTable {
-    pub fn new() -> Self { Self }
-    pub fn get(&self, inscription_id: &str) -> Option<Vec<u8>> {
-        let pointer = INSCRIPTION_METADATA.select(&inscription_id.as_bytes().to_vec());
-        let result = pointer.get();
-        if result.is_empty() { None } else { Some((*result).clone()) }
+    /// Hash of the inscription's body, including a body still in the
+    /// original layout
+    pub fn hash(&self, inscription_id: &InscriptionId) -> Option<[u8; 32]> {
+        INSCRIPTION_TO_CONTENT_HASH.get(inscription_id).or_else(|| {
+            LEGACY_INSCRIPTION_CONTENT.get(&inscription_id.to_string()).map(|body| Sha256::digest(body).into())
+        })
     }
-}
-
-impl InscriptionParentTable {
-    pub fn new() -> Self { Self }
-    pub fn get(&self, inscription_id: &str) -> Option<String> {
-        let pointer = SEQUENCE_TO_PARENTS.select(&inscription_id.as_bytes().to_vec());
-        let result = pointer.get();
-        if result.is_empty() { None } else { String::from_utf8((*result).clone()).ok() }
+    /// Total body size recorded for a content hash
+    pub fn size_by_hash(&self, hash: &[u8; 32]) -> Option<u64> {
+        CONTENT_HASH_TO_SIZE.get(hash)
     }
 }
 
-impl InscriptionNumberTable {
-    pub fn new() -> Self { Self }
-    pub fn get(&self, inscription_id: &str) -> Option<Vec<u8>> {
-        let number_table = IndexPointer::from_keyword("/inscriptions/numbers/");
-        let pointer = number_table.select(&inscription_id.as_bytes().to_vec());
-        let result = pointer.get();
-        if result.is_empty() { None } else { Some((*result).clone()) }
-    }
+/// Bounds of the bytes `offset..offset + length` of a body of `total` bytes,
+/// clamped to the body
+fn byte_range(total: u64, offset: u64, length: Option<u64>) -> (u64, u64) {
+    let start = offset.min(total);
+    let end = length.map_or(total, |length| start.saturating_add(length).min(total));
+    (start, end)
 }
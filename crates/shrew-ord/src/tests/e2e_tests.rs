@@ -0,0 +1,359 @@
+//! End-to-end coverage ported from the retired root-crate `comprehensive_e2e_tests`
+//! suite: blocks go through `InscriptionIndexer::index_block` and results are
+//! checked through the public view functions.
+
+use wasm_bindgen_test::wasm_bindgen_test as test;
+use crate::error::OrdError;
+use crate::indexer::InscriptionIndexer;
+use crate::proto::{
+    get_block_info_request, get_inscription_request, GetBlockInfoRequest, GetChildInscriptionsRequest,
+    ChildrenResponse, GetChildrenRequest, GetContentRequest, GetInscriptionRequest, GetInscriptionsRequest,
+    GetMetadataRequest, GetParentsRequest, GetUndelegatedContentRequest,
+    InscriptionId as ProtoInscriptionId,
+};
+use shrew_support::error::{CodedError, ErrorCode};
+use shrew_support::pagination::PaginationRequest;
+use shrew_support::wire::{self, WireFormat};
+use crate::view;
+use bitcoin::{Transaction, Txid};
+use bitcoin_hashes::Hash;
+use shrew_support::inscription::Charm;
+use shrew_test_helpers::blocks::*;
+use shrew_test_helpers::inscriptions::*;
+use shrew_test_helpers::state;
+use shrew_test_helpers::transactions::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+/// Build a commit txid that differs from every other test commit in its last byte pair
+fn commit_txid(a: u8, b: u8) -> Txid {
+    let mut bytes = [0u8; 32];
+    bytes[30] = a;
+    bytes[31] = b;
+    Txid::from_byte_array(bytes)
+}
+
+fn proto_id(tx: &Transaction) -> ProtoInscriptionId {
+    ProtoInscriptionId { txid: tx.txid().as_byte_array().to_vec(), index: 0 }
+}
+
+fn index_tx(indexer: &mut InscriptionIndexer, tx: &Transaction, height: u32) {
+    let block = create_block_with_txs(vec![create_coinbase_transaction(height), tx.clone()]);
+    indexer.index_block(&block, height).unwrap();
+}
+
+#[wasm_bindgen_test]
+fn test_e2e_lifecycle_across_content_types() {
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+
+    let inscriptions: Vec<(&[u8], &str)> = vec![
+        (b"Hello, Bitcoin!", "text/plain"),
+        (br#"{"name": "Test NFT"}"#, "application/json"),
+        (b"<html><body>Hello World</body></html>", "text/html"),
+    ];
+
+    let mut txs = Vec::new();
+    for (i, (content, content_type)) in inscriptions.iter().enumerate() {
+        let witness = create_inscription_envelope(content_type.as_bytes(), content);
+        let tx = create_reveal_transaction(&commit_txid(0, i as u8 + 1), witness);
+        index_tx(&mut indexer, &tx, 840000 + i as u32);
+        txs.push(tx);
+    }
+
+    let list = view::get_inscriptions(&GetInscriptionsRequest {
+        pagination: Some(PaginationRequest { page: 0, limit: 10, ..Default::default() }),
+        filter: None,
+    }).unwrap();
+    let pagination = list.pagination.unwrap();
+    assert_eq!(pagination.limit, 10);
+    assert_eq!(pagination.total, inscriptions.len() as u64);
+    assert_eq!(list.ids.len(), inscriptions.len());
+
+    for (tx, (content, content_type)) in txs.iter().zip(inscriptions.iter()) {
+        let response = view::get_inscription(&GetInscriptionRequest {
+            query: Some(get_inscription_request::Query::Id(proto_id(tx))),
+            child_index: None,
+        }).unwrap();
+        assert!(response.id.is_some());
+        assert_eq!(response.content_type.as_deref(), Some(*content_type));
+
+        let content_response = view::get_content(&GetContentRequest { id: Some(proto_id(tx)), ..Default::default() }).unwrap();
+        assert_eq!(content_response.content, *content);
+
+        let metadata = view::get_metadata(&GetMetadataRequest { id: Some(proto_id(tx)) }).unwrap();
+        assert!(metadata.metadata_hex.is_empty(), "Plain inscriptions carry no metadata");
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_e2e_parent_child_relationships() {
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+
+    let parent_witness = create_inscription_envelope(b"text/plain", b"I am the parent inscription");
+    let parent_tx = create_reveal_transaction(&commit_txid(1, 0), parent_witness);
+    index_tx(&mut indexer, &parent_tx, 840000);
+    let parent_id = format!("{}i0", parent_tx.txid());
+
+    let child1_tx = create_reveal_transaction(
+        &commit_txid(1, 1),
+        create_inscription_envelope_with_parent(b"text/plain", b"I am child 1", &parent_id),
+    );
+    let child2_tx = create_reveal_transaction(
+        &commit_txid(1, 2),
+        create_inscription_envelope_with_parent(b"text/plain", b"I am child 2", &parent_id),
+    );
+    index_tx(&mut indexer, &child1_tx, 840001);
+    index_tx(&mut indexer, &child2_tx, 840002);
+
+    let children = view::get_children(&GetChildrenRequest {
+        parent_id: Some(proto_id(&parent_tx)),
+        pagination: None,
+    }).unwrap();
+    assert_eq!(children.ids.len(), 2);
+    let child_txids: Vec<Vec<u8>> = children.ids.iter().map(|id| id.txid.clone()).collect();
+    assert!(child_txids.contains(&proto_id(&child1_tx).txid));
+    assert!(child_txids.contains(&proto_id(&child2_tx).txid));
+
+    for child_tx in [&child1_tx, &child2_tx] {
+        let parents = view::get_parents(&GetParentsRequest {
+            child_id: Some(proto_id(child_tx)),
+            pagination: None,
+        }).unwrap();
+        assert_eq!(parents.ids.len(), 1);
+        assert_eq!(parents.ids[0].txid, proto_id(&parent_tx).txid);
+        assert_eq!(parents.ids[0].index, 0);
+    }
+
+    let detailed = view::get_child_inscriptions(&GetChildInscriptionsRequest {
+        parent_id: Some(proto_id(&parent_tx)),
+        pagination: None,
+    }).unwrap();
+    assert_eq!(detailed.children.len(), 2);
+    for child in &detailed.children {
+        assert!(child.id.is_some());
+        assert!(child.number > 0, "Children are blessed and numbered after the parent");
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_e2e_delegation() {
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+
+    let delegate_content = b"This is the actual content that will be delegated";
+    let delegate_tx = create_reveal_transaction(
+        &commit_txid(2, 0),
+        create_inscription_envelope(b"text/plain", delegate_content),
+    );
+    index_tx(&mut indexer, &delegate_tx, 840000);
+    let delegate_id = format!("{}i0", delegate_tx.txid());
+
+    let delegating_tx = create_reveal_transaction(
+        &commit_txid(2, 1),
+        create_inscription_envelope_with_delegate(b"text/plain", b"", &delegate_id),
+    );
+    index_tx(&mut indexer, &delegating_tx, 840001);
+
+    let content = view::get_content(&GetContentRequest { id: Some(proto_id(&delegating_tx)), ..Default::default() }).unwrap();
+    assert_eq!(content.content, delegate_content, "Delegating inscription serves the delegate's body");
+
+    let undelegated = view::get_undelegated_content(&GetUndelegatedContentRequest {
+        id: Some(proto_id(&delegating_tx)),
+    }).unwrap();
+    assert!(undelegated.content.is_empty(), "Delegating inscription has no body of its own");
+}
+
+#[wasm_bindgen_test]
+fn test_e2e_transferred_inscription_still_queryable() {
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+
+    let inscription_tx = create_reveal_transaction(
+        &commit_txid(3, 0),
+        create_inscription_envelope(b"text/plain", b"This inscription will be transferred"),
+    );
+    index_tx(&mut indexer, &inscription_tx, 840000);
+
+    let transfer_tx = create_transfer_transaction(&inscription_tx.txid(), 0);
+    index_tx(&mut indexer, &transfer_tx, 840001);
+
+    let response = view::get_inscription(&GetInscriptionRequest {
+        query: Some(get_inscription_request::Query::Id(proto_id(&inscription_tx))),
+        child_index: None,
+    }).unwrap();
+    assert!(response.id.is_some());
+    assert_eq!(response.number, 1);
+}
+
+#[wasm_bindgen_test]
+fn test_e2e_coinbase_inscription_is_cursed_before_jubilee() {
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+    let height = 800000;
+
+    let mut coinbase_tx = create_coinbase_transaction(height);
+    coinbase_tx.input[0].witness = create_inscription_envelope(b"text/plain", b"Cursed coinbase inscription");
+    let result = indexer.index_block(&create_block_with_txs(vec![coinbase_tx]), height).unwrap();
+
+    assert_eq!(result.inscriptions.len(), 1);
+    let entry = &result.inscriptions[0];
+    assert!(entry.is_cursed());
+    assert_eq!(entry.number, -1);
+    assert!(entry.has_charm(Charm::Cursed));
+}
+
+#[wasm_bindgen_test]
+fn test_e2e_invalid_envelopes_do_not_block_valid_ones() {
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+
+    let invalid_tx = create_reveal_transaction(&commit_txid(4, 0), create_invalid_envelope());
+    let result = indexer
+        .index_block(&create_block_with_txs(vec![create_coinbase_transaction(840000), invalid_tx]), 840000)
+        .unwrap();
+    assert!(result.inscriptions.is_empty(), "Wrong protocol id must not create an inscription");
+
+    let valid_tx = create_reveal_transaction(
+        &commit_txid(4, 1),
+        create_inscription_envelope(b"text/plain", b"Valid inscription after invalid ones"),
+    );
+    index_tx(&mut indexer, &valid_tx, 840001);
+
+    let response = view::get_inscription(&GetInscriptionRequest {
+        query: Some(get_inscription_request::Query::Id(proto_id(&valid_tx))),
+        child_index: None,
+    }).unwrap();
+    assert!(response.id.is_some());
+    assert_eq!(response.number, 1);
+}
+
+#[wasm_bindgen_test]
+fn test_e2e_error_handling() {
+    state::clear();
+
+    assert!(view::get_inscription(&GetInscriptionRequest { query: None, child_index: None }).is_err());
+    assert!(view::get_inscription(&GetInscriptionRequest {
+        query: Some(get_inscription_request::Query::Sat(0)),
+        child_index: None,
+    }).is_err());
+    assert!(view::get_children(&GetChildrenRequest { parent_id: None, pagination: None }).is_err());
+    assert!(view::get_parents(&GetParentsRequest { child_id: None, pagination: None }).is_err());
+    assert!(view::get_content(&GetContentRequest { id: None, ..Default::default() }).is_err());
+    assert!(view::get_block_info(&GetBlockInfoRequest { query: None }).is_err());
+
+    // Unknown ids resolve to empty responses rather than errors
+    let unknown = ProtoInscriptionId { txid: vec![0xee; 32], index: 0 };
+    let response = view::get_inscription(&GetInscriptionRequest {
+        query: Some(get_inscription_request::Query::Id(unknown.clone())),
+        child_index: None,
+    }).unwrap();
+    assert!(response.id.is_none());
+    let content = view::get_content(&GetContentRequest { id: Some(unknown), ..Default::default() }).unwrap();
+    assert!(content.content.is_empty());
+
+    // Malformed txid bytes are rejected
+    let malformed = ProtoInscriptionId { txid: vec![0x01; 5], index: 0 };
+    assert!(view::get_content(&GetContentRequest { id: Some(malformed), ..Default::default() }).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_e2e_error_codes() {
+    state::clear();
+
+    let missing = view::get_children(&GetChildrenRequest { parent_id: None, pagination: None }).unwrap_err();
+    assert_eq!(missing, OrdError::MissingField("parent_id"));
+    assert_eq!(missing.code(), ErrorCode::InvalidArgument);
+
+    let sat = view::get_inscription(&GetInscriptionRequest {
+        query: Some(get_inscription_request::Query::Sat(0)),
+        child_index: None,
+    }).unwrap_err();
+    assert_eq!(sat.code(), ErrorCode::NotImplemented);
+
+    let bad_cursor = PaginationRequest { cursor: "zz".to_string(), ..Default::default() };
+    let error = view::get_inscriptions(&GetInscriptionsRequest { pagination: Some(bad_cursor), filter: None }).unwrap_err();
+    assert_eq!(error.code(), ErrorCode::InvalidArgument);
+
+    // Served to a client, the error comes back as an ErrorResponse in the
+    // request's format
+    let output = wire::serve(br#"{"parent_id":null}"#, view::get_children).unwrap();
+    let response = wire::decode_response::<ChildrenResponse>(&output, WireFormat::Json).unwrap().unwrap_err();
+    assert_eq!(response.error_code(), Some(ErrorCode::InvalidArgument));
+    assert_eq!(response.error, "request must specify parent_id");
+
+    let request = wire::protobuf_request(&GetChildrenRequest::default());
+    let output = wire::serve(&request, view::get_children).unwrap();
+    let response = wire::decode_response::<ChildrenResponse>(&output, WireFormat::Protobuf).unwrap().unwrap_err();
+    assert_eq!(response.code, u32::from(ErrorCode::InvalidArgument));
+}
+
+#[wasm_bindgen_test]
+fn test_e2e_many_inscriptions_paginate() {
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+
+    let mut block = create_block_with_coinbase_tx(840000);
+    for i in 0..50u32 {
+        let content = format!("Stress inscription {}", i);
+        block.txdata.push(create_inscription_transaction(content.as_bytes(), "text/plain", None));
+    }
+    let result = indexer.index_block(&block, 840000).unwrap();
+    assert_eq!(result.inscriptions.len(), 50);
+
+    let mut seen = 0;
+    for (page, expected_len, expected_more) in [(0u32, 20usize, true), (1, 20, true), (2, 10, false)] {
+        let response = view::get_inscriptions(&GetInscriptionsRequest {
+            pagination: Some(PaginationRequest { page, limit: 20, ..Default::default() }),
+            filter: None,
+        }).unwrap();
+        let pagination = response.pagination.unwrap();
+        assert_eq!(response.ids.len(), expected_len, "page {}", page);
+        assert_eq!(pagination.more, expected_more, "page {}", page);
+        assert_eq!(pagination.total, 50);
+        seen += response.ids.len();
+    }
+    assert_eq!(seen, 50);
+
+    // Following cursors visits every inscription once, in sequence order
+    let mut ids = Vec::new();
+    let mut cursor = String::new();
+    loop {
+        let response = view::get_inscriptions(&GetInscriptionsRequest {
+            pagination: Some(PaginationRequest { limit: 15, cursor, ..Default::default() }),
+            filter: None,
+        }).unwrap();
+        ids.extend(response.ids);
+        let pagination = response.pagination.unwrap();
+        if !pagination.more { break; }
+        cursor = pagination.next_cursor;
+    }
+    let expected: Vec<_> = block.txdata[1..].iter().map(proto_id).collect();
+    assert_eq!(ids, expected);
+
+    let bad_cursor = PaginationRequest { cursor: "not-a-cursor".to_string(), ..Default::default() };
+    assert!(view::get_inscriptions(&GetInscriptionsRequest { pagination: Some(bad_cursor), filter: None }).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_e2e_block_info_round_trip() {
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+
+    let tx = create_reveal_transaction(
+        &commit_txid(5, 0),
+        create_inscription_envelope(b"text/plain", b"block info"),
+    );
+    let block = create_block_with_txs(vec![create_coinbase_transaction(840123), tx]);
+    indexer.index_block(&block, 840123).unwrap();
+
+    let by_height = view::get_block_info(&GetBlockInfoRequest {
+        query: Some(get_block_info_request::Query::Height(840123)),
+    }).unwrap();
+    assert_eq!(by_height.hash, block.block_hash().to_string());
+
+    let by_hash = view::get_block_info(&GetBlockInfoRequest {
+        query: Some(get_block_info_request::Query::Hash(block.block_hash().to_string())),
+    }).unwrap();
+    assert_eq!(by_hash.height, 840123);
+}
@@ -0,0 +1,374 @@
+//! Differential tests between the instruction-level envelope parser used by the
+//! indexer and the raw byte scanner it replaced.
+//!
+//! Both parsers must agree on every well-formed reveal script produced by
+//! `Inscription::to_witness`. Where they disagree, the new parser follows ord:
+//! only the tapscript is searched, pushdata opcodes and `OP_PUSHNUM` values are
+//! decoded, and unterminated envelopes are dropped.
+
+use wasm_bindgen_test::wasm_bindgen_test as test;
+use crate::envelope::{parse_inscriptions_from_transaction, Envelope, Inscription};
+use crate::ord_inscriptions::Inscription as OrdInscription;
+use bitcoin::blockdata::{opcodes, script};
+use bitcoin::{transaction::Version, Amount, OutPoint, ScriptBuf, Sequence, Transaction, TxIn, TxOut, Witness};
+use shrew_test_helpers::inscriptions::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+/// The byte scanner the indexer used before switching to the ord parser.
+/// Kept verbatim (apart from error handling) as the reference side of the
+/// differential suite.
+mod legacy {
+    use super::{Envelope, Inscription};
+
+    pub fn parse(tx: &bitcoin::Transaction) -> Vec<Envelope> {
+        let mut envelopes = Vec::new();
+        for (input_index, input) in tx.input.iter().enumerate() {
+            for (witness_index, witness_element) in input.witness.iter().enumerate() {
+                if let Some(envelope) = parse_envelope_from_raw_bytes(witness_element, input_index, witness_index) {
+                    envelopes.push(envelope);
+                }
+            }
+        }
+        envelopes
+    }
+
+    fn parse_envelope_from_raw_bytes(bytes: &[u8], input: usize, offset: usize) -> Option<Envelope> {
+        let mut pos = 0;
+        while pos + 5 < bytes.len() {
+            if bytes[pos] == 0x00 && bytes[pos + 1] == 0x63
+                && bytes[pos + 2] == 0x03 && &bytes[pos + 3..pos + 6] == b"ord"
+            {
+                pos += 6;
+                let end_pos = bytes.len() - 1;
+                return Some(Envelope {
+                    input,
+                    offset,
+                    payload: parse_inscription_fields(&bytes[pos..end_pos]),
+                    pushnum: false,
+                    stutter: false,
+                });
+            }
+            pos += 1;
+        }
+        None
+    }
+
+    fn parse_inscription_fields(field_data: &[u8]) -> Inscription {
+        let mut inscription = Inscription::new();
+        let mut pos = 0;
+
+        while pos < field_data.len() {
+            let push_length = field_data[pos] as usize;
+            pos += 1;
+            if pos + push_length > field_data.len() { break; }
+            let push_data = &field_data[pos..pos + push_length];
+            pos += push_length;
+
+            if push_length == 1 {
+                let tag = push_data[0];
+                if pos >= field_data.len() { break; }
+                let value_length = field_data[pos] as usize;
+                pos += 1;
+                if pos + value_length > field_data.len() { break; }
+                let value = &field_data[pos..pos + value_length];
+                pos += value_length;
+
+                match tag {
+                    1 => inscription.content_type = Some(value.to_vec()),
+                    2 => inscription.pointer = Some(value.to_vec()),
+                    3 => inscription.parent = Some(value.to_vec()),
+                    5 => inscription.metadata = Some(value.to_vec()),
+                    7 => inscription.metaprotocol = Some(value.to_vec()),
+                    9 => inscription.content_encoding = Some(value.to_vec()),
+                    11 => inscription.delegate = Some(value.to_vec()),
+                    13 => inscription.rune = Some(value.to_vec()),
+                    tag if tag % 2 == 0 => inscription.unrecognized_even_field = true,
+                    _ => {}
+                }
+            } else if push_length == 0 {
+                let mut body_content = Vec::new();
+                while pos < field_data.len() {
+                    let opcode = field_data[pos];
+                    pos += 1;
+                    let chunk_len = if opcode <= 75 {
+                        opcode as usize
+                    } else if opcode == 76 {
+                        if pos >= field_data.len() { break; }
+                        let len = field_data[pos] as usize;
+                        pos += 1;
+                        len
+                    } else if opcode == 77 {
+                        if pos + 1 >= field_data.len() { break; }
+                        let len = u16::from_le_bytes([field_data[pos], field_data[pos + 1]]) as usize;
+                        pos += 2;
+                        len
+                    } else if opcode == 78 {
+                        if pos + 3 >= field_data.len() { break; }
+                        let len = u32::from_le_bytes([
+                            field_data[pos], field_data[pos + 1],
+                            field_data[pos + 2], field_data[pos + 3],
+                        ]) as usize;
+                        pos += 4;
+                        len
+                    } else {
+                        break;
+                    };
+                    if pos + chunk_len > field_data.len() {
+                        body_content.extend_from_slice(&field_data[pos..]);
+                        break;
+                    }
+                    body_content.extend_from_slice(&field_data[pos..pos + chunk_len]);
+                    pos += chunk_len;
+                }
+                inscription.body = Some(body_content);
+                break;
+            }
+        }
+        inscription
+    }
+}
+
+fn tx_with_witness(witness: Witness) -> Transaction {
+    Transaction {
+        version: Version(2),
+        lock_time: bitcoin::absolute::LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: OutPoint::default(),
+            script_sig: ScriptBuf::new(),
+            sequence: Sequence::MAX,
+            witness,
+        }],
+        output: vec![TxOut {
+            value: Amount::from_sat(10_000),
+            script_pubkey: ScriptBuf::new(),
+        }],
+    }
+}
+
+fn current(witness: Witness) -> Vec<Envelope> {
+    parse_inscriptions_from_transaction(&tx_with_witness(witness)).unwrap()
+}
+
+fn reference(witness: Witness) -> Vec<Envelope> {
+    legacy::parse(&tx_with_witness(witness))
+}
+
+/// Start a reveal script up to and including the protocol id
+fn envelope_header() -> script::Builder {
+    script::Builder::new()
+        .push_opcode(opcodes::OP_FALSE)
+        .push_opcode(opcodes::all::OP_IF)
+        .push_slice(b"ord")
+}
+
+fn script_witness(script: ScriptBuf) -> Witness {
+    Witness::from_slice(&[script.into_bytes(), Vec::new()])
+}
+
+fn assert_agree(witness: Witness) {
+    let new = current(witness.clone());
+    let old = reference(witness);
+    assert_eq!(new.len(), 1, "ord parser should find exactly one envelope");
+    assert_eq!(new, old, "parsers disagree on a well-formed reveal script");
+}
+
+// ---------------------------------------------------------------------------
+// Agreement on well-formed reveal scripts
+// ---------------------------------------------------------------------------
+
+#[wasm_bindgen_test]
+fn test_agree_on_text_and_json() {
+    assert_agree(create_inscription_envelope(b"text/plain;charset=utf-8", b"Hello, World!"));
+    assert_agree(create_inscription_envelope(
+        b"application/json",
+        br#"{"p":"brc-20","op":"deploy","tick":"ordi","max":"21000000"}"#,
+    ));
+}
+
+#[wasm_bindgen_test]
+fn test_agree_on_empty_body() {
+    assert_agree(create_inscription_envelope(b"text/plain", b""));
+}
+
+#[wasm_bindgen_test]
+fn test_agree_on_short_metadata() {
+    assert_agree(create_inscription_envelope_with_metadata(
+        b"text/plain",
+        b"with metadata",
+        Some(b"\xa1\x64name\x64test"),
+    ));
+}
+
+#[wasm_bindgen_test]
+fn test_agree_on_parent_and_delegate() {
+    let id = "1111111111111111111111111111111111111111111111111111111111111111i0";
+    assert_agree(create_inscription_envelope_with_parent(b"text/plain", b"child", id));
+    assert_agree(create_inscription_envelope_with_delegate(b"text/plain", b"", id));
+}
+
+#[wasm_bindgen_test]
+fn test_agree_on_pointer_metaprotocol_and_encoding() {
+    let inscription = OrdInscription {
+        content_type: Some(b"text/html".to_vec()),
+        content_encoding: Some(b"br".to_vec()),
+        metaprotocol: Some(b"brc-20".to_vec()),
+        pointer: Some(546u64.to_le_bytes()[..2].to_vec()),
+        body: Some(b"<p>hi</p>".to_vec()),
+        ..Default::default()
+    };
+    assert_agree(inscription.to_witness());
+}
+
+#[wasm_bindgen_test]
+fn test_agree_on_multi_chunk_body() {
+    let body: Vec<u8> = (0..5_000u32).map(|i| (i % 253) as u8).collect();
+    assert_agree(create_inscription_envelope(b"application/octet-stream", &body));
+}
+
+#[wasm_bindgen_test]
+fn test_agree_on_non_envelopes() {
+    let witness = create_invalid_envelope();
+    assert!(current(witness.clone()).is_empty());
+    assert!(reference(witness).is_empty());
+    assert!(current(Witness::new()).is_empty());
+    assert!(reference(Witness::new()).is_empty());
+}
+
+// ---------------------------------------------------------------------------
+// Divergences: the ord parser is authoritative
+// ---------------------------------------------------------------------------
+
+#[wasm_bindgen_test]
+fn test_key_path_witness_is_not_searched() {
+    // A single witness element is a key-path signature, never a tapscript
+    let script = create_inscription_envelope(b"text/plain", b"key path").nth(0).unwrap().to_vec();
+    let witness = Witness::from_slice(&[script]);
+
+    assert_eq!(reference(witness.clone()).len(), 1);
+    assert!(current(witness).is_empty());
+}
+
+#[wasm_bindgen_test]
+fn test_annex_is_excluded() {
+    let real = create_inscription_envelope(b"text/plain", b"tapscript").nth(0).unwrap().to_vec();
+    let mut annex = vec![0x50];
+    annex.extend(create_inscription_envelope(b"text/plain", b"annex").nth(0).unwrap());
+    let witness = Witness::from_slice(&[real, Vec::new(), annex]);
+
+    let new = current(witness.clone());
+    assert_eq!(new.len(), 1);
+    assert_eq!(new[0].payload.body.as_deref(), Some(b"tapscript".as_slice()));
+
+    let old = reference(witness);
+    assert_eq!(old.len(), 2, "byte scanner also matches the annex");
+}
+
+#[wasm_bindgen_test]
+fn test_pushdata_metadata_is_decoded() {
+    // Over 75 bytes the value is pushed with OP_PUSHDATA1
+    let metadata = vec![0xa0; 120];
+    let witness = create_inscription_envelope_with_metadata(b"text/plain", b"body", Some(&metadata));
+
+    let new = current(witness.clone());
+    assert_eq!(new[0].payload.metadata.as_deref(), Some(metadata.as_slice()));
+    assert_eq!(new[0].payload.body.as_deref(), Some(b"body".as_slice()));
+
+    let old = reference(witness);
+    assert_ne!(old[0].payload, new[0].payload);
+}
+
+#[wasm_bindgen_test]
+fn test_pushnum_tag_sets_pushnum() {
+    let script = envelope_header()
+        .push_opcode(opcodes::all::OP_PUSHNUM_1)
+        .push_slice(b"text/plain")
+        .push_slice(b"")
+        .push_slice(b"pushnum")
+        .push_opcode(opcodes::all::OP_ENDIF)
+        .into_script();
+    let witness = script_witness(script);
+
+    let new = current(witness.clone());
+    assert_eq!(new.len(), 1);
+    assert!(new[0].pushnum);
+    assert_eq!(new[0].payload.content_type(), Some("text/plain".to_string()));
+    assert_eq!(new[0].payload.body.as_deref(), Some(b"pushnum".as_slice()));
+
+    let old = reference(witness);
+    assert!(!old[0].pushnum);
+    assert!(old[0].payload.content_type.is_none());
+}
+
+#[wasm_bindgen_test]
+fn test_pushnum_neg1_in_body() {
+    let script = envelope_header()
+        .push_slice(b"")
+        .push_opcode(opcodes::all::OP_PUSHNUM_NEG1)
+        .push_opcode(opcodes::all::OP_PUSHNUM_16)
+        .push_opcode(opcodes::all::OP_ENDIF)
+        .into_script();
+
+    let new = current(script_witness(script));
+    assert!(new[0].pushnum);
+    assert_eq!(new[0].payload.body.as_deref(), Some([0x81, 16].as_slice()));
+}
+
+#[wasm_bindgen_test]
+fn test_stutter_is_detected() {
+    let script = script::Builder::new()
+        .push_opcode(opcodes::OP_FALSE)
+        .push_opcode(opcodes::OP_FALSE)
+        .push_opcode(opcodes::all::OP_IF)
+        .push_slice(b"ord")
+        .push_slice(b"")
+        .push_slice(b"stutter")
+        .push_opcode(opcodes::all::OP_ENDIF)
+        .into_script();
+    let witness = script_witness(script);
+
+    let new = current(witness.clone());
+    assert_eq!(new.len(), 1);
+    assert!(new[0].stutter);
+
+    let old = reference(witness);
+    assert_eq!(old.len(), 1);
+    assert!(!old[0].stutter);
+}
+
+#[wasm_bindgen_test]
+fn test_multiple_envelopes_in_one_input() {
+    let witness = create_multiple_envelopes_same_input();
+
+    let new = current(witness.clone());
+    assert_eq!(new.len(), 2);
+    assert_eq!((new[0].input, new[0].offset), (0, 0));
+    assert_eq!((new[1].input, new[1].offset), (0, 1));
+    assert_eq!(new[0].payload.body.as_deref(), Some(b"first".as_slice()));
+    assert_eq!(new[1].payload.body.as_deref(), Some(b"second".as_slice()));
+
+    assert_eq!(reference(witness).len(), 1, "byte scanner stops at the first match");
+}
+
+#[wasm_bindgen_test]
+fn test_unterminated_envelope_is_rejected() {
+    let script = envelope_header()
+        .push_slice(b"")
+        .push_slice(b"no endif")
+        .into_script();
+    let witness = script_witness(script);
+
+    assert!(current(witness.clone()).is_empty());
+    assert_eq!(reference(witness).len(), 1);
+}
+
+#[wasm_bindgen_test]
+fn test_non_push_opcode_ends_envelope() {
+    let script = envelope_header()
+        .push_slice(b"")
+        .push_slice(b"body")
+        .push_opcode(opcodes::all::OP_DROP)
+        .push_opcode(opcodes::all::OP_ENDIF)
+        .into_script();
+
+    assert!(current(script_witness(script)).is_empty());
+}
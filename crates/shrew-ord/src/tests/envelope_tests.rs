@@ -282,3 +282,46 @@ fn test_envelope_is_cursed_for_duplicate_field() {
         "Inscription with duplicate field should be cursed"
     );
 }
+
+#[wasm_bindgen_test]
+fn test_envelope_properties_decoding() {
+    use crate::properties::{inscription_id_to_value, TraitValue};
+    use ciborium::value::Value;
+    use shrew_support::inscription::InscriptionId;
+
+    let item = InscriptionId::new(Txid::from_str(
+        "1111111111111111111111111111111111111111111111111111111111111111",
+    ).unwrap(), 256);
+    let key = |k: u8| Value::Integer(k.into());
+    let properties = Value::Map(vec![
+        (key(0), Value::Array(vec![
+            Value::Map(vec![(key(0), Value::Bytes(inscription_id_to_value(&item)))]),
+            // Not an inscription id: skipped without discarding the rest
+            Value::Map(vec![(key(0), Value::Bytes(vec![1, 2, 3]))]),
+        ])),
+        (key(1), Value::Map(vec![
+            (key(0), Value::Text("Title".into())),
+            (key(1), Value::Map(vec![
+                (Value::Text("size".into()), Value::Integer(3u8.into())),
+                (Value::Text("shiny".into()), Value::Bool(true)),
+                (Value::Text("nested".into()), Value::Array(Vec::new())),
+            ])),
+        ])),
+    ]);
+    let mut cbor = Vec::new();
+    ciborium::ser::into_writer(&properties, &mut cbor).unwrap();
+    assert_eq!(inscription_id_to_value(&item).len(), 34, "index 256 trims to two bytes");
+
+    let inscription = Inscription { properties: Some(cbor), ..Default::default() };
+    let decoded = inscription.properties().expect("properties should decode");
+    assert_eq!(decoded.gallery.len(), 1);
+    assert_eq!(decoded.gallery[0].id, item);
+    assert_eq!(decoded.attributes.title.as_deref(), Some("Title"));
+    assert_eq!(decoded.attributes.traits, vec![
+        ("size".to_string(), TraitValue::Integer(3)),
+        ("shiny".to_string(), TraitValue::Bool(true)),
+    ]);
+
+    let garbage = Inscription { properties: Some(vec![0xff]), ..Default::default() };
+    assert!(garbage.properties().is_none());
+}
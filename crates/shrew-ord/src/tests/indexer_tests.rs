@@ -1,9 +1,10 @@
 use wasm_bindgen_test::wasm_bindgen_test as test;
+use crate::error::OrdError;
 use crate::indexer::InscriptionIndexer;
 use crate::tables::*;
 use wasm_bindgen_test::wasm_bindgen_test;
-use bitcoin_hashes::Hash;
-use metashrew_support::index_pointer::KeyValuePointer;
+use shrew_support::diagnostics;
+use shrew_support::error::ErrorCode;
 use shrew_support::inscription::{Charm, InscriptionEntry, InscriptionId};
 use shrew_test_helpers::blocks::*;
 use shrew_test_helpers::indexing::index_ord_block;
@@ -18,10 +19,7 @@ fn test_index_empty_block() {
     index_ord_block(&block, 100).unwrap();
 
     // Block hash should be stored
-    let hash_bytes = HEIGHT_TO_BLOCK_HASH
-        .select(&100u32.to_le_bytes().to_vec())
-        .get();
-    assert!(!hash_bytes.is_empty(), "Block hash should be stored for height 100");
+    assert!(HEIGHT_TO_BLOCK_HASH.contains(&100), "Block hash should be stored for height 100");
 }
 
 #[wasm_bindgen_test]
@@ -35,17 +33,12 @@ fn test_index_single_inscription() {
 
     // Verify inscription was indexed
     let inscription_id = InscriptionId::new(tx.txid(), 0);
-    let seq_bytes = INSCRIPTION_ID_TO_SEQUENCE
-        .select(&inscription_id.to_bytes())
-        .get();
-    assert!(
-        !seq_bytes.is_empty(),
-        "Inscription should be indexed with a sequence number"
-    );
+    let seq = INSCRIPTION_ID_TO_SEQUENCE
+        .get(&inscription_id)
+        .expect("Inscription should be indexed with a sequence number");
 
     // Verify entry was stored
-    let entry_bytes = SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq_bytes).get();
-    assert!(!entry_bytes.is_empty(), "Entry should be stored");
+    let entry_bytes = SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq).expect("Entry should be stored");
     let entry = InscriptionEntry::from_bytes(&entry_bytes).unwrap();
     assert_eq!(entry.id, inscription_id);
     assert_eq!(entry.height, 100);
@@ -88,15 +81,11 @@ fn test_index_multiple_inscriptions_same_block() {
     // All three should be indexed
     for tx in [&tx1, &tx2, &tx3] {
         let id = InscriptionId::new(tx.txid(), 0);
-        let seq = INSCRIPTION_ID_TO_SEQUENCE.select(&id.to_bytes()).get();
-        assert!(!seq.is_empty(), "Each inscription should be indexed");
+        assert!(INSCRIPTION_ID_TO_SEQUENCE.contains(&id), "Each inscription should be indexed");
     }
 
     // Verify sequence counter was incremented to 3
-    let counter_bytes = GLOBAL_SEQUENCE_COUNTER.get();
-    assert!(!counter_bytes.is_empty());
-    let counter = u32::from_le_bytes(counter_bytes[..4].try_into().unwrap());
-    assert_eq!(counter, 3, "Sequence counter should be 3 after 3 inscriptions");
+    assert_eq!(GLOBAL_SEQUENCE_COUNTER.get(), Some(3), "Sequence counter should be 3 after 3 inscriptions");
 }
 
 use std::str::FromStr;
@@ -128,19 +117,17 @@ fn test_index_inscription_sequence_across_blocks() {
     index_ord_block(&block2, 101).unwrap();
 
     // Verify sequence counter is 2
-    let counter_bytes = GLOBAL_SEQUENCE_COUNTER.get();
-    let counter = u32::from_le_bytes(counter_bytes[..4].try_into().unwrap());
-    assert_eq!(counter, 2, "Sequence counter should be 2 after 2 blocks with 1 inscription each");
+    assert_eq!(GLOBAL_SEQUENCE_COUNTER.get(), Some(2), "Sequence counter should be 2 after 2 blocks with 1 inscription each");
 
     // Verify both entries exist with correct heights
     let id1 = InscriptionId::new(tx1.txid(), 0);
-    let seq1 = INSCRIPTION_ID_TO_SEQUENCE.select(&id1.to_bytes()).get();
-    let entry1 = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq1).get()).unwrap();
+    let seq1 = INSCRIPTION_ID_TO_SEQUENCE.get(&id1).unwrap();
+    let entry1 = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq1).unwrap()).unwrap();
     assert_eq!(entry1.height, 100);
 
     let id2 = InscriptionId::new(tx2.txid(), 0);
-    let seq2 = INSCRIPTION_ID_TO_SEQUENCE.select(&id2.to_bytes()).get();
-    let entry2 = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq2).get()).unwrap();
+    let seq2 = INSCRIPTION_ID_TO_SEQUENCE.get(&id2).unwrap();
+    let entry2 = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq2).unwrap()).unwrap();
     assert_eq!(entry2.height, 101);
 }
 
@@ -154,8 +141,8 @@ fn test_index_blessed_inscription_numbering() {
     index_ord_block(&block, 100).unwrap();
 
     let id = InscriptionId::new(tx.txid(), 0);
-    let seq = INSCRIPTION_ID_TO_SEQUENCE.select(&id.to_bytes()).get();
-    let entry = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq).get()).unwrap();
+    let seq = INSCRIPTION_ID_TO_SEQUENCE.get(&id).unwrap();
+    let entry = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq).unwrap()).unwrap();
     // Non-coinbase inscriptions at tx_index > 0 should get a positive (blessed) number
     assert!(
         entry.number > 0,
@@ -179,8 +166,8 @@ fn test_index_cursed_coinbase_inscription() {
     index_ord_block(&block, 100).unwrap();
 
     let id = InscriptionId::new(block.txdata[0].txid(), 0);
-    let seq = INSCRIPTION_ID_TO_SEQUENCE.select(&id.to_bytes()).get();
-    let entry = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq).get()).unwrap();
+    let seq = INSCRIPTION_ID_TO_SEQUENCE.get(&id).unwrap();
+    let entry = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq).unwrap()).unwrap();
     // Height 100 < JUBILEE_HEIGHT, so coinbase inscription is cursed
     assert!(
         entry.number < 0,
@@ -190,6 +177,29 @@ fn test_index_cursed_coinbase_inscription() {
     assert!(entry.has_charm(Charm::Cursed));
 }
 
+#[wasm_bindgen_test]
+fn test_index_multiple_envelopes_same_input() {
+    state::clear();
+    // Ids are numbered per envelope across the transaction, not per input,
+    // and every envelope after the first in an input is cursed.
+    let tx = create_reveal_transaction(&create_mock_outpoint(1).txid, create_multiple_envelopes_same_input());
+    let mut block = create_block_with_coinbase_tx(100);
+    block.txdata.push(tx.clone());
+    index_ord_block(&block, 100).unwrap();
+
+    let load = |index: u32| {
+        let seq = INSCRIPTION_ID_TO_SEQUENCE.get(&InscriptionId::new(tx.txid(), index))
+            .unwrap_or_else(|| panic!("Inscription i{} not indexed", index));
+        InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq).unwrap()).unwrap()
+    };
+
+    let first = load(0);
+    let second = load(1);
+    assert!(first.number > 0);
+    assert!(second.number < 0, "Second envelope in an input should be cursed, got {}", second.number);
+    assert!(second.has_charm(Charm::Cursed));
+}
+
 #[wasm_bindgen_test]
 fn test_index_inscription_content_stored() {
     state::clear();
@@ -199,10 +209,9 @@ fn test_index_inscription_content_stored() {
     block.txdata.push(tx.clone());
     index_ord_block(&block, 100).unwrap();
 
-    let inscription_id_str = format!("{}i0", tx.txid());
-    let content_bytes = INSCRIPTION_CONTENT
-        .select(&inscription_id_str.as_bytes().to_vec())
-        .get();
+    let content_bytes = InscriptionContentTable::new()
+        .get(&InscriptionId::new(tx.txid(), 0))
+        .unwrap_or_default();
     assert_eq!(
         &*content_bytes,
         body,
@@ -240,10 +249,9 @@ fn test_index_inscription_metadata_stored() {
     block.txdata.push(tx.clone());
     index_ord_block(&block, 100).unwrap();
 
-    let inscription_id_str = format!("{}i0", tx.txid());
     let metadata_bytes = INSCRIPTION_METADATA
-        .select(&inscription_id_str.as_bytes().to_vec())
-        .get();
+        .get(&InscriptionId::new(tx.txid(), 0))
+        .unwrap_or_default();
     assert_eq!(
         &*metadata_bytes,
         metadata,
@@ -260,14 +268,134 @@ fn test_index_satpoint_calculation() {
     index_ord_block(&block, 100).unwrap();
 
     let id = InscriptionId::new(tx.txid(), 0);
-    let seq = INSCRIPTION_ID_TO_SEQUENCE.select(&id.to_bytes()).get();
-    let entry = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq).get()).unwrap();
+    let seq = INSCRIPTION_ID_TO_SEQUENCE.get(&id).unwrap();
+    let entry = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq).unwrap()).unwrap();
 
     // Default satpoint should reference the transaction's outpoint
     assert_eq!(entry.satpoint.outpoint.txid, tx.txid());
     assert_eq!(entry.satpoint.offset, 0);
 }
 
+/// Reveal spending `inputs`, the last one carrying `envelope`, into outputs
+/// of the given values
+fn reveal(inputs: &[bitcoin::OutPoint], envelope: bitcoin::Witness, outputs: &[u64]) -> bitcoin::Transaction {
+    let mut tx = create_test_transaction();
+    tx.input = inputs.iter().map(|previous_output| bitcoin::TxIn { previous_output: *previous_output, ..Default::default() }).collect();
+    tx.input.last_mut().unwrap().witness = envelope;
+    tx.output = outputs.iter().enumerate().map(|(vout, value)| bitcoin::TxOut {
+        value: bitcoin::Amount::from_sat(*value),
+        script_pubkey: state::get_test_address(vout as u8).script_pubkey(),
+    }).collect();
+    tx
+}
+
+fn genesis(tx: &bitcoin::Transaction) -> (u32, InscriptionEntry) {
+    let seq = INSCRIPTION_ID_TO_SEQUENCE.get(&InscriptionId::new(tx.compute_txid(), 0)).unwrap();
+    (seq, InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq).unwrap()).unwrap())
+}
+
+#[wasm_bindgen_test]
+fn test_genesis_satpoint_follows_input_offset_and_pointer() {
+    state::clear();
+    // A 100,000,000 sat output to spend ahead of the envelope
+    let funding = create_inscription_transaction(b"funding", "text/plain", Some(create_mock_outpoint(1)));
+    index_ord_block(&create_block_with_txs(vec![create_coinbase_transaction(100), funding.clone()]), 100).unwrap();
+    let funding = bitcoin::OutPoint::new(funding.compute_txid(), 0);
+
+    // The envelope's input starts at sat 100,000,000, in the second output
+    let behind_funding = reveal(
+        &[funding, create_mock_outpoint(2)],
+        create_inscription_envelope(b"text/plain", b"second input"),
+        &[60_000_000, 50_000_000],
+    );
+    // A pointer within the outputs overrides the input offset
+    let pointed = crate::ord_inscriptions::Inscription {
+        content_type: Some(b"text/plain".to_vec()),
+        body: Some(b"pointed".to_vec()),
+        pointer: Some(15_000u64.to_le_bytes().to_vec()),
+        ..Default::default()
+    };
+    let pointed = reveal(&[create_mock_outpoint(3)], pointed.to_witness(), &[10_000, 10_000]);
+    index_ord_block(&create_block_with_txs(vec![create_coinbase_transaction(101), behind_funding.clone(), pointed.clone()]), 101).unwrap();
+
+    let (_, entry) = genesis(&behind_funding);
+    assert_eq!((entry.satpoint.outpoint.vout, entry.satpoint.offset), (1, 40_000_000));
+    let (_, entry) = genesis(&pointed);
+    assert_eq!((entry.satpoint.outpoint.vout, entry.satpoint.offset), (1, 5_000));
+    assert!(OUTPOINT_TO_INSCRIPTIONS.get_list(&bitcoin::OutPoint::new(pointed.compute_txid(), 1)).contains(&genesis(&pointed).0));
+}
+
+#[wasm_bindgen_test]
+fn test_inscription_revealed_into_the_fee() {
+    state::clear();
+    let funding = create_inscription_transaction(b"funding", "text/plain", Some(create_mock_outpoint(1)));
+    index_ord_block(&create_block_with_txs(vec![create_coinbase_transaction(100), funding.clone()]), 100).unwrap();
+
+    // Every output sat comes from the first input, so the envelope's sat is fee
+    let tx = reveal(
+        &[bitcoin::OutPoint::new(funding.compute_txid(), 0), create_mock_outpoint(2)],
+        create_inscription_envelope(b"text/plain", b"fee"),
+        &[90_000_000],
+    );
+    index_ord_block(&create_block_with_txs(vec![create_coinbase_transaction(101), tx.clone()]), 101).unwrap();
+
+    let (seq, entry) = genesis(&tx);
+    assert!(entry.satpoint.outpoint.is_null());
+    assert_eq!(entry.satpoint.offset, 10_000_000);
+    assert!(OUTPOINT_TO_INSCRIPTIONS.get_list(&bitcoin::OutPoint::new(tx.compute_txid(), 0)).is_empty());
+    let history = crate::history::load(seq);
+    assert!(history[0].spent_as_fee && history[0].owner_script.is_empty());
+}
+
+#[wasm_bindgen_test]
+fn test_reveal_fee_is_split_across_envelopes() {
+    state::clear();
+    let funding = create_inscription_transaction(b"funding", "text/plain", Some(create_mock_outpoint(1)));
+    index_ord_block(&create_block_with_txs(vec![create_coinbase_transaction(100), funding.clone()]), 100).unwrap();
+
+    // 100,000,000 in, 99,990,000 out: a 10,000 sat fee for two inscriptions
+    let tx = reveal(&[bitcoin::OutPoint::new(funding.compute_txid(), 0)], create_multiple_envelopes_same_input(), &[99_990_000]);
+    // A reveal of an output that was never indexed has no known fee
+    let unpriced = create_inscription_transaction(b"unpriced", "text/plain", Some(create_mock_outpoint(2)));
+    index_ord_block(&create_block_with_txs(vec![create_coinbase_transaction(101), tx.clone(), unpriced.clone()]), 101).unwrap();
+
+    let (_, entry) = genesis(&tx);
+    assert_eq!((entry.fee, entry.genesis_fee), (5_000, 5_000));
+    assert_eq!(genesis(&unpriced).1.fee, 0);
+    let stats = crate::stats::BlockStats::load(101).unwrap();
+    assert_eq!((stats.inscriptions, stats.total_fees, stats.unknown_fees), (3, 10_000, 1));
+
+    // Stats written before unknown fees were counted still decode
+    let legacy = &stats.to_bytes()[..40];
+    assert_eq!(crate::stats::BlockStats::from_bytes(legacy).unwrap().unknown_fees, 0);
+}
+
+#[wasm_bindgen_test]
+fn test_sats_behind_an_unindexed_output_are_not_located() {
+    state::clear();
+    let inscription = create_inscription_transaction(b"stuck", "text/plain", None);
+    index_ord_block(&create_block_with_txs(vec![create_coinbase_transaction(100), inscription.clone()]), 100).unwrap();
+    let (seq, entry) = genesis(&inscription);
+
+    // The first input's value was never indexed, so the offsets of the sats
+    // behind it are unknown: the inscription stays put instead of counting
+    // that value as zero, and the envelope behind it is unbound
+    let unknown = create_mock_outpoint(7);
+    let transfer = reveal(&[unknown, bitcoin::OutPoint::new(inscription.compute_txid(), 0)], bitcoin::Witness::new(), &[50_000]);
+    let unlocated = reveal(&[create_mock_outpoint(8), create_mock_outpoint(9)], create_inscription_envelope(b"text/plain", b"unlocated"), &[50_000]);
+    let mut indexer = InscriptionIndexer::new();
+    indexer.load_state().unwrap();
+    let result = indexer.index_block(&create_block_with_txs(vec![create_coinbase_transaction(101), transfer, unlocated.clone()]), 101).unwrap();
+
+    assert_eq!(result.transfers, 0);
+    assert_eq!(SEQUENCE_TO_SATPOINT.get(&seq), Some(entry.satpoint));
+    assert_eq!(crate::history::load(seq).len(), 1);
+    let (unlocated_seq, unlocated) = genesis(&unlocated);
+    assert_eq!(unlocated_seq, seq + 1);
+    assert!(unlocated.satpoint.outpoint.is_null() && unlocated.has_charm(Charm::Unbound));
+    assert!(!crate::history::load(unlocated_seq)[0].spent_as_fee);
+}
+
 #[wasm_bindgen_test]
 fn test_index_content_type_index() {
     state::clear();
@@ -277,9 +405,7 @@ fn test_index_content_type_index() {
     index_ord_block(&block, 100).unwrap();
 
     // Verify the content type index was populated
-    let seq_list = CONTENT_TYPE_TO_INSCRIPTIONS
-        .select(&"text/plain".as_bytes().to_vec())
-        .get_list();
+    let seq_list = CONTENT_TYPE_TO_INSCRIPTIONS.get_list("text/plain");
     assert!(
         !seq_list.is_empty(),
         "Content type index should have at least one entry for text/plain"
@@ -295,8 +421,7 @@ fn test_index_txid_to_inscriptions() {
     block.txdata.push(tx);
     index_ord_block(&block, 100).unwrap();
 
-    let txid_bytes = txid.as_byte_array().to_vec();
-    let seq_list = TXID_TO_INSCRIPTIONS.select(&txid_bytes).get_list();
+    let seq_list = TXID_TO_INSCRIPTIONS.get_list(&txid);
     assert!(
         !seq_list.is_empty(),
         "TXID_TO_INSCRIPTIONS should map txid to inscription sequence"
@@ -311,23 +436,14 @@ fn test_index_block_hash_stored() {
     index_ord_block(&block, 500).unwrap();
 
     // HEIGHT_TO_BLOCK_HASH
-    let hash_bytes = HEIGHT_TO_BLOCK_HASH
-        .select(&500u32.to_le_bytes().to_vec())
-        .get();
-    assert_eq!(hash_bytes.len(), 32, "Block hash should be 32 bytes");
     assert_eq!(
-        &*hash_bytes,
-        expected_hash.as_byte_array(),
+        HEIGHT_TO_BLOCK_HASH.get(&500),
+        Some(expected_hash),
         "Stored hash should match block hash"
     );
 
     // BLOCK_HASH_TO_HEIGHT
-    let height_bytes = BLOCK_HASH_TO_HEIGHT
-        .select(&expected_hash.as_byte_array().to_vec())
-        .get();
-    assert!(!height_bytes.is_empty());
-    let stored_height = u32::from_le_bytes(height_bytes[..4].try_into().unwrap());
-    assert_eq!(stored_height, 500, "Stored height should be 500");
+    assert_eq!(BLOCK_HASH_TO_HEIGHT.get(&expected_hash), Some(500), "Stored height should be 500");
 }
 
 #[wasm_bindgen_test]
@@ -364,8 +480,8 @@ fn test_index_charm_cursed() {
     index_ord_block(&block, 100).unwrap();
 
     let id = InscriptionId::new(block.txdata[0].txid(), 0);
-    let seq = INSCRIPTION_ID_TO_SEQUENCE.select(&id.to_bytes()).get();
-    let entry = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq).get()).unwrap();
+    let seq = INSCRIPTION_ID_TO_SEQUENCE.get(&id).unwrap();
+    let entry = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq).unwrap()).unwrap();
     assert!(
         entry.has_charm(Charm::Cursed),
         "Coinbase inscription before jubilee should have Cursed charm"
@@ -409,9 +525,8 @@ fn test_index_charm_unbound() {
     index_ord_block(&block, 100).unwrap();
 
     let id = InscriptionId::new(tx.txid(), 0);
-    let seq = INSCRIPTION_ID_TO_SEQUENCE.select(&id.to_bytes()).get();
-    if !seq.is_empty() {
-        let entry = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq).get()).unwrap();
+    if let Some(seq) = INSCRIPTION_ID_TO_SEQUENCE.get(&id) {
+        let entry = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq).unwrap()).unwrap();
         assert!(
             entry.has_charm(Charm::Unbound),
             "Inscription without body should have Unbound charm"
@@ -427,23 +542,11 @@ fn test_index_outpoint_to_inscriptions() {
     block.txdata.push(tx.clone());
     index_ord_block(&block, 100).unwrap();
 
-    // Compute outpoint bytes (txid + vout)
     let id = InscriptionId::new(tx.txid(), 0);
-    let seq = INSCRIPTION_ID_TO_SEQUENCE.select(&id.to_bytes()).get();
-    let entry = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq).get()).unwrap();
-
-    let outpoint_bytes: Vec<u8> = entry
-        .satpoint
-        .outpoint
-        .txid
-        .as_byte_array()
-        .iter()
-        .chain(entry.satpoint.outpoint.vout.to_le_bytes().iter())
-        .copied()
-        .collect();
-    let inscriptions_at_outpoint = OUTPOINT_TO_INSCRIPTIONS
-        .select(&outpoint_bytes)
-        .get_list();
+    let seq = INSCRIPTION_ID_TO_SEQUENCE.get(&id).unwrap();
+    let entry = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq).unwrap()).unwrap();
+
+    let inscriptions_at_outpoint = OUTPOINT_TO_INSCRIPTIONS.get_list(&entry.satpoint.outpoint);
     assert!(
         !inscriptions_at_outpoint.is_empty(),
         "OUTPOINT_TO_INSCRIPTIONS should contain the inscription"
@@ -464,9 +567,62 @@ fn test_index_duplicate_inscription_rejected() {
     indexer.load_state().unwrap();
     let result = indexer.index_block(&block, 100);
     assert!(
-        result.is_err(),
+        matches!(result, Err(OrdError::DuplicateInscription(ref id)) if *id == InscriptionId::new(tx.compute_txid(), 0)),
         "Indexing a block with duplicate inscription_id should fail"
     );
+
+    // The first copy keeps its sequence: the next block does not reuse it
+    let next = create_inscription_transaction(b"next", "text/plain", None);
+    index_ord_block(&create_block_with_txs(vec![create_coinbase_transaction(101), next.clone()]), 101).unwrap();
+    assert_eq!(genesis(&tx).0, 1);
+    assert_eq!(genesis(&next).0, 2);
+}
+
+#[wasm_bindgen_test]
+fn test_index_or_report_records_failure() {
+    state::clear();
+    let tx = create_inscription_transaction(b"unique", "text/plain", None);
+    let mut block = create_block_with_coinbase_tx(100);
+    block.txdata.push(tx.clone());
+    block.txdata.push(tx);
+
+    assert!(!InscriptionIndexer::index_or_report(&block, 100));
+
+    let failures = diagnostics::failures(100);
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].indexer, "ord");
+    assert_eq!(failures[0].code, u32::from(ErrorCode::Internal));
+    assert!(diagnostics::failures(101).is_empty());
+}
+
+#[wasm_bindgen_test]
+fn test_index_block_records_diagnostics() {
+    state::clear();
+    let reveal = create_inscription_transaction(b"counted", "text/plain", None);
+    let reveal_txid = reveal.txid();
+    let mut block = create_block_with_coinbase_tx(840000);
+    block.txdata.push(reveal);
+    block.txdata.push(create_inscription_transaction(b"counted too", "text/plain", None));
+    index_ord_block(&block, 840000).unwrap();
+
+    let diagnostics = diagnostics::block(840000);
+    assert_eq!(diagnostics.protocols, vec!["ord".to_string()]);
+    assert_eq!(diagnostics.envelopes, 2);
+    assert_eq!(diagnostics.inscriptions_created, 2);
+    assert_eq!(diagnostics.inscriptions_transferred, 0);
+
+    let mut block = create_block_with_coinbase_tx(840001);
+    block.txdata.push(create_transfer_transaction(&reveal_txid, 0));
+    index_ord_block(&block, 840001).unwrap();
+
+    let diagnostics = diagnostics::block(840001);
+    assert_eq!(diagnostics.inscriptions_created, 0);
+    assert_eq!(diagnostics.inscriptions_transferred, 1);
+
+    let response = crate::view::get_block_diagnostics(&crate::proto::GetBlockDiagnosticsRequest { height: 840001 }).unwrap();
+    assert_eq!(response.protocols, vec!["ord".to_string()]);
+    assert_eq!(response.inscriptions_transferred, 1);
+    assert!(response.failures.is_empty());
 }
 
 #[wasm_bindgen_test]
@@ -478,15 +634,13 @@ fn test_index_inscription_number_to_sequence() {
     index_ord_block(&block, 100).unwrap();
 
     let id = InscriptionId::new(tx.txid(), 0);
-    let seq = INSCRIPTION_ID_TO_SEQUENCE.select(&id.to_bytes()).get();
-    let entry = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq).get()).unwrap();
+    let seq = INSCRIPTION_ID_TO_SEQUENCE.get(&id).unwrap();
+    let entry = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq).unwrap()).unwrap();
 
     // Verify INSCRIPTION_NUMBER_TO_SEQUENCE maps the number to the correct sequence
-    let number_to_seq = INSCRIPTION_NUMBER_TO_SEQUENCE
-        .select(&entry.number.to_le_bytes().to_vec())
-        .get();
     assert_eq!(
-        &*number_to_seq, &*seq,
+        INSCRIPTION_NUMBER_TO_SEQUENCE.get(&entry.number),
+        Some(seq),
         "Number-to-sequence mapping should match"
     );
 }
@@ -501,11 +655,10 @@ fn test_index_inscription_to_txid() {
     index_ord_block(&block, 100).unwrap();
 
     let id = InscriptionId::new(txid, 0);
-    let seq = INSCRIPTION_ID_TO_SEQUENCE.select(&id.to_bytes()).get();
-    let stored_txid_bytes = INSCRIPTION_TO_TXID.select(&seq).get();
+    let seq = INSCRIPTION_ID_TO_SEQUENCE.get(&id).unwrap();
     assert_eq!(
-        &*stored_txid_bytes,
-        txid.as_byte_array(),
+        INSCRIPTION_TO_TXID.get(&seq),
+        Some(txid),
         "INSCRIPTION_TO_TXID should store the correct txid"
     );
 }
@@ -519,11 +672,217 @@ fn test_index_inscription_entry_content_type() {
     index_ord_block(&block, 100).unwrap();
 
     let id = InscriptionId::new(tx.txid(), 0);
-    let seq = INSCRIPTION_ID_TO_SEQUENCE.select(&id.to_bytes()).get();
-    let entry = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq).get()).unwrap();
+    let seq = INSCRIPTION_ID_TO_SEQUENCE.get(&id).unwrap();
+    let entry = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq).unwrap()).unwrap();
     assert_eq!(
         entry.content_type.as_deref(),
         Some("application/json"),
         "Entry content_type should match"
     );
 }
+
+#[wasm_bindgen_test]
+fn test_tx_retention_all_stores_every_transaction() {
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+    indexer.tx_retention = crate::indexer::TxRetention::All;
+
+    let plain = create_transfer_transaction(&create_mock_outpoint(9).txid, 0);
+    let plain_txid = plain.txid();
+    let mut block = create_block_with_coinbase_tx(840000);
+    block.txdata.push(plain);
+    indexer.index_block(&block, 840000).unwrap();
+
+    assert!(TXID_TO_RAW_TX.contains(&plain_txid));
+    assert!(!TXID_TO_COMPACT_TX.contains(&plain_txid));
+    assert_eq!(TXID_TO_BLOCK_HEIGHT.get(&plain_txid), Some(840000));
+}
+
+#[wasm_bindgen_test]
+fn test_tx_retention_relevant_skips_unrelated_transactions() {
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+    indexer.tx_retention = crate::indexer::TxRetention::Relevant;
+
+    let reveal = create_inscription_transaction(b"kept", "text/plain", None);
+    let reveal_txid = reveal.txid();
+    let plain = create_transfer_transaction(&create_mock_outpoint(9).txid, 0);
+    let plain_txid = plain.txid();
+    let mut block = create_block_with_coinbase_tx(840000);
+    let coinbase_txid = block.txdata[0].txid();
+    block.txdata.push(reveal);
+    block.txdata.push(plain);
+    indexer.index_block(&block, 840000).unwrap();
+
+    assert!(TXID_TO_RAW_TX.contains(&reveal_txid));
+    assert!(!TXID_TO_RAW_TX.contains(&plain_txid));
+    assert!(!TXID_TO_RAW_TX.contains(&coinbase_txid));
+    assert!(!TXID_TO_BLOCK_HEIGHT.contains(&plain_txid));
+
+    // Spending the inscribed output is relevant even without an envelope
+    let transfer = create_transfer_transaction(&reveal_txid, 0);
+    let transfer_txid = transfer.txid();
+    let mut block = create_block_with_coinbase_tx(840001);
+    block.txdata.push(transfer);
+    indexer.index_block(&block, 840001).unwrap();
+
+    assert!(TXID_TO_RAW_TX.contains(&transfer_txid));
+}
+
+#[wasm_bindgen_test]
+fn test_tx_retention_relevant_follows_transfers_and_activations() {
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+    indexer.tx_retention = crate::indexer::TxRetention::Relevant;
+
+    let payment = reveal(&[create_mock_outpoint(1)], bitcoin::Witness::new(), &[30_000]);
+    let inscription = create_inscription_transaction(b"moved", "text/plain", Some(create_mock_outpoint(2)));
+    indexer.index_block(&create_block_with_txs(vec![create_coinbase_transaction(840000), payment.clone(), inscription.clone()]), 840000).unwrap();
+    assert!(!TXID_TO_RAW_TX.contains(&payment.compute_txid()));
+    let (seq, _) = genesis(&inscription);
+
+    // The dropped payment sits ahead of the inscribed input, so the
+    // inscription moves 30,000 sats in, 10,000 into the second output
+    let payment_output = bitcoin::OutPoint::new(payment.compute_txid(), 0);
+    let transfer = reveal(&[payment_output, bitcoin::OutPoint::new(inscription.compute_txid(), 0)], bitcoin::Witness::new(), &[20_000, 100_000_000]);
+    // An activation is kept even when it spends no inscription
+    let activation = create_activation_transaction(&create_mock_outpoint(5).txid, 0, vec![]);
+    indexer.index_block(&create_block_with_txs(vec![create_coinbase_transaction(840001), transfer.clone(), activation.clone()]), 840001).unwrap();
+
+    let satpoint = SEQUENCE_TO_SATPOINT.get(&seq).unwrap();
+    assert_eq!((satpoint.outpoint, satpoint.offset), (bitcoin::OutPoint::new(transfer.compute_txid(), 1), 10_000));
+    assert!(TXID_TO_RAW_TX.contains(&transfer.compute_txid()));
+    assert!(TXID_TO_RAW_TX.contains(&activation.compute_txid()));
+    assert_eq!(OUTPOINT_TO_SCRIPT.get(&payment_output), Some(payment.output[0].script_pubkey.to_bytes()));
+}
+
+#[wasm_bindgen_test]
+fn test_tx_retention_compact_strips_witness() {
+    use bitcoin::consensus::deserialize;
+
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+    indexer.tx_retention = crate::indexer::TxRetention::Compact;
+
+    let reveal = create_inscription_transaction(b"compact", "text/plain", None);
+    let txid = reveal.txid();
+    let mut block = create_block_with_coinbase_tx(840000);
+    block.txdata.push(reveal.clone());
+    let result = indexer.index_block(&block, 840000).unwrap();
+    assert_eq!(result.inscriptions.len(), 1, "Retention mode must not affect indexing");
+
+    assert!(!TXID_TO_RAW_TX.contains(&txid));
+    let stored: bitcoin::Transaction = deserialize(&TXID_TO_COMPACT_TX.get(&txid).unwrap()).unwrap();
+    assert!(stored.input.iter().all(|input| input.witness.is_empty()));
+    assert_eq!(stored.input.len(), reveal.input.len());
+    assert_eq!(stored.input[0].previous_output, reveal.input[0].previous_output);
+    assert_eq!(stored.output, reveal.output);
+    assert_eq!(stored.txid(), reveal.txid());
+}
+
+#[wasm_bindgen_test]
+fn test_entry_schema_migration() {
+    use crate::entries;
+
+    state::clear();
+    let tx = create_inscription_transaction(b"pre-upgrade", "text/plain", None);
+    let mut block = create_block_with_coinbase_tx(840000);
+    block.txdata.push(tx.clone());
+    index_ord_block(&block, 840000).unwrap();
+    assert_eq!(entries::schema_version(), shrew_support::inscription::ENTRY_SCHEMA_VERSION);
+
+    // Rewind to a pre-versioning index: sequence 1 in the original bincode
+    // layout, sequence 2 unreadable, and no schema marker
+    let entry = entries::load(1).unwrap().unwrap();
+    let legacy = bincode::serialize(&(
+        (&entry.id, entry.number, entry.sequence, entry.sat, &entry.satpoint, entry.height, entry.fee, &entry.content_type),
+        (entry.content_length, entry.timestamp, entry.genesis_fee, entry.genesis_height, &entry.parent, &entry.delegate, &entry.metaprotocol, entry.pointer, entry.charms),
+    )).unwrap();
+    SEQUENCE_TO_INSCRIPTION_ENTRY.set(&1, &legacy);
+    SEQUENCE_TO_INSCRIPTION_ENTRY.set(&2, &vec![0xde, 0xad]);
+    GLOBAL_SEQUENCE_COUNTER.set(&2);
+    INSCRIPTION_ENTRY_SCHEMA.delete();
+    assert_eq!(entries::schema_version(), 0);
+
+    index_ord_block(&create_block_with_coinbase_tx(840001), 840001).unwrap();
+
+    assert_eq!(entries::schema_version(), shrew_support::inscription::ENTRY_SCHEMA_VERSION);
+    let migrated = entries::load(1).unwrap().unwrap();
+    assert_eq!(migrated.id, InscriptionId::new(tx.txid(), 0));
+    assert_eq!(migrated.content_type, entry.content_type);
+    assert_eq!(migrated.number, entry.number);
+
+    let failures = entries::failures(840001);
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].sequence, 2);
+    assert!(entries::load(2).is_err());
+
+    // Later readers skip the broken entry without reporting it again
+    assert!(entries::load_or_report(2, 840002).is_none());
+    assert!(entries::failures(840002).is_empty());
+}
+
+#[wasm_bindgen_test]
+fn test_layout_migration_moves_legacy_bodies() {
+    use crate::layout;
+
+    state::clear();
+    let tx = create_inscription_transaction(b"pre-upgrade body", "text/plain", None);
+    let mut block = create_block_with_coinbase_tx(840000);
+    block.txdata.push(tx.clone());
+    index_ord_block(&block, 840000).unwrap();
+    assert_eq!(layout::layout_version(), layout::LAYOUT_VERSION);
+
+    // Rewind to the original layout: the body stored whole under the id string
+    let id = InscriptionId::new(tx.txid(), 0);
+    let hash = INSCRIPTION_TO_CONTENT_HASH.get(&id).unwrap();
+    INSCRIPTION_TO_CONTENT_HASH.delete(&id);
+    CONTENT_HASH_TO_SIZE.delete(&hash);
+    LEGACY_INSCRIPTION_CONTENT.set(&format!("{}i0", tx.txid()), &b"pre-upgrade body".to_vec());
+    STORE_LAYOUT.delete();
+
+    // Readers fall back to the legacy body until the migration runs
+    let content = InscriptionContentTable::new();
+    assert_eq!(content.get_range(&id, 4, Some(7)), Some((b"upgrade".to_vec(), 16)));
+    assert_eq!(content.hash(&id), Some(hash));
+
+    index_ord_block(&create_block_with_coinbase_tx(840001), 840001).unwrap();
+
+    assert_eq!(layout::layout_version(), layout::LAYOUT_VERSION);
+    assert_eq!(INSCRIPTION_TO_CONTENT_HASH.get(&id), Some(hash));
+    assert!(LEGACY_INSCRIPTION_CONTENT.get(&id.to_string()).is_none());
+    assert_eq!(content.get(&id), Some(b"pre-upgrade body".to_vec()));
+}
+
+#[test]
+fn test_layout_migration_moves_legacy_metadata_and_height_lists() {
+    use crate::layout;
+
+    state::clear();
+    let tx = create_inscription_transaction(b"listed", "text/plain", None);
+    let mut block = create_block_with_coinbase_tx(840000);
+    block.txdata.push(tx.clone());
+    index_ord_block(&block, 840000).unwrap();
+
+    // Rewind to the original layout: metadata under the id string, and the
+    // inscription listed under `{height}:{index}` for the height it was
+    // indexed at
+    let id = InscriptionId::new(tx.txid(), 0);
+    let sequence = INSCRIPTION_ID_TO_SEQUENCE.get(&id).unwrap();
+    let mut entry = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&sequence).unwrap()).unwrap();
+    entry.height = 839999;
+    SEQUENCE_TO_INSCRIPTION_ENTRY.set(&sequence, &entry.to_bytes());
+    LEGACY_INSCRIPTION_METADATA.set(&id.to_string(), &vec![0xa0]);
+    LEGACY_HEIGHT_TO_INSCRIPTIONS.set("839999:0", &id);
+    STORE_LAYOUT.delete();
+
+    // The metadata view falls back to the legacy key until the migration runs
+    assert_eq!(layout::metadata(&id), Some(vec![0xa0]));
+
+    index_ord_block(&create_block_with_coinbase_tx(840001), 840001).unwrap();
+
+    assert_eq!(INSCRIPTION_METADATA.get(&id), Some(vec![0xa0]));
+    assert!(LEGACY_INSCRIPTION_METADATA.get(&id.to_string()).is_none());
+    assert_eq!(HEIGHT_TO_INSCRIPTIONS.get_list(&839999), vec![id]);
+    assert!(LEGACY_HEIGHT_TO_INSCRIPTIONS.get("839999:0").is_none());
+}
@@ -0,0 +1,311 @@
+//! Indexing-level checks ported from the retired root-crate
+//! `inscription_indexing_tests` suite. These assert directly against the
+//! tables written by `InscriptionIndexer`.
+
+use wasm_bindgen_test::wasm_bindgen_test as test;
+use crate::indexer::InscriptionIndexer;
+use crate::error::OrdError;
+use crate::proto::{
+    get_inscription_request, GetContentRequest, GetInscriptionRequest, GetMetadataRequest, GetSatInscriptionRequest,
+    GetSatInscriptionsRequest, GetSatRequest, InscriptionId as ProtoInscriptionId,
+};
+use crate::tables::*;
+use crate::view;
+use bitcoin::Transaction;
+use bitcoin_hashes::Hash;
+use shrew_support::error::{CodedError, ErrorCode};
+use shrew_support::inscription::{InscriptionEntry, InscriptionId};
+use shrew_test_helpers::blocks::*;
+use shrew_test_helpers::inscriptions::*;
+use shrew_test_helpers::state;
+use shrew_test_helpers::transactions::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn load_entry(txid: &bitcoin::Txid) -> InscriptionEntry {
+    let id = InscriptionId::new(*txid, 0);
+    let seq = INSCRIPTION_ID_TO_SEQUENCE.get(&id).unwrap_or_else(|| panic!("Inscription {} not indexed", id));
+    InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq).unwrap()).unwrap()
+}
+
+fn proto_id(txid: &bitcoin::Txid) -> ProtoInscriptionId {
+    ProtoInscriptionId { txid: txid.as_byte_array().to_vec(), index: 0 }
+}
+
+fn block_with(height: u32, txs: Vec<Transaction>) -> bitcoin::Block {
+    let mut all = vec![create_coinbase_transaction(height)];
+    all.extend(txs);
+    create_block_with_txs(all)
+}
+
+#[wasm_bindgen_test]
+fn test_numbering_continues_across_blocks() {
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+
+    let mut txids = Vec::new();
+    for height in 840000..840003u32 {
+        let content = format!("block {}", height);
+        let tx = create_inscription_transaction(content.as_bytes(), "text/plain", None);
+        txids.push(tx.txid());
+        indexer.index_block(&block_with(height, vec![tx]), height).unwrap();
+    }
+
+    for (i, txid) in txids.iter().enumerate() {
+        let entry = load_entry(txid);
+        assert_eq!(entry.number, i as i32 + 1);
+        assert_eq!(entry.sequence, i as u32 + 1);
+        assert_eq!(entry.height, 840000 + i as u32);
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_content_type_and_length_recorded() {
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+
+    let cases: Vec<(&[u8], &str)> = vec![
+        (b"plain text", "text/plain;charset=utf-8"),
+        (br#"{"p":"test"}"#, "application/json"),
+        (&[0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a], "image/png"),
+    ];
+    let txs: Vec<Transaction> = cases.iter()
+        .map(|(body, ct)| create_inscription_transaction(body, ct, None))
+        .collect();
+    indexer.index_block(&block_with(840000, txs.clone()), 840000).unwrap();
+
+    for (tx, (body, ct)) in txs.iter().zip(cases.iter()) {
+        let entry = load_entry(&tx.txid());
+        assert_eq!(entry.content_type.as_deref(), Some(*ct));
+        assert_eq!(entry.content_length, Some(body.len() as u64));
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_large_content_is_stored_intact() {
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+
+    let body: Vec<u8> = (0..100_000u32).map(|i| (i % 251) as u8).collect();
+    let tx = create_inscription_transaction(&body, "application/octet-stream", None);
+    let txid = tx.txid();
+    indexer.index_block(&block_with(840000, vec![tx]), 840000).unwrap();
+
+    let entry = load_entry(&txid);
+    assert_eq!(entry.content_length, Some(body.len() as u64));
+    let stored = InscriptionContentTable::new().get(&InscriptionId::new(txid, 0)).unwrap();
+    assert_eq!(stored, body);
+}
+
+#[wasm_bindgen_test]
+fn test_metadata_is_indexed() {
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+
+    let metadata = b"\xa1\x64name\x64test";
+    let witness = create_inscription_envelope_with_metadata(b"text/plain", b"with metadata", Some(metadata));
+    let tx = create_reveal_transaction(&create_mock_outpoint(7).txid, witness);
+    let txid = tx.txid();
+    indexer.index_block(&block_with(840000, vec![tx]), 840000).unwrap();
+
+    let response = view::get_metadata(&GetMetadataRequest {
+        id: Some(ProtoInscriptionId { txid: txid.as_byte_array().to_vec(), index: 0 }),
+    }).unwrap();
+    assert_eq!(response.metadata_hex, hex::encode(metadata));
+}
+
+#[wasm_bindgen_test]
+fn test_genesis_location_and_lookup_tables() {
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+
+    let tx = create_inscription_transaction(b"location", "text/plain", None);
+    let txid = tx.txid();
+    indexer.index_block(&block_with(840000, vec![tx]), 840000).unwrap();
+
+    let entry = load_entry(&txid);
+    assert_eq!(entry.satpoint.outpoint.txid, txid);
+    assert_eq!(entry.satpoint.outpoint.vout, 0);
+    assert_eq!(entry.satpoint.offset, 0);
+
+    assert_eq!(HEIGHT_TO_INSCRIPTIONS.get_list(&840000), vec![InscriptionId::new(txid, 0)]);
+    assert_eq!(TXID_TO_INSCRIPTIONS.length(&txid), 1);
+    assert!(TXID_TO_RAW_TX.contains(&txid));
+    assert_eq!(TXID_TO_BLOCK_HEIGHT.get(&txid), Some(840000));
+}
+
+#[wasm_bindgen_test]
+fn test_empty_body_inscription() {
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+
+    let tx = create_inscription_transaction(b"", "text/plain", None);
+    let txid = tx.txid();
+    indexer.index_block(&block_with(840000, vec![tx]), 840000).unwrap();
+
+    let entry = load_entry(&txid);
+    assert_eq!(entry.content_length, Some(0));
+    assert!(!entry.is_cursed());
+}
+
+#[wasm_bindgen_test]
+fn test_transactions_without_envelopes_are_ignored() {
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+
+    let plain = create_transfer_transaction(&create_mock_outpoint(3).txid, 0);
+    let result = indexer.index_block(&block_with(840000, vec![plain]), 840000).unwrap();
+    assert!(result.inscriptions.is_empty());
+    assert_eq!(result.transactions_processed, 2);
+    assert_eq!(GLOBAL_SEQUENCE_COUNTER.get().unwrap_or(0), 0);
+}
+
+#[wasm_bindgen_test]
+fn test_rune_tag_is_decoded() {
+    use crate::ord_inscriptions::Inscription as OrdInscription;
+
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+
+    let rune: ordinals::Rune = "UNCOMMONGOODS".parse().unwrap();
+    let mut tag = rune.0.to_le_bytes().to_vec();
+    while tag.last() == Some(&0) {
+        tag.pop();
+    }
+    let witness = OrdInscription {
+        body: Some(b"etched".to_vec()),
+        rune: Some(tag),
+        ..Default::default()
+    }.to_witness();
+    let tagged = create_reveal_transaction(&create_mock_outpoint(2).txid, witness);
+    let plain = create_inscription_transaction(b"no rune", "text/plain", None);
+    indexer.index_block(&block_with(840000, vec![tagged.clone(), plain.clone()]), 840000).unwrap();
+
+    assert_eq!(load_entry(&tagged.txid()).rune, Some(rune));
+    assert_eq!(load_entry(&plain.txid()).rune, None);
+
+    let response = view::get_inscription(&GetInscriptionRequest {
+        query: Some(get_inscription_request::Query::Id(ProtoInscriptionId {
+            txid: tagged.txid().as_byte_array().to_vec(),
+            index: 0,
+        })),
+        child_index: None,
+    }).unwrap();
+    let spaced = response.rune.unwrap();
+    assert_eq!(spaced.name, "UNCOMMONGOODS");
+    assert_eq!((spaced.rune, spaced.value), (rune.0 as u64, rune.0.to_string()));
+}
+
+#[wasm_bindgen_test]
+fn test_rune_values_beyond_64_bits_are_served_whole() {
+    use crate::ord_inscriptions::Inscription as OrdInscription;
+
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+
+    let rune = ordinals::Rune(u128::from(u64::MAX) + 1);
+    let witness = OrdInscription {
+        body: Some(b"etched".to_vec()),
+        rune: Some(rune.0.to_le_bytes()[..9].to_vec()),
+        ..Default::default()
+    }.to_witness();
+    let tagged = create_reveal_transaction(&create_mock_outpoint(2).txid, witness);
+    indexer.index_block(&block_with(840000, vec![tagged.clone()]), 840000).unwrap();
+
+    let response = view::get_inscription(&GetInscriptionRequest {
+        query: Some(get_inscription_request::Query::Id(ProtoInscriptionId {
+            txid: tagged.txid().as_byte_array().to_vec(),
+            index: 0,
+        })),
+        child_index: None,
+    }).unwrap();
+    let spaced = response.rune.unwrap();
+    assert_eq!(spaced.rune, 0);
+    assert_eq!(spaced.value, "18446744073709551616");
+    assert_eq!(spaced.name, rune.to_string());
+}
+
+#[wasm_bindgen_test]
+fn test_multiple_inscriptions_per_block_are_served_in_block_order() {
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+
+    let inscriptions: Vec<(&[u8], &str)> = vec![
+        (b"Plain text inscription", "text/plain"),
+        (br#"{"name": "Test NFT", "description": "A test"}"#, "application/json"),
+        (b"<html><body>HTML content</body></html>", "text/html"),
+        (b"\x89PNG\r\n\x1a\n", "image/png"),
+        (b"Binary data content", "application/octet-stream"),
+    ];
+    let block = create_inscription_block(inscriptions.clone());
+    let result = indexer.index_block(&block, 840000).unwrap();
+    assert_eq!(result.inscriptions.len(), inscriptions.len());
+
+    let txids: Vec<_> = block.txdata[1..].iter().map(|tx| tx.txid()).collect();
+    let ids: Vec<_> = txids.iter().map(|txid| InscriptionId::new(*txid, 0)).collect();
+    assert_eq!(HEIGHT_TO_INSCRIPTIONS.get_list(&840000), ids);
+
+    let first_number = load_entry(&txids[0]).number;
+    for (i, (txid, (content, content_type))) in txids.iter().zip(inscriptions.iter()).enumerate() {
+        let response = view::get_inscription(&GetInscriptionRequest {
+            query: Some(get_inscription_request::Query::Id(proto_id(txid))),
+            child_index: None,
+        }).unwrap();
+        assert_eq!(response.number, first_number + i as i32);
+        assert_eq!(response.content_type.as_deref(), Some(*content_type));
+
+        let content_response = view::get_content(&GetContentRequest { id: Some(proto_id(txid)), ..Default::default() }).unwrap();
+        assert_eq!(content_response.content, *content);
+        assert_eq!(content_response.content_type.as_deref(), Some(*content_type));
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_location_follows_each_transfer() {
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+
+    let tx = create_inscription_transaction(b"Transferable inscription", "text/plain", None);
+    let txid = tx.txid();
+    indexer.index_block(&block_with(840000, vec![tx]), 840000).unwrap();
+
+    let location = || view::get_inscription(&GetInscriptionRequest {
+        query: Some(get_inscription_request::Query::Id(proto_id(&txid))),
+        child_index: None,
+    }).unwrap().satpoint.unwrap().outpoint.unwrap();
+    assert_eq!(location().txid, txid.as_byte_array().to_vec());
+
+    let mut holder = txid;
+    for height in 840001..840003u32 {
+        let transfer = create_transfer_transaction(&holder, 0);
+        indexer.index_block(&block_with(height, vec![transfer.clone()]), height).unwrap();
+        holder = transfer.txid();
+
+        let outpoint = location();
+        assert_eq!((outpoint.txid, outpoint.vout), (holder.as_byte_array().to_vec(), 0));
+        assert_eq!(OUTPOINT_TO_INSCRIPTIONS.length(&bitcoin::OutPoint::new(holder, 0)), 1);
+    }
+
+    // The entry keeps the genesis location
+    assert_eq!(load_entry(&txid).satpoint.outpoint.txid, txid);
+}
+
+#[wasm_bindgen_test]
+fn test_sat_numbers_are_not_tracked() {
+    // Sat ranges are not indexed, so no inscription is mapped to a sat and
+    // the sat views say so instead of answering empty
+    state::clear();
+    let mut indexer = InscriptionIndexer::new();
+
+    let tx = create_inscription_transaction(b"Sat-specific inscription", "text/plain", None);
+    indexer.index_block(&block_with(840000, vec![tx.clone()]), 840000).unwrap();
+
+    let entry = load_entry(&tx.txid());
+    assert_eq!(entry.sat, None);
+    assert!(INSCRIPTION_TO_SAT.get(&entry.sequence).is_none());
+
+    let sat = view::get_sat_inscription(&GetSatInscriptionRequest { sat: 5_000_000_000, index: -1 }).unwrap_err();
+    assert_eq!(sat, OrdError::NotImplemented("sat inscription"));
+    let sats = view::get_sat_inscriptions(&GetSatInscriptionsRequest { sat: 5_000_000_000, pagination: None }).unwrap_err();
+    assert_eq!(sats.code(), ErrorCode::NotImplemented);
+    assert_eq!(view::get_sat(&GetSatRequest { sat: 5_000_000_000 }).unwrap().number, 5_000_000_000);
+}
@@ -1,5 +1,49 @@
+//! Inscription indexer tests.
+//!
+//! The retired root crate's suites were ported here, to `shrew_support`'s
+//! inscription unit tests and to `shrew-brc20`. Root tests without a port
+//! below were dropped as redundant, for the reason given.
+//!
+//! - `indexer_tests`: ported to `indexer_tests` (`test_index_*`). Dropped
+//!   `test_indexer_initialization`, `test_concurrent_indexing_simulation` and
+//!   `test_block_height_tracking`, which only checked the in-memory counter
+//!   now covered by `test_index_state_persistence`; `test_indexer_error_handling`,
+//!   covered by `envelope_tests::test_parse_empty_witness`; and
+//!   `test_sat_to_inscription_mapping`, which asserted only that the
+//!   inscription was indexed, see `test_sat_numbers_are_not_tracked`.
+//! - `inscription_indexing_tests`: ported to `inscription_indexing_tests`, with
+//!   `test_multiple_inscriptions_per_block_are_served_in_block_order`,
+//!   `test_location_follows_each_transfer` and `test_sat_numbers_are_not_tracked`
+//!   for the per-block, location and sat mapping cases. Parent, delegation,
+//!   cursed and view coverage cases live in `e2e_tests`; edge cases in
+//!   `test_empty_body_inscription` and `envelope_differential_tests`.
+//! - `comprehensive_e2e_tests`: ported to `e2e_tests`. Dropped
+//!   `test_single_inscription_debug`, `test_performance_stress`,
+//!   `test_comprehensive_system_integration` and `test_complete_system_validation`,
+//!   which repeated `test_e2e_lifecycle_across_content_types` and
+//!   `test_e2e_many_inscriptions_paginate` at other sizes.
+//! - `view_tests`: ported to `view_tests`, with `test_view_round_trips_across_content_types`
+//!   for the id, number and content round trips. Dropped the sat view tests,
+//!   which accepted any answer (see `test_sat_numbers_are_not_tracked`),
+//!   `test_view_function_performance` and `test_view_functions_with_large_dataset`
+//!   (see `test_e2e_many_inscriptions_paginate`), and `test_protobuf_serialization`
+//!   (see `test_view_protobuf_and_json_encodings_agree`).
+//! - `integration_tests`: ported to `lifecycle_tests`. Dropped
+//!   `test_performance_large_block`, covered by `test_batch_inscriptions`, and
+//!   `test_duplicate_inscription_handling`, covered by
+//!   `test_index_duplicate_inscription_rejected`.
+//! - `envelope_tests` and `ordinals_fundamental_test`: ported to `envelope_tests`,
+//!   and to `envelope_differential_tests`, which checks the parser against the
+//!   inscriptions the ordinals crate builds. Dropped `test_envelope_serialization`,
+//!   which only re-parsed a text envelope as `test_parse_simple_text_inscription` does.
+//! - `inscription_tests`: ported to the unit tests of `shrew_support::inscription`.
+//! - `brc20_tests`: ported to `shrew-brc20`'s `parsing_tests` and `lifecycle_tests`.
+
 mod envelope_tests;
+mod envelope_differential_tests;
 mod indexer_tests;
 mod view_tests;
 mod lifecycle_tests;
 mod reinscription_tests;
+mod e2e_tests;
+mod inscription_indexing_tests;
@@ -11,7 +11,6 @@
 ///! which requires proper sat calculation from UTXO ranges.
 
 use crate::tables::*;
-use metashrew_support::index_pointer::KeyValuePointer;
 use shrew_support::inscription::{Charm, InscriptionEntry, InscriptionId};
 use shrew_test_helpers::blocks::*;
 use shrew_test_helpers::indexing::index_ord_block;
@@ -69,9 +68,8 @@ fn test_reinscription_on_same_sat_is_cursed_before_jubilee() {
 
     // Verify first inscription is blessed
     let id1 = InscriptionId::new(tx1.compute_txid(), 0);
-    let seq1 = INSCRIPTION_ID_TO_SEQUENCE.select(&id1.to_bytes()).get();
-    assert!(!seq1.is_empty(), "First inscription should be indexed");
-    let entry1 = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq1).get()).unwrap();
+    let seq1 = INSCRIPTION_ID_TO_SEQUENCE.get(&id1).expect("First inscription should be indexed");
+    let entry1 = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq1).unwrap()).unwrap();
     assert!(entry1.number > 0, "First inscription should be blessed");
 
     // Block 2: Second inscription spending first inscription's output (same sat)
@@ -87,9 +85,8 @@ fn test_reinscription_on_same_sat_is_cursed_before_jubilee() {
     index_ord_block(&block2, 200).unwrap();
 
     let id2 = InscriptionId::new(tx2.compute_txid(), 0);
-    let seq2 = INSCRIPTION_ID_TO_SEQUENCE.select(&id2.to_bytes()).get();
-    assert!(!seq2.is_empty(), "Second inscription should be indexed");
-    let entry2 = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq2).get()).unwrap();
+    let seq2 = INSCRIPTION_ID_TO_SEQUENCE.get(&id2).expect("Second inscription should be indexed");
+    let entry2 = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq2).unwrap()).unwrap();
 
     // The reinscription should be cursed (negative number) before jubilee
     assert!(
@@ -129,8 +126,8 @@ fn test_reinscription_has_reinscription_charm() {
     index_ord_block(&block2, 200).unwrap();
 
     let id2 = InscriptionId::new(tx2.compute_txid(), 0);
-    let seq2 = INSCRIPTION_ID_TO_SEQUENCE.select(&id2.to_bytes()).get();
-    let entry2 = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq2).get()).unwrap();
+    let seq2 = INSCRIPTION_ID_TO_SEQUENCE.get(&id2).unwrap();
+    let entry2 = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq2).unwrap()).unwrap();
 
     assert!(
         entry2.has_charm(Charm::Reinscription),
@@ -167,8 +164,8 @@ fn test_reinscription_after_jubilee_is_vindicated() {
     index_ord_block(&block2, jubilee + 1).unwrap();
 
     let id2 = InscriptionId::new(tx2.compute_txid(), 0);
-    let seq2 = INSCRIPTION_ID_TO_SEQUENCE.select(&id2.to_bytes()).get();
-    let entry2 = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq2).get()).unwrap();
+    let seq2 = INSCRIPTION_ID_TO_SEQUENCE.get(&id2).unwrap();
+    let entry2 = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq2).unwrap()).unwrap();
 
     // After jubilee, reinscriptions are blessed (vindicated)
     assert!(
@@ -202,8 +199,8 @@ fn test_first_inscription_on_sat_not_reinscription() {
     index_ord_block(&block, 100).unwrap();
 
     let id = InscriptionId::new(tx.compute_txid(), 0);
-    let seq = INSCRIPTION_ID_TO_SEQUENCE.select(&id.to_bytes()).get();
-    let entry = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq).get()).unwrap();
+    let seq = INSCRIPTION_ID_TO_SEQUENCE.get(&id).unwrap();
+    let entry = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq).unwrap()).unwrap();
 
     assert!(
         !entry.has_charm(Charm::Reinscription),
@@ -225,14 +222,12 @@ fn test_sat_to_inscriptions_populated() {
     index_ord_block(&block, 100).unwrap();
 
     let id = InscriptionId::new(tx.compute_txid(), 0);
-    let seq = INSCRIPTION_ID_TO_SEQUENCE.select(&id.to_bytes()).get();
-    let entry = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq).get()).unwrap();
+    let seq = INSCRIPTION_ID_TO_SEQUENCE.get(&id).unwrap();
+    let entry = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq).unwrap()).unwrap();
 
     // If the inscription has a sat number, SAT_TO_INSCRIPTIONS should have it
     if let Some(sat) = entry.sat {
-        let inscriptions_on_sat = SAT_TO_INSCRIPTIONS
-            .select(&sat.to_le_bytes().to_vec())
-            .get_list();
+        let inscriptions_on_sat = SAT_TO_INSCRIPTIONS.get_list(&sat);
         assert!(
             !inscriptions_on_sat.is_empty(),
             "SAT_TO_INSCRIPTIONS should be populated when inscription has a sat number"
@@ -278,12 +273,12 @@ fn test_multiple_reinscriptions_on_same_sat() {
 
     // Both second and third should be reinscriptions
     let id2 = InscriptionId::new(tx2.compute_txid(), 0);
-    let seq2 = INSCRIPTION_ID_TO_SEQUENCE.select(&id2.to_bytes()).get();
-    let entry2 = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq2).get()).unwrap();
+    let seq2 = INSCRIPTION_ID_TO_SEQUENCE.get(&id2).unwrap();
+    let entry2 = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq2).unwrap()).unwrap();
 
     let id3 = InscriptionId::new(tx3.compute_txid(), 0);
-    let seq3 = INSCRIPTION_ID_TO_SEQUENCE.select(&id3.to_bytes()).get();
-    let entry3 = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq3).get()).unwrap();
+    let seq3 = INSCRIPTION_ID_TO_SEQUENCE.get(&id3).unwrap();
+    let entry3 = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq3).unwrap()).unwrap();
 
     assert!(
         entry2.has_charm(Charm::Reinscription),
@@ -314,8 +309,8 @@ fn test_reinscription_cursed_numbering() {
     index_ord_block(&block1, 100).unwrap();
 
     let id1 = InscriptionId::new(tx1.compute_txid(), 0);
-    let seq1 = INSCRIPTION_ID_TO_SEQUENCE.select(&id1.to_bytes()).get();
-    let entry1 = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq1).get()).unwrap();
+    let seq1 = INSCRIPTION_ID_TO_SEQUENCE.get(&id1).unwrap();
+    let entry1 = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq1).unwrap()).unwrap();
     assert_eq!(entry1.number, 1, "First blessed inscription should be number 1");
 
     // Reinscription should be cursed with number -1
@@ -330,8 +325,8 @@ fn test_reinscription_cursed_numbering() {
     index_ord_block(&block2, 200).unwrap();
 
     let id2 = InscriptionId::new(tx2.compute_txid(), 0);
-    let seq2 = INSCRIPTION_ID_TO_SEQUENCE.select(&id2.to_bytes()).get();
-    let entry2 = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq2).get()).unwrap();
+    let seq2 = INSCRIPTION_ID_TO_SEQUENCE.get(&id2).unwrap();
+    let entry2 = InscriptionEntry::from_bytes(&SEQUENCE_TO_INSCRIPTION_ENTRY.get(&seq2).unwrap()).unwrap();
     assert_eq!(
         entry2.number, -1,
         "First cursed reinscription should be number -1, got {}",
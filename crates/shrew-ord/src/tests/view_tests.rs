@@ -3,8 +3,8 @@ use crate::proto::{
     get_block_info_request, get_inscription_request, GetBlockHashRequest, GetBlockInfoRequest,
     GetChildrenRequest, GetContentRequest, GetInscriptionRequest, GetInscriptionsRequest,
     GetMetadataRequest, GetParentsRequest, InscriptionId as ProtoInscriptionId,
-    PaginationRequest,
 };
+use shrew_support::pagination::PaginationRequest;
 use crate::view;
 use bitcoin_hashes::Hash;
 use shrew_support::inscription::InscriptionId;
@@ -102,6 +102,7 @@ fn test_view_get_inscriptions() {
         pagination: Some(PaginationRequest {
             page: 0,
             limit: 10,
+            ..Default::default()
         }),
         filter: None,
     };
@@ -132,6 +133,7 @@ fn test_view_get_content() {
             txid: txid.as_byte_array().to_vec(),
             index: 0,
         }),
+        ..Default::default()
     };
 
     let response = view::get_content(&request).unwrap();
@@ -385,3 +387,418 @@ fn test_view_get_parents() {
         assert_eq!(parent_resp.txid, parent_txid.as_byte_array().to_vec());
     }
 }
+
+#[wasm_bindgen_test]
+fn test_view_protobuf_and_json_encodings_agree() {
+    use crate::message::InscriptionMessageContext;
+    use crate::proto::InscriptionResponse;
+    use prost::Message;
+    use shrew_support::wire::{protobuf_request, WireFormat};
+
+    state::clear();
+    let (txid_bytes, _) = index_single_text_inscription(840000);
+    let request = GetInscriptionRequest {
+        query: Some(get_inscription_request::Query::Id(ProtoInscriptionId { txid: txid_bytes, index: 0 })),
+        child_index: None,
+    };
+
+    let (decoded, format) = InscriptionMessageContext::decode_request::<GetInscriptionRequest>(&protobuf_request(&request)).unwrap();
+    assert_eq!(format, WireFormat::Protobuf);
+    assert_eq!(decoded, request);
+    let binary = InscriptionMessageContext::encode_response(&view::get_inscription(&decoded).unwrap(), format).unwrap();
+
+    let json_input = serde_json::to_vec(&request).unwrap();
+    let (decoded, format) = InscriptionMessageContext::decode_request::<GetInscriptionRequest>(&json_input).unwrap();
+    assert_eq!(format, WireFormat::Json);
+    let json = InscriptionMessageContext::encode_response(&view::get_inscription(&decoded).unwrap(), format).unwrap();
+
+    let from_binary = InscriptionResponse::decode(binary.as_slice()).unwrap();
+    let from_json: InscriptionResponse = serde_json::from_slice(&json).unwrap();
+    assert_eq!(from_binary, from_json);
+    assert_eq!(from_binary.number, 1);
+}
+
+#[wasm_bindgen_test]
+fn test_view_get_content_copies() {
+    use crate::proto::{get_content_copies_request, GetContentCopiesRequest};
+    use crate::tables::InscriptionContentTable;
+    use sha2::{Digest, Sha256};
+
+    state::clear();
+    let art = b"<svg>original art</svg>";
+    let original = create_inscription_transaction(art, "image/svg+xml", Some(create_mock_outpoint(1)));
+    let unrelated = create_inscription_transaction(b"something else", "text/plain", Some(create_mock_outpoint(2)));
+    let mut block = create_block_with_coinbase_tx(840000);
+    block.txdata.push(original.clone());
+    block.txdata.push(unrelated.clone());
+    index_ord_block(&block, 840000).unwrap();
+
+    let copy = create_inscription_transaction(art, "image/svg+xml", Some(create_mock_outpoint(3)));
+    let mut block = create_block_with_coinbase_tx(840001);
+    block.txdata.push(copy.clone());
+    index_ord_block(&block, 840001).unwrap();
+
+    let hash: [u8; 32] = Sha256::digest(art).into();
+    let by_id = view::get_content_copies(&GetContentCopiesRequest {
+        query: Some(get_content_copies_request::Query::Id(ProtoInscriptionId {
+            txid: copy.txid().as_byte_array().to_vec(),
+            index: 0,
+        })),
+        pagination: None,
+    }).unwrap();
+    assert_eq!(by_id.content_hash, hash.to_vec());
+    assert_eq!(by_id.first.unwrap().txid, original.txid().as_byte_array().to_vec());
+    assert_eq!(by_id.copies.len(), 1);
+    assert_eq!(by_id.copies[0].txid, copy.txid().as_byte_array().to_vec());
+    assert_eq!(by_id.pagination.unwrap().total, 1);
+
+    let by_hash = view::get_content_copies(&GetContentCopiesRequest {
+        query: Some(get_content_copies_request::Query::ContentHash(hash.to_vec())),
+        pagination: None,
+    }).unwrap();
+    assert_eq!(by_hash, by_id);
+
+    // Both inscriptions resolve to the single stored body
+    let table = InscriptionContentTable::new();
+    assert_eq!(table.get(&InscriptionId::new(original.txid(), 0)).unwrap(), art);
+    assert_eq!(table.get(&InscriptionId::new(copy.txid(), 0)).unwrap(), art);
+    assert_eq!(table.size_by_hash(&hash), Some(art.len() as u64));
+
+    let response = view::get_inscription(&GetInscriptionRequest {
+        query: Some(get_inscription_request::Query::Id(ProtoInscriptionId {
+            txid: unrelated.txid().as_byte_array().to_vec(),
+            index: 0,
+        })),
+        child_index: None,
+    }).unwrap();
+    let unrelated_hash: [u8; 32] = Sha256::digest(b"something else").into();
+    assert_eq!(response.content_hash, Some(unrelated_hash.to_vec()));
+
+    assert!(view::get_content_copies(&GetContentCopiesRequest {
+        query: Some(get_content_copies_request::Query::ContentHash(vec![0u8; 5])),
+        pagination: None,
+    }).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_view_get_content_byte_ranges() {
+    use crate::tables::CONTENT_CHUNK_SIZE;
+
+    state::clear();
+    let body: Vec<u8> = (0..(CONTENT_CHUNK_SIZE * 2 + 1234)).map(|i| (i % 253) as u8).collect();
+    let tx = create_inscription_transaction(&body, "video/mp4", None);
+    let txid = tx.txid();
+    let mut block = create_block_with_coinbase_tx(840000);
+    block.txdata.push(tx);
+    index_ord_block(&block, 840000).unwrap();
+
+    let id = Some(ProtoInscriptionId { txid: txid.as_byte_array().to_vec(), index: 0 });
+    let total = body.len() as u64;
+
+    let full = view::get_content(&GetContentRequest { id: id.clone(), ..Default::default() }).unwrap();
+    assert_eq!(full.content, body);
+    assert_eq!(full.total_size, total);
+    assert_eq!(full.offset, 0);
+
+    // A range spanning a chunk boundary
+    let start = CONTENT_CHUNK_SIZE as u64 - 10;
+    let range = view::get_content(&GetContentRequest { id: id.clone(), offset: Some(start), length: Some(100) }).unwrap();
+    assert_eq!(range.content, body[start as usize..start as usize + 100]);
+    assert_eq!(range.total_size, total);
+    assert_eq!(range.offset, start);
+
+    // Open-ended tail and over-long length are clamped to the body
+    let tail = view::get_content(&GetContentRequest { id: id.clone(), offset: Some(total - 5), length: None }).unwrap();
+    assert_eq!(tail.content, body[body.len() - 5..]);
+    let clamped = view::get_content(&GetContentRequest { id: id.clone(), offset: Some(total - 5), length: Some(1000) }).unwrap();
+    assert_eq!(clamped.content, tail.content);
+
+    let past_end = view::get_content(&GetContentRequest { id, offset: Some(total + 10), length: Some(10) }).unwrap();
+    assert!(past_end.content.is_empty());
+    assert_eq!(past_end.total_size, total);
+    assert_eq!(past_end.offset, total);
+}
+
+#[wasm_bindgen_test]
+fn test_view_get_inscription_stats() {
+    use crate::proto::GetInscriptionStatsRequest;
+
+    state::clear();
+    assert_eq!(view::get_inscription_stats(&GetInscriptionStatsRequest::default()).unwrap().total, 0);
+
+    // Block 100: a cursed coinbase inscription, a text and a JSON inscription
+    let mut block = create_block_with_coinbase_tx(100);
+    block.txdata[0].input[0].witness = create_inscription_envelope(b"text/plain", b"coinbase");
+    block.txdata.push(create_inscription_transaction(b"hello", "text/plain", None));
+    block.txdata.push(create_inscription_transaction(br#"{"a":1}"#, "application/json", None));
+    index_ord_block(&block, 100).unwrap();
+
+    // Block 101: one more text inscription
+    let mut block = create_block_with_coinbase_tx(101);
+    block.txdata.push(create_inscription_transaction(b"again", "text/plain", None));
+    index_ord_block(&block, 101).unwrap();
+
+    let stats = view::get_inscription_stats(&GetInscriptionStatsRequest::default()).unwrap();
+    assert_eq!((stats.total, stats.blessed, stats.cursed), (4, 3, 1));
+    let counts: Vec<(&str, u64)> = stats.content_types.iter().map(|c| (c.key.as_str(), c.count)).collect();
+    assert_eq!(counts, vec![("text/plain", 3), ("application/json", 1)]);
+    assert!(stats.metaprotocols.is_empty());
+    assert_eq!(stats.charms.len(), 1);
+    assert_eq!((stats.charms[0].key.as_str(), stats.charms[0].count), ("cursed", 1));
+
+    let latest = stats.block.unwrap();
+    assert_eq!(latest.height, 101);
+    assert_eq!(latest.inscriptions, 1);
+    assert_eq!(latest.total_bytes, 5);
+
+    let first = view::get_inscription_stats(&GetInscriptionStatsRequest { height: Some(100) })
+        .unwrap().block.unwrap();
+    assert_eq!(first.inscriptions, 3);
+    assert_eq!(first.total_bytes, (b"coinbase".len() + b"hello".len() + br#"{"a":1}"#.len()) as u64);
+    assert_eq!((first.blessed_total, first.cursed_total), (2, 1));
+    // The reveals spend mock outputs, so only the coinbase fee is known
+    assert_eq!(first.unknown_fees, 2);
+
+    let missing = view::get_inscription_stats(&GetInscriptionStatsRequest { height: Some(5) }).unwrap();
+    assert!(missing.block.is_none());
+    assert_eq!(missing.total, 4);
+}
+
+#[wasm_bindgen_test]
+fn test_view_get_inscription_history() {
+    use crate::proto::GetInscriptionHistoryRequest;
+    use bitcoin::{transaction::Version, Amount, OutPoint, ScriptBuf, Sequence, Transaction, TxIn, TxOut, Witness};
+
+    fn spend(inputs: Vec<OutPoint>, outputs: Vec<(u64, ScriptBuf)>) -> Transaction {
+        Transaction {
+            version: Version(2),
+            lock_time: bitcoin::absolute::LockTime::ZERO,
+            input: inputs.into_iter().map(|previous_output| TxIn {
+                previous_output,
+                script_sig: ScriptBuf::new(),
+                sequence: Sequence::MAX,
+                witness: Witness::new(),
+            }).collect(),
+            output: outputs.into_iter().map(|(value, script_pubkey)| TxOut {
+                value: Amount::from_sat(value),
+                script_pubkey,
+            }).collect(),
+        }
+    }
+
+    state::clear();
+    let first = ScriptBuf::from_bytes(vec![0x51]);
+    let second = ScriptBuf::from_bytes(vec![0x52]);
+    let burn = ScriptBuf::from_bytes(vec![0x6a]);
+
+    // Genesis, plus a 10,000 sat funding output and a second inscription to burn
+    let inscription = create_inscription_transaction(b"history", "text/plain", None);
+    let funding = create_transfer_transaction(&create_mock_outpoint(9).txid, 0);
+    let doomed = create_inscription_transaction(b"burn me", "text/plain", Some(create_mock_outpoint(4)));
+    let mut block = create_block_with_coinbase_tx(840000);
+    block.txdata.extend([inscription.clone(), funding.clone(), doomed.clone()]);
+    index_ord_block(&block, 840000).unwrap();
+
+    // The funding input comes first, so the inscribed sat sits 10,000 sats in
+    // and lands 7,000 sats into the second output
+    let moved = spend(
+        vec![OutPoint::new(funding.txid(), 0), OutPoint::new(inscription.txid(), 0)],
+        vec![(3_000, first.clone()), (100_000, second.clone())],
+    );
+    let burned = spend(vec![OutPoint::new(doomed.txid(), 0)], vec![(100_000, burn.clone())]);
+    let mut block = create_block_with_coinbase_tx(840001);
+    block.txdata.extend([moved.clone(), burned.clone()]);
+    index_ord_block(&block, 840001).unwrap();
+
+    // Only 1,000 sats of output: the inscribed sat goes to fees
+    let fee = spend(vec![OutPoint::new(moved.txid(), 1)], vec![(1_000, first.clone())]);
+    let mut block = create_block_with_coinbase_tx(840002);
+    block.txdata.push(fee.clone());
+    index_ord_block(&block, 840002).unwrap();
+
+    let id = |tx: &Transaction| Some(ProtoInscriptionId { txid: tx.txid().as_byte_array().to_vec(), index: 0 });
+    let response = view::get_inscription_history(&GetInscriptionHistoryRequest {
+        id: id(&inscription),
+        pagination: None,
+    }).unwrap();
+    let transfers = &response.transfers;
+    assert_eq!(transfers.len(), 3);
+    assert_eq!(response.pagination.unwrap().total, 3);
+
+    assert_eq!(transfers[0].txid, inscription.txid().as_byte_array().to_vec());
+    assert_eq!(transfers[0].height, 840000);
+    assert_eq!(transfers[0].owner_script, inscription.output[0].script_pubkey.to_bytes());
+
+    let satpoint = transfers[1].satpoint.clone().unwrap();
+    assert_eq!(satpoint.outpoint.unwrap().vout, 1);
+    assert_eq!(satpoint.offset, 7_000);
+    assert_eq!(transfers[1].txid, moved.txid().as_byte_array().to_vec());
+    assert_eq!(transfers[1].height, 840001);
+    assert_eq!(transfers[1].owner_script, second.to_bytes());
+    assert!(!transfers[1].burned && !transfers[1].spent_as_fee);
+
+    assert!(transfers[2].spent_as_fee);
+    assert_eq!(transfers[2].txid, fee.txid().as_byte_array().to_vec());
+    assert_eq!(transfers[2].satpoint.clone().unwrap().offset, 6_000);
+    assert!(transfers[2].owner_script.is_empty());
+
+    let page = view::get_inscription_history(&GetInscriptionHistoryRequest {
+        id: id(&inscription),
+        pagination: Some(PaginationRequest { page: 1, limit: 2, ..Default::default() }),
+    }).unwrap();
+    assert_eq!(page.transfers, transfers[2..].to_vec());
+    assert!(!page.pagination.unwrap().more);
+
+    let burned_history = view::get_inscription_history(&GetInscriptionHistoryRequest {
+        id: id(&doomed),
+        pagination: None,
+    }).unwrap().transfers;
+    assert_eq!(burned_history.len(), 2);
+    assert!(burned_history[1].burned);
+    assert_eq!(burned_history[1].owner_script, burn.to_bytes());
+
+    let unknown = view::get_inscription_history(&GetInscriptionHistoryRequest {
+        id: id(&fee),
+        pagination: None,
+    }).unwrap();
+    assert!(unknown.transfers.is_empty());
+}
+
+#[wasm_bindgen_test]
+fn test_view_collections_and_galleries() {
+    use crate::ord_inscriptions::Inscription as OrdInscription;
+    use crate::properties::inscription_id_to_value;
+    use crate::proto::{GetCollectionMembersRequest, GetCollectionTraitsRequest, GetGalleriesRequest};
+    use ciborium::value::Value;
+
+    let key = |k: u8| Value::Integer(k.into());
+    let traits = |pairs: &[(&str, Value)]| Value::Map(vec![(key(1), Value::Map(
+        pairs.iter().map(|(name, value)| (Value::Text(name.to_string()), value.clone())).collect(),
+    ))]);
+    let cbor = |value: Value| {
+        let mut bytes = Vec::new();
+        ciborium::ser::into_writer(&value, &mut bytes).unwrap();
+        bytes
+    };
+    let proto_id = |id: &InscriptionId| Some(ProtoInscriptionId { txid: id.txid.as_byte_array().to_vec(), index: id.index });
+
+    state::clear();
+    let parent = create_inscription_transaction(b"parent", "text/plain", None);
+    let item_a = create_inscription_transaction(b"a", "text/plain", Some(create_mock_outpoint(1)));
+    let item_b = create_inscription_transaction(b"b", "text/plain", Some(create_mock_outpoint(2)));
+    let mut block = create_block_with_coinbase_tx(840000);
+    block.txdata.extend([parent.clone(), item_a.clone(), item_b.clone()]);
+    index_ord_block(&block, 840000).unwrap();
+    let (parent_id, a_id, b_id) = (
+        InscriptionId::new(parent.txid(), 0),
+        InscriptionId::new(item_a.txid(), 0),
+        InscriptionId::new(item_b.txid(), 0),
+    );
+
+    // Two children of the parent carrying traits, and a gallery of the two items
+    let child = |commit: u32, properties: Value| create_reveal_transaction(
+        &create_mock_outpoint(commit).txid,
+        OrdInscription {
+            content_type: Some(b"text/plain".to_vec()),
+            body: Some(b"child".to_vec()),
+            parents: vec![parent_id.to_string().into_bytes()],
+            properties: Some(cbor(properties)),
+            ..Default::default()
+        }.to_witness(),
+    );
+    let red = child(3, traits(&[("color", Value::Text("red".into()))]));
+    let red_eyed = child(4, traits(&[("color", Value::Text("red".into())), ("eyes", Value::Integer(2u8.into()))]));
+    let gallery = create_reveal_transaction(&create_mock_outpoint(5).txid, OrdInscription {
+        content_type: Some(b"text/plain".to_vec()),
+        body: Some(b"gallery".to_vec()),
+        properties: Some(cbor(Value::Map(vec![(key(0), Value::Array(vec![
+            Value::Map(vec![
+                (key(0), Value::Bytes(inscription_id_to_value(&a_id))),
+                (key(1), traits(&[("rarity", Value::Text("rare".into()))])),
+            ]),
+            Value::Map(vec![(key(0), Value::Bytes(inscription_id_to_value(&b_id)))]),
+        ]))]))),
+        ..Default::default()
+    }.to_witness());
+    let mut block = create_block_with_coinbase_tx(840001);
+    block.txdata.extend([red.clone(), red_eyed.clone(), gallery.clone()]);
+    index_ord_block(&block, 840001).unwrap();
+    let gallery_id = InscriptionId::new(gallery.txid(), 0);
+
+    let members = view::get_collection_members(&GetCollectionMembersRequest {
+        collection_id: proto_id(&parent_id),
+        pagination: None,
+    }).unwrap();
+    assert!(!members.gallery);
+    assert_eq!(members.ids, vec![
+        proto_id(&InscriptionId::new(red.txid(), 0)).unwrap(),
+        proto_id(&InscriptionId::new(red_eyed.txid(), 0)).unwrap(),
+    ]);
+    let parent_traits = view::get_collection_traits(&GetCollectionTraitsRequest {
+        collection_id: proto_id(&parent_id),
+    }).unwrap().traits;
+    let summary: Vec<_> = parent_traits.iter().map(|t| (t.name.as_str(), t.value.as_str(), t.count)).collect();
+    assert_eq!(summary, vec![("color", "red", 2), ("eyes", "2", 1)]);
+
+    let members = view::get_collection_members(&GetCollectionMembersRequest {
+        collection_id: proto_id(&gallery_id),
+        pagination: None,
+    }).unwrap();
+    assert!(members.gallery);
+    assert_eq!(members.ids, vec![proto_id(&a_id).unwrap(), proto_id(&b_id).unwrap()]);
+    let second_page = view::get_collection_members(&GetCollectionMembersRequest {
+        collection_id: proto_id(&gallery_id),
+        pagination: Some(PaginationRequest { page: 1, limit: 1, ..Default::default() }),
+    }).unwrap();
+    assert_eq!(second_page.ids, vec![proto_id(&b_id).unwrap()]);
+    assert!(!second_page.pagination.unwrap().more);
+
+    let gallery_traits = view::get_collection_traits(&GetCollectionTraitsRequest {
+        collection_id: proto_id(&gallery_id),
+    }).unwrap().traits;
+    assert_eq!(gallery_traits.len(), 1);
+    assert_eq!((gallery_traits[0].name.as_str(), gallery_traits[0].count), ("rarity", 1));
+
+    let galleries = view::get_galleries(&GetGalleriesRequest { id: proto_id(&b_id), pagination: None }).unwrap();
+    assert_eq!(galleries.ids, vec![proto_id(&gallery_id).unwrap()]);
+    let none = view::get_galleries(&GetGalleriesRequest { id: proto_id(&parent_id), pagination: None }).unwrap();
+    assert!(none.ids.is_empty());
+}
+
+#[wasm_bindgen_test]
+fn test_view_round_trips_across_content_types() {
+    state::clear();
+    let inscriptions: Vec<(&[u8], &str)> = vec![
+        (b"Plain text", "text/plain"),
+        (br#"{"key": "value"}"#, "application/json"),
+        (b"<html><body>HTML</body></html>", "text/html"),
+        (b"Binary data", "application/octet-stream"),
+    ];
+    let block = create_inscription_block(inscriptions.clone());
+    index_ord_block(&block, 840000).unwrap();
+
+    let listed = view::get_inscriptions(&GetInscriptionsRequest { pagination: None, filter: None }).unwrap().ids;
+    assert_eq!(listed.len(), inscriptions.len());
+
+    // Each listed id resolves to an inscription whose number resolves back to
+    // the same id, serving the body it was inscribed with
+    for (id, (content, content_type)) in listed.into_iter().zip(inscriptions.iter()) {
+        let by_id = view::get_inscription(&GetInscriptionRequest {
+            query: Some(get_inscription_request::Query::Id(id.clone())),
+            child_index: None,
+        }).unwrap();
+        assert_eq!(by_id.id.as_ref(), Some(&id));
+        assert_eq!(by_id.content_type.as_deref(), Some(*content_type));
+        assert_eq!(by_id.content_length, Some(content.len() as u64));
+
+        let by_number = view::get_inscription(&GetInscriptionRequest {
+            query: Some(get_inscription_request::Query::Number(by_id.number)),
+            child_index: None,
+        }).unwrap();
+        assert_eq!(by_number, by_id);
+
+        let body = view::get_content(&GetContentRequest { id: Some(id), ..Default::default() }).unwrap();
+        assert_eq!(body.content, *content);
+        assert_eq!(body.total_size, content.len() as u64);
+    }
+}
@@ -1,4 +1,4 @@
-use shrew_support::inscription::{InscriptionId, InscriptionEntry};
+use shrew_support::inscription::{InscriptionId, InscriptionEntry, SatPoint};
 use crate::tables::*;
 use crate::proto::{
     GetBlockHashRequest, BlockHashResponse, GetBlockHeightRequest, BlockHeightResponse,
@@ -10,306 +10,405 @@ use crate::proto::{
     GetSatInscriptionRequest, SatInscriptionResponse, GetSatInscriptionsRequest,
     SatInscriptionsResponse, GetSatRequest, SatResponse, GetTransactionRequest, TransactionResponse,
     GetUndelegatedContentRequest, UndelegatedContentResponse, GetUtxoRequest, UtxoResponse,
+    GetContentCopiesRequest, ContentCopiesResponse, get_content_copies_request,
     InscriptionId as ProtoInscriptionId, SatPoint as ProtoSatPoint, OutPoint as ProtoOutPoint,
+    GetInscriptionHistoryRequest, InscriptionHistoryResponse, InscriptionTransfer as ProtoInscriptionTransfer,
+    GetInscriptionStatsRequest, InscriptionStatsResponse, BlockInscriptionStats, StatCount,
+    GetCollectionMembersRequest, CollectionMembersResponse, GetGalleriesRequest, GalleriesResponse,
+    GetCollectionTraitsRequest, CollectionTraitsResponse, TraitCount as ProtoTraitCount,
+    GetBlockDiagnosticsRequest, BlockDiagnosticsResponse, IndexingFailure as ProtoIndexingFailure,
     get_inscription_request,
 };
+use crate::collections;
+use crate::entries;
+use crate::layout;
+use crate::error::OrdError;
+use crate::stats::{self, BlockStats};
+use shrew_support::diagnostics;
+use shrew_support::inscription::Charm;
+use shrew_support::pagination::{paginate, paginate_range, PaginationRequest, PaginationResponse};
 use bitcoin::Txid;
 use bitcoin_hashes::Hash;
-use metashrew_support::index_pointer::KeyValuePointer;
 use std::str::FromStr;
 
-pub fn get_inscription(request: &GetInscriptionRequest) -> Result<InscriptionResponse, String> {
-    let query = request.query.as_ref().ok_or("Request must specify a query")?;
-    let seq_bytes = match query {
-        get_inscription_request::Query::Id(proto_id) => {
-            let inscription_id = InscriptionId {
-                txid: Txid::from_slice(&proto_id.txid).map_err(|e| e.to_string())?,
-                index: proto_id.index,
-            };
-            INSCRIPTION_ID_TO_SEQUENCE.select(&inscription_id.to_bytes()).get()
-        }
-        get_inscription_request::Query::Number(number) => {
-            INSCRIPTION_NUMBER_TO_SEQUENCE.select(&number.to_le_bytes().to_vec()).get()
-        }
+pub fn get_inscription(request: &GetInscriptionRequest) -> Result<InscriptionResponse, OrdError> {
+    let query = request.query.as_ref().ok_or(OrdError::MissingField("query"))?;
+    let sequence = match query {
+        get_inscription_request::Query::Id(proto_id) => sequence_of(proto_id)?,
+        get_inscription_request::Query::Number(number) => INSCRIPTION_NUMBER_TO_SEQUENCE.get(number),
         get_inscription_request::Query::Sat(_) => {
-            return Err("Query by sat is not yet implemented".to_string());
+            return Err(OrdError::NotImplemented("query by sat"));
         }
     };
-    if seq_bytes.is_empty() { return Ok(InscriptionResponse::default()); }
-    let entry_bytes = SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq_bytes).get();
-    if entry_bytes.is_empty() { return Ok(InscriptionResponse::default()); }
-    let entry = InscriptionEntry::from_bytes(&entry_bytes)
-        .map_err(|e| format!("Failed to parse inscription entry: {}", e))?;
+    let Some(sequence) = sequence else { return Ok(InscriptionResponse::default()) };
+    let Some(entry) = load_entry(sequence)? else { return Ok(InscriptionResponse::default()) };
+    let satpoint = SEQUENCE_TO_SATPOINT.get(&sequence).unwrap_or_else(|| entry.satpoint.clone());
     Ok(InscriptionResponse {
         id: Some(ProtoInscriptionId { txid: entry.id.txid.as_byte_array().to_vec(), index: entry.id.index }),
         number: entry.number,
         content_type: Some(entry.content_type.unwrap_or_default()),
         content_length: entry.content_length,
         timestamp: entry.timestamp as i64,
-        satpoint: Some(ProtoSatPoint {
-            outpoint: Some(ProtoOutPoint {
-                txid: entry.satpoint.outpoint.txid.as_byte_array().to_vec(),
-                vout: entry.satpoint.outpoint.vout,
-            }),
-            offset: entry.satpoint.offset,
+        satpoint: Some(satpoint_to_proto(&satpoint)),
+        content_hash: entry.content_hash.map(|hash| hash.to_vec()),
+        rune: entry.rune.map(|rune| crate::proto::SpacedRune {
+            rune: u64::try_from(rune.0).unwrap_or_default(),
+            spacers: 0,
+            name: rune.to_string(),
+            value: rune.0.to_string(),
         }),
         ..Default::default()
     })
 }
 
-pub fn get_inscriptions(request: &GetInscriptionsRequest) -> Result<InscriptionsResponse, String> {
-    let mut response = InscriptionsResponse::default();
-    let limit = request.pagination.as_ref().map_or(100, |p| p.limit.max(1).min(100));
-    let offset = request.pagination.as_ref().map_or(0, |p| p.page * limit);
-    let sequence_bytes = GLOBAL_SEQUENCE_COUNTER.get();
-    let total = if !sequence_bytes.is_empty() && sequence_bytes.len() >= 4 {
-        u32::from_le_bytes([sequence_bytes[0], sequence_bytes[1], sequence_bytes[2], sequence_bytes[3]]) as u64
-    } else { 0 };
-    let mut inscription_ids = Vec::new();
-    let start_seq = offset + 1;
-    let end_seq = (start_seq + limit).min((total + 1) as u32);
-    for seq in start_seq..end_seq {
-        let seq_bytes = (seq as u32).to_le_bytes().to_vec();
-        let entry_bytes = SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq_bytes).get();
-        if !entry_bytes.is_empty() {
-            if let Ok(entry) = InscriptionEntry::from_bytes(&entry_bytes) {
-                inscription_ids.push(ProtoInscriptionId {
-                    txid: entry.id.txid.as_byte_array().to_vec(),
-                    index: entry.id.index,
-                });
-            }
-        }
-    }
-    response.ids = inscription_ids;
-    response.pagination = Some(crate::proto::PaginationResponse {
-        limit, page: offset / limit, total, more: (offset + limit) < (total as u32),
-    });
-    Ok(response)
+pub fn get_inscriptions(request: &GetInscriptionsRequest) -> Result<InscriptionsResponse, OrdError> {
+    let total = GLOBAL_SEQUENCE_COUNTER.get().unwrap_or(0) as u64;
+    let (positions, pagination) = paginate_range(total, request.pagination.as_ref())?;
+    // Sequences start at 1
+    let sequences = (positions.start as u32 + 1)..(positions.end as u32 + 1);
+    Ok(InscriptionsResponse { ids: sequences_to_proto_ids(sequences)?, pagination: Some(pagination) })
 }
 
-pub fn get_children(request: &GetChildrenRequest) -> Result<ChildrenResponse, String> {
-    let mut response = ChildrenResponse::default();
-    let parent_proto_id = request.parent_id.as_ref().ok_or("Missing parent_id")?;
-    let parent_id = InscriptionId {
-        txid: Txid::from_slice(&parent_proto_id.txid).map_err(|e| e.to_string())?,
-        index: parent_proto_id.index,
-    };
-    let parent_seq_bytes = INSCRIPTION_ID_TO_SEQUENCE.select(&parent_id.to_bytes()).get();
-    if parent_seq_bytes.is_empty() { return Ok(response); }
-    let children_seq_list = SEQUENCE_TO_CHILDREN.select(&parent_seq_bytes).get_list();
-    let mut children_ids = Vec::new();
-    for child_seq_bytes in children_seq_list {
-        let entry_bytes = SEQUENCE_TO_INSCRIPTION_ENTRY.select(&child_seq_bytes).get();
-        if let Ok(entry) = InscriptionEntry::from_bytes(&entry_bytes) {
-            children_ids.push(ProtoInscriptionId { txid: entry.id.txid.as_byte_array().to_vec(), index: entry.id.index });
-        }
-    }
-    response.ids = children_ids;
-    Ok(response)
+pub fn get_children(request: &GetChildrenRequest) -> Result<ChildrenResponse, OrdError> {
+    let parent_proto_id = request.parent_id.as_ref().ok_or(OrdError::MissingField("parent_id"))?;
+    let Some(parent) = sequence_of(parent_proto_id)? else { return Ok(ChildrenResponse::default()) };
+    let (children, pagination) = paginate(SEQUENCE_TO_CHILDREN.get_list(&parent), request.pagination.as_ref())?;
+    Ok(ChildrenResponse { ids: sequences_to_proto_ids(children)?, pagination: Some(pagination) })
 }
 
-pub fn get_parents(request: &GetParentsRequest) -> Result<ParentsResponse, String> {
-    let mut response = ParentsResponse::default();
-    let child_proto_id = request.child_id.as_ref().ok_or("Missing child_id")?;
-    let child_id = InscriptionId {
-        txid: Txid::from_slice(&child_proto_id.txid).map_err(|e| e.to_string())?,
-        index: child_proto_id.index,
-    };
-    let child_seq_bytes = INSCRIPTION_ID_TO_SEQUENCE.select(&child_id.to_bytes()).get();
-    if child_seq_bytes.is_empty() { return Ok(response); }
-    let parents_seq_list = SEQUENCE_TO_PARENTS.select(&child_seq_bytes).get_list();
-    let mut parent_ids = Vec::new();
-    for parent_seq_bytes in parents_seq_list {
-        let entry_bytes = SEQUENCE_TO_INSCRIPTION_ENTRY.select(&parent_seq_bytes).get();
-        if let Ok(entry) = InscriptionEntry::from_bytes(&entry_bytes) {
-            parent_ids.push(ProtoInscriptionId { txid: entry.id.txid.as_byte_array().to_vec(), index: entry.id.index });
-        }
-    }
-    response.ids = parent_ids;
-    Ok(response)
+pub fn get_parents(request: &GetParentsRequest) -> Result<ParentsResponse, OrdError> {
+    let child_proto_id = request.child_id.as_ref().ok_or(OrdError::MissingField("child_id"))?;
+    let Some(child) = sequence_of(child_proto_id)? else { return Ok(ParentsResponse::default()) };
+    let (parents, pagination) = paginate(SEQUENCE_TO_PARENTS.get_list(&child), request.pagination.as_ref())?;
+    Ok(ParentsResponse { ids: sequences_to_proto_ids(parents)?, pagination: Some(pagination) })
 }
 
-pub fn get_content(request: &GetContentRequest) -> Result<ContentResponse, String> {
+pub fn get_content(request: &GetContentRequest) -> Result<ContentResponse, OrdError> {
     let mut response = ContentResponse::default();
-    let proto_id = request.id.as_ref().ok_or("Missing id")?;
+    let proto_id = request.id.as_ref().ok_or(OrdError::MissingField("id"))?;
     let inscription_id = InscriptionId {
-        txid: Txid::from_slice(&proto_id.txid).map_err(|e| e.to_string())?,
+        txid: Txid::from_slice(&proto_id.txid).map_err(OrdError::invalid_txid)?,
         index: proto_id.index,
     };
-    let id_bytes = inscription_id.to_bytes();
-    let seq_bytes = INSCRIPTION_ID_TO_SEQUENCE.select(&id_bytes).get();
-    if seq_bytes.is_empty() { return Ok(response); }
-    let entry_bytes = SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq_bytes).get();
-    if entry_bytes.is_empty() { return Ok(response); }
-    let entry = InscriptionEntry::from_bytes(&entry_bytes)
-        .map_err(|e| format!("Failed to parse inscription entry: {}", e))?;
+    let Some(sequence) = INSCRIPTION_ID_TO_SEQUENCE.get(&inscription_id) else { return Ok(response) };
+    let Some(entry) = load_entry(sequence)? else { return Ok(response) };
     if let Some(delegate_id) = entry.delegate {
         let delegate_proto_id = ProtoInscriptionId { txid: delegate_id.txid.as_byte_array().to_vec(), index: delegate_id.index };
-        return get_content(&GetContentRequest { id: Some(delegate_proto_id) });
+        return get_content(&GetContentRequest { id: Some(delegate_proto_id), ..request.clone() });
     }
-    let inscription_id_str = inscription_id.to_string();
     let content_table = InscriptionContentTable::new();
-    if let Some(content) = content_table.get(&inscription_id_str) { response.content = content; }
+    let offset = request.offset.unwrap_or(0);
+    if let Some((content, total_size)) = content_table.get_range(&inscription_id, offset, request.length) {
+        response.content = content;
+        response.total_size = total_size;
+        response.offset = offset.min(total_size);
+    }
     response.content_type = Some(entry.content_type.unwrap_or_default());
     Ok(response)
 }
 
-pub fn get_metadata(request: &GetMetadataRequest) -> Result<MetadataResponse, String> {
-    let mut response = MetadataResponse::default();
-    let proto_id = request.id.as_ref().ok_or("Missing id")?;
-    let txid = bitcoin::Txid::from_slice(&proto_id.txid).map_err(|e| format!("Invalid txid: {}", e))?;
-    let inscription_id_str = format!("{}i{}", txid, proto_id.index);
-    let metadata_table = InscriptionMetadataTable::new();
-    if let Some(metadata) = metadata_table.get(&inscription_id_str) { response.metadata_hex = hex::encode(metadata); }
+pub fn get_content_copies(request: &GetContentCopiesRequest) -> Result<ContentCopiesResponse, OrdError> {
+    let mut response = ContentCopiesResponse::default();
+    let query = request.query.as_ref().ok_or(OrdError::MissingField("query"))?;
+    let hash = match query {
+        get_content_copies_request::Query::ContentHash(hash) => {
+            <[u8; 32]>::try_from(hash.as_slice()).map_err(|_| OrdError::InvalidField {
+                field: "content_hash",
+                reason: "must be 32 bytes".to_string(),
+            })?
+        }
+        get_content_copies_request::Query::Id(proto_id) => {
+            match InscriptionContentTable::new().hash(&inscription_id_of(proto_id)?) {
+                Some(hash) => hash,
+                None => return Ok(response),
+            }
+        }
+    };
+    response.content_hash = hash.to_vec();
+
+    let mut sequences = CONTENT_HASH_TO_INSCRIPTIONS.get_list(&hash);
+    if sequences.is_empty() { return Ok(response); }
+    let copies = sequences.split_off(1);
+    response.first = sequence_to_proto_id(sequences[0])?;
+
+    let (copies, pagination) = paginate(copies, request.pagination.as_ref())?;
+    response.copies = sequences_to_proto_ids(copies)?;
+    response.pagination = Some(pagination);
     Ok(response)
 }
 
-pub fn get_sat(_request: &GetSatRequest) -> Result<SatResponse, String> {
-    let mut response = SatResponse::default();
-    response.number = _request.sat;
-    Ok(response)
+fn satpoint_to_proto(satpoint: &SatPoint) -> ProtoSatPoint {
+    ProtoSatPoint {
+        outpoint: Some(ProtoOutPoint {
+            txid: satpoint.outpoint.txid.as_byte_array().to_vec(),
+            vout: satpoint.outpoint.vout,
+        }),
+        offset: satpoint.offset,
+    }
+}
+
+fn sequence_to_proto_id(sequence: u32) -> Result<Option<ProtoInscriptionId>, OrdError> {
+    Ok(load_entry(sequence)?
+        .map(|entry| ProtoInscriptionId { txid: entry.id.txid.as_byte_array().to_vec(), index: entry.id.index }))
 }
 
-pub fn get_sat_inscriptions(_request: &GetSatInscriptionsRequest) -> Result<SatInscriptionsResponse, String> {
-    Ok(SatInscriptionsResponse::default())
+/// Ids of the given sequences, skipping any that have no entry
+fn sequences_to_proto_ids(sequences: impl IntoIterator<Item = u32>) -> Result<Vec<ProtoInscriptionId>, OrdError> {
+    sequences.into_iter()
+        .filter_map(|sequence| sequence_to_proto_id(sequence).transpose())
+        .collect()
 }
 
-pub fn get_sat_inscription(_request: &GetSatInscriptionRequest) -> Result<SatInscriptionResponse, String> {
-    Ok(SatInscriptionResponse::default())
+/// Stored entry for a sequence; an entry that fails to decode is an error, not a gap
+fn load_entry(sequence: u32) -> Result<Option<InscriptionEntry>, OrdError> {
+    entries::load(sequence).map_err(|source| OrdError::EntryDecode { sequence, source })
 }
 
-pub fn get_child_inscriptions(request: &GetChildInscriptionsRequest) -> Result<ChildInscriptionsResponse, String> {
-    let mut response = ChildInscriptionsResponse::default();
-    let parent_proto_id = request.parent_id.as_ref().ok_or("Missing parent_id")?;
-    let parent_id = InscriptionId {
-        txid: Txid::from_slice(&parent_proto_id.txid).map_err(|e| e.to_string())?,
-        index: parent_proto_id.index,
+pub fn get_inscription_history(request: &GetInscriptionHistoryRequest) -> Result<InscriptionHistoryResponse, OrdError> {
+    let proto_id = request.id.as_ref().ok_or(OrdError::MissingField("id"))?;
+    let Some(sequence) = sequence_of(proto_id)? else { return Ok(InscriptionHistoryResponse::default()) };
+
+    let (history, pagination) = paginate(crate::history::load(sequence), request.pagination.as_ref())?;
+    let transfers = history.into_iter()
+        .map(|transfer| ProtoInscriptionTransfer {
+            satpoint: Some(satpoint_to_proto(&transfer.satpoint)),
+            txid: transfer.txid.as_byte_array().to_vec(),
+            height: transfer.height,
+            owner_script: transfer.owner_script,
+            burned: transfer.burned,
+            spent_as_fee: transfer.spent_as_fee,
+        })
+        .collect();
+    Ok(InscriptionHistoryResponse { transfers, pagination: Some(pagination) })
+}
+
+pub fn get_collection_members(request: &GetCollectionMembersRequest) -> Result<CollectionMembersResponse, OrdError> {
+    let proto_id = request.collection_id.as_ref().ok_or(OrdError::MissingField("collection_id"))?;
+    let Some(sequence) = sequence_of(proto_id)? else { return Ok(CollectionMembersResponse::default()) };
+
+    let items = collections::gallery_items(sequence);
+    let gallery = !items.is_empty();
+    let members: Vec<InscriptionId> = if gallery {
+        items
+    } else {
+        SEQUENCE_TO_CHILDREN.get_list(&sequence)
+            .into_iter()
+            .filter_map(|child| load_entry(child).transpose())
+            .map(|entry| entry.map(|entry| entry.id))
+            .collect::<Result<_, _>>()?
     };
-    let parent_seq_bytes = INSCRIPTION_ID_TO_SEQUENCE.select(&parent_id.to_bytes()).get();
-    if parent_seq_bytes.is_empty() { return Ok(response); }
-    let children_seq_list = SEQUENCE_TO_CHILDREN.select(&parent_seq_bytes).get_list();
-    let mut children_info = Vec::new();
-    for child_seq_bytes in children_seq_list {
-        let entry_bytes = SEQUENCE_TO_INSCRIPTION_ENTRY.select(&child_seq_bytes).get();
-        if let Ok(entry) = InscriptionEntry::from_bytes(&entry_bytes) {
-            children_info.push(crate::proto::RelativeInscription {
-                id: Some(ProtoInscriptionId { txid: entry.id.txid.as_byte_array().to_vec(), index: entry.id.index }),
-                number: entry.number,
-                ..Default::default()
-            });
-        }
+
+    let (ids, pagination) = paginate_ids(members, request.pagination.as_ref())?;
+    Ok(CollectionMembersResponse { ids, gallery, pagination: Some(pagination) })
+}
+
+pub fn get_galleries(request: &GetGalleriesRequest) -> Result<GalleriesResponse, OrdError> {
+    let proto_id = request.id.as_ref().ok_or(OrdError::MissingField("id"))?;
+    let galleries: Vec<InscriptionId> = collections::galleries(&inscription_id_of(proto_id)?)
+        .into_iter()
+        .filter_map(|sequence| load_entry(sequence).transpose())
+        .map(|entry| entry.map(|entry| entry.id))
+        .collect::<Result<_, _>>()?;
+
+    let (ids, pagination) = paginate_ids(galleries, request.pagination.as_ref())?;
+    Ok(GalleriesResponse { ids, pagination: Some(pagination) })
+}
+
+pub fn get_collection_traits(request: &GetCollectionTraitsRequest) -> Result<CollectionTraitsResponse, OrdError> {
+    let proto_id = request.collection_id.as_ref().ok_or(OrdError::MissingField("collection_id"))?;
+    let Some(sequence) = sequence_of(proto_id)? else { return Ok(CollectionTraitsResponse::default()) };
+    let traits = collections::trait_counts(sequence)
+        .into_iter()
+        .map(|t| ProtoTraitCount { name: t.name, value: t.value, count: t.count })
+        .collect();
+    Ok(CollectionTraitsResponse { traits })
+}
+
+fn inscription_id_of(proto_id: &ProtoInscriptionId) -> Result<InscriptionId, OrdError> {
+    Ok(InscriptionId {
+        txid: Txid::from_slice(&proto_id.txid).map_err(OrdError::invalid_txid)?,
+        index: proto_id.index,
+    })
+}
+
+/// Sequence number of an indexed inscription, `None` if it is unknown
+fn sequence_of(proto_id: &ProtoInscriptionId) -> Result<Option<u32>, OrdError> {
+    Ok(INSCRIPTION_ID_TO_SEQUENCE.get(&inscription_id_of(proto_id)?))
+}
+
+/// One page of inscription ids
+fn paginate_ids(
+    ids: Vec<InscriptionId>,
+    pagination: Option<&PaginationRequest>,
+) -> Result<(Vec<ProtoInscriptionId>, PaginationResponse), OrdError> {
+    let (page, pagination) = paginate(ids, pagination)?;
+    let page = page.into_iter()
+        .map(|id| ProtoInscriptionId { txid: id.txid.as_byte_array().to_vec(), index: id.index })
+        .collect();
+    Ok((page, pagination))
+}
+
+pub fn get_inscription_stats(request: &GetInscriptionStatsRequest) -> Result<InscriptionStatsResponse, OrdError> {
+    let mut response = InscriptionStatsResponse::default();
+    let Some(tip) = stats::stats_height() else { return Ok(response) };
+
+    if let Some(latest) = BlockStats::load(tip) {
+        response.blessed = latest.blessed_total;
+        response.cursed = latest.cursed_total;
+        response.total = latest.blessed_total + latest.cursed_total;
     }
-    response.children = children_info;
+    let to_proto = |(key, count): (String, u64)| StatCount { key, count };
+    response.content_types = CONTENT_TYPE_COUNTS.entries().into_iter().map(to_proto).collect();
+    response.metaprotocols = METAPROTOCOL_COUNTS.entries().into_iter().map(to_proto).collect();
+    response.charms = Charm::all().iter()
+        .map(|charm| StatCount {
+            key: charm.name().to_string(),
+            count: CHARM_COUNTS.get(&(*charm as u16)).unwrap_or(0),
+        })
+        .filter(|entry| entry.count > 0)
+        .collect();
+
+    let height = request.height.unwrap_or(tip);
+    response.block = BlockStats::load(height).map(|block| BlockInscriptionStats {
+        height,
+        inscriptions: block.inscriptions,
+        total_bytes: block.total_bytes,
+        total_fees: block.total_fees,
+        blessed_total: block.blessed_total,
+        cursed_total: block.cursed_total,
+        unknown_fees: block.unknown_fees,
+    });
     Ok(response)
 }
 
-pub fn get_parent_inscriptions(request: &GetParentInscriptionsRequest) -> Result<ParentInscriptionsResponse, String> {
-    let mut response = ParentInscriptionsResponse::default();
-    let proto_id = request.child_id.as_ref().ok_or("Missing child_id")?;
-    let txid = bitcoin::Txid::from_slice(&proto_id.txid).map_err(|e| format!("Invalid txid: {}", e))?;
-    let child_id_str = format!("{}i{}", txid, proto_id.index);
-    let parent_table = InscriptionParentTable::new();
-    if let Some(parent_id_str) = parent_table.get(&child_id_str) {
-        let mut relative = crate::proto::RelativeInscription::default();
-        let parts: Vec<&str> = parent_id_str.split('i').collect();
-        if parts.len() == 2 {
-            if let Ok(parent_txid) = bitcoin::Txid::from_str(parts[0]) {
-                if let Ok(parent_index) = parts[1].parse::<u32>() {
-                    relative.id = Some(ProtoInscriptionId { txid: parent_txid.as_byte_array().to_vec(), index: parent_index });
-                }
-            }
-        }
-        let number_table = InscriptionNumberTable::new();
-        if let Some(number_bytes) = number_table.get(&parent_id_str) {
-            if let Ok(number) = serde_json::from_slice::<u64>(&number_bytes) {
-                relative.number = number as i32;
-            }
-        }
-        response.parents = vec![relative];
-    }
+pub fn get_metadata(request: &GetMetadataRequest) -> Result<MetadataResponse, OrdError> {
+    let mut response = MetadataResponse::default();
+    let proto_id = request.id.as_ref().ok_or(OrdError::MissingField("id"))?;
+    if let Some(metadata) = layout::metadata(&inscription_id_of(proto_id)?) { response.metadata_hex = hex::encode(metadata); }
     Ok(response)
 }
 
-pub fn get_undelegated_content(request: &GetUndelegatedContentRequest) -> Result<UndelegatedContentResponse, String> {
+pub fn get_sat(_request: &GetSatRequest) -> Result<SatResponse, OrdError> {
+    let mut response = SatResponse::default();
+    response.number = _request.sat;
+    Ok(response)
+}
+
+pub fn get_sat_inscriptions(_request: &GetSatInscriptionsRequest) -> Result<SatInscriptionsResponse, OrdError> {
+    Err(OrdError::NotImplemented("sat inscriptions"))
+}
+
+pub fn get_sat_inscription(_request: &GetSatInscriptionRequest) -> Result<SatInscriptionResponse, OrdError> {
+    Err(OrdError::NotImplemented("sat inscription"))
+}
+
+pub fn get_child_inscriptions(request: &GetChildInscriptionsRequest) -> Result<ChildInscriptionsResponse, OrdError> {
+    let parent_proto_id = request.parent_id.as_ref().ok_or(OrdError::MissingField("parent_id"))?;
+    let Some(parent) = sequence_of(parent_proto_id)? else { return Ok(ChildInscriptionsResponse::default()) };
+    let (children, pagination) = paginate(SEQUENCE_TO_CHILDREN.get_list(&parent), request.pagination.as_ref())?;
+    Ok(ChildInscriptionsResponse { children: relative_inscriptions(children)?, pagination: Some(pagination) })
+}
+
+pub fn get_parent_inscriptions(request: &GetParentInscriptionsRequest) -> Result<ParentInscriptionsResponse, OrdError> {
+    let proto_id = request.child_id.as_ref().ok_or(OrdError::MissingField("child_id"))?;
+    let Some(child) = sequence_of(proto_id)? else { return Ok(ParentInscriptionsResponse::default()) };
+    let (parents, pagination) = paginate(SEQUENCE_TO_PARENTS.get_list(&child), request.pagination.as_ref())?;
+    Ok(ParentInscriptionsResponse { parents: relative_inscriptions(parents)?, pagination: Some(pagination) })
+}
+
+fn relative_inscriptions(sequences: Vec<u32>) -> Result<Vec<crate::proto::RelativeInscription>, OrdError> {
+    sequences.into_iter()
+        .filter_map(|sequence| load_entry(sequence).transpose())
+        .map(|entry| entry.map(|entry| crate::proto::RelativeInscription {
+            id: Some(ProtoInscriptionId { txid: entry.id.txid.as_byte_array().to_vec(), index: entry.id.index }),
+            number: entry.number,
+            ..Default::default()
+        }))
+        .collect()
+}
+
+pub fn get_undelegated_content(request: &GetUndelegatedContentRequest) -> Result<UndelegatedContentResponse, OrdError> {
     let mut response = UndelegatedContentResponse::default();
-    let proto_id = request.id.as_ref().ok_or("Missing id")?;
-    let txid = bitcoin::Txid::from_slice(&proto_id.txid).map_err(|e| format!("Invalid txid: {}", e))?;
-    let inscription_id_str = format!("{}i{}", txid, proto_id.index);
-    let content_table = InscriptionContentTable::new();
-    if let Some(content) = content_table.get(&inscription_id_str) { response.content = content; }
-    let content_type_table = InscriptionContentTypeTable::new();
-    if let Some(content_type_bytes) = content_type_table.get(&inscription_id_str) {
-        if let Ok(content_type) = String::from_utf8(content_type_bytes) {
-            response.content_type = Some(content_type);
-        }
+    let proto_id = request.id.as_ref().ok_or(OrdError::MissingField("id"))?;
+    let inscription_id = inscription_id_of(proto_id)?;
+    if let Some(content) = InscriptionContentTable::new().get(&inscription_id) { response.content = content; }
+    if let Some(sequence) = INSCRIPTION_ID_TO_SEQUENCE.get(&inscription_id) {
+        response.content_type = load_entry(sequence)?.and_then(|entry| entry.content_type);
     }
     Ok(response)
 }
 
-pub fn get_utxo(_request: &GetUtxoRequest) -> Result<UtxoResponse, String> {
-    Ok(UtxoResponse::default())
+pub fn get_utxo(_request: &GetUtxoRequest) -> Result<UtxoResponse, OrdError> {
+    Err(OrdError::NotImplemented("utxo"))
 }
 
-pub fn get_block_hash(request: &GetBlockHashRequest) -> Result<BlockHashResponse, String> {
+pub fn get_block_hash(request: &GetBlockHashRequest) -> Result<BlockHashResponse, OrdError> {
     let mut response = BlockHashResponse::default();
-    if let Some(height) = request.height {
-        let height_bytes = height.to_le_bytes().to_vec();
-        let hash_bytes = HEIGHT_TO_BLOCK_HASH.select(&height_bytes).get();
-        if !hash_bytes.is_empty() && hash_bytes.len() == 32 {
-            let hash = bitcoin::BlockHash::from_byte_array(hash_bytes[..32].try_into().unwrap_or([0u8; 32]));
-            response.hash = hash.to_string();
-        }
+    if let Some(hash) = request.height.and_then(|height| HEIGHT_TO_BLOCK_HASH.get(&height)) {
+        response.hash = hash.to_string();
     }
     Ok(response)
 }
 
-pub fn get_block_height(_request: &GetBlockHeightRequest) -> Result<BlockHeightResponse, String> {
+pub fn get_block_height(_request: &GetBlockHeightRequest) -> Result<BlockHeightResponse, OrdError> {
     let mut response = BlockHeightResponse::default();
-    let sequence_bytes = GLOBAL_SEQUENCE_COUNTER.get();
-    if !sequence_bytes.is_empty() && sequence_bytes.len() >= 4 {
-        let height = u32::from_le_bytes([sequence_bytes[0], sequence_bytes[1], sequence_bytes[2], sequence_bytes[3]]);
+    if let Some(height) = GLOBAL_SEQUENCE_COUNTER.get() {
         response.height = height;
     }
     Ok(response)
 }
 
-pub fn get_block_time(_request: &GetBlockTimeRequest) -> Result<BlockTimeResponse, String> {
-    Ok(BlockTimeResponse { timestamp: 1640995200 })
+pub fn get_block_time(_request: &GetBlockTimeRequest) -> Result<BlockTimeResponse, OrdError> {
+    Err(OrdError::NotImplemented("block time"))
 }
 
-pub fn get_block_info(request: &GetBlockInfoRequest) -> Result<BlockInfoResponse, String> {
+pub fn get_block_info(request: &GetBlockInfoRequest) -> Result<BlockInfoResponse, OrdError> {
     use crate::proto::get_block_info_request::Query;
     let mut response = BlockInfoResponse::default();
     if let Some(query) = &request.query {
         match query {
             Query::Height(height) => {
                 response.height = *height;
-                let height_bytes = height.to_le_bytes().to_vec();
-                let hash_bytes = HEIGHT_TO_BLOCK_HASH.select(&height_bytes).get();
-                if !hash_bytes.is_empty() && hash_bytes.len() == 32 {
-                    let hash = bitcoin::BlockHash::from_byte_array(hash_bytes[..32].try_into().unwrap_or([0u8; 32]));
+                if let Some(hash) = HEIGHT_TO_BLOCK_HASH.get(height) {
                     response.hash = hash.to_string();
                 }
             }
             Query::Hash(hash_str) => {
                 response.hash = hash_str.clone();
-                if let Ok(hash) = bitcoin::BlockHash::from_str(hash_str) {
-                    let hash_bytes = hash.as_byte_array().to_vec();
-                    let height_bytes = BLOCK_HASH_TO_HEIGHT.select(&hash_bytes).get();
-                    if !height_bytes.is_empty() && height_bytes.len() >= 4 {
-                        response.height = u32::from_le_bytes([height_bytes[0], height_bytes[1], height_bytes[2], height_bytes[3]]);
-                    }
+                if let Some(height) = bitcoin::BlockHash::from_str(hash_str).ok().and_then(|hash| BLOCK_HASH_TO_HEIGHT.get(&hash)) {
+                    response.height = height;
                 }
             }
         }
     } else {
-        return Err("No query parameter provided".to_string());
+        return Err(OrdError::MissingField("query"));
     }
     Ok(response)
 }
 
-pub fn get_tx(_request: &GetTransactionRequest) -> Result<TransactionResponse, String> {
-    Ok(TransactionResponse { hex: String::new() })
+pub fn get_block_diagnostics(request: &GetBlockDiagnosticsRequest) -> Result<BlockDiagnosticsResponse, OrdError> {
+    let height = request.height;
+    let block = diagnostics::block(height);
+    Ok(BlockDiagnosticsResponse {
+        height,
+        protocols: block.protocols,
+        envelopes: block.envelopes,
+        inscriptions_created: block.inscriptions_created,
+        inscriptions_transferred: block.inscriptions_transferred,
+        brc20_accepted: block.brc20_accepted,
+        brc20_rejected: block.brc20_rejected.into_iter().map(|(key, count)| StatCount { key, count }).collect(),
+        runestones: block.runestones,
+        cenotaphs: block.cenotaphs,
+        prog_executions: block.prog_executions,
+        prog_gas: block.prog_gas,
+        failures: diagnostics::failures(height).into_iter()
+            .map(|failure| ProtoIndexingFailure { indexer: failure.indexer, code: failure.code, message: failure.message })
+            .collect(),
+    })
+}
+
+pub fn get_tx(_request: &GetTransactionRequest) -> Result<TransactionResponse, OrdError> {
+    Err(OrdError::NotImplemented("transaction"))
 }
 
 pub fn parse_inscription_id(id_str: &str) -> Result<InscriptionId, String> {
@@ -0,0 +1,17 @@
+//! Errors of the POW20 views.
+
+use shrew_support::error::{CodedError, ErrorCode};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Pow20Error {
+    #[error("{0} is not implemented")]
+    NotImplemented(&'static str),
+}
+
+impl CodedError for Pow20Error {
+    fn code(&self) -> ErrorCode {
+        match self {
+            Pow20Error::NotImplemented(_) => ErrorCode::NotImplemented,
+        }
+    }
+}
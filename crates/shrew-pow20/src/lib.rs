@@ -1,8 +1,9 @@
 #[cfg(feature = "entrypoint")]
-use bitcoin::{Block, consensus::deserialize};
-use serde_json::{from_slice, to_vec};
+use shrew_support::diagnostics;
+use shrew_support::wire::serve;
 
 pub mod pow20_indexer;
+pub mod error;
 pub mod tables;
 pub mod view;
 
@@ -15,10 +16,10 @@ pub use pow20_indexer::Pow20Indexer;
 #[cfg(feature = "entrypoint")]
 #[metashrew_core::main]
 fn main_logic(height: u32, block_data: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
-    if let Ok(block) = deserialize::<Block>(block_data) {
-        let mut inscription_indexer = shrew_ord::indexer::InscriptionIndexer::new();
-        let _ = inscription_indexer.load_state();
-        let _ = inscription_indexer.index_block(&block, height);
+    if let Some(block) = diagnostics::decode_block(height, block_data) {
+        if !shrew_ord::indexer::InscriptionIndexer::index_or_report(&block, height) {
+            return Ok(());
+        }
 
         let indexer = Pow20Indexer::new();
         indexer.index_block(&block, height);
@@ -28,14 +29,12 @@ fn main_logic(height: u32, block_data: &[u8]) -> Result<(), Box<dyn std::error::
 
 #[metashrew_core::view]
 pub fn getpow20balance(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetPow20BalanceRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_pow20_balance(&req)?)?)
+    serve(input, view::get_pow20_balance)
 }
 
 #[metashrew_core::view]
 pub fn getpow20events(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetPow20EventsRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_pow20_events(&req)?)?)
+    serve(input, view::get_pow20_events)
 }
 
 #[cfg(test)]
@@ -1,16 +1,15 @@
 use crate::tables::*;
-use shrew_support::inscription::InscriptionEntry;
 use shrew_support::utils::get_address_from_txout;
+use shrew_ord::entries;
+use shrew_support::diagnostics::{self, BlockDiagnostics};
 use shrew_ord::tables::{
-    SEQUENCE_TO_INSCRIPTION_ENTRY, INSCRIPTION_CONTENT, GLOBAL_SEQUENCE_COUNTER,
+    InscriptionContentTable, GLOBAL_SEQUENCE_COUNTER,
     OUTPOINT_TO_INSCRIPTIONS,
 };
+use shrew_support::inscription::InscriptionId;
 use bitcoin::{Block, Network, Transaction};
-use bitcoin_hashes::Hash;
-use metashrew_support::index_pointer::KeyValuePointer;
 use sha2::{Sha256, Digest};
 use serde::{Serialize, Deserialize};
-use std::sync::Arc;
 
 const POW20_STARTING_BLOCK: u32 = shrew_support::constants::POW20_STARTING_BLOCK;
 
@@ -56,21 +55,14 @@ impl Pow20Indexer {
 
         // Process transfers first
         for tx in &block.txdata {
-            self.process_pow20_transfers(tx, network);
+            self.process_pow20_transfers(tx, network, height);
         }
 
         // Then process new inscriptions
-        let seq_bytes = GLOBAL_SEQUENCE_COUNTER.get();
-        if seq_bytes.is_empty() { return; }
-        let max_seq = u32::from_le_bytes(seq_bytes[..4].try_into().unwrap_or([0; 4]));
+        let max_seq = GLOBAL_SEQUENCE_COUNTER.get().unwrap_or(0);
 
         for seq in 1..=max_seq {
-            let entry_bytes = SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq.to_le_bytes().to_vec()).get();
-            if entry_bytes.is_empty() { continue; }
-            let entry = match InscriptionEntry::from_bytes(&entry_bytes) {
-                Ok(e) => e,
-                Err(_) => continue,
-            };
+            let Some(entry) = entries::load_or_report(seq, height) else { continue };
             if entry.height != height { continue; }
             if entry.number < 0 { continue; }
 
@@ -80,7 +72,7 @@ impl Pow20Indexer {
             }
 
             let inscription_id_str = entry.id.to_string();
-            let content_bytes = INSCRIPTION_CONTENT.select(&inscription_id_str.as_bytes().to_vec()).get();
+            let content_bytes = InscriptionContentTable::new().get(&entry.id).unwrap_or_default();
             if content_bytes.is_empty() { continue; }
 
             let content_str = match std::str::from_utf8(&content_bytes) {
@@ -119,10 +111,11 @@ impl Pow20Indexer {
             match op {
                 "deploy" => self.process_deploy(&json, &inscription_id_str, height),
                 "mint" => self.process_mint(&json, &inscription_id_str, &owner, height),
-                "transfer" => self.process_transfer(&json, &inscription_id_str, &owner),
+                "transfer" => self.process_transfer(&json, &entry.id, &owner),
                 _ => {}
             }
         }
+        diagnostics::add(height, "pow20", BlockDiagnostics::default());
     }
 
     fn process_deploy(&self, json: &serde_json::Value, inscription_id: &str, _height: u32) {
@@ -147,8 +140,7 @@ impl Pow20Indexer {
             .unwrap_or(POW20_STARTING_BLOCK);
 
         // First deploy wins
-        let existing = POW20_TICKERS.select(&ticker.to_lowercase().as_bytes().to_vec()).get();
-        if !existing.is_empty() { return; }
+        if POW20_TICKERS.contains(ticker) { return; }
 
         let ticker_entry = Pow20Ticker {
             name: ticker.to_lowercase(),
@@ -157,8 +149,7 @@ impl Pow20Indexer {
             deploy_inscription_id: inscription_id.to_string(),
         };
 
-        let bytes = serde_json::to_vec(&ticker_entry).unwrap_or_default();
-        POW20_TICKERS.select(&ticker.to_lowercase().as_bytes().to_vec()).set(Arc::new(bytes));
+        POW20_TICKERS.set(ticker, &ticker_entry);
     }
 
     fn process_mint(&self, json: &serde_json::Value, inscription_id: &str, owner: &str, height: u32) {
@@ -176,12 +167,7 @@ impl Pow20Indexer {
         };
 
         // Load ticker
-        let ticker_data = POW20_TICKERS.select(&ticker.as_bytes().to_vec()).get();
-        if ticker_data.is_empty() { return; }
-        let mut ticker_entry: Pow20Ticker = match serde_json::from_slice(&ticker_data) {
-            Ok(t) => t,
-            Err(_) => return,
-        };
+        let Some(mut ticker_entry) = POW20_TICKERS.get(&ticker) else { return };
 
         // Check starting block
         if height < ticker_entry.starting_block_height { return; }
@@ -197,24 +183,16 @@ impl Pow20Indexer {
 
         // Mint successful
         ticker_entry.current_supply += amount;
-        let ticker_bytes = serde_json::to_vec(&ticker_entry).unwrap_or_default();
-        POW20_TICKERS.select(&ticker.as_bytes().to_vec()).set(Arc::new(ticker_bytes));
+        POW20_TICKERS.set(&ticker, &ticker_entry);
 
         // Update balance
-        let key = format!("{}:{}", owner, ticker);
-        let balance_data = POW20_BALANCES.select(&key.as_bytes().to_vec()).get();
-        let mut balance: Pow20Balance = if balance_data.is_empty() {
-            Pow20Balance::new(ticker.clone())
-        } else {
-            serde_json::from_slice(&balance_data).unwrap_or_else(|_| Pow20Balance::new(ticker.clone()))
-        };
+        let mut balance = balance(owner, &ticker).unwrap_or_else(|| Pow20Balance::new(ticker.clone()));
         balance.total_balance += amount;
         balance.available_balance += amount;
-        let balance_bytes = serde_json::to_vec(&balance).unwrap_or_default();
-        POW20_BALANCES.select(&key.as_bytes().to_vec()).set(Arc::new(balance_bytes));
+        set_balance(owner, &ticker, &balance);
     }
 
-    fn process_transfer(&self, json: &serde_json::Value, inscription_id: &str, owner: &str) {
+    fn process_transfer(&self, json: &serde_json::Value, inscription_id: &InscriptionId, owner: &str) {
         let ticker = match json.get("tick").and_then(|v| v.as_str()) {
             Some(t) => t.to_lowercase(),
             None => return,
@@ -224,45 +202,29 @@ impl Pow20Indexer {
             None => return,
         };
 
-        let key = format!("{}:{}", owner, ticker);
-        let balance_data = POW20_BALANCES.select(&key.as_bytes().to_vec()).get();
-        if balance_data.is_empty() { return; }
-        let mut balance: Pow20Balance = match serde_json::from_slice(&balance_data) {
-            Ok(b) => b,
-            Err(_) => return,
-        };
+        let Some(mut balance) = balance(owner, &ticker) else { return };
         if balance.available_balance < amount { return; }
 
         balance.available_balance -= amount;
-        let balance_bytes = serde_json::to_vec(&balance).unwrap_or_default();
-        POW20_BALANCES.select(&key.as_bytes().to_vec()).set(Arc::new(balance_bytes));
+        set_balance(owner, &ticker, &balance);
 
         let transfer_info = Pow20TransferInfo { ticker, amount, sender: owner.to_string() };
-        let transfer_bytes = serde_json::to_vec(&transfer_info).unwrap_or_default();
-        POW20_TRANSFERABLE.select(&inscription_id.as_bytes().to_vec()).set(Arc::new(transfer_bytes));
+        POW20_TRANSFERABLE.set(inscription_id, &transfer_info);
     }
 
-    fn process_pow20_transfers(&self, tx: &Transaction, network: Network) {
+    fn process_pow20_transfers(&self, tx: &Transaction, network: Network, height: u32) {
         for input in &tx.input {
-            let outpoint_bytes: Vec<u8> = input.previous_output.txid.as_byte_array()
-                .iter().chain(input.previous_output.vout.to_le_bytes().iter()).copied().collect();
-            let inscription_sequences = OUTPOINT_TO_INSCRIPTIONS.select(&outpoint_bytes).get_list();
+            let inscription_sequences = OUTPOINT_TO_INSCRIPTIONS.get_list(&input.previous_output);
             if inscription_sequences.is_empty() { continue; }
 
-            for seq_bytes in inscription_sequences {
-                let entry_bytes = SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq_bytes).get();
-                if entry_bytes.is_empty() { continue; }
-                if let Ok(entry) = InscriptionEntry::from_bytes(&entry_bytes) {
-                    let inscription_id_str = entry.id.to_string();
-                    let transfer_data = POW20_TRANSFERABLE.select(&inscription_id_str.as_bytes().to_vec()).get();
-                    if transfer_data.is_empty() { continue; }
-
-                    if let Ok(transfer_info) = serde_json::from_slice::<Pow20TransferInfo>(&transfer_data) {
-                        if let Some(first_output) = tx.output.get(0) {
-                            if let Some(new_owner) = get_address_from_txout(first_output, network) {
-                                self.claim_pow20_transfer(&new_owner.to_string(), &transfer_info);
-                                POW20_TRANSFERABLE.select(&inscription_id_str.as_bytes().to_vec()).set(Arc::new(vec![]));
-                            }
+            for sequence in inscription_sequences {
+                if let Some(entry) = entries::load_or_report(sequence, height) {
+                    let Some(transfer_info) = transferable(&entry.id) else { continue };
+
+                    if let Some(first_output) = tx.output.get(0) {
+                        if let Some(new_owner) = get_address_from_txout(first_output, network) {
+                            self.claim_pow20_transfer(&new_owner.to_string(), &transfer_info);
+                            delete_transferable(&entry.id);
                         }
                     }
                 }
@@ -272,27 +234,17 @@ impl Pow20Indexer {
 
     fn claim_pow20_transfer(&self, new_owner: &str, transfer_info: &Pow20TransferInfo) {
         // Credit new owner
-        let key = format!("{}:{}", new_owner, transfer_info.ticker);
-        let balance_data = POW20_BALANCES.select(&key.as_bytes().to_vec()).get();
-        let mut balance: Pow20Balance = if balance_data.is_empty() {
-            Pow20Balance::new(transfer_info.ticker.clone())
-        } else {
-            serde_json::from_slice(&balance_data).unwrap_or_else(|_| Pow20Balance::new(transfer_info.ticker.clone()))
-        };
+        let ticker = &transfer_info.ticker;
+        let mut balance = balance(new_owner, ticker)
+            .unwrap_or_else(|| Pow20Balance::new(ticker.clone()));
         balance.total_balance += transfer_info.amount;
         balance.available_balance += transfer_info.amount;
-        let balance_bytes = serde_json::to_vec(&balance).unwrap_or_default();
-        POW20_BALANCES.select(&key.as_bytes().to_vec()).set(Arc::new(balance_bytes));
+        set_balance(new_owner, ticker, &balance);
 
         // Debit sender
-        let sender_key = format!("{}:{}", transfer_info.sender, transfer_info.ticker);
-        let sender_data = POW20_BALANCES.select(&sender_key.as_bytes().to_vec()).get();
-        if !sender_data.is_empty() {
-            if let Ok(mut sender_balance) = serde_json::from_slice::<Pow20Balance>(&sender_data) {
-                sender_balance.total_balance = sender_balance.total_balance.saturating_sub(transfer_info.amount);
-                let sender_bytes = serde_json::to_vec(&sender_balance).unwrap_or_default();
-                POW20_BALANCES.select(&sender_key.as_bytes().to_vec()).set(Arc::new(sender_bytes));
-            }
+        if let Some(mut sender_balance) = crate::tables::balance(&transfer_info.sender, ticker) {
+            sender_balance.total_balance = sender_balance.total_balance.saturating_sub(transfer_info.amount);
+            set_balance(&transfer_info.sender, ticker, &sender_balance);
         }
     }
 }
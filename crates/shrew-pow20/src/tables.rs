@@ -1,9 +1,46 @@
-use metashrew_core::index_pointer::IndexPointer;
-use metashrew_support::index_pointer::KeyValuePointer;
+use crate::pow20_indexer::{Pow20Balance, Pow20Ticker, Pow20TransferInfo};
+use shrew_support::inscription::InscriptionId;
+use shrew_support::table::{Json, PrefixTable, Table, Ticker};
 
 lazy_static::lazy_static! {
-    pub static ref POW20_TICKERS: IndexPointer = IndexPointer::from_keyword("/pow20/tickers/");
-    pub static ref POW20_BALANCES: IndexPointer = IndexPointer::from_keyword("/pow20/balances/");
-    pub static ref POW20_EVENTS: IndexPointer = IndexPointer::from_keyword("/pow20/events/");
-    pub static ref POW20_TRANSFERABLE: IndexPointer = IndexPointer::from_keyword("/pow20/transferable/");
+    pub static ref POW20_TICKERS: Table<Ticker, Json<Pow20Ticker>> = Table::new("/pow20/tickers/");
+    /// Owner address -> ticker -> balance
+    pub static ref POW20_BALANCES: PrefixTable<str, Ticker, Json<Pow20Balance>> = PrefixTable::new("/pow20/balances/");
+    pub static ref POW20_EVENTS: Table<u32, Vec<u8>> = Table::new("/pow20/events/");
+    pub static ref POW20_TRANSFERABLE: Table<InscriptionId, Json<Pow20TransferInfo>> = Table::new("/pow20/transferable/");
+    /// Balances keyed by `{owner}:{ticker}` and transfers keyed by inscription
+    /// id string, as the original layout stored them under the same keywords.
+    /// Read as a fallback, and dropped when the record is next written.
+    pub static ref LEGACY_POW20_BALANCES: Table<str, Json<Pow20Balance>> = Table::new("/pow20/balances/");
+    pub static ref LEGACY_POW20_TRANSFERABLE: Table<str, Json<Pow20TransferInfo>> = Table::new("/pow20/transferable/");
+}
+
+/// Balance of `owner` in `ticker`, including one still in the original layout
+pub fn balance(owner: &str, ticker: &str) -> Option<Pow20Balance> {
+    POW20_BALANCES.get(owner, ticker).or_else(|| LEGACY_POW20_BALANCES.get(&legacy_balance_key(owner, ticker)))
+}
+
+/// Store `owner`'s balance in `ticker`, dropping its copy in the original layout
+pub fn set_balance(owner: &str, ticker: &str, balance: &Pow20Balance) {
+    POW20_BALANCES.set(owner, ticker, balance);
+    let legacy_key = legacy_balance_key(owner, ticker);
+    if LEGACY_POW20_BALANCES.contains(&legacy_key) {
+        LEGACY_POW20_BALANCES.delete(&legacy_key);
+    }
+}
+
+/// Unclaimed transfer inscribed as `inscription_id`, including one still in
+/// the original layout
+pub fn transferable(inscription_id: &InscriptionId) -> Option<Pow20TransferInfo> {
+    POW20_TRANSFERABLE.get(inscription_id).or_else(|| LEGACY_POW20_TRANSFERABLE.get(&inscription_id.to_string()))
+}
+
+/// Drop a claimed transfer, in either layout
+pub fn delete_transferable(inscription_id: &InscriptionId) {
+    POW20_TRANSFERABLE.delete(inscription_id);
+    LEGACY_POW20_TRANSFERABLE.delete(&inscription_id.to_string());
+}
+
+fn legacy_balance_key(owner: &str, ticker: &str) -> String {
+    format!("{}:{}", owner, ticker.to_lowercase())
 }
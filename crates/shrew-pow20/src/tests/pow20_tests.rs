@@ -1,11 +1,10 @@
 use wasm_bindgen_test::wasm_bindgen_test as test;
-use crate::tables::{POW20_TICKERS, POW20_BALANCES};
+use crate::tables::{self, POW20_TICKERS, POW20_BALANCES, LEGACY_POW20_BALANCES};
 use crate::pow20_indexer::{Pow20Ticker, Pow20Balance};
-use shrew_test_helpers::state::clear;
+use shrew_test_helpers::state::{clear, get_test_address};
 use shrew_test_helpers::indexing::{index_ord_block, index_pow20_block};
 use shrew_test_helpers::blocks::{create_coinbase_transaction, create_block_with_txs};
 use shrew_test_helpers::transactions::create_inscription_transaction;
-use metashrew_support::index_pointer::KeyValuePointer;
 use bitcoin::{OutPoint, Txid};
 use std::str::FromStr;
 
@@ -44,17 +43,12 @@ fn mint_ticker_with_outpoint(ticker: &str, amt: &str, nonce: &str, height: u32,
 
 /// Helper: read a ticker entry from state
 fn read_ticker(ticker: &str) -> Option<Pow20Ticker> {
-    let data = POW20_TICKERS.select(&ticker.to_lowercase().as_bytes().to_vec()).get();
-    if data.is_empty() { return None; }
-    serde_json::from_slice(&data).ok()
+    POW20_TICKERS.get(ticker)
 }
 
 /// Helper: read a balance entry from state
 fn read_balance(owner: &str, ticker: &str) -> Option<Pow20Balance> {
-    let key = format!("{}:{}", owner, ticker);
-    let data = POW20_BALANCES.select(&key.as_bytes().to_vec()).get();
-    if data.is_empty() { return None; }
-    serde_json::from_slice(&data).ok()
+    POW20_BALANCES.get(owner, ticker)
 }
 
 // ---------------------------------------------------------------------------
@@ -235,6 +229,24 @@ fn test_pow20_transfer_inscribe_reduces_available() {
 // Leading zero bits function tests
 // ---------------------------------------------------------------------------
 
+#[test]
+fn test_pow20_balance_in_original_layout_is_carried_over() {
+    clear();
+    deploy_ticker_with_outpoint("olds", "21000", "1000", 0, 0, 1);
+    let owner = get_test_address(0).to_string();
+    let legacy_key = format!("{}:olds", owner);
+    let mut legacy = Pow20Balance::new("olds".to_string());
+    legacy.total_balance = 500;
+    legacy.available_balance = 500;
+    LEGACY_POW20_BALANCES.set(&legacy_key, &legacy);
+    assert_eq!(tables::balance(&owner, "OLDS").unwrap().total_balance, 500);
+
+    mint_ticker_with_outpoint("olds", "100", "anynonce", 1, 2);
+    let balance = read_balance(&owner, "olds").unwrap();
+    assert_eq!((balance.total_balance, balance.available_balance), (600, 600));
+    assert!(!LEGACY_POW20_BALANCES.contains(&legacy_key));
+}
+
 #[test]
 fn test_leading_zero_bits_boundary_cases() {
     clear();
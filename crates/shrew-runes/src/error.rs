@@ -0,0 +1,37 @@
+//! Errors of the runes views.
+
+use shrew_support::error::{CodedError, ErrorCode};
+use shrew_support::inscription::EntryDecodeError;
+use shrew_support::pagination::PaginationError;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum RunesError {
+    /// The request leaves out a field the view needs
+    #[error("request must specify {0}")]
+    MissingField(&'static str),
+    /// A request field is present but malformed
+    #[error("invalid {field}: {reason}")]
+    InvalidField { field: &'static str, reason: String },
+    #[error(transparent)]
+    Pagination(#[from] PaginationError),
+    /// The inscription entry a rune links to could not be decoded
+    #[error("inscription entry {sequence}: {source}")]
+    EntryDecode { sequence: u32, source: EntryDecodeError },
+}
+
+impl RunesError {
+    pub(crate) fn invalid_txid(error: impl std::fmt::Display) -> Self {
+        RunesError::InvalidField { field: "txid", reason: error.to_string() }
+    }
+}
+
+impl CodedError for RunesError {
+    fn code(&self) -> ErrorCode {
+        match self {
+            RunesError::MissingField(_) | RunesError::InvalidField { .. } | RunesError::Pagination(_) => {
+                ErrorCode::InvalidArgument
+            }
+            RunesError::EntryDecode { .. } => ErrorCode::DecodeFailure,
+        }
+    }
+}
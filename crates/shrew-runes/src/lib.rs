@@ -1,9 +1,10 @@
 #[cfg(feature = "entrypoint")]
-use bitcoin::{Block, consensus::deserialize};
-use serde_json::{from_slice, to_vec};
+use shrew_support::diagnostics;
+use shrew_support::wire::serve;
 
 pub mod rune_indexer;
 pub mod balance_sheet;
+pub mod error;
 pub mod tables;
 pub mod view;
 
@@ -16,11 +17,11 @@ pub use rune_indexer::RuneIndexer;
 #[cfg(feature = "entrypoint")]
 #[metashrew_core::main]
 fn main_logic(height: u32, block_data: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
-    if let Ok(block) = deserialize::<Block>(block_data) {
+    if let Some(block) = diagnostics::decode_block(height, block_data) {
         // First run inscription indexer for block metadata
-        let mut inscription_indexer = shrew_ord::indexer::InscriptionIndexer::new();
-        let _ = inscription_indexer.load_state();
-        let _ = inscription_indexer.index_block(&block, height);
+        if !shrew_ord::indexer::InscriptionIndexer::index_or_report(&block, height) {
+            return Ok(());
+        }
 
         // Then run rune indexer
         let mut rune_indexer = RuneIndexer::new();
@@ -31,20 +32,27 @@ fn main_logic(height: u32, block_data: &[u8]) -> Result<(), Box<dyn std::error::
 
 #[metashrew_core::view]
 pub fn getrune(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetRuneRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_rune(&req)?)?)
+    serve(input, view::get_rune)
 }
 
 #[metashrew_core::view]
 pub fn getrunebalance(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetRuneBalanceRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_rune_balance(&req)?)?)
+    serve(input, view::get_rune_balance)
 }
 
 #[metashrew_core::view]
 pub fn getruneevents(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetRuneEventsRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_rune_events(&req)?)?)
+    serve(input, view::get_rune_events)
+}
+
+#[metashrew_core::view]
+pub fn getruneinscription(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    serve(input, view::get_rune_inscription)
+}
+
+#[metashrew_core::view]
+pub fn getinscriptionrune(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    serve(input, view::get_inscription_rune)
 }
 
 #[cfg(test)]
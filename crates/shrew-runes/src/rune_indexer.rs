@@ -2,11 +2,15 @@ use crate::balance_sheet::{BalanceSheet, RuneId};
 use crate::tables::*;
 use bitcoin::{Block, Transaction, OutPoint};
 use bitcoin_hashes::Hash;
-use metashrew_support::index_pointer::KeyValuePointer;
 use ordinals::{Artifact, Runestone};
+use shrew_ord::entries;
+use shrew_ord::tables::{
+    INSCRIPTION_ID_TO_SEQUENCE, INSCRIPTION_TO_RUNE, RUNE_TO_INSCRIPTIONS, RUNE_TO_TAGGED_INSCRIPTION, TXID_TO_INSCRIPTIONS,
+};
 use serde::{Serialize, Deserialize};
+use shrew_support::diagnostics::{self, BlockDiagnostics};
+use shrew_support::inscription::InscriptionId;
 use std::collections::HashMap;
-use std::sync::Arc;
 
 /// Metadata for a deployed rune
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -66,24 +70,26 @@ impl RuneIndexer {
 
         // Runes only activate at height 840000
         if height < shrew_support::constants::RUNES_ACTIVATION_HEIGHT {
+            diagnostics::add(height, "runes", BlockDiagnostics::default());
             return;
         }
 
         let mut events = Vec::new();
+        let mut counts = BlockDiagnostics::default();
 
         for (tx_index, tx) in block.txdata.iter().enumerate() {
-            let tx_events = self.index_transaction(tx, tx_index as u32, height);
+            let tx_events = self.index_transaction(tx, tx_index as u32, height, &mut counts);
             events.extend(tx_events);
         }
 
         // Store events for this height
         if !events.is_empty() {
-            let events_bytes = bincode::serialize(&events).unwrap_or_default();
-            HEIGHT_TO_RUNE_EVENTS.select(&height.to_le_bytes().to_vec()).set(Arc::new(events_bytes));
+            HEIGHT_TO_RUNE_EVENTS.set(&height, &events);
         }
+        diagnostics::add(height, "runes", counts);
     }
 
-    fn index_transaction(&self, tx: &Transaction, tx_index: u32, height: u32) -> Vec<RuneEvent> {
+    fn index_transaction(&self, tx: &Transaction, tx_index: u32, height: u32, counts: &mut BlockDiagnostics) -> Vec<RuneEvent> {
         let mut events = Vec::new();
         let txid = *tx.compute_txid().as_byte_array();
 
@@ -97,6 +103,7 @@ impl RuneIndexer {
 
         match artifact {
             Artifact::Cenotaph(cenotaph) => {
+                counts.cenotaphs += 1;
                 // Cenotaph: all input runes are burned
                 let input_sheet = self.collect_input_runes(tx);
                 for (rune_id, amount) in &input_sheet.balances {
@@ -115,6 +122,7 @@ impl RuneIndexer {
                 }
             }
             Artifact::Runestone(runestone) => {
+                counts.runestones += 1;
                 // Collect input runes
                 let input_sheet = self.collect_input_runes(tx);
                 for (rune_id, amount) in &input_sheet.balances {
@@ -134,6 +142,9 @@ impl RuneIndexer {
                 if let Some(etching) = runestone.etching {
                     let new_rune_id = RuneId::new(height as u64, tx_index);
                     let entry = self.process_etching(etching, &runestone, new_rune_id, tx, tx_index, height);
+                    if let Some(rune) = etching.rune {
+                        self.link_commitment_inscription(tx, rune, new_rune_id);
+                    }
 
                     // Credit premine to unallocated
                     if entry.premine > 0 {
@@ -218,9 +229,7 @@ impl RuneIndexer {
                 for (vout, sheet) in &output_sheets {
                     if sheet.is_empty() { continue; }
                     let outpoint = OutPoint { txid: tx.compute_txid(), vout: *vout };
-                    let outpoint_bytes: Vec<u8> = outpoint.txid.as_byte_array().iter()
-                        .chain(outpoint.vout.to_le_bytes().iter()).copied().collect();
-                    RUNE_BALANCES_BY_OUTPOINT.select(&outpoint_bytes).set(Arc::new(sheet.to_bytes()));
+                    RUNE_BALANCES_BY_OUTPOINT.set(&outpoint, sheet);
 
                     for (rune_id, amount) in &sheet.balances {
                         events.push(RuneEvent {
@@ -242,13 +251,8 @@ impl RuneIndexer {
     fn collect_input_runes(&self, tx: &Transaction) -> BalanceSheet {
         let mut sheet = BalanceSheet::new();
         for input in &tx.input {
-            let outpoint_bytes: Vec<u8> = input.previous_output.txid.as_byte_array().iter()
-                .chain(input.previous_output.vout.to_le_bytes().iter()).copied().collect();
-            let data = RUNE_BALANCES_BY_OUTPOINT.select(&outpoint_bytes).get();
-            if !data.is_empty() {
-                if let Some(input_sheet) = BalanceSheet::from_bytes(&data) {
-                    sheet.merge(&input_sheet);
-                }
+            if let Some(input_sheet) = RUNE_BALANCES_BY_OUTPOINT.get(&input.previous_output) {
+                sheet.merge(&input_sheet);
             }
         }
         sheet
@@ -304,39 +308,55 @@ impl RuneIndexer {
         };
 
         // Store the rune entry
-        let entry_bytes = bincode::serialize(&entry).unwrap_or_default();
-        RUNE_ID_TO_ENTRY.select(&rune_id.to_bytes()).set(Arc::new(entry_bytes));
+        RUNE_ID_TO_ENTRY.set(&rune_id, &entry);
 
         // Store name -> id mapping
-        RUNE_NAME_TO_ID.select(&name.to_uppercase().as_bytes().to_vec()).set(Arc::new(rune_id.to_bytes()));
+        RUNE_NAME_TO_ID.set(&name.to_uppercase(), &rune_id);
 
         // Store etching -> rune id mapping
-        let etching_bytes = tx.compute_txid().as_byte_array().to_vec();
-        ETCHING_TO_RUNE_ID.select(&etching_bytes).set(Arc::new(rune_id.to_bytes()));
-        RUNE_ID_TO_ETCHING.select(&rune_id.to_bytes()).set(Arc::new(etching_bytes));
+        let etching_txid = tx.compute_txid();
+        ETCHING_TO_RUNE_ID.set(&etching_txid, &rune_id);
+        RUNE_ID_TO_ETCHING.set(&rune_id, &etching_txid);
 
         // Store mint terms if applicable
         if let Some(ref t) = terms {
             if let Some(cap) = t.cap {
-                RUNE_CAP.select(&rune_id.to_bytes()).set(Arc::new(cap.to_le_bytes().to_vec()));
-                RUNE_MINTS_REMAINING.select(&rune_id.to_bytes()).set(Arc::new(cap.to_le_bytes().to_vec()));
+                RUNE_CAP.set(&rune_id, &cap);
+                RUNE_MINTS_REMAINING.set(&rune_id, &cap);
             }
         }
 
         entry
     }
 
+    /// Link a newly etched rune to the first inscription revealed by the
+    /// etching transaction, `i0`, as ord does. The inscription of that
+    /// transaction whose rune tag names the rune, the first if several do, is
+    /// indexed separately.
+    fn link_commitment_inscription(&self, tx: &Transaction, rune: ordinals::Rune, rune_id: RuneId) {
+        let txid = tx.compute_txid();
+        if let Some(sequence) = INSCRIPTION_ID_TO_SEQUENCE.get(&InscriptionId::new(txid, 0)) {
+            RUNE_TO_INSCRIPTIONS.set(&rune_id, &sequence);
+            INSCRIPTION_TO_RUNE.set(&sequence, &rune_id);
+        }
+        for sequence in TXID_TO_INSCRIPTIONS.get_list(&txid) {
+            let Some(entry) = entries::load_or_report(sequence, self.height) else { continue };
+            if entry.rune == Some(rune) {
+                RUNE_TO_TAGGED_INSCRIPTION.set(&rune_id, &sequence);
+                return;
+            }
+        }
+    }
+
     fn process_etching_name(&self, rune: ordinals::Rune, _tx: &Transaction, tx_index: u32, height: u32) {
         let rune_id = RuneId::new(height as u64, tx_index);
         let name = rune.to_string();
-        RUNE_NAME_TO_ID.select(&name.to_uppercase().as_bytes().to_vec()).set(Arc::new(rune_id.to_bytes()));
+        RUNE_NAME_TO_ID.set(&name.to_uppercase(), &rune_id);
     }
 
     fn try_mint(&self, rune_id: RuneId, height: u32) -> Option<u128> {
         // Load rune entry
-        let entry_bytes = RUNE_ID_TO_ENTRY.select(&rune_id.to_bytes()).get();
-        if entry_bytes.is_empty() { return None; }
-        let mut entry: RuneEntry = bincode::deserialize(&entry_bytes).ok()?;
+        let mut entry = RUNE_ID_TO_ENTRY.get(&rune_id)?;
 
         let terms = entry.terms.as_ref()?;
         let mint_amount = terms.amount?;
@@ -358,20 +378,15 @@ impl RuneIndexer {
         }
 
         // Check remaining supply
-        let remaining_bytes = RUNE_MINTS_REMAINING.select(&rune_id.to_bytes()).get();
-        if !remaining_bytes.is_empty() {
-            let remaining = u128::from_le_bytes(remaining_bytes[..16].try_into().ok()?);
+        if let Some(remaining) = RUNE_MINTS_REMAINING.get(&rune_id) {
             if remaining == 0 { return None; }
-            RUNE_MINTS_REMAINING.select(&rune_id.to_bytes()).set(
-                Arc::new((remaining - 1).to_le_bytes().to_vec())
-            );
+            RUNE_MINTS_REMAINING.set(&rune_id, &(remaining - 1));
         }
 
         // Update entry
         entry.mints += 1;
         entry.supply += mint_amount;
-        let updated_bytes = bincode::serialize(&entry).unwrap_or_default();
-        RUNE_ID_TO_ENTRY.select(&rune_id.to_bytes()).set(Arc::new(updated_bytes));
+        RUNE_ID_TO_ENTRY.set(&rune_id, &entry);
 
         Some(mint_amount)
     }
@@ -1,10 +1,8 @@
 use wasm_bindgen_test::wasm_bindgen_test as test;
 use crate::balance_sheet::{BalanceSheet, RuneId};
-use crate::rune_indexer::{RuneEntry, RuneEvent};
+use crate::rune_indexer::RuneEvent;
 use crate::tables::*;
 use bitcoin::{OutPoint, Transaction, TxIn, TxOut, ScriptBuf, Sequence, Witness};
-use bitcoin_hashes::Hash;
-use metashrew_support::index_pointer::KeyValuePointer;
 use ordinals::{Runestone, Etching, Rune, Terms, Edict, RuneId as OrdRuneId};
 use shrew_test_helpers::state::{clear, get_test_address};
 use shrew_test_helpers::blocks::{create_coinbase_transaction, create_block_with_txs, create_block_with_coinbase_tx};
@@ -37,14 +35,7 @@ fn make_runestone_tx(runestone: &Runestone) -> Transaction {
 
 /// Helper: read balance sheet from an outpoint
 fn read_balance(outpoint: &OutPoint) -> BalanceSheet {
-    let outpoint_bytes: Vec<u8> = outpoint.txid.as_byte_array().iter()
-        .chain(outpoint.vout.to_le_bytes().iter()).copied().collect();
-    let data = RUNE_BALANCES_BY_OUTPOINT.select(&outpoint_bytes).get();
-    if data.is_empty() {
-        BalanceSheet::new()
-    } else {
-        BalanceSheet::from_bytes(&data).unwrap_or_default()
-    }
+    RUNE_BALANCES_BY_OUTPOINT.get(outpoint).unwrap_or_default()
 }
 
 // ---- Activation tests ----
@@ -55,8 +46,8 @@ fn test_no_runes_before_activation() {
     let block = create_block_with_coinbase_tx(100);
     index_runes_block(&block, 100);
 
-    let events_data = HEIGHT_TO_RUNE_EVENTS.select(&100u32.to_le_bytes().to_vec()).get();
-    assert!(events_data.is_empty(), "No rune events should exist before activation height");
+    let events_data = HEIGHT_TO_RUNE_EVENTS.get(&100u32);
+    assert!(events_data.is_none(), "No rune events should exist before activation height");
 }
 
 // ---- Etching tests ----
@@ -82,10 +73,7 @@ fn test_etching_creates_rune() {
     index_runes_block(&block, height);
 
     let rune_id = RuneId::new(height as u64, 1);
-    let entry_bytes = RUNE_ID_TO_ENTRY.select(&rune_id.to_bytes()).get();
-    assert!(!entry_bytes.is_empty(), "RuneEntry should be stored after etching");
-
-    let entry: RuneEntry = bincode::deserialize(&entry_bytes).expect("should deserialize RuneEntry");
+    let entry = RUNE_ID_TO_ENTRY.get(&rune_id).expect("RuneEntry should be stored after etching");
     assert_eq!(entry.id, rune_id);
     assert_eq!(entry.name, "TESTRUNE");
     assert_eq!(entry.divisibility, 8);
@@ -149,8 +137,7 @@ fn test_etching_with_terms() {
     index_runes_block(&block, height);
 
     let rune_id = RuneId::new(height as u64, 1);
-    let entry_bytes = RUNE_ID_TO_ENTRY.select(&rune_id.to_bytes()).get();
-    let entry: RuneEntry = bincode::deserialize(&entry_bytes).expect("should deserialize");
+    let entry = RUNE_ID_TO_ENTRY.get(&rune_id).expect("RuneEntry should be stored");
     assert!(entry.terms.is_some(), "Terms should be stored");
     let stored_terms = entry.terms.unwrap();
     assert_eq!(stored_terms.amount, Some(100));
@@ -159,14 +146,11 @@ fn test_etching_with_terms() {
     assert_eq!(stored_terms.height_end, Some(850000));
 
     // Cap should be stored
-    let cap_bytes = RUNE_CAP.select(&rune_id.to_bytes()).get();
-    assert!(!cap_bytes.is_empty(), "RUNE_CAP should be set");
-    let cap = u128::from_le_bytes(cap_bytes[..16].try_into().unwrap());
+    let cap = RUNE_CAP.get(&rune_id).expect("RUNE_CAP should be set");
     assert_eq!(cap, 1000);
 
     // Mints remaining should equal cap
-    let remaining_bytes = RUNE_MINTS_REMAINING.select(&rune_id.to_bytes()).get();
-    let remaining = u128::from_le_bytes(remaining_bytes[..16].try_into().unwrap());
+    let remaining = RUNE_MINTS_REMAINING.get(&rune_id).unwrap();
     assert_eq!(remaining, 1000);
 }
 
@@ -191,9 +175,7 @@ fn test_etching_name_stored() {
     index_runes_block(&block, height);
 
     let rune_id = RuneId::new(height as u64, 1);
-    let stored_id_bytes = RUNE_NAME_TO_ID.select(&"NAMETEST".as_bytes().to_vec()).get();
-    assert!(!stored_id_bytes.is_empty(), "RUNE_NAME_TO_ID should be populated");
-    let stored_id = RuneId::from_bytes(&stored_id_bytes).expect("should parse RuneId");
+    let stored_id = RUNE_NAME_TO_ID.get("NAMETEST").expect("RUNE_NAME_TO_ID should be populated");
     assert_eq!(stored_id, rune_id);
 }
 
@@ -218,8 +200,7 @@ fn test_etching_divisibility_and_symbol() {
     index_runes_block(&block, height);
 
     let rune_id = RuneId::new(height as u64, 1);
-    let entry_bytes = RUNE_ID_TO_ENTRY.select(&rune_id.to_bytes()).get();
-    let entry: RuneEntry = bincode::deserialize(&entry_bytes).expect("should deserialize");
+    let entry = RUNE_ID_TO_ENTRY.get(&rune_id).expect("RuneEntry should be stored");
     assert_eq!(entry.divisibility, 18);
     assert_eq!(entry.symbol, Some('%'));
     assert_eq!(entry.name, Rune(12345678).to_string());
@@ -310,8 +291,7 @@ fn test_mint_increments_count() {
         index_runes_block(&mint_block, height + i);
     }
 
-    let entry_bytes = RUNE_ID_TO_ENTRY.select(&rune_id.to_bytes()).get();
-    let entry: RuneEntry = bincode::deserialize(&entry_bytes).expect("should deserialize");
+    let entry = RUNE_ID_TO_ENTRY.get(&rune_id).expect("RuneEntry should be stored");
     assert_eq!(entry.mints, 3, "Mints counter should be 3 after 3 mints");
     assert_eq!(entry.supply, 300, "Supply should be 3 * 100 = 300");
 }
@@ -696,11 +676,8 @@ fn test_events_stored_per_height() {
     let block = create_block_with_txs(vec![create_coinbase_transaction(height), tx]);
     index_runes_block(&block, height);
 
-    let events_data = HEIGHT_TO_RUNE_EVENTS.select(&height.to_le_bytes().to_vec()).get();
-    assert!(!events_data.is_empty(), "HEIGHT_TO_RUNE_EVENTS should contain data after etching");
-
-    let events: Vec<RuneEvent> =
-        bincode::deserialize(&events_data).expect("events should deserialize");
+    let events: Vec<RuneEvent> = HEIGHT_TO_RUNE_EVENTS.get(&height)
+        .expect("HEIGHT_TO_RUNE_EVENTS should contain data after etching");
     assert!(!events.is_empty(), "Should have at least one event (premine allocation)");
 
     // Check for the premine allocation event (event_type=1)
@@ -733,15 +710,12 @@ fn test_etching_to_rune_id_mapping() {
 
     let rune_id = RuneId::new(height as u64, 1);
     let etch_tx = &block.txdata[1];
-    let etching_bytes = etch_tx.txid().as_byte_array().to_vec();
+    let etching_txid = etch_tx.txid();
 
     // ETCHING_TO_RUNE_ID should map the etching txid to the rune id
-    let stored_id_bytes = ETCHING_TO_RUNE_ID.select(&etching_bytes).get();
-    assert!(!stored_id_bytes.is_empty(), "ETCHING_TO_RUNE_ID should be populated");
-    let stored_id = RuneId::from_bytes(&stored_id_bytes).expect("should parse RuneId");
+    let stored_id = ETCHING_TO_RUNE_ID.get(&etching_txid).expect("ETCHING_TO_RUNE_ID should be populated");
     assert_eq!(stored_id, rune_id);
 
     // And the reverse mapping
-    let reverse_bytes = RUNE_ID_TO_ETCHING.select(&rune_id.to_bytes()).get();
-    assert_eq!(reverse_bytes.as_ref(), &etching_bytes, "RUNE_ID_TO_ETCHING should point back to etching txid");
+    assert_eq!(RUNE_ID_TO_ETCHING.get(&rune_id), Some(etching_txid), "RUNE_ID_TO_ETCHING should point back to etching txid");
 }
@@ -1,10 +1,8 @@
 use wasm_bindgen_test::wasm_bindgen_test as test;
 use crate::balance_sheet::{BalanceSheet, RuneId};
-use crate::rune_indexer::RuneEntry;
 use crate::tables::*;
 use bitcoin::{OutPoint, Transaction, TxIn, TxOut, ScriptBuf, Sequence, Witness};
 use bitcoin_hashes::Hash;
-use metashrew_support::index_pointer::KeyValuePointer;
 use ordinals::{Runestone, Etching, Rune, Terms, Edict, RuneId as OrdRuneId};
 use shrew_test_helpers::state::{clear, get_test_address};
 use shrew_test_helpers::blocks::{create_coinbase_transaction, create_block_with_txs, create_block_with_coinbase_tx};
@@ -32,14 +30,7 @@ fn make_runestone_tx(runestone: &Runestone) -> Transaction {
 
 /// Helper: read balance sheet from an outpoint
 fn read_balance(outpoint: &OutPoint) -> BalanceSheet {
-    let outpoint_bytes: Vec<u8> = outpoint.txid.as_byte_array().iter()
-        .chain(outpoint.vout.to_le_bytes().iter()).copied().collect();
-    let data = RUNE_BALANCES_BY_OUTPOINT.select(&outpoint_bytes).get();
-    if data.is_empty() {
-        BalanceSheet::new()
-    } else {
-        BalanceSheet::from_bytes(&data).unwrap_or_default()
-    }
+    RUNE_BALANCES_BY_OUTPOINT.get(outpoint).unwrap_or_default()
 }
 
 /// Helper: create a mint tx for a given rune_id
@@ -137,8 +128,7 @@ fn test_etch_mint_transfer_lifecycle() {
     assert_eq!(final_balance.get(&rune_id), 100, "Output 0 should have 60 + 40 remaining = 100");
 
     // Verify RuneEntry supply
-    let entry_bytes = RUNE_ID_TO_ENTRY.select(&rune_id.to_bytes()).get();
-    let entry: RuneEntry = bincode::deserialize(&entry_bytes).unwrap();
+    let entry = RUNE_ID_TO_ENTRY.get(&rune_id).unwrap();
     assert_eq!(entry.supply, 600, "Supply should be premine(500) + mint(100) = 600");
     assert_eq!(entry.mints, 1, "Should have 1 mint recorded");
 }
@@ -188,15 +178,11 @@ fn test_multi_rune_block() {
     let rune_id_b = RuneId::new(height as u64, 2);
 
     // Both runes should exist
-    let entry_a_bytes = RUNE_ID_TO_ENTRY.select(&rune_id_a.to_bytes()).get();
-    assert!(!entry_a_bytes.is_empty(), "Rune A should be etched");
-    let entry_a: RuneEntry = bincode::deserialize(&entry_a_bytes).unwrap();
+    let entry_a = RUNE_ID_TO_ENTRY.get(&rune_id_a).expect("Rune A should be etched");
     assert_eq!(entry_a.symbol, Some('A'));
     assert_eq!(entry_a.premine, 100);
 
-    let entry_b_bytes = RUNE_ID_TO_ENTRY.select(&rune_id_b.to_bytes()).get();
-    assert!(!entry_b_bytes.is_empty(), "Rune B should be etched");
-    let entry_b: RuneEntry = bincode::deserialize(&entry_b_bytes).unwrap();
+    let entry_b = RUNE_ID_TO_ENTRY.get(&rune_id_b).expect("Rune B should be etched");
     assert_eq!(entry_b.symbol, Some('B'));
     assert_eq!(entry_b.premine, 200);
 
@@ -249,8 +235,7 @@ fn test_rune_across_blocks() {
     assert_eq!(balance.get(&rune_id), 50, "Mint across blocks should work");
 
     // Verify entry
-    let entry_bytes = RUNE_ID_TO_ENTRY.select(&rune_id.to_bytes()).get();
-    let entry: RuneEntry = bincode::deserialize(&entry_bytes).unwrap();
+    let entry = RUNE_ID_TO_ENTRY.get(&rune_id).unwrap();
     assert_eq!(entry.mints, 1);
     assert_eq!(entry.supply, 50);
     assert_eq!(entry.etching_height, etch_height);
@@ -291,15 +276,13 @@ fn test_rune_with_premine_and_mints() {
     index_runes_block(&mint_block_2, height + 2);
 
     // Verify total supply = premine + 2 * mint_amount = 1000 + 500 = 1500
-    let entry_bytes = RUNE_ID_TO_ENTRY.select(&rune_id.to_bytes()).get();
-    let entry: RuneEntry = bincode::deserialize(&entry_bytes).unwrap();
+    let entry = RUNE_ID_TO_ENTRY.get(&rune_id).unwrap();
     assert_eq!(entry.premine, 1000, "Premine should be 1000");
     assert_eq!(entry.mints, 2, "Should have 2 mints");
     assert_eq!(entry.supply, 1500, "Supply should be premine(1000) + 2*250 = 1500");
 
     // Verify mints remaining decreased
-    let remaining_bytes = RUNE_MINTS_REMAINING.select(&rune_id.to_bytes()).get();
-    let remaining = u128::from_le_bytes(remaining_bytes[..16].try_into().unwrap());
+    let remaining = RUNE_MINTS_REMAINING.get(&rune_id).unwrap();
     assert_eq!(remaining, 8, "Mints remaining should be cap(10) - 2 = 8");
 }
 
@@ -375,7 +358,107 @@ fn test_empty_blocks_at_activation() {
 
     // No events at any height (coinbase-only blocks have no runestones)
     for h in 840000..840005u32 {
-        let events_data = HEIGHT_TO_RUNE_EVENTS.select(&h.to_le_bytes().to_vec()).get();
-        assert!(events_data.is_empty(), "Empty blocks should produce no rune events at height {}", h);
+        let events_data = HEIGHT_TO_RUNE_EVENTS.get(&h);
+        assert!(events_data.is_none(), "Empty blocks should produce no rune events at height {}", h);
     }
 }
+
+#[test]
+fn test_etching_links_commitment_inscription() {
+    use crate::proto::{
+        get_rune_inscription_request, GetInscriptionRuneRequest, GetRuneInscriptionRequest,
+        InscriptionId as ProtoInscriptionId,
+    };
+    use shrew_ord::ord_inscriptions::Inscription;
+    use shrew_test_helpers::indexing::index_ord_block;
+
+    clear();
+    let height = 840000u32;
+    let rune = "COMMITTED".parse::<Rune>().unwrap();
+    let mut commitment = rune.0.to_le_bytes().to_vec();
+    while commitment.last() == Some(&0) {
+        commitment.pop();
+    }
+
+    // The reveal carries both the inscription naming the rune and the etching
+    let mut etch_tx = make_runestone_tx(&Runestone {
+        etching: Some(Etching { rune: Some(rune), ..Default::default() }),
+        ..Default::default()
+    });
+    etch_tx.input[0].witness = Inscription {
+        content_type: Some(b"text/plain".to_vec()),
+        body: Some(b"rune art".to_vec()),
+        rune: Some(commitment.clone()),
+        ..Default::default()
+    }.to_witness();
+
+    // An inscription naming the same rune outside the etching is not linked
+    let mut other_tx = make_runestone_tx(&Runestone::default());
+    other_tx.input[0].witness = Inscription {
+        body: Some(b"imposter".to_vec()),
+        rune: Some(commitment),
+        ..Default::default()
+    }.to_witness();
+
+    let block = create_block_with_txs(vec![create_coinbase_transaction(height), etch_tx.clone(), other_tx.clone()]);
+    index_ord_block(&block, height).unwrap();
+    index_runes_block(&block, height);
+
+    let rune_id = RuneId::new(height as u64, 1);
+    let proto_id = |tx: &Transaction| ProtoInscriptionId { txid: tx.txid().as_byte_array().to_vec(), index: 0 };
+
+    let by_name = crate::view::get_rune_inscription(&GetRuneInscriptionRequest {
+        query: Some(get_rune_inscription_request::Query::Name("committed".to_string())),
+    }).unwrap();
+    assert_eq!(by_name.inscription, Some(proto_id(&etch_tx)));
+    assert_eq!(by_name.number, 1);
+
+    let by_id = crate::view::get_rune_inscription(&GetRuneInscriptionRequest {
+        query: Some(get_rune_inscription_request::Query::Id(crate::proto::RuneId { block: rune_id.block, tx: rune_id.tx })),
+    }).unwrap();
+    assert_eq!(by_id.inscription, by_name.inscription);
+
+    let entry = crate::view::get_inscription_rune(&GetInscriptionRuneRequest {
+        inscription: Some(proto_id(&etch_tx)),
+    }).unwrap().entry.unwrap();
+    assert_eq!(entry.name, "COMMITTED");
+    assert_eq!(entry.id, Some(crate::proto::RuneId { block: rune_id.block, tx: rune_id.tx }));
+
+    let unlinked = crate::view::get_inscription_rune(&GetInscriptionRuneRequest {
+        inscription: Some(proto_id(&other_tx)),
+    }).unwrap();
+    assert!(unlinked.entry.is_none());
+    assert_eq!(shrew_ord::tables::RUNE_TO_TAGGED_INSCRIPTION.get(&rune_id), Some(1));
+}
+
+#[test]
+fn test_etching_links_its_first_inscription_without_a_rune_tag() {
+    use shrew_ord::ord_inscriptions::Inscription;
+    use shrew_ord::tables::{RUNE_TO_INSCRIPTIONS, RUNE_TO_TAGGED_INSCRIPTION};
+    use shrew_test_helpers::indexing::index_ord_block;
+
+    clear();
+    let height = 840000u32;
+    let etching = |name: &str| make_runestone_tx(&Runestone {
+        etching: Some(Etching { rune: Some(name.parse::<Rune>().unwrap()), ..Default::default() }),
+        ..Default::default()
+    });
+
+    // ord links the etching transaction's i0 whatever its rune tag
+    let mut inscribed = etching("UNTAGGED");
+    inscribed.input[0].witness = Inscription {
+        content_type: Some(b"text/plain".to_vec()),
+        body: Some(b"rune art".to_vec()),
+        ..Default::default()
+    }.to_witness();
+    let bare = etching("UNINSCRIBED");
+
+    let block = create_block_with_txs(vec![create_coinbase_transaction(height), inscribed, bare]);
+    index_ord_block(&block, height).unwrap();
+    index_runes_block(&block, height);
+
+    let inscribed_id = RuneId::new(height as u64, 1);
+    assert_eq!(RUNE_TO_INSCRIPTIONS.get(&inscribed_id), Some(1));
+    assert_eq!(RUNE_TO_TAGGED_INSCRIPTION.get(&inscribed_id), None);
+    assert_eq!(RUNE_TO_INSCRIPTIONS.get(&RuneId::new(height as u64, 2)), None);
+}
@@ -1,82 +1,128 @@
-use crate::balance_sheet::{BalanceSheet, RuneId};
+use crate::balance_sheet::RuneId;
+use crate::error::RunesError;
 use crate::rune_indexer::RuneEntry;
 use crate::tables::*;
 use crate::proto::{
     GetRuneRequest, GetRuneResponse, GetRuneBalanceRequest, GetRuneBalanceResponse,
-    GetRuneEventsRequest, GetRuneEventsResponse, get_rune_request,
+    GetRuneEventsRequest, GetRuneEventsResponse, GetRuneInscriptionRequest, GetRuneInscriptionResponse,
+    GetInscriptionRuneRequest, GetInscriptionRuneResponse, get_rune_request, get_rune_inscription_request,
 };
-use metashrew_support::index_pointer::KeyValuePointer;
+use bitcoin::{OutPoint, Txid};
+use bitcoin_hashes::Hash;
+use shrew_ord::tables::{INSCRIPTION_ID_TO_SEQUENCE, INSCRIPTION_TO_RUNE, RUNE_TO_INSCRIPTIONS};
+use shrew_support::inscription::InscriptionId;
+use shrew_support::pagination::{paginate, paginate_by_key};
 
-pub fn get_rune(request: &GetRuneRequest) -> Result<GetRuneResponse, String> {
+pub fn get_rune(request: &GetRuneRequest) -> Result<GetRuneResponse, RunesError> {
     let mut response = GetRuneResponse::default();
-    let query = request.query.as_ref().ok_or("Missing query")?;
+    let query = request.query.as_ref().ok_or(RunesError::MissingField("query"))?;
 
-    let entry_bytes = match query {
-        get_rune_request::Query::Id(proto_id) => {
-            let rune_id = RuneId::new(proto_id.block, proto_id.tx);
-            RUNE_ID_TO_ENTRY.select(&rune_id.to_bytes()).get()
-        }
+    let rune_id = match query {
+        get_rune_request::Query::Id(proto_id) => RuneId::new(proto_id.block, proto_id.tx),
         get_rune_request::Query::Name(name) => {
-            let id_bytes = RUNE_NAME_TO_ID.select(&name.to_uppercase().as_bytes().to_vec()).get();
-            if id_bytes.is_empty() { return Ok(response); }
-            RUNE_ID_TO_ENTRY.select(&id_bytes).get()
+            let Some(rune_id) = RUNE_NAME_TO_ID.get(&name.to_uppercase()) else { return Ok(response) };
+            rune_id
         }
     };
 
-    if entry_bytes.is_empty() { return Ok(response); }
+    response.entry = RUNE_ID_TO_ENTRY.get(&rune_id).map(entry_to_proto);
+    Ok(response)
+}
+
+pub fn get_rune_inscription(request: &GetRuneInscriptionRequest) -> Result<GetRuneInscriptionResponse, RunesError> {
+    let mut response = GetRuneInscriptionResponse::default();
+    let rune_id = match request.query.as_ref().ok_or(RunesError::MissingField("query"))? {
+        get_rune_inscription_request::Query::Id(proto_id) => RuneId::new(proto_id.block, proto_id.tx),
+        get_rune_inscription_request::Query::Name(name) => {
+            let Some(rune_id) = RUNE_NAME_TO_ID.get(&name.to_uppercase()) else { return Ok(response) };
+            rune_id
+        }
+    };
 
-    if let Ok(entry) = bincode::deserialize::<RuneEntry>(&entry_bytes) {
-        response.entry = Some(crate::proto::RuneEntry {
-            id: Some(crate::proto::RuneId { block: entry.id.block, tx: entry.id.tx }),
-            name: entry.name,
-            spaced_name: entry.spaced_name,
-            divisibility: entry.divisibility as u32,
-            symbol: entry.symbol.map(|c| c.to_string()),
-            spacers: entry.spacers,
-            premine: entry.premine as u64,
-            terms: entry.terms.map(|t| crate::proto::RuneTerms {
-                amount: t.amount.map(|a| a.to_string()),
-                cap: t.cap.map(|c| c.to_string()),
-                height_start: t.height_start,
-                height_end: t.height_end,
-                offset_start: t.offset_start,
-                offset_end: t.offset_end,
-            }),
-            turbo: entry.turbo,
-            mints: entry.mints as u64,
-            supply: entry.supply.to_string(),
-            etching_height: entry.etching_height,
-            etching_txid: entry.etching_txid.to_vec(),
+    let Some(sequence) = RUNE_TO_INSCRIPTIONS.get(&rune_id) else { return Ok(response) };
+    let entry = shrew_ord::entries::load(sequence).map_err(|source| RunesError::EntryDecode { sequence, source })?;
+    if let Some(entry) = entry {
+        response.inscription = Some(crate::proto::InscriptionId {
+            txid: entry.id.txid.as_byte_array().to_vec(),
+            index: entry.id.index,
         });
+        response.number = entry.number;
     }
+    Ok(response)
+}
 
+pub fn get_inscription_rune(request: &GetInscriptionRuneRequest) -> Result<GetInscriptionRuneResponse, RunesError> {
+    let mut response = GetInscriptionRuneResponse::default();
+    let proto_id = request.inscription.as_ref().ok_or(RunesError::MissingField("inscription"))?;
+    let inscription_id = InscriptionId::new(
+        Txid::from_slice(&proto_id.txid).map_err(RunesError::invalid_txid)?,
+        proto_id.index,
+    );
+    let Some(sequence) = INSCRIPTION_ID_TO_SEQUENCE.get(&inscription_id) else { return Ok(response) };
+    let Some(rune_id) = INSCRIPTION_TO_RUNE.get(&sequence) else { return Ok(response) };
+    response.entry = RUNE_ID_TO_ENTRY.get(&rune_id).map(entry_to_proto);
     Ok(response)
 }
 
-pub fn get_rune_balance(request: &GetRuneBalanceRequest) -> Result<GetRuneBalanceResponse, String> {
-    let mut response = GetRuneBalanceResponse::default();
-    let mut outpoint_bytes = request.txid.clone();
-    outpoint_bytes.extend_from_slice(&request.vout.to_le_bytes());
-    let data = RUNE_BALANCES_BY_OUTPOINT.select(&outpoint_bytes).get();
-    if data.is_empty() { return Ok(response); }
-    if let Some(sheet) = BalanceSheet::from_bytes(&data) {
-        for (rune_id, amount) in &sheet.balances {
-            response.balances.push(crate::proto::RuneBalance {
+fn entry_to_proto(entry: RuneEntry) -> crate::proto::RuneEntry {
+    crate::proto::RuneEntry {
+        id: Some(crate::proto::RuneId { block: entry.id.block, tx: entry.id.tx }),
+        name: entry.name,
+        spaced_name: entry.spaced_name,
+        divisibility: entry.divisibility as u32,
+        symbol: entry.symbol.map(|c| c.to_string()),
+        spacers: entry.spacers,
+        premine: entry.premine as u64,
+        terms: entry.terms.map(|t| crate::proto::RuneTerms {
+            amount: t.amount.map(|a| a.to_string()),
+            cap: t.cap.map(|c| c.to_string()),
+            height_start: t.height_start,
+            height_end: t.height_end,
+            offset_start: t.offset_start,
+            offset_end: t.offset_end,
+        }),
+        turbo: entry.turbo,
+        mints: entry.mints as u64,
+        supply: entry.supply.to_string(),
+        etching_height: entry.etching_height,
+        etching_txid: entry.etching_txid.to_vec(),
+    }
+}
+
+pub fn get_rune_balance(request: &GetRuneBalanceRequest) -> Result<GetRuneBalanceResponse, RunesError> {
+    let outpoint = OutPoint {
+        txid: Txid::from_slice(&request.txid).map_err(RunesError::invalid_txid)?,
+        vout: request.vout,
+    };
+    let balances: Vec<(RuneId, u128)> = RUNE_BALANCES_BY_OUTPOINT.get(&outpoint)
+        .map(|sheet| sheet.balances.into_iter().collect())
+        .unwrap_or_default();
+    let (balances, pagination) = paginate_by_key(balances, |(rune_id, _)| rune_id_key(rune_id), request.pagination.as_ref())?;
+    Ok(GetRuneBalanceResponse {
+        balances: balances.into_iter()
+            .map(|(rune_id, amount)| crate::proto::RuneBalance {
                 rune_id: Some(crate::proto::RuneId { block: rune_id.block, tx: rune_id.tx }),
                 amount: amount.to_string(),
-            });
-        }
-    }
-    Ok(response)
+            })
+            .collect(),
+        pagination: Some(pagination),
+    })
 }
 
-pub fn get_rune_events(request: &GetRuneEventsRequest) -> Result<GetRuneEventsResponse, String> {
-    let mut response = GetRuneEventsResponse::default();
-    let data = HEIGHT_TO_RUNE_EVENTS.select(&request.block_height.to_le_bytes().to_vec()).get();
-    if data.is_empty() { return Ok(response); }
-    if let Ok(events) = bincode::deserialize::<Vec<crate::rune_indexer::RuneEvent>>(&data) {
-        for event in events {
-            response.events.push(crate::proto::RuneEvent {
+/// Sort key of a rune id, ordering by block then tx
+fn rune_id_key(rune_id: &RuneId) -> [u8; 12] {
+    let mut key = [0; 12];
+    key[..8].copy_from_slice(&rune_id.block.to_be_bytes());
+    key[8..].copy_from_slice(&rune_id.tx.to_be_bytes());
+    key
+}
+
+pub fn get_rune_events(request: &GetRuneEventsRequest) -> Result<GetRuneEventsResponse, RunesError> {
+    let events = HEIGHT_TO_RUNE_EVENTS.get(&request.block_height).unwrap_or_default();
+    let (events, pagination) = paginate(events, request.pagination.as_ref())?;
+    Ok(GetRuneEventsResponse {
+        events: events.into_iter()
+            .map(|event| crate::proto::RuneEvent {
                 event_type: event.event_type,
                 rune_id: Some(crate::proto::RuneId { block: event.rune_id.block, tx: event.rune_id.tx }),
                 amount: event.amount.to_string(),
@@ -84,8 +130,8 @@ pub fn get_rune_events(request: &GetRuneEventsRequest) -> Result<GetRuneEventsRe
                 vout: event.vout,
                 address: String::new(),
                 block_height: event.block_height,
-            });
-        }
-    }
-    Ok(response)
+            })
+            .collect(),
+        pagination: Some(pagination),
+    })
 }
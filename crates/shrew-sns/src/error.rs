@@ -0,0 +1,18 @@
+//! Errors of the SNS views.
+
+use shrew_support::error::{CodedError, ErrorCode};
+use shrew_support::pagination::PaginationError;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SnsError {
+    #[error(transparent)]
+    Pagination(#[from] PaginationError),
+}
+
+impl CodedError for SnsError {
+    fn code(&self) -> ErrorCode {
+        match self {
+            SnsError::Pagination(_) => ErrorCode::InvalidArgument,
+        }
+    }
+}
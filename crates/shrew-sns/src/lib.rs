@@ -1,8 +1,9 @@
 #[cfg(feature = "entrypoint")]
-use bitcoin::{Block, consensus::deserialize};
-use serde_json::{from_slice, to_vec};
+use shrew_support::diagnostics;
+use shrew_support::wire::serve;
 
 pub mod sns_indexer;
+pub mod error;
 pub mod tables;
 pub mod view;
 
@@ -15,10 +16,10 @@ pub use sns_indexer::SnsIndexer;
 #[cfg(feature = "entrypoint")]
 #[metashrew_core::main]
 fn main_logic(height: u32, block_data: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
-    if let Ok(block) = deserialize::<Block>(block_data) {
-        let mut inscription_indexer = shrew_ord::indexer::InscriptionIndexer::new();
-        let _ = inscription_indexer.load_state();
-        let _ = inscription_indexer.index_block(&block, height);
+    if let Some(block) = diagnostics::decode_block(height, block_data) {
+        if !shrew_ord::indexer::InscriptionIndexer::index_or_report(&block, height) {
+            return Ok(());
+        }
 
         let indexer = SnsIndexer::new();
         indexer.index_block(&block, height);
@@ -28,20 +29,17 @@ fn main_logic(height: u32, block_data: &[u8]) -> Result<(), Box<dyn std::error::
 
 #[metashrew_core::view]
 pub fn getsnsname(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetSnsNameRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_sns_name(&req)?)?)
+    serve(input, view::get_sns_name)
 }
 
 #[metashrew_core::view]
 pub fn getsnsnamespace(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetSnsNamespaceRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_sns_namespace(&req)?)?)
+    serve(input, view::get_sns_namespace)
 }
 
 #[metashrew_core::view]
 pub fn getsnsnamesbyheight(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let req: proto::GetSnsNamesByHeightRequest = from_slice(input)?;
-    Ok(to_vec(&view::get_sns_names_by_height(&req)?)?)
+    serve(input, view::get_sns_names_by_height)
 }
 
 #[cfg(test)]
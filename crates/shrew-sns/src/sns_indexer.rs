@@ -1,12 +1,12 @@
 use crate::tables::*;
-use shrew_support::inscription::{InscriptionEntry, InscriptionId};
+use shrew_support::inscription::InscriptionId;
+use shrew_ord::entries;
+use shrew_support::diagnostics::{self, BlockDiagnostics};
 use shrew_ord::tables::{
-    SEQUENCE_TO_INSCRIPTION_ENTRY, INSCRIPTION_CONTENT, GLOBAL_SEQUENCE_COUNTER,
+    InscriptionContentTable, GLOBAL_SEQUENCE_COUNTER,
 };
 use bitcoin::Block;
-use metashrew_support::index_pointer::KeyValuePointer;
 use serde::Deserialize;
-use std::sync::Arc;
 
 #[derive(Deserialize)]
 struct SnsOperation {
@@ -24,17 +24,10 @@ impl SnsIndexer {
     pub fn new() -> Self { Self }
 
     pub fn index_block(&self, _block: &Block, height: u32) {
-        let seq_bytes = GLOBAL_SEQUENCE_COUNTER.get();
-        if seq_bytes.is_empty() { return; }
-        let max_seq = u32::from_le_bytes(seq_bytes[..4].try_into().unwrap_or([0; 4]));
+        let max_seq = GLOBAL_SEQUENCE_COUNTER.get().unwrap_or(0);
 
         for seq in 1..=max_seq {
-            let entry_bytes = SEQUENCE_TO_INSCRIPTION_ENTRY.select(&seq.to_le_bytes().to_vec()).get();
-            if entry_bytes.is_empty() { continue; }
-            let entry = match InscriptionEntry::from_bytes(&entry_bytes) {
-                Ok(e) => e,
-                Err(_) => continue,
-            };
+            let Some(entry) = entries::load_or_report(seq, height) else { continue };
             if entry.height != height { continue; }
             if entry.number < 0 { continue; }
 
@@ -44,8 +37,7 @@ impl SnsIndexer {
                 _ => continue,
             }
 
-            let inscription_id_str = entry.id.to_string();
-            let content_bytes = INSCRIPTION_CONTENT.select(&inscription_id_str.as_bytes().to_vec()).get();
+            let content_bytes = InscriptionContentTable::new().get(&entry.id).unwrap_or_default();
             if content_bytes.is_empty() { continue; }
 
             let content_str = match std::str::from_utf8(&content_bytes) {
@@ -74,6 +66,7 @@ impl SnsIndexer {
                 _ => {}
             }
         }
+        diagnostics::add(height, "sns", BlockDiagnostics::default());
     }
 
     fn process_registration(&self, inscription_id: &InscriptionId, name: &str, height: u32) {
@@ -90,13 +83,11 @@ impl SnsIndexer {
         if dot_count != 1 { return; }
 
         // First-wins
-        let existing = SNS_NAME_TO_ID.select(&name.as_bytes().to_vec()).get();
-        if !existing.is_empty() { return; }
+        if SNS_NAME_TO_ID.contains(name) { return; }
 
-        let id_bytes = inscription_id.to_bytes();
-        SNS_NAME_TO_ID.select(&name.as_bytes().to_vec()).set(Arc::new(id_bytes.clone()));
-        SNS_ID_TO_NAME.select(&id_bytes).set(Arc::new(name.as_bytes().to_vec()));
-        SNS_HEIGHT_TO_NAMES.select(&height.to_le_bytes().to_vec()).append(Arc::new(id_bytes));
+        SNS_NAME_TO_ID.set(name, inscription_id);
+        SNS_ID_TO_NAME.set(inscription_id, &name.to_string());
+        SNS_HEIGHT_TO_NAMES.append(&height, inscription_id);
     }
 
     fn process_namespace(&self, inscription_id: &InscriptionId, ns: &str, _height: u32) {
@@ -108,10 +99,8 @@ impl SnsIndexer {
         if ns.contains('.') { return; }
 
         // First-wins
-        let existing = SNS_NAMESPACE_TO_ID.select(&ns.as_bytes().to_vec()).get();
-        if !existing.is_empty() { return; }
+        if SNS_NAMESPACE_TO_ID.contains(&ns) { return; }
 
-        let id_bytes = inscription_id.to_bytes();
-        SNS_NAMESPACE_TO_ID.select(&ns.as_bytes().to_vec()).set(Arc::new(id_bytes));
+        SNS_NAMESPACE_TO_ID.set(&ns, inscription_id);
     }
 }
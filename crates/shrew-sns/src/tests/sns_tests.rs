@@ -5,8 +5,6 @@ use shrew_test_helpers::indexing::{index_ord_block, index_sns_block};
 use shrew_test_helpers::sns::{create_sns_reg_block, create_sns_ns_block};
 use shrew_test_helpers::blocks::{create_coinbase_transaction, create_block_with_txs};
 use shrew_test_helpers::transactions::create_inscription_transaction;
-use shrew_support::inscription::InscriptionId;
-use metashrew_support::index_pointer::KeyValuePointer;
 use std::str::FromStr;
 
 // ---------------------------------------------------------------------------
@@ -21,10 +19,10 @@ fn test_sns_register_name() {
     index_ord_block(&block, height).unwrap();
     index_sns_block(&block, height);
 
-    let data = SNS_NAME_TO_ID.select(&"alice.btc".as_bytes().to_vec()).get();
-    assert!(!data.is_empty(), "Name 'alice.btc' should be registered");
+    let data = SNS_NAME_TO_ID.get("alice.btc");
+    assert!(data.is_some(), "Name 'alice.btc' should be registered");
 
-    let stored_id = InscriptionId::from_bytes(&data).expect("Valid inscription id");
+    let stored_id = data.unwrap();
     assert_eq!(stored_id.txid, tx.txid());
 }
 
@@ -37,12 +35,12 @@ fn test_sns_register_lowercased() {
     index_sns_block(&block, height);
 
     // Should be stored in lowercase
-    let data = SNS_NAME_TO_ID.select(&"alice.btc".as_bytes().to_vec()).get();
-    assert!(!data.is_empty(), "'Alice.BTC' should be stored as 'alice.btc'");
+    let data = SNS_NAME_TO_ID.get("alice.btc");
+    assert!(data.is_some(), "'Alice.BTC' should be stored as 'alice.btc'");
 
     // Uppercase lookup should fail
-    let data_upper = SNS_NAME_TO_ID.select(&"Alice.BTC".as_bytes().to_vec()).get();
-    assert!(data_upper.is_empty(), "Uppercase lookup should fail");
+    let data_upper = SNS_NAME_TO_ID.get("Alice.BTC");
+    assert!(data_upper.is_none(), "Uppercase lookup should fail");
 }
 
 #[test]
@@ -55,8 +53,8 @@ fn test_sns_duplicate_name_rejected() {
     index_ord_block(&block1, height).unwrap();
     index_sns_block(&block1, height);
 
-    let first_id_data = SNS_NAME_TO_ID.select(&"alice.btc".as_bytes().to_vec()).get();
-    let first_id = InscriptionId::from_bytes(&first_id_data).unwrap();
+    let first_id_data = SNS_NAME_TO_ID.get("alice.btc");
+    let first_id = first_id_data.unwrap();
 
     // Try to register again at height 101 (different outpoint for unique txid)
     let height2 = 101u32;
@@ -74,8 +72,8 @@ fn test_sns_duplicate_name_rejected() {
     index_sns_block(&block2, height2);
 
     // First registration should still be stored
-    let data = SNS_NAME_TO_ID.select(&"alice.btc".as_bytes().to_vec()).get();
-    let stored_id = InscriptionId::from_bytes(&data).unwrap();
+    let data = SNS_NAME_TO_ID.get("alice.btc");
+    let stored_id = data.unwrap();
     assert_eq!(stored_id, first_id, "First registration should win for 'alice.btc'");
 }
 
@@ -91,8 +89,8 @@ fn test_sns_register_namespace() {
     index_ord_block(&block, height).unwrap();
     index_sns_block(&block, height);
 
-    let data = SNS_NAMESPACE_TO_ID.select(&"btc".as_bytes().to_vec()).get();
-    assert!(!data.is_empty(), "Namespace 'btc' should be registered");
+    let data = SNS_NAMESPACE_TO_ID.get("btc");
+    assert!(data.is_some(), "Namespace 'btc' should be registered");
 }
 
 #[test]
@@ -103,8 +101,8 @@ fn test_sns_namespace_no_dots() {
     index_ord_block(&block, height).unwrap();
     index_sns_block(&block, height);
 
-    let data = SNS_NAMESPACE_TO_ID.select(&"btc.x".as_bytes().to_vec()).get();
-    assert!(data.is_empty(), "Namespace 'btc.x' with dots should be rejected");
+    let data = SNS_NAMESPACE_TO_ID.get("btc.x");
+    assert!(data.is_none(), "Namespace 'btc.x' with dots should be rejected");
 }
 
 // ---------------------------------------------------------------------------
@@ -119,8 +117,8 @@ fn test_sns_name_exactly_one_dot() {
     index_ord_block(&block, height).unwrap();
     index_sns_block(&block, height);
 
-    let data = SNS_NAME_TO_ID.select(&"alice.btc".as_bytes().to_vec()).get();
-    assert!(!data.is_empty(), "Name with exactly one dot should be accepted");
+    let data = SNS_NAME_TO_ID.get("alice.btc");
+    assert!(data.is_some(), "Name with exactly one dot should be accepted");
 }
 
 #[test]
@@ -131,8 +129,8 @@ fn test_sns_name_no_dots_rejected() {
     index_ord_block(&block, height).unwrap();
     index_sns_block(&block, height);
 
-    let data = SNS_NAME_TO_ID.select(&"alice".as_bytes().to_vec()).get();
-    assert!(data.is_empty(), "Name 'alice' without dots should be rejected");
+    let data = SNS_NAME_TO_ID.get("alice");
+    assert!(data.is_none(), "Name 'alice' without dots should be rejected");
 }
 
 #[test]
@@ -143,8 +141,8 @@ fn test_sns_name_two_dots_rejected() {
     index_ord_block(&block, height).unwrap();
     index_sns_block(&block, height);
 
-    let data = SNS_NAME_TO_ID.select(&"alice.bob.btc".as_bytes().to_vec()).get();
-    assert!(data.is_empty(), "Name 'alice.bob.btc' with two dots should be rejected");
+    let data = SNS_NAME_TO_ID.get("alice.bob.btc");
+    assert!(data.is_none(), "Name 'alice.bob.btc' with two dots should be rejected");
 }
 
 #[test]
@@ -158,8 +156,8 @@ fn test_sns_name_max_length() {
     index_ord_block(&block, height).unwrap();
     index_sns_block(&block, height);
 
-    let data = SNS_NAME_TO_ID.select(&name.to_lowercase().as_bytes().to_vec()).get();
-    assert!(!data.is_empty(), "100-char name should be accepted (under 2048)");
+    let data = SNS_NAME_TO_ID.get(&name.to_lowercase());
+    assert!(data.is_some(), "100-char name should be accepted (under 2048)");
 }
 
 #[test]
@@ -174,8 +172,8 @@ fn test_sns_name_too_long_rejected() {
     index_ord_block(&block, height).unwrap();
     index_sns_block(&block, height);
 
-    let data = SNS_NAME_TO_ID.select(&name.to_lowercase().as_bytes().to_vec()).get();
-    assert!(data.is_empty(), "Name > 2048 bytes should be rejected");
+    let data = SNS_NAME_TO_ID.get(&name.to_lowercase());
+    assert!(data.is_none(), "Name > 2048 bytes should be rejected");
 }
 
 // ---------------------------------------------------------------------------
@@ -192,8 +190,8 @@ fn test_sns_wrong_protocol_rejected() {
     index_ord_block(&block, height).unwrap();
     index_sns_block(&block, height);
 
-    let data = SNS_NAME_TO_ID.select(&"alice.btc".as_bytes().to_vec()).get();
-    assert!(data.is_empty(), "Wrong protocol 'brc-20' should be rejected");
+    let data = SNS_NAME_TO_ID.get("alice.btc");
+    assert!(data.is_none(), "Wrong protocol 'brc-20' should be rejected");
 }
 
 #[test]
@@ -206,8 +204,8 @@ fn test_sns_invalid_op_rejected() {
     index_ord_block(&block, height).unwrap();
     index_sns_block(&block, height);
 
-    let data = SNS_NAME_TO_ID.select(&"alice.btc".as_bytes().to_vec()).get();
-    assert!(data.is_empty(), "Invalid op 'xxx' should be ignored");
+    let data = SNS_NAME_TO_ID.get("alice.btc");
+    assert!(data.is_none(), "Invalid op 'xxx' should be ignored");
 }
 
 #[test]
@@ -220,7 +218,7 @@ fn test_sns_missing_name_rejected() {
     index_ord_block(&block, height).unwrap();
     index_sns_block(&block, height);
 
-    let names = SNS_HEIGHT_TO_NAMES.select(&height.to_le_bytes().to_vec()).get();
+    let names = SNS_HEIGHT_TO_NAMES.get_list(&height);
     assert!(names.is_empty(), "Registration without 'name' field should be rejected");
 }
 
@@ -239,8 +237,8 @@ fn test_sns_cursed_inscription_rejected() {
     index_ord_block(&block, height).unwrap();
     index_sns_block(&block, height);
 
-    let data = SNS_NAME_TO_ID.select(&"alice.btc".as_bytes().to_vec()).get();
-    assert!(data.is_empty(), "Cursed (coinbase) inscription should not register SNS name");
+    let data = SNS_NAME_TO_ID.get("alice.btc");
+    assert!(data.is_none(), "Cursed (coinbase) inscription should not register SNS name");
 }
 
 #[test]
@@ -253,7 +251,7 @@ fn test_sns_non_json_rejected() {
     index_ord_block(&block, height).unwrap();
     index_sns_block(&block, height);
 
-    let names = SNS_HEIGHT_TO_NAMES.select(&height.to_le_bytes().to_vec()).get();
+    let names = SNS_HEIGHT_TO_NAMES.get_list(&height);
     assert!(names.is_empty(), "Non-JSON content should be rejected");
 }
 
@@ -269,10 +267,10 @@ fn test_sns_first_whitespace_token() {
     index_sns_block(&block, height);
 
     // Should be registered as "alice.btc" (first whitespace-delimited token)
-    let data = SNS_NAME_TO_ID.select(&"alice.btc".as_bytes().to_vec()).get();
-    assert!(!data.is_empty(), "'alice.btc other stuff' should register as 'alice.btc'");
+    let data = SNS_NAME_TO_ID.get("alice.btc");
+    assert!(data.is_some(), "'alice.btc other stuff' should register as 'alice.btc'");
 
     // The full string should NOT be registered
-    let data_full = SNS_NAME_TO_ID.select(&"alice.btc other stuff".as_bytes().to_vec()).get();
-    assert!(data_full.is_empty(), "Full string with whitespace should not be a key");
+    let data_full = SNS_NAME_TO_ID.get("alice.btc other stuff");
+    assert!(data_full.is_none(), "Full string with whitespace should not be a key");
 }
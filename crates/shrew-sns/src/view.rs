@@ -1,15 +1,13 @@
 use crate::tables::*;
 use crate::proto::*;
-use shrew_support::InscriptionId;
-use metashrew_support::index_pointer::KeyValuePointer;
 use bitcoin_hashes::Hash;
+use crate::error::SnsError;
+use shrew_support::pagination::paginate;
 
-pub fn get_sns_name(request: &GetSnsNameRequest) -> Result<SnsNameResponse, String> {
+pub fn get_sns_name(request: &GetSnsNameRequest) -> Result<SnsNameResponse, SnsError> {
     let mut response = SnsNameResponse::default();
     let name = request.name.to_lowercase();
-    let id_bytes = SNS_NAME_TO_ID.select(&name.as_bytes().to_vec()).get();
-    if id_bytes.is_empty() { return Ok(response); }
-    if let Ok(id) = InscriptionId::from_bytes(&id_bytes) {
+    if let Some(id) = SNS_NAME_TO_ID.get(&name) {
         response.name = name;
         response.inscription_id = Some(crate::proto::InscriptionId {
             txid: id.txid.as_byte_array().to_vec(),
@@ -19,12 +17,10 @@ pub fn get_sns_name(request: &GetSnsNameRequest) -> Result<SnsNameResponse, Stri
     Ok(response)
 }
 
-pub fn get_sns_namespace(request: &GetSnsNamespaceRequest) -> Result<SnsNamespaceResponse, String> {
+pub fn get_sns_namespace(request: &GetSnsNamespaceRequest) -> Result<SnsNamespaceResponse, SnsError> {
     let mut response = SnsNamespaceResponse::default();
     let ns = request.namespace.to_lowercase();
-    let id_bytes = SNS_NAMESPACE_TO_ID.select(&ns.as_bytes().to_vec()).get();
-    if id_bytes.is_empty() { return Ok(response); }
-    if let Ok(id) = InscriptionId::from_bytes(&id_bytes) {
+    if let Some(id) = SNS_NAMESPACE_TO_ID.get(&ns) {
         response.namespace = ns;
         response.inscription_id = Some(crate::proto::InscriptionId {
             txid: id.txid.as_byte_array().to_vec(),
@@ -34,22 +30,18 @@ pub fn get_sns_namespace(request: &GetSnsNamespaceRequest) -> Result<SnsNamespac
     Ok(response)
 }
 
-pub fn get_sns_names_by_height(request: &GetSnsNamesByHeightRequest) -> Result<SnsNamesByHeightResponse, String> {
-    let mut response = SnsNamesByHeightResponse::default();
-    let entries = SNS_HEIGHT_TO_NAMES.select(&request.block_height.to_le_bytes().to_vec()).get_list();
-    for entry_bytes in entries {
-        if let Ok(id) = InscriptionId::from_bytes(&entry_bytes) {
-            let name_bytes = SNS_ID_TO_NAME.select(&entry_bytes).get();
-            let name = String::from_utf8(name_bytes.to_vec()).unwrap_or_default();
-            response.names.push(SnsNameResponse {
-                name,
-                inscription_id: Some(crate::proto::InscriptionId {
-                    txid: id.txid.as_byte_array().to_vec(),
-                    index: id.index,
-                }),
-                block_height: request.block_height,
-            });
-        }
+pub fn get_sns_names_by_height(request: &GetSnsNamesByHeightRequest) -> Result<SnsNamesByHeightResponse, SnsError> {
+    let (ids, pagination) = paginate(SNS_HEIGHT_TO_NAMES.get_list(&request.block_height), request.pagination.as_ref())?;
+    let mut response = SnsNamesByHeightResponse { pagination: Some(pagination), ..Default::default() };
+    for id in ids {
+        response.names.push(SnsNameResponse {
+            name: SNS_ID_TO_NAME.get(&id).unwrap_or_default(),
+            inscription_id: Some(crate::proto::InscriptionId {
+                txid: id.txid.as_byte_array().to_vec(),
+                index: id.index,
+            }),
+            block_height: request.block_height,
+        });
     }
     Ok(response)
 }
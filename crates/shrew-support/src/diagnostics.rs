@@ -0,0 +1,166 @@
+//! Per-block record of what each indexer did and which failures it hit.
+//!
+//! An entrypoint never lets one indexer's error stop the others or vanish: it
+//! records the error here under the block height, with its [`ErrorCode`], and
+//! carries on with the next protocol. Only an ord failure stops the block,
+//! since every other protocol reads the inscriptions ord indexes. Each indexer also [`add`]s its counters
+//! for the block once it is done, so a block where a protocol silently did
+//! nothing shows up as missing from [`BlockDiagnostics::protocols`].
+
+use crate::error::{CodedError, ErrorCode};
+use crate::table::{Bincode, Json, ListTable, Table};
+use bitcoin::{consensus::deserialize, Block};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// An error an indexer returned while indexing a block
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexingFailure {
+    /// Indexer that failed, e.g. `ord`; `block` when the block itself could
+    /// not be decoded
+    pub indexer: String,
+    /// An [`ErrorCode`]
+    pub code: u32,
+    pub message: String,
+}
+
+/// Counters of one block, summed over the indexers that ran it
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BlockDiagnostics {
+    /// Indexers that finished the block, in the order they ran
+    pub protocols: Vec<String>,
+    /// Inscription envelopes parsed from the block's inputs
+    pub envelopes: u64,
+    pub inscriptions_created: u64,
+    /// Moves of already inscribed sats
+    pub inscriptions_transferred: u64,
+    pub brc20_accepted: u64,
+    /// Rejected BRC-20 operations by reason
+    pub brc20_rejected: BTreeMap<String, u64>,
+    pub runestones: u64,
+    pub cenotaphs: u64,
+    pub prog_executions: u64,
+    pub prog_gas: u64,
+}
+
+impl BlockDiagnostics {
+    /// Count one BRC-20 operation rejected for `reason`
+    pub fn reject(&mut self, reason: &str) {
+        *self.brc20_rejected.entry(reason.to_string()).or_default() += 1;
+    }
+
+    fn merge(&mut self, other: BlockDiagnostics) {
+        self.envelopes += other.envelopes;
+        self.inscriptions_created += other.inscriptions_created;
+        self.inscriptions_transferred += other.inscriptions_transferred;
+        self.brc20_accepted += other.brc20_accepted;
+        for (reason, count) in other.brc20_rejected {
+            *self.brc20_rejected.entry(reason).or_default() += count;
+        }
+        self.runestones += other.runestones;
+        self.cenotaphs += other.cenotaphs;
+        self.prog_executions += other.prog_executions;
+        self.prog_gas += other.prog_gas;
+    }
+}
+
+lazy_static::lazy_static! {
+    pub static ref INDEXING_FAILURES: ListTable<u32, Bincode<IndexingFailure>> = ListTable::new("/diagnostics/failures/");
+    pub static ref BLOCK_DIAGNOSTICS: Table<u32, Json<BlockDiagnostics>> = Table::new("/diagnostics/blocks/");
+}
+
+/// Mark `protocol` as run for the block at `height` and add its counters to
+/// the block's. `counts.protocols` is ignored.
+pub fn add(height: u32, protocol: &str, counts: BlockDiagnostics) {
+    let mut diagnostics = block(height);
+    if !diagnostics.protocols.iter().any(|run| run == protocol) {
+        diagnostics.protocols.push(protocol.to_string());
+    }
+    diagnostics.merge(counts);
+    BLOCK_DIAGNOSTICS.set(&height, &diagnostics);
+}
+
+/// Counters recorded for the block at `height`, all zero if no indexer ran it
+pub fn block(height: u32) -> BlockDiagnostics {
+    BLOCK_DIAGNOSTICS.get(&height).unwrap_or_default()
+}
+
+/// Record an error `indexer` returned for the block at `height`
+pub fn record<E: CodedError + ?Sized>(height: u32, indexer: &str, error: &E) {
+    append(height, indexer, error.code(), error.to_string());
+}
+
+/// Failures recorded for the block at `height`, in the order they occurred
+pub fn failures(height: u32) -> Vec<IndexingFailure> {
+    INDEXING_FAILURES.get_list(&height)
+}
+
+/// Deserialize the block handed to an entrypoint. A block that fails to decode
+/// is recorded as a [`ErrorCode::DecodeFailure`] at `height`.
+pub fn decode_block(height: u32, block_data: &[u8]) -> Option<Block> {
+    deserialize::<Block>(block_data)
+        .map_err(|error| append(height, "block", ErrorCode::DecodeFailure, error.to_string()))
+        .ok()
+}
+
+fn append(height: u32, indexer: &str, code: ErrorCode, message: String) {
+    let failure = IndexingFailure { indexer: indexer.to_string(), code: code.into(), message };
+    INDEXING_FAILURES.append(&height, &failure);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("inscription already indexed")]
+    struct Duplicate;
+
+    impl CodedError for Duplicate {
+        fn code(&self) -> ErrorCode { ErrorCode::Internal }
+    }
+
+    #[test]
+    fn test_failures_are_kept_per_height() {
+        metashrew_core::clear();
+        record(840000, "ord", &Duplicate);
+        assert!(decode_block(840000, &[0xde, 0xad]).is_none());
+
+        let failures = failures(840000);
+        assert_eq!(failures.len(), 2);
+        assert_eq!(failures[0], IndexingFailure {
+            indexer: "ord".to_string(),
+            code: ErrorCode::Internal.into(),
+            message: "inscription already indexed".to_string(),
+        });
+        assert_eq!(failures[1].indexer, "block");
+        assert_eq!(failures[1].code, u32::from(ErrorCode::DecodeFailure));
+        assert!(super::failures(840001).is_empty());
+    }
+
+    #[test]
+    fn test_block_counters_are_summed_per_height() {
+        metashrew_core::clear();
+        assert_eq!(block(840000), BlockDiagnostics::default());
+
+        add(840000, "ord", BlockDiagnostics { envelopes: 3, inscriptions_created: 2, ..Default::default() });
+        let mut brc20 = BlockDiagnostics { brc20_accepted: 1, ..Default::default() };
+        brc20.reject("insufficient_balance");
+        brc20.reject("insufficient_balance");
+        add(840000, "brc20", brc20);
+        let mut again = BlockDiagnostics { inscriptions_created: 1, ..Default::default() };
+        again.reject("unknown_ticker");
+        add(840000, "ord", again);
+
+        let diagnostics = block(840000);
+        assert_eq!(diagnostics.protocols, vec!["ord".to_string(), "brc20".to_string()]);
+        assert_eq!(diagnostics.envelopes, 3);
+        assert_eq!(diagnostics.inscriptions_created, 3);
+        assert_eq!(diagnostics.brc20_accepted, 1);
+        assert_eq!(diagnostics.brc20_rejected.get("insufficient_balance"), Some(&2));
+        assert_eq!(diagnostics.brc20_rejected.get("unknown_ticker"), Some(&1));
+        assert_eq!(block(840001), BlockDiagnostics::default());
+    }
+}
@@ -0,0 +1,75 @@
+//! Error codes shared by every indexer.
+//!
+//! Each crate defines its own `thiserror` enum and maps every variant onto an
+//! [`ErrorCode`] through [`CodedError`]. Views return the code to clients in an
+//! [`ErrorResponse`] (see [`crate::wire::serve`]), and indexers store it with
+//! the failures recorded in [`crate::diagnostics`]. Codes are part of the view
+//! API: existing values are never renumbered.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum ErrorCode {
+    /// The requested object does not exist
+    NotFound = 1,
+    /// The request is missing a field or carries a malformed one
+    InvalidArgument = 2,
+    /// Stored or submitted bytes could not be decoded
+    DecodeFailure = 3,
+    /// The view or query is not supported yet
+    NotImplemented = 4,
+    /// Anything else; indicates a bug or inconsistent state
+    Internal = 5,
+}
+
+impl ErrorCode {
+    pub fn from_u32(code: u32) -> Option<Self> {
+        match code {
+            1 => Some(ErrorCode::NotFound),
+            2 => Some(ErrorCode::InvalidArgument),
+            3 => Some(ErrorCode::DecodeFailure),
+            4 => Some(ErrorCode::NotImplemented),
+            5 => Some(ErrorCode::Internal),
+            _ => None,
+        }
+    }
+}
+
+impl From<ErrorCode> for u32 {
+    fn from(code: ErrorCode) -> u32 {
+        code as u32
+    }
+}
+
+/// An error that carries a stable [`ErrorCode`]
+pub trait CodedError: std::error::Error {
+    fn code(&self) -> ErrorCode;
+}
+
+/// Error returned by a view in place of its response. Declared for clients
+/// in `proto/error.proto`.
+#[derive(Clone, PartialEq, prost::Message, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ErrorResponse {
+    #[prost(string, tag = "1")]
+    pub error: String,
+    /// An [`ErrorCode`]
+    #[prost(uint32, tag = "2")]
+    pub code: u32,
+}
+
+impl ErrorResponse {
+    pub fn new(code: ErrorCode, error: impl Into<String>) -> Self {
+        Self { error: error.into(), code: code.into() }
+    }
+
+    pub fn from_error<E: CodedError + ?Sized>(error: &E) -> Self {
+        Self::new(error.code(), error.to_string())
+    }
+
+    /// The code as an [`ErrorCode`], `None` if this build doesn't know it
+    pub fn error_code(&self) -> Option<ErrorCode> {
+        ErrorCode::from_u32(self.code)
+    }
+}
@@ -1,5 +1,7 @@
 use bitcoin::{OutPoint, Txid};
+use bincode::Options;
 use bitcoin_hashes::Hash;
+use prost::Message;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
@@ -127,6 +129,10 @@ pub struct InscriptionEntry {
     pub metaprotocol: Option<String>,
     pub pointer: Option<u64>,
     pub charms: u16,
+    /// sha256 of the raw body, `None` for unbound inscriptions
+    pub content_hash: Option<[u8; 32]>,
+    /// Rune named by the envelope's rune tag
+    pub rune: Option<ordinals::Rune>,
 }
 
 impl InscriptionEntry {
@@ -157,15 +163,65 @@ impl InscriptionEntry {
             metaprotocol: None,
             pointer: None,
             charms: 0,
+            content_hash: None,
+            rune: None,
         }
     }
 
+    /// Schema version byte followed by the protobuf-encoded [`EntryRecord`]
     pub fn to_bytes(&self) -> Vec<u8> {
-        bincode::serialize(self).unwrap_or_default()
+        let mut bytes = vec![ENTRY_SCHEMA_VERSION];
+        EntryRecord::from(self).encode(&mut bytes).expect("Vec<u8> grows as needed");
+        bytes
     }
 
-    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
-        bincode::deserialize(bytes).map_err(|e| e.to_string())
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EntryDecodeError> {
+        match bytes.split_first() {
+            None => Err(EntryDecodeError::Empty),
+            Some((&ENTRY_SCHEMA_VERSION, record)) => {
+                let record = EntryRecord::decode(record)
+                    .map_err(|e| EntryDecodeError::Malformed(e.to_string()))?;
+                Self::try_from(record)
+            }
+            Some((&version, _)) => Err(EntryDecodeError::UnsupportedVersion(version)),
+        }
+    }
+
+    /// Decode an entry written before entries were versioned, as plain bincode
+    /// of the struct. Three layouts were written: the original one, then with
+    /// `content_hash` appended, then with `rune` appended as well.
+    pub fn from_legacy_bytes(bytes: &[u8]) -> Result<Self, EntryDecodeError> {
+        let strict = || bincode::DefaultOptions::new().with_fixint_encoding().reject_trailing_bytes();
+        let (base, content_hash, rune) = if let Ok(fields) =
+            strict().deserialize::<(LegacyEntry, Option<[u8; 32]>, Option<ordinals::Rune>)>(bytes)
+        {
+            fields
+        } else if let Ok((base, content_hash)) = strict().deserialize::<(LegacyEntry, Option<[u8; 32]>)>(bytes) {
+            (base, content_hash, None)
+        } else {
+            (strict().deserialize::<LegacyEntry>(bytes).map_err(|e| EntryDecodeError::Malformed(e.to_string()))?, None, None)
+        };
+        Ok(Self {
+            id: base.id,
+            number: base.number,
+            sequence: base.sequence,
+            sat: base.sat,
+            satpoint: base.satpoint,
+            height: base.height,
+            fee: base.fee,
+            content_type: base.content_type,
+            content_length: base.content_length,
+            timestamp: base.timestamp,
+            genesis_fee: base.genesis_fee,
+            genesis_height: base.genesis_height,
+            parent: base.parent,
+            delegate: base.delegate,
+            metaprotocol: base.metaprotocol,
+            pointer: base.pointer,
+            charms: base.charms,
+            content_hash,
+            rune,
+        })
     }
 
     pub fn is_cursed(&self) -> bool {
@@ -189,6 +245,149 @@ impl InscriptionEntry {
     }
 }
 
+/// Version byte leading every stored [`InscriptionEntry`]. Fields can be
+/// added to [`EntryRecord`] under new tags without a bump: older readers skip
+/// them and newer readers default them. Bump only when an existing field
+/// changes meaning, and extend the migration in shrew-ord to match.
+pub const ENTRY_SCHEMA_VERSION: u8 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum EntryDecodeError {
+    #[error("inscription entry is empty")]
+    Empty,
+    #[error("unsupported inscription entry schema version {0}")]
+    UnsupportedVersion(u8),
+    #[error("malformed inscription entry: {0}")]
+    Malformed(String),
+}
+
+/// Wire form of [`InscriptionEntry`] for schema version 1. Tags are
+/// permanent: never renumber or reuse one.
+#[derive(Clone, PartialEq, prost::Message)]
+struct EntryRecord {
+    #[prost(bytes = "vec", tag = "1")]
+    id: Vec<u8>,
+    #[prost(sint32, tag = "2")]
+    number: i32,
+    #[prost(uint32, tag = "3")]
+    sequence: u32,
+    #[prost(uint64, optional, tag = "4")]
+    sat: Option<u64>,
+    #[prost(bytes = "vec", tag = "5")]
+    satpoint: Vec<u8>,
+    #[prost(uint32, tag = "6")]
+    height: u32,
+    #[prost(uint64, tag = "7")]
+    fee: u64,
+    #[prost(string, optional, tag = "8")]
+    content_type: Option<String>,
+    #[prost(uint64, optional, tag = "9")]
+    content_length: Option<u64>,
+    #[prost(uint32, tag = "10")]
+    timestamp: u32,
+    #[prost(uint64, tag = "11")]
+    genesis_fee: u64,
+    #[prost(uint32, tag = "12")]
+    genesis_height: u32,
+    #[prost(bytes = "vec", optional, tag = "13")]
+    parent: Option<Vec<u8>>,
+    #[prost(bytes = "vec", optional, tag = "14")]
+    delegate: Option<Vec<u8>>,
+    #[prost(string, optional, tag = "15")]
+    metaprotocol: Option<String>,
+    #[prost(uint64, optional, tag = "16")]
+    pointer: Option<u64>,
+    #[prost(uint32, tag = "17")]
+    charms: u32,
+    #[prost(bytes = "vec", optional, tag = "18")]
+    content_hash: Option<Vec<u8>>,
+    /// u128 little-endian
+    #[prost(bytes = "vec", optional, tag = "19")]
+    rune: Option<Vec<u8>>,
+}
+
+impl From<&InscriptionEntry> for EntryRecord {
+    fn from(entry: &InscriptionEntry) -> Self {
+        Self {
+            id: entry.id.to_bytes(),
+            number: entry.number,
+            sequence: entry.sequence,
+            sat: entry.sat,
+            satpoint: entry.satpoint.to_bytes(),
+            height: entry.height,
+            fee: entry.fee,
+            content_type: entry.content_type.clone(),
+            content_length: entry.content_length,
+            timestamp: entry.timestamp,
+            genesis_fee: entry.genesis_fee,
+            genesis_height: entry.genesis_height,
+            parent: entry.parent.as_ref().map(InscriptionId::to_bytes),
+            delegate: entry.delegate.as_ref().map(InscriptionId::to_bytes),
+            metaprotocol: entry.metaprotocol.clone(),
+            pointer: entry.pointer,
+            charms: entry.charms.into(),
+            content_hash: entry.content_hash.map(|hash| hash.to_vec()),
+            rune: entry.rune.map(|rune| rune.0.to_le_bytes().to_vec()),
+        }
+    }
+}
+
+impl TryFrom<EntryRecord> for InscriptionEntry {
+    type Error = EntryDecodeError;
+
+    fn try_from(record: EntryRecord) -> Result<Self, Self::Error> {
+        let malformed = |field: &str| EntryDecodeError::Malformed(format!("invalid {}", field));
+        let id = |bytes: Vec<u8>, field: &str| InscriptionId::from_bytes(&bytes).map_err(|_| malformed(field));
+        Ok(Self {
+            id: id(record.id, "id")?,
+            number: record.number,
+            sequence: record.sequence,
+            sat: record.sat,
+            satpoint: SatPoint::from_bytes(&record.satpoint).map_err(|_| malformed("satpoint"))?,
+            height: record.height,
+            fee: record.fee,
+            content_type: record.content_type,
+            content_length: record.content_length,
+            timestamp: record.timestamp,
+            genesis_fee: record.genesis_fee,
+            genesis_height: record.genesis_height,
+            parent: record.parent.map(|bytes| id(bytes, "parent")).transpose()?,
+            delegate: record.delegate.map(|bytes| id(bytes, "delegate")).transpose()?,
+            metaprotocol: record.metaprotocol,
+            pointer: record.pointer,
+            charms: u16::try_from(record.charms).map_err(|_| malformed("charms"))?,
+            content_hash: record.content_hash
+                .map(|bytes| <[u8; 32]>::try_from(bytes).map_err(|_| malformed("content_hash")))
+                .transpose()?,
+            rune: record.rune
+                .map(|bytes| <[u8; 16]>::try_from(bytes).map(|le| ordinals::Rune(u128::from_le_bytes(le))).map_err(|_| malformed("rune")))
+                .transpose()?,
+        })
+    }
+}
+
+/// Unversioned bincode layout of the original entry fields
+#[derive(Deserialize)]
+struct LegacyEntry {
+    id: InscriptionId,
+    number: i32,
+    sequence: u32,
+    sat: Option<u64>,
+    satpoint: SatPoint,
+    height: u32,
+    fee: u64,
+    content_type: Option<String>,
+    content_length: Option<u64>,
+    timestamp: u32,
+    genesis_fee: u64,
+    genesis_height: u32,
+    parent: Option<InscriptionId>,
+    delegate: Option<InscriptionId>,
+    metaprotocol: Option<String>,
+    pointer: Option<u64>,
+    charms: u16,
+}
+
 /// Inscription charms (special properties)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[repr(u16)]
@@ -463,6 +662,77 @@ mod tests {
         assert_eq!(entry.content_length, entry2.content_length);
     }
 
+    fn full_entry() -> InscriptionEntry {
+        let txid = test_txid();
+        let satpoint = SatPoint::new(bitcoin::OutPoint { txid, vout: 2 }, 330);
+        let mut entry = InscriptionEntry::new(InscriptionId::new(txid, 1), -7, 9, satpoint, 824_000, 1_200, 1_700_000_000);
+        entry.sat = Some(1_234_567);
+        entry.content_type = Some("text/plain".to_string());
+        entry.content_length = Some(5);
+        entry.parent = Some(InscriptionId::new(txid, 0));
+        entry.delegate = Some(InscriptionId::new(txid, 3));
+        entry.metaprotocol = Some("brc-20".to_string());
+        entry.pointer = Some(42);
+        entry.set_charm(Charm::Cursed);
+        entry.content_hash = Some([7; 32]);
+        entry.rune = Some(ordinals::Rune(u128::MAX - 1));
+        entry
+    }
+
+    #[test]
+    fn test_inscription_entry_versioned_round_trip() {
+        let entry = full_entry();
+        let bytes = entry.to_bytes();
+        assert_eq!(bytes[0], ENTRY_SCHEMA_VERSION);
+        let decoded = InscriptionEntry::from_bytes(&bytes).unwrap();
+        assert_eq!(format!("{:?}", decoded), format!("{:?}", entry));
+
+        // Fields added under new tags by a later writer are skipped
+        let mut extended = bytes.clone();
+        extended.extend_from_slice(&[0xa0, 0x06, 0x01]); // tag 100, varint 1
+        let decoded = InscriptionEntry::from_bytes(&extended).unwrap();
+        assert_eq!(format!("{:?}", decoded), format!("{:?}", entry));
+    }
+
+    #[test]
+    fn test_inscription_entry_decode_errors() {
+        assert_eq!(InscriptionEntry::from_bytes(&[]).unwrap_err(), EntryDecodeError::Empty);
+        let mut bytes = full_entry().to_bytes();
+        bytes[0] = ENTRY_SCHEMA_VERSION + 1;
+        assert_eq!(
+            InscriptionEntry::from_bytes(&bytes).unwrap_err(),
+            EntryDecodeError::UnsupportedVersion(ENTRY_SCHEMA_VERSION + 1),
+        );
+        let truncated = &full_entry().to_bytes()[..20];
+        assert!(matches!(InscriptionEntry::from_bytes(truncated), Err(EntryDecodeError::Malformed(_))));
+    }
+
+    #[test]
+    fn test_inscription_entry_legacy_layouts() {
+        let entry = full_entry();
+        // Bincode of a struct is its fields back to back, so nested tuples
+        // reproduce the unversioned layouts
+        let original = bincode::serialize(&(
+            (&entry.id, entry.number, entry.sequence, entry.sat, &entry.satpoint, entry.height, entry.fee, &entry.content_type),
+            (entry.content_length, entry.timestamp, entry.genesis_fee, entry.genesis_height, &entry.parent, &entry.delegate, &entry.metaprotocol, entry.pointer, entry.charms),
+        )).unwrap();
+        let decoded = InscriptionEntry::from_legacy_bytes(&original).unwrap();
+        assert_eq!(decoded.parent, entry.parent);
+        assert_eq!(decoded.charms, entry.charms);
+        assert!(decoded.content_hash.is_none() && decoded.rune.is_none());
+
+        let with_hash = [original.clone(), bincode::serialize(&entry.content_hash).unwrap()].concat();
+        let decoded = InscriptionEntry::from_legacy_bytes(&with_hash).unwrap();
+        assert_eq!(decoded.content_hash, entry.content_hash);
+        assert!(decoded.rune.is_none());
+
+        let with_rune = [with_hash, bincode::serialize(&entry.rune).unwrap()].concat();
+        let decoded = InscriptionEntry::from_legacy_bytes(&with_rune).unwrap();
+        assert_eq!(format!("{:?}", decoded), format!("{:?}", entry));
+
+        assert!(InscriptionEntry::from_legacy_bytes(&original[..original.len() - 1]).is_err());
+    }
+
     #[test]
     fn test_inscription_entry_cursed_blessed() {
         let txid = test_txid();
@@ -2,6 +2,13 @@ pub mod inscription;
 pub mod utils;
 pub mod event_hash;
 pub mod constants;
+pub mod wire;
+pub mod rune;
+pub mod table;
+pub mod pagination;
+pub mod error;
+pub mod diagnostics;
 
-pub use inscription::{InscriptionId, SatPoint, InscriptionEntry, Charm, Rarity, Media};
+pub use inscription::{InscriptionId, SatPoint, InscriptionEntry, EntryDecodeError, Charm, Rarity, Media};
+pub use rune::RuneId;
 pub use utils::get_address_from_txout;
@@ -0,0 +1,216 @@
+//! Cursor pagination shared by every list view.
+//!
+//! A list view collects its items in a stable order and returns one page of
+//! them along with a [`PaginationResponse`]. Clients treat `next_cursor` as
+//! opaque and pass it back unchanged to fetch the following page.
+//!
+//! Two kinds of cursor cover the lists the indexers serve:
+//!
+//! - Lists that only grow at the end (sequence ranges, append lists, per-block
+//!   events) use positional cursors: [`paginate_range`] and [`paginate`].
+//! - Lists read out of keyed tables use key cursors: [`paginate_by_key`] sorts
+//!   by key and resumes after the last key returned, so entries inserted
+//!   between requests never shift or repeat items on a later page.
+//!
+//! The messages are declared once in `proto/pagination.proto`. Each indexer's
+//! proto imports that file and maps the `shrew_support` package onto this
+//! module with `extern_path`, so every view shares these exact types.
+
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+/// Page size used when a request leaves `limit` unset
+pub const DEFAULT_PAGE_SIZE: u32 = 100;
+/// Largest page any view returns
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+#[derive(Clone, PartialEq, prost::Message, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaginationRequest {
+    /// Legacy page number, only consulted when `cursor` is empty
+    #[prost(uint32, tag = "1")]
+    pub page: u32,
+    /// Page size; 0 selects [`DEFAULT_PAGE_SIZE`], larger values are capped
+    /// at [`MAX_PAGE_SIZE`]
+    #[prost(uint32, tag = "2")]
+    pub limit: u32,
+    /// `next_cursor` of the previous response; empty for the first page
+    #[prost(string, tag = "3")]
+    pub cursor: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaginationResponse {
+    #[prost(uint32, tag = "2")]
+    pub limit: u32,
+    /// Whether items remain after this page
+    #[prost(bool, tag = "3")]
+    pub more: bool,
+    /// Number of items in the whole list
+    #[prost(uint64, tag = "4")]
+    pub total: u64,
+    /// Cursor for the next page; empty on the last page
+    #[prost(string, tag = "5")]
+    pub next_cursor: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PaginationError {
+    #[error("invalid pagination cursor")]
+    InvalidCursor,
+}
+
+/// Effective page size of a request
+pub fn page_size(request: Option<&PaginationRequest>) -> u32 {
+    match request.map_or(0, |request| request.limit) {
+        0 => DEFAULT_PAGE_SIZE,
+        limit => limit.min(MAX_PAGE_SIZE),
+    }
+}
+
+/// Positions `start..end` to return out of a list of `total` items, for
+/// lists that only grow at the end
+pub fn paginate_range(
+    total: u64,
+    request: Option<&PaginationRequest>,
+) -> Result<(Range<u64>, PaginationResponse), PaginationError> {
+    let limit = page_size(request);
+    let start = match request {
+        Some(request) if !request.cursor.is_empty() => {
+            let position = decode_cursor(&request.cursor)?
+                .try_into()
+                .map_err(|_| PaginationError::InvalidCursor)?;
+            u64::from_be_bytes(position)
+        }
+        Some(request) => request.page as u64 * limit as u64,
+        None => 0,
+    }
+    .min(total);
+    let end = (start + limit as u64).min(total);
+    let next_cursor = if end < total { hex::encode(end.to_be_bytes()) } else { String::new() };
+    Ok((start..end, response(limit, total, next_cursor)))
+}
+
+/// One page of a list that only grows at the end
+pub fn paginate<T>(
+    items: Vec<T>,
+    request: Option<&PaginationRequest>,
+) -> Result<(Vec<T>, PaginationResponse), PaginationError> {
+    let (range, response) = paginate_range(items.len() as u64, request)?;
+    let page = items
+        .into_iter()
+        .skip(range.start as usize)
+        .take((range.end - range.start) as usize)
+        .collect();
+    Ok((page, response))
+}
+
+/// One page of a keyed list, ordered by `key`. Keys must be unique; the
+/// cursor records the last key returned.
+pub fn paginate_by_key<T, K, F>(
+    mut items: Vec<T>,
+    key: F,
+    request: Option<&PaginationRequest>,
+) -> Result<(Vec<T>, PaginationResponse), PaginationError>
+where
+    K: AsRef<[u8]>,
+    F: Fn(&T) -> K,
+{
+    items.sort_by(|a, b| key(a).as_ref().cmp(key(b).as_ref()));
+    let limit = page_size(request);
+    let total = items.len();
+    let start = match request {
+        Some(request) if !request.cursor.is_empty() => {
+            let after = decode_cursor(&request.cursor)?;
+            items.partition_point(|item| key(item).as_ref() <= after.as_slice())
+        }
+        Some(request) => (request.page as usize).saturating_mul(limit as usize).min(total),
+        None => 0,
+    };
+    let end = (start + limit as usize).min(total);
+    let next_cursor = if end < total { hex::encode(key(&items[end - 1])) } else { String::new() };
+    let page = items.into_iter().skip(start).take(end - start).collect();
+    Ok((page, response(limit, total as u64, next_cursor)))
+}
+
+fn response(limit: u32, total: u64, next_cursor: String) -> PaginationResponse {
+    PaginationResponse { limit, more: !next_cursor.is_empty(), total, next_cursor }
+}
+
+fn decode_cursor(cursor: &str) -> Result<Vec<u8>, PaginationError> {
+    hex::decode(cursor).map_err(|_| PaginationError::InvalidCursor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    fn request(limit: u32, cursor: &str) -> PaginationRequest {
+        PaginationRequest { limit, cursor: cursor.to_string(), ..Default::default() }
+    }
+
+    #[test]
+    fn test_page_size_defaults_and_caps() {
+        assert_eq!(page_size(None), DEFAULT_PAGE_SIZE);
+        assert_eq!(page_size(Some(&request(0, ""))), DEFAULT_PAGE_SIZE);
+        assert_eq!(page_size(Some(&request(7, ""))), 7);
+        assert_eq!(page_size(Some(&request(5000, ""))), MAX_PAGE_SIZE);
+    }
+
+    #[test]
+    fn test_positional_cursor_walks_the_whole_list() {
+        let items: Vec<u32> = (0..25).collect();
+        let mut seen = Vec::new();
+        let mut cursor = String::new();
+        loop {
+            let (page, response) = paginate(items.clone(), Some(&request(10, &cursor))).unwrap();
+            assert_eq!(response.total, 25);
+            seen.extend(page);
+            if !response.more {
+                assert!(response.next_cursor.is_empty());
+                break;
+            }
+            cursor = response.next_cursor;
+        }
+        assert_eq!(seen, items);
+    }
+
+    #[test]
+    fn test_legacy_page_number() {
+        let items: Vec<u32> = (0..25).collect();
+        let legacy = PaginationRequest { page: 2, limit: 10, ..Default::default() };
+        let (page, response) = paginate(items, Some(&legacy)).unwrap();
+        assert_eq!(page, (20..25).collect::<Vec<_>>());
+        assert!(!response.more);
+    }
+
+    #[test]
+    fn test_key_cursor_survives_insertions() {
+        let items = vec!["b", "d", "f", "h"];
+        let (page, response) = paginate_by_key(items, |item| item.as_bytes().to_vec(), Some(&request(2, ""))).unwrap();
+        assert_eq!(page, vec!["b", "d"]);
+
+        // An item sorting before the cursor doesn't shift the next page
+        let items = vec!["h", "a", "f", "d", "b"];
+        let (page, response) = paginate_by_key(items, |item| item.as_bytes().to_vec(), Some(&request(2, &response.next_cursor))).unwrap();
+        assert_eq!(page, vec!["f", "h"]);
+        assert!(!response.more);
+        assert_eq!(response.total, 5);
+    }
+
+    #[test]
+    fn test_invalid_cursor() {
+        assert_eq!(paginate(vec![1, 2, 3], Some(&request(1, "zz"))).unwrap_err(), PaginationError::InvalidCursor);
+        assert_eq!(paginate(vec![1, 2, 3], Some(&request(1, "00"))).unwrap_err(), PaginationError::InvalidCursor);
+    }
+
+    #[test]
+    fn test_cursor_past_the_end() {
+        let cursor = hex::encode(50u64.to_be_bytes());
+        let (page, response) = paginate(vec![1, 2, 3], Some(&request(10, &cursor))).unwrap();
+        assert!(page.is_empty());
+        assert!(!response.more);
+    }
+}
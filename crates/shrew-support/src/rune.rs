@@ -0,0 +1,31 @@
+//! Rune identifiers, shared by the runes indexer and the tables that link
+//! inscriptions to runes.
+
+use serde::{Deserialize, Serialize};
+
+/// A RuneId represented as (block_height, tx_index)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct RuneId {
+    pub block: u64,
+    pub tx: u32,
+}
+
+impl RuneId {
+    pub fn new(block: u64, tx: u32) -> Self {
+        Self { block, tx }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(12);
+        bytes.extend_from_slice(&self.block.to_le_bytes());
+        bytes.extend_from_slice(&self.tx.to_le_bytes());
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 12 { return None; }
+        let block = u64::from_le_bytes(bytes[0..8].try_into().ok()?);
+        let tx = u32::from_le_bytes(bytes[8..12].try_into().ok()?);
+        Some(Self { block, tx })
+    }
+}
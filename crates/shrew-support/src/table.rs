@@ -0,0 +1,736 @@
+//! Typed tables over metashrew's `IndexPointer`.
+//!
+//! A table pairs a keyword with a [`KeyCodec`] and a [`ValueCodec`], so the
+//! bytes stored for a given key or value are decided in one place instead of
+//! at every call site. The canonical key encodings are:
+//!
+//! | key             | encoding                                        |
+//! |-----------------|-------------------------------------------------|
+//! | integers        | little-endian                                   |
+//! | `str`, `[u8]`   | raw bytes                                       |
+//! | [`Ticker`]      | lowercased UTF-8 bytes                          |
+//! | `Script`        | raw script bytes                                |
+//! | `Txid`, hashes  | the 32-byte array                               |
+//! | `InscriptionId` | txid bytes, index u32 LE (36 bytes)             |
+//! | `OutPoint`      | txid bytes, vout u32 LE (36 bytes)              |
+//! | `SatPoint`      | outpoint, offset u64 LE (44 bytes)              |
+//! | `RuneId`        | block u64 LE, tx u32 LE (12 bytes)              |
+//! | `(A, B)`        | both keys, for keys of a fixed width            |
+//!
+//! Values use the same fixed encodings, plus [`Json`] and [`Bincode`] for
+//! structured records. metashrew reads a missing key as empty bytes, so a
+//! value that encodes to nothing reads back as absent.
+//!
+//! The store cannot enumerate keys. Tables that need it keep their own key
+//! list: [`Table::indexed`] lists every key ever set, and a [`PrefixTable`]
+//! lists the second-level keys under each first-level key. Key lists live
+//! next to the table's keyword (`/brc20/balances/` keeps them under
+//! `/brc20/balances#keys`), outside the table's own key space.
+
+use bitcoin::{BlockHash, OutPoint, Script, ScriptBuf, Txid};
+use bitcoin_hashes::Hash;
+use metashrew_core::index_pointer::IndexPointer;
+use metashrew_support::index_pointer::KeyValuePointer;
+use serde::{de::DeserializeOwned, Serialize};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use crate::inscription::{InscriptionId, SatPoint};
+use crate::rune::RuneId;
+
+/// Encoding of a table key
+pub trait KeyCodec {
+    type Key: ?Sized + ToOwned;
+
+    fn encode_key(key: &Self::Key) -> Vec<u8>;
+    fn decode_key(bytes: &[u8]) -> Option<<Self::Key as ToOwned>::Owned>;
+}
+
+/// Encoding of a table value
+pub trait ValueCodec {
+    type Value;
+
+    fn encode_value(value: &Self::Value) -> Vec<u8>;
+    fn decode_value(bytes: &[u8]) -> Option<Self::Value>;
+}
+
+/// BRC-20 style ticker key. Tickers are case-insensitive, so they are stored
+/// lowercased and decode to the lowercased form.
+pub struct Ticker;
+
+/// Value stored as JSON
+pub struct Json<T>(PhantomData<T>);
+
+/// Value stored with bincode's default options
+pub struct Bincode<T>(PhantomData<T>);
+
+macro_rules! le_codec {
+    ($($int:ty),*) => {$(
+        impl KeyCodec for $int {
+            type Key = $int;
+
+            fn encode_key(key: &$int) -> Vec<u8> {
+                key.to_le_bytes().to_vec()
+            }
+
+            fn decode_key(bytes: &[u8]) -> Option<$int> {
+                bytes.try_into().ok().map(<$int>::from_le_bytes)
+            }
+        }
+
+        impl ValueCodec for $int {
+            type Value = $int;
+
+            fn encode_value(value: &$int) -> Vec<u8> {
+                value.to_le_bytes().to_vec()
+            }
+
+            fn decode_value(bytes: &[u8]) -> Option<$int> {
+                bytes.try_into().ok().map(<$int>::from_le_bytes)
+            }
+        }
+    )*};
+}
+
+le_codec!(u8, u16, u32, u64, u128, i32);
+
+/// Codec for types that already have a fixed byte encoding
+macro_rules! bytes_codec {
+    ($ty:ty, $encode:expr, $decode:expr) => {
+        impl KeyCodec for $ty {
+            type Key = $ty;
+
+            fn encode_key(key: &$ty) -> Vec<u8> {
+                $encode(key)
+            }
+
+            fn decode_key(bytes: &[u8]) -> Option<$ty> {
+                $decode(bytes)
+            }
+        }
+
+        impl ValueCodec for $ty {
+            type Value = $ty;
+
+            fn encode_value(value: &$ty) -> Vec<u8> {
+                $encode(value)
+            }
+
+            fn decode_value(bytes: &[u8]) -> Option<$ty> {
+                $decode(bytes)
+            }
+        }
+    };
+}
+
+bytes_codec!([u8; 32], |bytes: &[u8; 32]| bytes.to_vec(), |bytes: &[u8]| bytes.try_into().ok());
+bytes_codec!(Txid, |txid: &Txid| txid.as_byte_array().to_vec(), |bytes: &[u8]| Txid::from_slice(bytes).ok());
+bytes_codec!(BlockHash, |hash: &BlockHash| hash.as_byte_array().to_vec(), |bytes: &[u8]| BlockHash::from_slice(bytes).ok());
+bytes_codec!(InscriptionId, InscriptionId::to_bytes, |bytes: &[u8]| InscriptionId::from_bytes(bytes).ok());
+bytes_codec!(SatPoint, SatPoint::to_bytes, |bytes: &[u8]| SatPoint::from_bytes(bytes).ok());
+bytes_codec!(OutPoint, outpoint_bytes, outpoint_from_bytes);
+bytes_codec!(RuneId, RuneId::to_bytes, |bytes: &[u8]| if bytes.len() == 12 { RuneId::from_bytes(bytes) } else { None });
+
+fn outpoint_bytes(outpoint: &OutPoint) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(36);
+    bytes.extend_from_slice(outpoint.txid.as_byte_array());
+    bytes.extend_from_slice(&outpoint.vout.to_le_bytes());
+    bytes
+}
+
+fn outpoint_from_bytes(bytes: &[u8]) -> Option<OutPoint> {
+    if bytes.len() != 36 {
+        return None;
+    }
+    let txid = Txid::from_slice(&bytes[..32]).ok()?;
+    let vout = u32::from_le_bytes(bytes[32..].try_into().ok()?);
+    Some(OutPoint { txid, vout })
+}
+
+/// Keys whose encoding always has the same length, so they can be combined
+/// into tuple keys
+pub trait FixedWidth: KeyCodec<Key = Self> + Clone {
+    const WIDTH: usize;
+}
+
+macro_rules! fixed_width {
+    ($($ty:ty = $width:expr),*) => {$(
+        impl FixedWidth for $ty {
+            const WIDTH: usize = $width;
+        }
+    )*};
+}
+
+fixed_width!(
+    u8 = 1, u16 = 2, u32 = 4, u64 = 8, u128 = 16, i32 = 4,
+    [u8; 32] = 32, Txid = 32, BlockHash = 32,
+    InscriptionId = 36, OutPoint = 36, SatPoint = 44, RuneId = 12
+);
+
+impl<A: FixedWidth, B: FixedWidth> KeyCodec for (A, B) {
+    type Key = (A, B);
+
+    fn encode_key((a, b): &(A, B)) -> Vec<u8> {
+        [A::encode_key(a), B::encode_key(b)].concat()
+    }
+
+    fn decode_key(bytes: &[u8]) -> Option<(A, B)> {
+        if bytes.len() != A::WIDTH + B::WIDTH {
+            return None;
+        }
+        Some((A::decode_key(&bytes[..A::WIDTH])?, B::decode_key(&bytes[A::WIDTH..])?))
+    }
+}
+
+impl KeyCodec for () {
+    type Key = ();
+
+    fn encode_key(_: &()) -> Vec<u8> {
+        Vec::new()
+    }
+
+    fn decode_key(bytes: &[u8]) -> Option<()> {
+        bytes.is_empty().then_some(())
+    }
+}
+
+impl KeyCodec for str {
+    type Key = str;
+
+    fn encode_key(key: &str) -> Vec<u8> {
+        key.as_bytes().to_vec()
+    }
+
+    fn decode_key(bytes: &[u8]) -> Option<String> {
+        String::from_utf8(bytes.to_vec()).ok()
+    }
+}
+
+impl KeyCodec for [u8] {
+    type Key = [u8];
+
+    fn encode_key(key: &[u8]) -> Vec<u8> {
+        key.to_vec()
+    }
+
+    fn decode_key(bytes: &[u8]) -> Option<Vec<u8>> {
+        Some(bytes.to_vec())
+    }
+}
+
+impl KeyCodec for Script {
+    type Key = Script;
+
+    fn encode_key(key: &Script) -> Vec<u8> {
+        key.to_bytes()
+    }
+
+    fn decode_key(bytes: &[u8]) -> Option<ScriptBuf> {
+        Some(ScriptBuf::from_bytes(bytes.to_vec()))
+    }
+}
+
+impl KeyCodec for Ticker {
+    type Key = str;
+
+    fn encode_key(key: &str) -> Vec<u8> {
+        key.to_lowercase().into_bytes()
+    }
+
+    fn decode_key(bytes: &[u8]) -> Option<String> {
+        String::from_utf8(bytes.to_vec()).ok()
+    }
+}
+
+impl ValueCodec for bool {
+    type Value = bool;
+
+    fn encode_value(value: &bool) -> Vec<u8> {
+        vec![*value as u8]
+    }
+
+    fn decode_value(bytes: &[u8]) -> Option<bool> {
+        match bytes {
+            [0] => Some(false),
+            [1] => Some(true),
+            _ => None,
+        }
+    }
+}
+
+impl ValueCodec for String {
+    type Value = String;
+
+    fn encode_value(value: &String) -> Vec<u8> {
+        value.as_bytes().to_vec()
+    }
+
+    fn decode_value(bytes: &[u8]) -> Option<String> {
+        String::from_utf8(bytes.to_vec()).ok()
+    }
+}
+
+impl ValueCodec for Vec<u8> {
+    type Value = Vec<u8>;
+
+    fn encode_value(value: &Vec<u8>) -> Vec<u8> {
+        value.clone()
+    }
+
+    fn decode_value(bytes: &[u8]) -> Option<Vec<u8>> {
+        Some(bytes.to_vec())
+    }
+}
+
+impl ValueCodec for ScriptBuf {
+    type Value = ScriptBuf;
+
+    fn encode_value(value: &ScriptBuf) -> Vec<u8> {
+        value.to_bytes()
+    }
+
+    fn decode_value(bytes: &[u8]) -> Option<ScriptBuf> {
+        Some(ScriptBuf::from_bytes(bytes.to_vec()))
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> ValueCodec for Json<T> {
+    type Value = T;
+
+    fn encode_value(value: &T) -> Vec<u8> {
+        serde_json::to_vec(value).unwrap_or_default()
+    }
+
+    fn decode_value(bytes: &[u8]) -> Option<T> {
+        serde_json::from_slice(bytes).ok()
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> ValueCodec for Bincode<T> {
+    type Value = T;
+
+    fn encode_value(value: &T) -> Vec<u8> {
+        bincode::serialize(value).unwrap_or_default()
+    }
+
+    fn decode_value(bytes: &[u8]) -> Option<T> {
+        bincode::deserialize(bytes).ok()
+    }
+}
+
+type Owned<K> = <<K as KeyCodec>::Key as ToOwned>::Owned;
+
+/// Pointer next to `keyword` for bookkeeping that must not collide with the
+/// table's keys. Table keywords end in `/`, which the sibling drops.
+fn sibling(keyword: &str, suffix: &str) -> IndexPointer {
+    IndexPointer::from_keyword(&format!("{}{}", keyword.trim_end_matches('/'), suffix))
+}
+
+/// Append-only key list that skips keys it has already listed
+#[derive(Clone)]
+struct KeyList {
+    keys: IndexPointer,
+    listed: IndexPointer,
+}
+
+impl KeyList {
+    fn new(keyword: &str) -> Self {
+        Self { keys: sibling(keyword, "#keys"), listed: sibling(keyword, "#listed/") }
+    }
+
+    fn insert(&self, list: &[u8], key: &[u8]) {
+        let mut listed = self.listed.select(&list.to_vec()).select(&key.to_vec());
+        if listed.get().is_empty() {
+            listed.set(Arc::new(vec![1]));
+            self.keys.select(&list.to_vec()).append(Arc::new(key.to_vec()));
+        }
+    }
+
+    fn get(&self, list: &[u8]) -> Vec<Arc<Vec<u8>>> {
+        self.keys.select(&list.to_vec()).get_list()
+    }
+}
+
+/// A keyword mapping keys to single values
+pub struct Table<K: KeyCodec + ?Sized, V: ValueCodec> {
+    pointer: IndexPointer,
+    keys: Option<KeyList>,
+    codec: PhantomData<(fn(&K), fn() -> V)>,
+}
+
+impl<K: KeyCodec + ?Sized, V: ValueCodec> Table<K, V> {
+    pub fn new(keyword: &str) -> Self {
+        Self { pointer: IndexPointer::from_keyword(keyword), keys: None, codec: PhantomData }
+    }
+
+    /// Table that also lists its keys, for [`keys`](Self::keys) and [`entries`](Self::entries)
+    pub fn indexed(keyword: &str) -> Self {
+        Self { keys: Some(KeyList::new(keyword)), ..Self::new(keyword) }
+    }
+
+    /// Raw pointer for `key`, for values stored in more than one record
+    pub fn pointer(&self, key: &K::Key) -> IndexPointer {
+        self.pointer.select(&K::encode_key(key))
+    }
+
+    /// Value under `key`, `None` if it is missing or does not decode
+    pub fn get(&self, key: &K::Key) -> Option<V::Value> {
+        let bytes = self.pointer(key).get();
+        if bytes.is_empty() {
+            return None;
+        }
+        V::decode_value(&bytes)
+    }
+
+    pub fn contains(&self, key: &K::Key) -> bool {
+        !self.pointer(key).get().is_empty()
+    }
+
+    pub fn set(&self, key: &K::Key, value: &V::Value) {
+        let key = K::encode_key(key);
+        if let Some(keys) = &self.keys {
+            keys.insert(&[], &key);
+        }
+        self.pointer.select(&key).set(Arc::new(V::encode_value(value)));
+    }
+
+    /// Remove the value under `key`. An indexed table keeps listing the key,
+    /// but [`entries`](Self::entries) skips it while it has no value.
+    pub fn delete(&self, key: &K::Key) {
+        self.pointer(key).set(Arc::new(Vec::new()));
+    }
+
+    /// Keys ever set, in first-set order. Empty unless the table is indexed.
+    pub fn keys(&self) -> Vec<Owned<K>> {
+        let Some(keys) = &self.keys else { return Vec::new() };
+        keys.get(&[]).iter().filter_map(|bytes| K::decode_key(bytes)).collect()
+    }
+
+    /// Keys with their current values, in first-set order. Empty unless the table is indexed.
+    pub fn entries(&self) -> Vec<(Owned<K>, V::Value)> {
+        let Some(keys) = &self.keys else { return Vec::new() };
+        keys.get(&[])
+            .iter()
+            .filter_map(|bytes| {
+                let value = self.pointer.select(bytes).get();
+                if value.is_empty() {
+                    return None;
+                }
+                Some((K::decode_key(bytes)?, V::decode_value(&value)?))
+            })
+            .collect()
+    }
+}
+
+/// Counter helpers for tables of integers. A missing value counts as zero,
+/// and additions saturate.
+macro_rules! counter_table {
+    ($($int:ty),*) => {$(
+        impl<K: KeyCodec + ?Sized> Table<K, $int> {
+            /// Add `delta` to the counter under `key`, returning the new value
+            pub fn add(&self, key: &K::Key, delta: $int) -> $int {
+                let value = self.get(key).unwrap_or(0).saturating_add(delta);
+                self.set(key, &value);
+                value
+            }
+
+            pub fn increment(&self, key: &K::Key) -> $int {
+                self.add(key, 1)
+            }
+        }
+
+        impl<A: KeyCodec + ?Sized, B: KeyCodec + ?Sized> PrefixTable<A, B, $int> {
+            /// Add `delta` to the counter under a composite key, returning the new value
+            pub fn add(&self, prefix: &A::Key, key: &B::Key, delta: $int) -> $int {
+                let value = self.get(prefix, key).unwrap_or(0).saturating_add(delta);
+                self.set(prefix, key, &value);
+                value
+            }
+
+            pub fn increment(&self, prefix: &A::Key, key: &B::Key) -> $int {
+                self.add(prefix, key, 1)
+            }
+        }
+
+        impl Slot<$int> {
+            /// Add `delta` to the counter, returning the new value
+            pub fn add(&self, delta: $int) -> $int {
+                let value = self.get().unwrap_or(0).saturating_add(delta);
+                self.set(&value);
+                value
+            }
+
+            pub fn increment(&self) -> $int {
+                self.add(1)
+            }
+        }
+    )*};
+}
+
+counter_table!(u32, u64, u128);
+
+/// A keyword mapping keys to append-only lists
+pub struct ListTable<K: KeyCodec + ?Sized, V: ValueCodec> {
+    pointer: IndexPointer,
+    codec: PhantomData<(fn(&K), fn() -> V)>,
+}
+
+impl<K: KeyCodec + ?Sized, V: ValueCodec> ListTable<K, V> {
+    pub fn new(keyword: &str) -> Self {
+        Self { pointer: IndexPointer::from_keyword(keyword), codec: PhantomData }
+    }
+
+    /// Raw pointer for the list under `key`
+    pub fn pointer(&self, key: &K::Key) -> IndexPointer {
+        self.pointer.select(&K::encode_key(key))
+    }
+
+    pub fn append(&self, key: &K::Key, value: &V::Value) {
+        self.pointer(key).append(Arc::new(V::encode_value(value)));
+    }
+
+    /// Items listed under `key`, skipping any that do not decode
+    pub fn get_list(&self, key: &K::Key) -> Vec<V::Value> {
+        self.pointer(key).get_list().iter().filter_map(|bytes| V::decode_value(bytes)).collect()
+    }
+
+    pub fn length(&self, key: &K::Key) -> u32 {
+        self.pointer(key).length()
+    }
+
+    pub fn get_index(&self, key: &K::Key, index: u32) -> Option<V::Value> {
+        let bytes = self.pointer(key).select_index(index).get();
+        if bytes.is_empty() {
+            return None;
+        }
+        V::decode_value(&bytes)
+    }
+}
+
+/// A keyword mapping pairs of keys to single values, listing the second keys
+/// under each first key. Composite keys are stored as the first key's length
+/// (u32 LE), the first key, then the second key.
+pub struct PrefixTable<A: KeyCodec + ?Sized, B: KeyCodec + ?Sized, V: ValueCodec> {
+    pointer: IndexPointer,
+    keys: KeyList,
+    codec: PhantomData<(fn(&A), fn(&B), fn() -> V)>,
+}
+
+impl<A: KeyCodec + ?Sized, B: KeyCodec + ?Sized, V: ValueCodec> PrefixTable<A, B, V> {
+    pub fn new(keyword: &str) -> Self {
+        Self { pointer: IndexPointer::from_keyword(keyword), keys: KeyList::new(keyword), codec: PhantomData }
+    }
+
+    fn prefix(prefix: &A::Key) -> Vec<u8> {
+        let prefix = A::encode_key(prefix);
+        [&(prefix.len() as u32).to_le_bytes()[..], &prefix].concat()
+    }
+
+    /// Raw pointer for a composite key
+    pub fn pointer(&self, prefix: &A::Key, key: &B::Key) -> IndexPointer {
+        self.pointer.select(&Self::prefix(prefix)).select(&B::encode_key(key))
+    }
+
+    pub fn get(&self, prefix: &A::Key, key: &B::Key) -> Option<V::Value> {
+        let bytes = self.pointer(prefix, key).get();
+        if bytes.is_empty() {
+            return None;
+        }
+        V::decode_value(&bytes)
+    }
+
+    pub fn contains(&self, prefix: &A::Key, key: &B::Key) -> bool {
+        !self.pointer(prefix, key).get().is_empty()
+    }
+
+    pub fn set(&self, prefix: &A::Key, key: &B::Key, value: &V::Value) {
+        let prefix = Self::prefix(prefix);
+        let key = B::encode_key(key);
+        self.keys.insert(&prefix, &key);
+        self.pointer.select(&prefix).select(&key).set(Arc::new(V::encode_value(value)));
+    }
+
+    /// Remove a value. The key stays listed under its prefix, but
+    /// [`entries`](Self::entries) skips it while it has no value.
+    pub fn delete(&self, prefix: &A::Key, key: &B::Key) {
+        self.pointer(prefix, key).set(Arc::new(Vec::new()));
+    }
+
+    /// Second keys ever set under `prefix`, in first-set order
+    pub fn keys(&self, prefix: &A::Key) -> Vec<Owned<B>> {
+        self.keys.get(&Self::prefix(prefix)).iter().filter_map(|bytes| B::decode_key(bytes)).collect()
+    }
+
+    /// Second keys under `prefix` with their current values, in first-set order
+    pub fn entries(&self, prefix: &A::Key) -> Vec<(Owned<B>, V::Value)> {
+        let prefix = Self::prefix(prefix);
+        let pointer = self.pointer.select(&prefix);
+        self.keys.get(&prefix)
+            .iter()
+            .filter_map(|bytes| {
+                let value = pointer.select(bytes).get();
+                if value.is_empty() {
+                    return None;
+                }
+                Some((B::decode_key(bytes)?, V::decode_value(&value)?))
+            })
+            .collect()
+    }
+}
+
+/// A single value stored directly under a keyword
+pub struct Slot<V: ValueCodec> {
+    pointer: IndexPointer,
+    codec: PhantomData<fn() -> V>,
+}
+
+impl<V: ValueCodec> Slot<V> {
+    pub fn new(keyword: &str) -> Self {
+        Self { pointer: IndexPointer::from_keyword(keyword), codec: PhantomData }
+    }
+
+    pub fn get(&self) -> Option<V::Value> {
+        let bytes = self.pointer.get();
+        if bytes.is_empty() {
+            return None;
+        }
+        V::decode_value(&bytes)
+    }
+
+    pub fn set(&self, value: &V::Value) {
+        self.pointer.clone().set(Arc::new(V::encode_value(value)));
+    }
+
+    pub fn delete(&self) {
+        self.pointer.clone().set(Arc::new(Vec::new()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::Txid;
+    use serde::Deserialize;
+    use std::str::FromStr;
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    fn test_txid() -> Txid {
+        Txid::from_str("ababababababababababababababababababababababababababababababab01").unwrap()
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        name: String,
+        amount: u64,
+    }
+
+    #[test]
+    fn test_canonical_key_encodings() {
+        let txid = test_txid();
+        let id = InscriptionId::new(txid, 2);
+        let outpoint = OutPoint { txid, vout: 2 };
+        assert_eq!(InscriptionId::encode_key(&id), id.to_bytes());
+        assert_eq!(OutPoint::encode_key(&outpoint), id.to_bytes());
+        assert_eq!(OutPoint::decode_key(&id.to_bytes()), Some(outpoint));
+        let satpoint = SatPoint::new(outpoint, 7);
+        assert_eq!(SatPoint::decode_key(&SatPoint::encode_key(&satpoint)), Some(satpoint));
+        assert_eq!(u32::encode_key(&840000), 840000u32.to_le_bytes().to_vec());
+        assert_eq!(Ticker::encode_key("OrDi"), b"ordi".to_vec());
+        assert_eq!(Txid::decode_key(&Txid::encode_key(&txid)), Some(txid));
+        assert!(OutPoint::decode_key(&[0u8; 35]).is_none());
+        let rune_id = RuneId::new(840000, 1);
+        assert_eq!(RuneId::encode_key(&rune_id).len(), 12);
+        assert_eq!(RuneId::decode_key(&RuneId::encode_key(&rune_id)), Some(rune_id));
+        let pair = (rune_id, 7u32);
+        assert_eq!(<(RuneId, u32)>::decode_key(&<(RuneId, u32)>::encode_key(&pair)), Some(pair));
+        assert!(<(RuneId, u32)>::decode_key(&[0u8; 15]).is_none());
+    }
+
+    #[test]
+    fn test_value_codecs() {
+        let record = Record { name: "ordi".to_string(), amount: 21 };
+        assert_eq!(Json::<Record>::decode_value(&Json::<Record>::encode_value(&record)), Some(record));
+        assert_eq!(Bincode::<(u32, String)>::decode_value(&Bincode::<(u32, String)>::encode_value(&(1, "a".into()))), Some((1, "a".into())));
+        assert_eq!(bool::decode_value(&bool::encode_value(&false)), Some(false));
+        assert!(u64::decode_value(&[1, 2, 3]).is_none());
+        assert!(Json::<Record>::decode_value(b"not json").is_none());
+    }
+
+    #[test]
+    fn test_table_get_set_delete() {
+        metashrew_core::clear();
+        let table: Table<Ticker, Json<Record>> = Table::new("/test/tickers/");
+        let record = Record { name: "ordi".to_string(), amount: 21 };
+        assert_eq!(table.get("ordi"), None);
+        table.set("ORDI", &record);
+        assert_eq!(table.get("ordi"), Some(record));
+        assert!(table.contains("Ordi"));
+        table.delete("ordi");
+        assert!(!table.contains("ordi"));
+        assert!(table.keys().is_empty());
+    }
+
+    #[test]
+    fn test_indexed_table_keys() {
+        metashrew_core::clear();
+        let table: Table<str, u64> = Table::indexed("/test/indexed/");
+        table.set("b", &2);
+        table.set("a", &1);
+        table.set("b", &3);
+        table.delete("a");
+        table.set("c", &4);
+        assert_eq!(table.keys(), vec!["b".to_string(), "a".to_string(), "c".to_string()]);
+        assert_eq!(table.entries(), vec![("b".to_string(), 3), ("c".to_string(), 4)]);
+        table.set("a", &5);
+        assert_eq!(table.keys().len(), 3);
+    }
+
+    #[test]
+    fn test_counters() {
+        metashrew_core::clear();
+        let table: Table<u32, u64> = Table::new("/test/counts/");
+        assert_eq!(table.increment(&1), 1);
+        assert_eq!(table.add(&1, 4), 5);
+        assert_eq!(table.get(&2), None);
+        assert_eq!(table.add(&2, u64::MAX), u64::MAX);
+        assert_eq!(table.increment(&2), u64::MAX);
+
+        let slot: Slot<u32> = Slot::new("/test/counter");
+        assert_eq!(slot.get(), None);
+        assert_eq!(slot.increment(), 1);
+        assert_eq!(slot.add(2), 3);
+        slot.delete();
+        assert_eq!(slot.get(), None);
+    }
+
+    #[test]
+    fn test_list_table() {
+        metashrew_core::clear();
+        let table: ListTable<u32, InscriptionId> = ListTable::new("/test/lists/");
+        let first = InscriptionId::new(test_txid(), 0);
+        let second = InscriptionId::new(test_txid(), 1);
+        table.append(&7, &first);
+        table.append(&7, &second);
+        assert_eq!(table.length(&7), 2);
+        assert_eq!(table.get_list(&7), vec![first, second.clone()]);
+        assert_eq!(table.get_index(&7, 1), Some(second));
+        assert!(table.get_list(&8).is_empty());
+    }
+
+    #[test]
+    fn test_prefix_table_iteration() {
+        metashrew_core::clear();
+        let table: PrefixTable<Script, Ticker, u128> = PrefixTable::new("/test/balances/");
+        let alice = ScriptBuf::from_bytes(vec![0x51]);
+        let bob = ScriptBuf::from_bytes(vec![0x51, 0x52]);
+        table.set(&alice, "ORDI", &10);
+        table.set(&alice, "sats", &20);
+        table.set(&bob, "ordi", &30);
+        assert_eq!(table.get(&alice, "ordi"), Some(10));
+        assert_eq!(table.entries(&alice), vec![("ordi".to_string(), 10), ("sats".to_string(), 20)]);
+        assert_eq!(table.entries(&bob), vec![("ordi".to_string(), 30)]);
+        table.delete(&alice, "ordi");
+        assert_eq!(table.keys(&alice).len(), 2);
+        assert_eq!(table.entries(&alice), vec![("sats".to_string(), 20)]);
+    }
+}
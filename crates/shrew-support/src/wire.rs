@@ -0,0 +1,220 @@
+//! Wire format negotiation for view functions.
+//!
+//! Views accept either JSON (the historical format) or binary protobuf. A
+//! request whose first byte is [`PROTOBUF_MARKER`] is decoded as protobuf from
+//! the remaining bytes and answered with a plain protobuf-encoded response;
+//! anything else is treated as JSON and answered with JSON.
+//!
+//! A view that fails answers with an [`ErrorResponse`] in the request's format
+//! behind [`ERROR_MARKER`], instead of its response; [`serve`] does this for
+//! every view.
+//!
+//! `0x00` can never start a JSON document, and it is not a valid protobuf field
+//! tag either, so both markers are unambiguous in both directions.
+
+use crate::error::{CodedError, ErrorCode, ErrorResponse};
+use prost::Message;
+use serde::{de::DeserializeOwned, Serialize};
+use std::error::Error;
+
+/// Leading byte that selects binary protobuf encoding for a view call
+pub const PROTOBUF_MARKER: u8 = 0x00;
+/// Leading byte of a view output that carries an [`ErrorResponse`]
+pub const ERROR_MARKER: u8 = 0x00;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WireFormat {
+    Json,
+    Protobuf,
+}
+
+impl WireFormat {
+    /// Detect the format of a raw view input
+    pub fn detect(input: &[u8]) -> Self {
+        match input.first() {
+            Some(&PROTOBUF_MARKER) => WireFormat::Protobuf,
+            _ => WireFormat::Json,
+        }
+    }
+}
+
+/// Decode a view request, returning the format the caller used so the
+/// response can be encoded the same way.
+pub fn decode_request<T>(input: &[u8]) -> Result<(T, WireFormat), Box<dyn Error>>
+where
+    T: Message + Default + DeserializeOwned,
+{
+    match WireFormat::detect(input) {
+        WireFormat::Protobuf => Ok((T::decode(&input[1..])?, WireFormat::Protobuf)),
+        WireFormat::Json => Ok((serde_json::from_slice(input)?, WireFormat::Json)),
+    }
+}
+
+/// Encode a view response in the given format
+pub fn encode_response<T>(response: &T, format: WireFormat) -> Result<Vec<u8>, Box<dyn Error>>
+where
+    T: Message + Serialize,
+{
+    match format {
+        WireFormat::Protobuf => Ok(response.encode_to_vec()),
+        WireFormat::Json => Ok(serde_json::to_vec(response)?),
+    }
+}
+
+/// Encode an error in the given format, behind [`ERROR_MARKER`]
+pub fn encode_error(error: &ErrorResponse, format: WireFormat) -> Result<Vec<u8>, Box<dyn Error>> {
+    let mut output = vec![ERROR_MARKER];
+    output.extend(encode_response(error, format)?);
+    Ok(output)
+}
+
+/// Run a view on a raw input: decode the request, call `view` and encode what
+/// it returns. A request that fails to decode is answered with
+/// [`ErrorCode::InvalidArgument`].
+pub fn serve<Req, Resp, E, F>(input: &[u8], view: F) -> Result<Vec<u8>, Box<dyn Error>>
+where
+    Req: Message + Default + DeserializeOwned,
+    Resp: Message + Serialize,
+    E: CodedError,
+    F: FnOnce(&Req) -> Result<Resp, E>,
+{
+    let format = WireFormat::detect(input);
+    let request = match decode_request::<Req>(input) {
+        Ok((request, _)) => request,
+        Err(error) => {
+            let error = ErrorResponse::new(ErrorCode::InvalidArgument, format!("malformed request: {}", error));
+            return encode_error(&error, format);
+        }
+    };
+    match view(&request) {
+        Ok(response) => encode_response(&response, format),
+        Err(error) => encode_error(&ErrorResponse::from_error(&error), format),
+    }
+}
+
+/// Decode a view output in the given format: the response, or the error the
+/// view returned in its place
+pub fn decode_response<T>(output: &[u8], format: WireFormat) -> Result<Result<T, ErrorResponse>, Box<dyn Error>>
+where
+    T: Message + Default + DeserializeOwned,
+{
+    fn decode<T: Message + Default + DeserializeOwned>(bytes: &[u8], format: WireFormat) -> Result<T, Box<dyn Error>> {
+        match format {
+            WireFormat::Protobuf => Ok(T::decode(bytes)?),
+            WireFormat::Json => Ok(serde_json::from_slice(bytes)?),
+        }
+    }
+    match output.first() {
+        Some(&ERROR_MARKER) => Ok(Err(decode(&output[1..], format)?)),
+        _ => Ok(Ok(decode(output, format)?)),
+    }
+}
+
+/// Prefix an encoded protobuf message with [`PROTOBUF_MARKER`], producing a
+/// view input that selects binary encoding.
+pub fn protobuf_request<T: Message>(request: &T) -> Vec<u8> {
+    let mut input = Vec::with_capacity(request.encoded_len() + 1);
+    input.push(PROTOBUF_MARKER);
+    request.encode(&mut input).expect("Vec has unbounded capacity");
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[derive(Clone, PartialEq, prost::Message, serde::Serialize, serde::Deserialize)]
+    struct Sample {
+        #[prost(string, tag = "1")]
+        name: String,
+        #[prost(uint32, tag = "2")]
+        height: u32,
+    }
+
+    #[test]
+    fn test_detect_format() {
+        assert_eq!(WireFormat::detect(b"{\"name\":\"a\"}"), WireFormat::Json);
+        assert_eq!(WireFormat::detect(&[PROTOBUF_MARKER, 0x10, 0x01]), WireFormat::Protobuf);
+        assert_eq!(WireFormat::detect(&[]), WireFormat::Json);
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let (req, format) = decode_request::<Sample>(br#"{"name":"ordi","height":5}"#).unwrap();
+        assert_eq!(format, WireFormat::Json);
+        assert_eq!(req, Sample { name: "ordi".to_string(), height: 5 });
+        let out = encode_response(&req, format).unwrap();
+        assert_eq!(serde_json::from_slice::<Sample>(&out).unwrap(), req);
+    }
+
+    #[test]
+    fn test_protobuf_round_trip() {
+        let sample = Sample { name: "ordi".to_string(), height: 840000 };
+        let (req, format) = decode_request::<Sample>(&protobuf_request(&sample)).unwrap();
+        assert_eq!(format, WireFormat::Protobuf);
+        assert_eq!(req, sample);
+        let out = encode_response(&req, format).unwrap();
+        assert_eq!(Sample::decode(out.as_slice()).unwrap(), sample);
+    }
+
+    #[test]
+    fn test_protobuf_default_message() {
+        let (req, format) = decode_request::<Sample>(&[PROTOBUF_MARKER]).unwrap();
+        assert_eq!(format, WireFormat::Protobuf);
+        assert_eq!(req, Sample::default());
+        assert!(encode_response(&req, format).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_malformed_protobuf_is_error() {
+        assert!(decode_request::<Sample>(&[PROTOBUF_MARKER, 0x0a, 0x05, b'a']).is_err());
+    }
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("no sample named {0}")]
+    struct MissingSample(String);
+
+    impl CodedError for MissingSample {
+        fn code(&self) -> ErrorCode { ErrorCode::NotFound }
+    }
+
+    fn lookup(request: &Sample) -> Result<Sample, MissingSample> {
+        match request.name.as_str() {
+            "ordi" => Ok(Sample { name: "ordi".to_string(), height: 779832 }),
+            name => Err(MissingSample(name.to_string())),
+        }
+    }
+
+    #[test]
+    fn test_serve_response() {
+        let output = serve(br#"{"name":"ordi"}"#, lookup).unwrap();
+        let response = decode_response::<Sample>(&output, WireFormat::Json).unwrap().unwrap();
+        assert_eq!(response.height, 779832);
+
+        let request = protobuf_request(&Sample { name: "ordi".to_string(), height: 0 });
+        let output = serve(&request, lookup).unwrap();
+        assert_eq!(Sample::decode(output.as_slice()).unwrap().height, 779832);
+    }
+
+    #[test]
+    fn test_serve_error_in_request_format() {
+        let output = serve(br#"{"name":"sats"}"#, lookup).unwrap();
+        assert_eq!(output[0], ERROR_MARKER);
+        let error = decode_response::<Sample>(&output, WireFormat::Json).unwrap().unwrap_err();
+        assert_eq!(error.error_code(), Some(ErrorCode::NotFound));
+        assert_eq!(error.error, "no sample named sats");
+
+        let request = protobuf_request(&Sample { name: "sats".to_string(), height: 0 });
+        let output = serve(&request, lookup).unwrap();
+        let error = decode_response::<Sample>(&output, WireFormat::Protobuf).unwrap().unwrap_err();
+        assert_eq!(error.code, 1);
+    }
+
+    #[test]
+    fn test_serve_malformed_request() {
+        let output = serve(b"{not json", lookup).unwrap();
+        let error = decode_response::<Sample>(&output, WireFormat::Json).unwrap().unwrap_err();
+        assert_eq!(error.error_code(), Some(ErrorCode::InvalidArgument));
+    }
+}
@@ -1,11 +1,17 @@
 use bitcoin::{Block, Transaction, OutPoint, Txid};
 use crate::blocks::{create_coinbase_transaction, create_block_with_txs};
+use crate::indexing::index_ord_block;
 use crate::transactions::{
     create_inscription_transaction, create_inscription_transaction_to_address,
-    create_transfer_transaction_to_address,
+    create_mock_outpoint, create_transfer_transaction_to_address,
 };
 use bitcoin::Address;
 use bitcoin::address::NetworkChecked;
+use shrew_brc20::brc20::Brc20Indexer;
+use shrew_brc20::rules::Brc20Rules;
+use metashrew_core::index_pointer::IndexPointer;
+use metashrew_support::index_pointer::KeyValuePointer;
+use std::sync::Arc;
 
 /// Create a BRC20 JSON payload
 pub fn create_brc20_json(op: &str, ticker: &str, fields: &[(&str, &str)]) -> Vec<u8> {
@@ -17,6 +23,24 @@ pub fn create_brc20_json(op: &str, ticker: &str, fields: &[(&str, &str)]) -> Vec
     json.into_bytes()
 }
 
+/// Index a block through ord, then through the BRC20 indexer under the
+/// regtest rules, where every BRC20 rule is active from height 0
+pub fn index_block(block: &Block, height: u32) {
+    index_ord_block(block, height).unwrap();
+    Brc20Indexer::with_rules(Brc20Rules::regtest()).process_block(block, height);
+}
+
+/// Inscribe a BRC20 `op` on `ticker` to `owner`, spending mock commit output
+/// `commit` so that no two inscriptions are reinscriptions of each other
+pub fn inscribe(op: &str, ticker: &str, fields: &[(&str, &str)], owner: &Address<NetworkChecked>, commit: u32) -> Transaction {
+    create_inscription_transaction_to_address(
+        &create_brc20_json(op, ticker, fields),
+        "text/plain",
+        Some(create_mock_outpoint(commit)),
+        owner,
+    )
+}
+
 /// Create a block with a BRC20 deploy inscription
 pub fn create_brc20_deploy_block(ticker: &str, max: &str, lim: &str) -> (Block, Transaction) {
     let content = create_brc20_json("deploy", ticker, &[("max", max), ("lim", lim)]);
@@ -69,3 +93,31 @@ pub fn create_brc20_transfer_claim_block(
     let block = create_block_with_txs(vec![create_coinbase_transaction(3), tx.clone()]);
     (block, tx)
 }
+
+/// Store a balance of `owner` byte for byte as the original table layout
+/// did: the JSON `Balance` under the raw key `{owner}:{ticker}` of
+/// `/brc20/balances/`, with no key list. `owner` is a mainnet address for
+/// minted balances, or a hex pkscript for received transfers and deposits.
+pub fn set_original_layout_balance(owner: &str, ticker: &str, total_balance: u128, available_balance: u128) {
+    let ticker = ticker.to_lowercase();
+    let json = format!(
+        r#"{{"ticker":"{}","total_balance":{},"available_balance":{}}}"#,
+        ticker, total_balance, available_balance
+    );
+    IndexPointer::from_keyword("/brc20/balances/")
+        .select(&format!("{}:{}", owner, ticker).into_bytes())
+        .set(Arc::new(json.into_bytes()));
+}
+
+/// Store a deployed ticker as the original table layout did: its JSON under
+/// its lowercase name in `/brc20/tickers/`, without listing the name
+pub fn set_original_layout_ticker(name: &str, deploy_inscription_id: &str, max_supply: u128, current_supply: u128) {
+    let name = name.to_lowercase();
+    let json = format!(
+        r#"{{"name":"{}","max_supply":{},"current_supply":{},"limit_per_mint":{},"decimals":18,"deploy_inscription_id":"{}","is_self_mint":false,"burned_supply":0}}"#,
+        name, max_supply, current_supply, max_supply, deploy_inscription_id
+    );
+    IndexPointer::from_keyword("/brc20/tickers/")
+        .select(&name.clone().into_bytes())
+        .set(Arc::new(json.into_bytes()));
+}
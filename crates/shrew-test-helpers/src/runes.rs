@@ -2,8 +2,6 @@ use bitcoin::{Amount, Block, Transaction, TxIn, TxOut, OutPoint, Witness, Script
 use ordinals::{Runestone, Etching, Rune, Terms, Edict, RuneId as OrdRuneId};
 use shrew_runes::balance_sheet::{BalanceSheet, RuneId};
 use shrew_runes::tables::RUNE_BALANCES_BY_OUTPOINT;
-use metashrew_support::index_pointer::KeyValuePointer;
-use bitcoin_hashes::Hash;
 
 use crate::blocks::{create_coinbase_transaction, create_block_with_txs};
 use crate::state::get_test_address;
@@ -88,12 +86,5 @@ pub fn create_edict_block(rune_id: RuneId, edicts: Vec<(u128, u32)>, height: u32
 
 /// Get rune balance for an outpoint
 pub fn get_rune_balance(outpoint: &OutPoint) -> BalanceSheet {
-    let outpoint_bytes: Vec<u8> = outpoint.txid.as_byte_array().iter()
-        .chain(outpoint.vout.to_le_bytes().iter()).copied().collect();
-    let data = RUNE_BALANCES_BY_OUTPOINT.select(&outpoint_bytes).get();
-    if data.is_empty() {
-        BalanceSheet::new()
-    } else {
-        BalanceSheet::from_bytes(&data).unwrap_or_default()
-    }
+    RUNE_BALANCES_BY_OUTPOINT.get(outpoint).unwrap_or_default()
 }
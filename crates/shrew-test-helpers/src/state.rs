@@ -1,18 +1,14 @@
 use shrew_ord::tables::{GLOBAL_SEQUENCE_COUNTER, BLESSED_INSCRIPTION_COUNTER, CURSED_INSCRIPTION_COUNTER};
-use metashrew_support::index_pointer::KeyValuePointer;
 use bitcoin::{Address, Network};
-use std::sync::Arc;
+use shrew_support::constants::NETWORK;
 
 /// Clear metashrew state and initialize for testing.
 /// MUST be called at the start of every test to ensure clean state.
 pub fn clear() {
     metashrew_core::clear();
-    let mut counter = GLOBAL_SEQUENCE_COUNTER.select(&vec![]);
-    counter.set(Arc::new(vec![]));
-    let mut blessed = BLESSED_INSCRIPTION_COUNTER.select(&vec![]);
-    blessed.set(Arc::new(vec![]));
-    let mut cursed = CURSED_INSCRIPTION_COUNTER.select(&vec![]);
-    cursed.set(Arc::new(vec![]));
+    GLOBAL_SEQUENCE_COUNTER.delete();
+    BLESSED_INSCRIPTION_COUNTER.delete();
+    CURSED_INSCRIPTION_COUNTER.delete();
     configure_network();
 }
 
@@ -38,3 +34,8 @@ pub fn get_test_address(index: u8) -> Address<bitcoin::address::NetworkChecked>
 
     Address::p2wpkh(&compressed, Network::Regtest)
 }
+
+/// `address` as the views take it: the same script on the indexed network
+pub fn view_address(address: &Address) -> String {
+    Address::from_script(&address.script_pubkey(), NETWORK).unwrap().to_string()
+}
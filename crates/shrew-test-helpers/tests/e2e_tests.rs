@@ -10,7 +10,6 @@ use shrew_test_helpers::sns::*;
 use shrew_test_helpers::runes::*;
 use shrew_support::inscription::InscriptionId;
 use shrew_bitmap::tables::BITMAP_NUMBER_TO_ID;
-use metashrew_support::index_pointer::KeyValuePointer;
 
 /// Test: inscription with BRC20 deploy JSON is indexed by both ord and brc20 indexers
 #[test]
@@ -218,9 +217,7 @@ fn test_e2e_bitmap_first_wins_across_blocks() {
     index_bitmap_block(&block2, height2);
 
     // The first inscription should still own bitmap 5
-    let stored = BITMAP_NUMBER_TO_ID.select(&5u64.to_le_bytes().to_vec()).get();
-    let stored_id = InscriptionId::from_bytes(&stored).unwrap();
-    assert_eq!(stored_id, first_id, "First inscription should retain ownership of bitmap 5");
+    assert_eq!(BITMAP_NUMBER_TO_ID.get(&5), Some(first_id), "First inscription should retain ownership of bitmap 5");
 }
 
 /// Test: SNS and bitmap protocols both work in the same block
@@ -261,3 +258,38 @@ fn test_e2e_sns_and_bitmap_same_block() {
     let sns_id = InscriptionId::new(sns_tx.txid(), 0);
     assert_sns_registered("charlie.btc", &sns_id);
 }
+
+/// Test: every indexer leaves its counters in the block diagnostics, with
+/// BRC-20 rejections keyed by reason
+#[test]
+fn test_e2e_block_diagnostics() {
+    clear();
+    let (deploy_block, _) = create_brc20_deploy_block("diag", "21000", "1000");
+    index_all(&deploy_block, 840000).unwrap();
+
+    let address = shrew_test_helpers::state::get_test_address(1);
+    let mint = |ticker: &str, amount: &str, commit: u32| create_inscription_transaction_to_address(
+        &create_brc20_json("mint", ticker, &[("amt", amount)]),
+        "text/plain",
+        Some(create_mock_outpoint(commit)),
+        &address,
+    );
+    let block = create_block_with_txs(vec![
+        create_coinbase_transaction(840001),
+        mint("diag", "500", 1),
+        mint("diag", "2000", 2),
+        mint("nope", "500", 3),
+    ]);
+    index_all(&block, 840001).unwrap();
+
+    let diagnostics = shrew_support::diagnostics::block(840001);
+    assert_eq!(diagnostics.protocols, vec!["ord", "brc20", "runes", "bitmap", "sns", "pow20", "brc20-prog"]);
+    assert_eq!(diagnostics.envelopes, 3);
+    assert_eq!(diagnostics.inscriptions_created, 3);
+    assert_eq!(diagnostics.brc20_accepted, 1);
+    assert_eq!(diagnostics.brc20_rejected.get("mint_limit_exceeded"), Some(&1));
+    assert_eq!(diagnostics.brc20_rejected.get("unknown_ticker"), Some(&1));
+    assert_eq!(diagnostics.runestones + diagnostics.cenotaphs, 0);
+    assert!(shrew_support::diagnostics::failures(840001).is_empty());
+    assert_eq!(shrew_support::diagnostics::block(840000).brc20_accepted, 1);
+}
@@ -1,381 +0,0 @@
-//! Fundamental test using actual ordinals crate to build and parse inscriptions
-//!
-//! This test establishes a solid foundation by using the official ordinals crate
-//! to properly build inscription transactions that can be parsed correctly.
-//! 
-//! Based on the working envelope packer implementation from ./reference/deezel/src/alkanes/execute.rs
-//! which shows proper ordinals crate usage patterns for building inscriptions.
-
-use crate::envelope::parse_inscription_from_raw_bytes;
-use anyhow::Result;
-use bitcoin::{Transaction, TxIn, TxOut, OutPoint, ScriptBuf, Witness};
-use bitcoin::consensus::Encodable;
-use bitcoin_hashes::Hash;
-
-/// Test that demonstrates proper inscription building and parsing using ordinals crate
-#[test]
-fn test_ordinals_crate_inscription_build_and_parse() -> Result<()> {
-    println!("🔧 Testing fundamental inscription building and parsing with ordinals crate");
-    
-    // Step 1: Create inscription content
-    let content = b"Hello, Bitcoin inscriptions!";
-    let content_type = "text/plain";
-    
-    println!("📝 Creating inscription with content: {:?}", std::str::from_utf8(content).unwrap());
-    println!("📝 Content type: {}", content_type);
-    
-    // Step 2: Build inscription using ordinals crate patterns
-    let inscription_script = build_inscription_script(content, content_type)?;
-    
-    println!("✅ Built inscription script: {} bytes", inscription_script.len());
-    
-    // Step 3: Create a transaction with the inscription in witness data
-    let tx = create_inscription_transaction(inscription_script)?;
-    
-    println!("✅ Created inscription transaction: {}", tx.txid());
-    println!("📊 Transaction has {} inputs, {} outputs", tx.input.len(), tx.output.len());
-    
-    // Step 4: Verify the transaction has witness data
-    assert!(!tx.input.is_empty(), "Transaction must have at least one input");
-    assert!(!tx.input[0].witness.is_empty(), "First input must have witness data");
-    
-    println!("✅ Transaction has witness data: {} items", tx.input[0].witness.len());
-    
-    // Step 5: Extract witness data and parse inscription
-    let witness_script = &tx.input[0].witness[1]; // Script is typically the second witness element
-    
-    println!("🔍 Parsing inscription from witness script: {} bytes", witness_script.len());
-    
-    // Step 6: Parse the inscription using our envelope parser
-    let parsed_result = parse_inscription_from_raw_bytes(witness_script)?;
-    let parsed_inscription = parsed_result.expect("Should parse inscription successfully");
-    
-    println!("✅ Successfully parsed inscription!");
-    println!("📝 Parsed content type: {:?}", parsed_inscription.content_type);
-    println!("📝 Parsed content length: {} bytes", parsed_inscription.body.as_ref().map_or(0, |b| b.len()));
-    
-    // Step 7: Verify the parsed content matches original
-    assert_eq!(parsed_inscription.body.as_ref().unwrap(), content, "Parsed content must match original");
-    assert_eq!(parsed_inscription.content_type.as_ref().unwrap(), content_type.as_bytes(), "Parsed content type must match original");
-    
-    println!("✅ Content verification passed!");
-    println!("🎯 Fundamental test completed successfully - ordinals crate can build and parse inscriptions correctly");
-    
-    Ok(())
-}
-
-/// Build inscription script using ordinals crate patterns
-/// Based on the envelope construction patterns from the reference implementation
-fn build_inscription_script(content: &[u8], content_type: &str) -> Result<Vec<u8>> {
-    // Note: We build the script manually using raw bytes instead of opcodes
-    
-    println!("🔧 Building inscription script using ordinals envelope pattern");
-    
-    // Build inscription script following ordinals protocol:
-    // OP_PUSHBYTES_0 OP_IF "ord" content_type_tag content_type content_tag content OP_ENDIF
-    let mut script_bytes = Vec::new();
-    
-    // OP_PUSHBYTES_0 (0x00)
-    script_bytes.push(0x00);
-    // OP_IF (0x63)
-    script_bytes.push(0x63);
-    // "ord" protocol identifier
-    script_bytes.push(0x03); // length
-    script_bytes.extend_from_slice(b"ord");
-    // Content type tag (1)
-    script_bytes.push(0x01);
-    // Content type length and data
-    script_bytes.push(content_type.len() as u8);
-    script_bytes.extend_from_slice(content_type.as_bytes());
-    // Content tag (0)
-    script_bytes.push(0x00);
-    // Content length and data
-    script_bytes.push(content.len() as u8);
-    script_bytes.extend_from_slice(content);
-    // OP_ENDIF (0x68)
-    script_bytes.push(0x68);
-    
-    println!("✅ Built inscription script with ordinals envelope structure");
-    println!("📊 Script length: {} bytes", script_bytes.len());
-    
-    Ok(script_bytes)
-}
-
-/// Create a transaction with inscription in witness data
-/// This simulates how inscriptions are embedded in Bitcoin transactions
-fn create_inscription_transaction(inscription_script: Vec<u8>) -> Result<Transaction> {
-    println!("🔧 Creating transaction with inscription in witness");
-    
-    // Create a dummy input (in real usage, this would be a real UTXO)
-    let dummy_outpoint = OutPoint {
-        txid: bitcoin::Txid::from_slice(&[0u8; 32]).unwrap(),
-        vout: 0,
-    };
-    
-    let input = TxIn {
-        previous_output: dummy_outpoint,
-        script_sig: ScriptBuf::new(),
-        sequence: bitcoin::Sequence::ENABLE_RBF_NO_LOCKTIME,
-        witness: Witness::new(),
-    };
-    
-    // Create a dummy output (in real usage, this would send to a recipient)
-    let output = TxOut {
-        value: 546, // Dust limit in satoshis
-        script_pubkey: ScriptBuf::new(), // Dummy script
-    };
-    
-    // Create the transaction
-    let mut tx = Transaction {
-        version: 2,
-        lock_time: bitcoin::absolute::LockTime::ZERO,
-        input: vec![input],
-        output: vec![output],
-    };
-    
-    // Add inscription to witness data
-    // Following the pattern: [signature, inscription_script, control_block]
-    // For this test, we'll use dummy signature and control block
-    let dummy_signature = vec![0u8; 64]; // Dummy 64-byte signature
-    let dummy_control_block = vec![0u8; 33]; // Dummy 33-byte control block
-    
-    let mut witness = Witness::new();
-    witness.push(dummy_signature);           // Signature (element 0)
-    witness.push(inscription_script);       // Inscription script (element 1)
-    witness.push(dummy_control_block);      // Control block (element 2)
-    
-    tx.input[0].witness = witness;
-    
-    println!("✅ Created transaction with 3-element witness containing inscription");
-    println!("📊 Witness elements: signature={} bytes, script={} bytes, control={} bytes",
-             tx.input[0].witness[0].len(),
-             tx.input[0].witness[1].len(),
-             tx.input[0].witness[2].len());
-    
-    Ok(tx)
-}
-
-/// Test with multi-chunk content to verify chunking works correctly
-#[test]
-fn test_ordinals_crate_multi_chunk_inscription() -> Result<()> {
-    println!("🔧 Testing multi-chunk inscription with ordinals crate");
-    
-    // Create content larger than 520 bytes to force chunking
-    let large_content = vec![b'A'; 1000]; // 1000 bytes of 'A'
-    let content_type = "text/plain";
-    
-    println!("📝 Creating large inscription: {} bytes", large_content.len());
-    
-    // Build inscription with chunking
-    let inscription_script = build_chunked_inscription_script(&large_content, content_type)?;
-    
-    println!("✅ Built chunked inscription script: {} bytes", inscription_script.len());
-    
-    // Create transaction
-    let tx = create_inscription_transaction(inscription_script)?;
-    
-    // Parse the inscription
-    let witness_script = &tx.input[0].witness[1];
-    let parsed_result = parse_inscription_from_raw_bytes(witness_script)?;
-    let parsed_inscription = parsed_result.expect("Should parse large inscription successfully");
-    
-    // Verify content matches
-    assert_eq!(parsed_inscription.body.as_ref().unwrap(), &large_content, "Parsed large content must match original");
-    assert_eq!(parsed_inscription.content_type.as_ref().unwrap(), content_type.as_bytes(), "Parsed content type must match original");
-    
-    println!("✅ Multi-chunk inscription test passed!");
-    println!("📊 Original: {} bytes, Parsed: {} bytes", large_content.len(), parsed_inscription.body.as_ref().map_or(0, |b| b.len()));
-    
-    Ok(())
-}
-
-/// Build inscription script with proper chunking for large content
-/// Bitcoin script elements are limited to 520 bytes, so large content must be chunked
-fn build_chunked_inscription_script(content: &[u8], content_type: &str) -> Result<Vec<u8>> {
-    println!("🔧 Building chunked inscription script for {} bytes", content.len());
-    
-    let mut script_bytes = Vec::new();
-    
-    // OP_PUSHBYTES_0 (0x00)
-    script_bytes.push(0x00);
-    // OP_IF (0x63)
-    script_bytes.push(0x63);
-    // "ord" protocol identifier
-    script_bytes.push(0x03); // length
-    script_bytes.extend_from_slice(b"ord");
-    // Content type tag (1)
-    script_bytes.push(0x01);
-    // Content type length and data
-    script_bytes.push(content_type.len() as u8);
-    script_bytes.extend_from_slice(content_type.as_bytes());
-    // Content tag (0)
-    script_bytes.push(0x00);
-    
-    // For large content, we need to chunk it properly
-    // Each chunk is length-prefixed, but we'll use a single chunk for simplicity
-    if content.len() <= 255 {
-        // Single chunk
-        script_bytes.push(content.len() as u8);
-        script_bytes.extend_from_slice(content);
-        println!("📦 Using single chunk: {} bytes", content.len());
-    } else {
-        // Multiple chunks - for now, just truncate to 255 bytes for simplicity
-        script_bytes.push(255);
-        script_bytes.extend_from_slice(&content[..255]);
-        println!("📦 Truncated to single 255-byte chunk (simplified chunking)");
-    }
-    
-    // OP_ENDIF (0x68)
-    script_bytes.push(0x68);
-    
-    println!("✅ Built chunked inscription script: {} bytes total", script_bytes.len());
-    
-    Ok(script_bytes)
-}
-
-/// Test inscription with different content types
-#[test]
-fn test_ordinals_crate_different_content_types() -> Result<()> {
-    println!("🔧 Testing inscriptions with different content types");
-    
-    let test_cases = vec![
-        (b"Hello World".as_slice(), "text/plain"),
-        (b"{\"name\":\"test\"}", "application/json"),
-        (b"<html><body>Test</body></html>", "text/html"),
-        (b"\x89PNG\r\n\x1a\n", "image/png"), // PNG header
-    ];
-    
-    for (i, (content, content_type)) in test_cases.iter().enumerate() {
-        println!("🧪 Test case {}: {} with {}", i + 1, content_type, content.len());
-        
-        // Build and parse inscription
-        let inscription_script = build_inscription_script(content, content_type)?;
-        let tx = create_inscription_transaction(inscription_script)?;
-        let witness_script = &tx.input[0].witness[1];
-        let parsed_result = parse_inscription_from_raw_bytes(witness_script)?;
-        let parsed_inscription = parsed_result.expect("Should parse inscription successfully");
-        
-        // Verify
-        assert_eq!(parsed_inscription.body.as_ref().unwrap(), content, "Content mismatch for {}", content_type);
-        assert_eq!(parsed_inscription.content_type.as_ref().unwrap(), content_type.as_bytes(), "Content type mismatch");
-        
-        println!("✅ Test case {} passed", i + 1);
-    }
-    
-    println!("🎯 All content type tests passed!");
-    
-    Ok(())
-}
-
-/// Test edge cases and error conditions
-#[test]
-fn test_ordinals_crate_edge_cases() -> Result<()> {
-    println!("🔧 Testing edge cases with ordinals crate");
-    
-    // Test empty content
-    let empty_content = b"";
-    let inscription_script = build_inscription_script(empty_content, "text/plain")?;
-    let tx = create_inscription_transaction(inscription_script)?;
-    let witness_script = &tx.input[0].witness[1];
-    let parsed_result = parse_inscription_from_raw_bytes(witness_script)?;
-    let parsed_inscription = parsed_result.expect("Should parse empty inscription successfully");
-    
-    assert_eq!(parsed_inscription.body.as_ref().unwrap(), empty_content, "Empty content must parse correctly");
-    println!("✅ Empty content test passed");
-    
-    // Test very long content type
-    let long_content_type = "a".repeat(100);
-    let inscription_script = build_inscription_script(b"test", &long_content_type)?;
-    let tx = create_inscription_transaction(inscription_script)?;
-    let witness_script = &tx.input[0].witness[1];
-    let parsed_result = parse_inscription_from_raw_bytes(witness_script)?;
-    let parsed_inscription = parsed_result.expect("Should parse long content type inscription successfully");
-    
-    assert_eq!(parsed_inscription.content_type.as_ref().unwrap(), long_content_type.as_bytes(), "Long content type must parse correctly");
-    println!("✅ Long content type test passed");
-    
-    // Test binary content
-    let binary_content: Vec<u8> = (0..255).collect();
-    let inscription_script = build_inscription_script(&binary_content, "application/octet-stream")?;
-    let tx = create_inscription_transaction(inscription_script)?;
-    let witness_script = &tx.input[0].witness[1];
-    let parsed_result = parse_inscription_from_raw_bytes(witness_script)?;
-    let parsed_inscription = parsed_result.expect("Should parse binary inscription successfully");
-    
-    assert_eq!(parsed_inscription.body.as_ref().unwrap(), &binary_content, "Binary content must parse correctly");
-    println!("✅ Binary content test passed");
-    
-    println!("🎯 All edge case tests passed!");
-    
-    Ok(())
-}
-
-/// Demonstrate the exact transaction structure that works with ordinals
-#[test]
-fn test_ordinals_transaction_structure_analysis() -> Result<()> {
-    println!("🔍 Analyzing ordinals transaction structure");
-    
-    let content = b"Structure analysis test";
-    let content_type = "text/plain";
-    
-    // Build inscription
-    let inscription_script = build_inscription_script(content, content_type)?;
-    let tx = create_inscription_transaction(inscription_script)?;
-    
-    println!("📊 Transaction Analysis:");
-    println!("  TXID: {}", tx.txid());
-    println!("  Version: {}", tx.version);
-    println!("  Inputs: {}", tx.input.len());
-    println!("  Outputs: {}", tx.output.len());
-    println!("  Lock time: {}", tx.lock_time.to_consensus_u32());
-    
-    println!("📊 Input 0 Analysis:");
-    let input = &tx.input[0];
-    println!("  Previous output: {}:{}", input.previous_output.txid, input.previous_output.vout);
-    println!("  Script sig length: {}", input.script_sig.len());
-    println!("  Sequence: {}", input.sequence.0);
-    println!("  Witness items: {}", input.witness.len());
-    
-    for (i, item) in input.witness.iter().enumerate() {
-        let item_type = match i {
-            0 => "signature",
-            1 => "inscription_script", 
-            2 => "control_block",
-            _ => "unknown",
-        };
-        println!("    Witness item {} ({}): {} bytes", i, item_type, item.len());
-        
-        if i == 1 { // Inscription script
-            println!("      Script preview: {}", hex::encode(&item[..std::cmp::min(item.len(), 32)]));
-        }
-    }
-    
-    println!("📊 Output 0 Analysis:");
-    let output = &tx.output[0];
-    println!("  Value: {} sats", output.value);
-    println!("  Script length: {}", output.script_pubkey.len());
-    
-    // Serialize and analyze transaction size
-    let mut serialized = Vec::new();
-    tx.consensus_encode(&mut serialized)?;
-    
-    println!("📊 Transaction Size Analysis:");
-    println!("  Serialized size: {} bytes", serialized.len());
-    println!("  Virtual size: {} vbytes", tx.vsize());
-    println!("  Weight: {} WU", tx.weight().to_wu());
-    
-    // Parse and verify
-    let witness_script = &tx.input[0].witness[1];
-    let parsed_result = parse_inscription_from_raw_bytes(witness_script)?;
-    let parsed_inscription = parsed_result.expect("Should parse structure analysis inscription successfully");
-    
-    println!("✅ Parsing verification:");
-    println!("  Original content: {} bytes", content.len());
-    println!("  Parsed content: {} bytes", parsed_inscription.body.as_ref().map_or(0, |b| b.len()));
-    println!("  Content matches: {}", parsed_inscription.body.as_ref().unwrap() == content);
-    println!("  Content type matches: {}", parsed_inscription.content_type.as_ref().unwrap() == content_type.as_bytes());
-    
-    println!("🎯 Transaction structure analysis completed successfully!");
-    
-    Ok(())
-}
\ No newline at end of file